@@ -1,17 +1,23 @@
+pub use broadcast::BroadcastCmd;
 pub use debug::DebugCmd;
 pub use init::InitCmd;
+pub use policy::PolicyCmd;
 pub use query::QueryCmd;
-pub use threshold::ThresholdCmd;
-pub use tx::TxCmd;
+pub use sign::SignCmd;
+pub use threshold::{coordinator_client, ReshareParams, ThresholdCmd};
+pub use tx::{TransactionCmd, TxCmd};
 pub use validator::ValidatorCmd;
 pub use view::ViewCmd;
 
 use self::ceremony::CeremonyCmd;
 
+mod broadcast;
 mod ceremony;
 mod debug;
 mod init;
+mod policy;
 mod query;
+mod sign;
 mod threshold;
 mod tx;
 mod utils;
@@ -36,8 +42,8 @@ pub enum Command {
     /// Initialize `pcli` with a new wallet, or reset it.
     ///
     /// This command requires selecting a custody backend.  The `SoftKMS`
-    /// backend is a good default choice.  More backends (e.g., threshold
-    /// custody, hardware wallets) may be added in the future.
+    /// backend is a good default choice.  Hardware wallets and other signers
+    /// can be used through the `external` backend.
     #[clap(display_order = 100)]
     Init(InitCmd),
     /// Query the public chain state, like the validator set.
@@ -51,8 +57,17 @@ pub enum Command {
     #[clap(subcommand, display_order = 300, visible_alias = "v")]
     View(ViewCmd),
     /// Create and broadcast a transaction.
-    #[clap(subcommand, display_order = 400, visible_alias = "tx")]
-    Transaction(TxCmd),
+    #[clap(display_order = 400, visible_alias = "tx")]
+    Transaction(TransactionCmd),
+    /// Sign a transaction plan produced with `pcli tx --plan-only`.
+    ///
+    /// This command does not need network access, so it can be run on an
+    /// air-gapped machine holding the spend key.
+    #[clap(display_order = 410)]
+    Sign(SignCmd),
+    /// Build and submit a transaction from a plan and its authorization data.
+    #[clap(display_order = 420)]
+    Broadcast(BroadcastCmd),
     /// Manage a validator.
     #[clap(subcommand, display_order = 900)]
     Validator(ValidatorCmd),
@@ -65,6 +80,9 @@ pub enum Command {
     /// Follow the threshold signing protocol.
     #[clap(subcommand, display_order = 500)]
     Threshold(ThresholdCmd),
+    /// Inspect custody authorization policies.
+    #[clap(subcommand, display_order = 600)]
+    Policy(PolicyCmd),
 }
 
 impl Command {
@@ -73,12 +91,31 @@ impl Command {
         match self {
             Command::Init(_) => true,
             Command::Transaction(cmd) => cmd.offline(),
+            Command::Sign(_) => true,
+            Command::Broadcast(_) => false,
             Command::View(cmd) => cmd.offline(),
             Command::Validator(cmd) => cmd.offline(),
             Command::Query(cmd) => cmd.offline(),
             Command::Debug(cmd) => cmd.offline(),
             Command::Ceremony(_) => false,
             Command::Threshold(cmd) => cmd.offline(),
+            Command::Policy(cmd) => cmd.offline(),
+        }
+    }
+
+    /// Determine if this command can print its results with `--output json`.
+    pub fn supports_json(&self) -> bool {
+        match self {
+            Command::Transaction(cmd) => cmd.supports_json(),
+            Command::Sign(_) | Command::Broadcast(_) => true,
+            Command::View(cmd) => cmd.supports_json(),
+            Command::Query(cmd) => cmd.supports_json(),
+            Command::Init(_)
+            | Command::Validator(_)
+            | Command::Debug(_)
+            | Command::Ceremony(_)
+            | Command::Threshold(_)
+            | Command::Policy(_) => false,
         }
     }
 }
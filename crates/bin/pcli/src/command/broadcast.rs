@@ -0,0 +1,62 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use penumbra_transaction::{AuthorizationData, TransactionPlan};
+
+use crate::{
+    output::{print_json, OutputFormat, TransactionResult},
+    App,
+};
+
+#[derive(Debug, clap::Parser)]
+pub struct BroadcastCmd {
+    /// A JSON file containing the transaction plan, as written by `pcli tx --plan-only`.
+    #[clap(long)]
+    plan: Utf8PathBuf,
+    /// A JSON file containing the authorization data, as written by `pcli sign`.
+    #[clap(long, default_value = "authorization.json")]
+    auth: Utf8PathBuf,
+}
+
+impl BroadcastCmd {
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let plan: TransactionPlan = serde_json::from_str(
+            &fs::read_to_string(&self.plan)
+                .with_context(|| format!("failed to read transaction plan {}", self.plan))?,
+        )
+        .with_context(|| format!("failed to parse transaction plan {}", self.plan))?;
+        let auth_data: AuthorizationData = serde_json::from_str(
+            &fs::read_to_string(&self.auth)
+                .with_context(|| format!("failed to read authorization data {}", self.auth))?,
+        )
+        .with_context(|| format!("failed to parse authorization data {}", self.auth))?;
+
+        let effect_hash = plan.effect_hash(&app.config.full_viewing_key)?;
+        anyhow::ensure!(
+            auth_data.effect_hash == Some(effect_hash),
+            "authorization data in {} was not produced for the plan in {}",
+            self.auth,
+            self.plan,
+        );
+
+        let view = app.display_plan(&plan).await?;
+
+        let tx = penumbra_wallet::build_authorized_transaction(
+            &app.config.full_viewing_key,
+            app.view
+                .as_mut()
+                .context("view service must be initialized")?,
+            plan,
+            auth_data,
+        )
+        .await?;
+        let tx_id = app.submit_transaction(tx).await?;
+        match app.output {
+            OutputFormat::Text => println!("posted with transaction id: {tx_id}"),
+            OutputFormat::Json => print_json(&TransactionResult::submitted(tx_id, view))?,
+        }
+
+        Ok(())
+    }
+}
@@ -1,15 +1,18 @@
 use std::{
     io::{IsTerminal as _, Read},
+    path::PathBuf,
     str::FromStr,
 };
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use camino::Utf8PathBuf;
-use penumbra_custody::threshold;
-use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+use penumbra_custody::{external, threshold};
+use penumbra_keys::keys::{Bip44Path, SeedPhrase, SeedPhraseShare, SpendKey};
+use penumbra_proto::custody::v1::custody_service_server::CustodyService as _;
 use rand_core::OsRng;
 use url::Url;
 
+use super::{coordinator_client, ReshareParams};
 use crate::{
     config::{CustodyConfig, GovernanceCustodyConfig, PcliConfig},
     terminal::ActualTerminal,
@@ -65,6 +68,9 @@ pub enum InitSubCmd {
     /// Initialize using a manual threshold signing backend.
     #[clap(subcommand, display_order = 150)]
     Threshold(ThresholdInitCmd),
+    /// Initialize using a separate signer process, such as a hardware wallet integration.
+    #[clap(display_order = 175)]
+    External(ExternalInitCmd),
     // This is not accessible directly by the user, because it's impermissible to initialize the
     // governance subkey as view-only.
     #[clap(skip, display_order = 200)]
@@ -74,11 +80,43 @@ pub enum InitSubCmd {
     ReEncrypt,
 }
 
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExternalInitCmd {
+    /// The path of a Unix socket the signer is listening on.
+    #[clap(long, required_unless_present = "command", conflicts_with = "command")]
+    socket: Option<PathBuf>,
+    /// A command to run for each request, which exchanges messages over stdio.
+    #[clap(long)]
+    command: Option<PathBuf>,
+    /// Arguments to pass to the command, after `--`.
+    #[clap(last = true, requires = "command")]
+    args: Vec<String>,
+}
+
+impl ExternalInitCmd {
+    fn config(&self) -> external::Config {
+        match (&self.socket, &self.command) {
+            (Some(path), _) => external::Config::Socket { path: path.clone() },
+            (None, Some(program)) => external::Config::Command {
+                program: program.clone(),
+                args: self.args.clone(),
+            },
+            (None, None) => unreachable!("clap requires a socket or a command"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, clap::Subcommand)]
 pub enum SoftKmsInitCmd {
     /// Generate a new seed phrase and import its corresponding key.
     #[clap(display_order = 100)]
-    Generate,
+    Generate {
+        /// If set, print the seed phrase as shares, any K of which can recover it, instead.
+        ///
+        /// The shares are written as K/N, e.g. `2/3`, with at most 16 shares.
+        #[clap(long, value_name = "K/N")]
+        shares: Option<ShareSplit>,
+    },
     /// Import a spend key from an existing seed phrase.
     #[clap(display_order = 200)]
     ImportPhrase {
@@ -90,24 +128,67 @@ pub enum SoftKmsInitCmd {
         #[clap(long, action)]
         legacy_raw_bip39_derivation: bool,
     },
+    /// Import a spend key from shares of a seed phrase, as printed by `generate --shares`.
+    #[clap(display_order = 300)]
+    ImportShares {
+        /// If set, will use legacy BIP39 derivation.
+        ///
+        /// Use this ONLY if:
+        /// - you generated your wallet prior to Testnet 62.
+        /// - you need to replicate legacy derivation for some reason.
+        #[clap(long, action)]
+        legacy_raw_bip39_derivation: bool,
+    },
+}
+
+/// How many shares to split a seed phrase into, and how many of them are needed to recover it.
+#[derive(Debug, Clone, Copy)]
+pub struct ShareSplit {
+    threshold: u8,
+    count: u8,
+}
+
+impl FromStr for ShareSplit {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (threshold, count) = s
+            .split_once('/')
+            .ok_or_else(|| anyhow!("shares should be written as K/N, e.g. 2/3"))?;
+        Ok(Self {
+            threshold: threshold.trim().parse()?,
+            count: count.trim().parse()?,
+        })
+    }
 }
 
 impl SoftKmsInitCmd {
     fn spend_key(&self, init_type: InitType) -> Result<SpendKey> {
         Ok(match self {
-            SoftKmsInitCmd::Generate => {
+            SoftKmsInitCmd::Generate { shares } => {
                 let seed_phrase = SeedPhrase::generate(OsRng);
+                let governance = if let InitType::SpendKey = init_type {
+                    ""
+                } else {
+                    "GOVERNANCE "
+                };
 
                 // TODO: Something better should be done here, this is in danger of being
                 // shared by users accidentally in log output.
-                println!(
-                    "YOUR PRIVATE {}SEED PHRASE:\n\n  {seed_phrase}\n\nSave this in a safe place!\nDO NOT SHARE WITH ANYONE!\n",
-                    if let InitType::SpendKey = init_type {
-                        ""
-                    } else {
-                        "GOVERNANCE "
-                    },
-                );
+                match shares {
+                    None => println!(
+                        "YOUR PRIVATE {governance}SEED PHRASE:\n\n  {seed_phrase}\n\nSave this in a safe place!\nDO NOT SHARE WITH ANYONE!\n",
+                    ),
+                    Some(ShareSplit { threshold, count }) => {
+                        let shares = seed_phrase.split(*threshold, *count, OsRng)?;
+                        println!(
+                            "YOUR PRIVATE {governance}SEED PHRASE SHARES:\n\nAny {threshold} of these {count} shares can recover your seed phrase.\nSave each of them in a separate safe place!\nDO NOT SHARE ANY OF THEM WITH ANYONE!\n",
+                        );
+                        for share in shares {
+                            println!("SHARE {} OF {count}:\n\n  {share}\n", share.index + 1);
+                        }
+                    }
+                }
 
                 let path = Bip44Path::new(0);
                 SpendKey::from_seed_phrase_bip44(seed_phrase, &path)
@@ -133,6 +214,40 @@ impl SoftKmsInitCmd {
 
                 let seed_phrase = SeedPhrase::from_str(&seed_phrase)?;
 
+                if *legacy_raw_bip39_derivation {
+                    SpendKey::from_seed_phrase_bip39(seed_phrase, 0)
+                } else {
+                    let path = Bip44Path::new(0);
+                    SpendKey::from_seed_phrase_bip44(seed_phrase, &path)
+                }
+            }
+            SoftKmsInitCmd::ImportShares {
+                legacy_raw_bip39_derivation,
+            } => {
+                let mut shares = Vec::<SeedPhraseShare>::new();
+                // As above, we support non-interactive use, reading one share per line.
+                if std::io::stdin().is_terminal() {
+                    // The first share tells us how many we need.
+                    while shares
+                        .first()
+                        .map_or(true, |first| shares.len() < first.threshold as usize)
+                    {
+                        let share = rpassword::prompt_password(format!(
+                            "Enter seed phrase share {}: ",
+                            shares.len() + 1
+                        ))?;
+                        shares.push(SeedPhraseShare::from_str(&share)?);
+                    }
+                } else {
+                    let mut input = String::new();
+                    std::io::stdin().lock().read_to_string(&mut input)?;
+                    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+                        shares.push(SeedPhraseShare::from_str(line)?);
+                    }
+                }
+
+                let seed_phrase = SeedPhrase::from_shares(&shares)?;
+
                 if *legacy_raw_bip39_derivation {
                     SpendKey::from_seed_phrase_bip39(seed_phrase, 0)
                 } else {
@@ -170,7 +285,21 @@ pub enum ThresholdInitCmd {
         /// The maximum number of signers that can make a signature
         #[clap(short, long)]
         num_participants: u16,
+        /// Exchange messages through the coordinator at this URL, instead of
+        /// copying and pasting them.
+        #[clap(long, requires = "session")]
+        coordinator: Option<Url>,
+        /// The name of the DKG session to join on the coordinator.
+        ///
+        /// All participants must use the same session name, which should be
+        /// hard for anyone else to guess.
+        #[clap(long, requires = "coordinator")]
+        session: Option<String>,
     },
+    /// Generate a config file by receiving a share of an existing threshold key.
+    ///
+    /// The current participants need to run `pcli threshold reshare` at the same time.
+    Reshare(ReshareParams),
 }
 
 fn exec_deal(
@@ -220,6 +349,8 @@ fn exec_deal(
                 view_url: None,
                 disable_warning: false,
                 governance_custody: None,
+                threshold_coordinator: None,
+                threshold_audit_log: None,
             }
         } else {
             let mut pcli_config = PcliConfig::load(config_path.join(crate::CONFIG_FILE_NAME))?;
@@ -312,10 +443,39 @@ impl InitCmd {
                 InitSubCmd::Threshold(ThresholdInitCmd::Dkg {
                     threshold,
                     num_participants,
+                    coordinator,
+                    session,
                 }),
                 false,
             ) => {
-                let config = threshold::dkg(*threshold, *num_participants, &ActualTerminal).await?;
+                let config = match (coordinator, session) {
+                    (Some(coordinator), Some(session)) => {
+                        let terminal = threshold::NetworkTerminal::peer(
+                            ActualTerminal,
+                            coordinator_client(coordinator)?,
+                            session.clone(),
+                        );
+                        threshold::dkg(*threshold, *num_participants, &terminal).await?
+                    }
+                    _ => threshold::dkg(*threshold, *num_participants, &ActualTerminal).await?,
+                };
+                let fvk = config.fvk().clone();
+                let custody_config = if self.encrypted {
+                    let password = ActualTerminal.get_confirmed_password().await?;
+                    CustodyConfig::Encrypted(penumbra_custody::encrypted::Config::create(
+                        &password,
+                        penumbra_custody::encrypted::InnerConfig::Threshold(config),
+                    )?)
+                } else {
+                    CustodyConfig::Threshold(config)
+                };
+                (fvk, custody_config)
+            }
+            (_, InitSubCmd::Threshold(ThresholdInitCmd::Reshare(params)), false) => {
+                let config = params
+                    .run(None, true)
+                    .await?
+                    .expect("members always receive a config");
                 let fvk = config.fvk().clone();
                 let custody_config = if self.encrypted {
                     let password = ActualTerminal.get_confirmed_password().await?;
@@ -328,6 +488,18 @@ impl InitCmd {
                 };
                 (fvk, custody_config)
             }
+            (_, InitSubCmd::External(cmd), false) => {
+                let config = cmd.config();
+                // The signer holds the keys, so we ask it for the viewing key to sync with.
+                let full_viewing_key = external::External::new(config.clone())
+                    .export_full_viewing_key(tonic::Request::new(Default::default()))
+                    .await?
+                    .into_inner()
+                    .full_viewing_key
+                    .ok_or(anyhow!("external signer did not return a full viewing key"))?
+                    .try_into()?;
+                (full_viewing_key, CustodyConfig::External(config))
+            }
             (_, InitSubCmd::Threshold(ThresholdInitCmd::Deal { .. }), _) => {
                 unreachable!("this should already have been handled above")
             }
@@ -357,6 +529,8 @@ impl InitCmd {
                 let custody = match custody {
                     x @ CustodyConfig::ViewOnly => x,
                     x @ CustodyConfig::Encrypted(_) => x,
+                    // The keys are held by the signer, so there's nothing for us to encrypt.
+                    x @ CustodyConfig::External(_) => x,
                     CustodyConfig::SoftKms(spend_key) => {
                         let password = ActualTerminal.get_confirmed_password().await?;
                         CustodyConfig::Encrypted(penumbra_custody::encrypted::Config::create(
@@ -399,6 +573,8 @@ impl InitCmd {
                 view_url: None,
                 disable_warning: false,
                 governance_custody: None,
+                threshold_coordinator: None,
+                threshold_audit_log: None,
             }
         } else {
             let config_path = home_dir.join(crate::CONFIG_FILE_NAME);
@@ -410,6 +586,9 @@ impl InitCmd {
                     fvk: full_viewing_key,
                     config,
                 },
+                CustodyConfig::External(_) => {
+                    anyhow::bail!("external signers can't yet be used for governance keys")
+                }
                 _ => unreachable!("governance keys can't be initialized in view-only mode"),
             };
             config.governance_custody = Some(governance_custody);
@@ -0,0 +1,77 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use penumbra_custody::{policy::AuthPolicy, AuthorizeRequest};
+use serde::Deserialize;
+
+use crate::{config::CustodyConfig, App};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum PolicyCmd {
+    /// Check whether an authorization policy would allow a transaction plan.
+    ///
+    /// This is a dry run: stateful policies, like spend limits, are evaluated
+    /// against their current state but don't record the plan, and
+    /// pre-authorization policies are evaluated as if none were provided.
+    Check {
+        /// A JSON file containing the transaction plan to check.
+        #[clap(long)]
+        plan: Utf8PathBuf,
+        /// A TOML file containing `[[auth_policy]]` entries to check against.
+        ///
+        /// If unset, the policy of the configured software KMS is used.
+        #[clap(long)]
+        policy: Option<Utf8PathBuf>,
+    },
+}
+
+/// The policies in a standalone policy file, laid out as in the `SoftKms` config.
+#[derive(Debug, Deserialize)]
+struct PolicyFile {
+    #[serde(default)]
+    auth_policy: Vec<AuthPolicy>,
+}
+
+impl PolicyCmd {
+    pub fn offline(&self) -> bool {
+        true
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        match self {
+            PolicyCmd::Check { plan, policy } => {
+                let policies = match (policy, &app.config.custody) {
+                    (Some(path), _) => {
+                        let contents = fs::read_to_string(path)
+                            .with_context(|| format!("failed to read policy file {}", path))?;
+                        toml::from_str::<PolicyFile>(&contents)
+                            .with_context(|| format!("failed to parse policy file {}", path))?
+                            .auth_policy
+                    }
+                    (None, CustodyConfig::SoftKms(config)) => config.auth_policy.clone(),
+                    (None, _) => anyhow::bail!(
+                        "the configured custody backend has no policy, use --policy to provide one"
+                    ),
+                };
+
+                let plan = serde_json::from_str(
+                    &fs::read_to_string(plan)
+                        .with_context(|| format!("failed to read transaction plan {}", plan))?,
+                )
+                .with_context(|| format!("failed to parse transaction plan {}", plan))?;
+                let request = AuthorizeRequest {
+                    plan,
+                    pre_authorizations: Vec::new(),
+                };
+
+                AuthPolicy::All { policies }
+                    .dry_run(&request)
+                    .context("transaction plan is not allowed by the policy")?;
+                println!("Transaction plan is allowed by the policy.");
+
+                Ok(())
+            }
+        }
+    }
+}
@@ -25,18 +25,6 @@ pub(super) use validator::ValidatorCmd;
 
 use crate::App;
 
-#[derive(Clone, clap::ValueEnum, Debug)]
-pub enum OutputFormat {
-    Json,
-    Base64,
-}
-
-impl Default for OutputFormat {
-    fn default() -> Self {
-        Self::Json
-    }
-}
-
 #[derive(Debug, clap::Subcommand)]
 pub enum QueryCmd {
     /// Queries an arbitrary key.
@@ -243,6 +231,23 @@ impl QueryCmd {
         }
     }
 
+    /// Determine if this command can print its results with `--output json`.
+    pub fn supports_json(&self) -> bool {
+        match self {
+            // Transactions are always printed as JSON.
+            QueryCmd::Tx(_) | QueryCmd::Validator(_) => true,
+            QueryCmd::Dex(dex_cmd) => dex_cmd.supports_json(),
+            QueryCmd::Key { .. }
+            | QueryCmd::ShieldedPool(_)
+            | QueryCmd::Chain(_)
+            | QueryCmd::Governance(_)
+            | QueryCmd::CommunityPool(_)
+            | QueryCmd::Ibc(_)
+            | QueryCmd::Watch { .. }
+            | QueryCmd::Auction(_) => false,
+        }
+    }
+
     fn display_value(&self, bytes: &[u8]) -> Result<()> {
         match self {
             QueryCmd::Key { .. } => {
@@ -8,12 +8,14 @@ use penumbra_dex::{
     lp::position::{self, Position},
     BatchSwapOutputData, DirectedTradingPair, SwapExecution, TradingPair,
 };
+use penumbra_num::Amount;
 use penumbra_proto::core::component::{
     dex::v1::{
         query_service_client::QueryServiceClient as DexQueryServiceClient,
-        simulation_service_client::SimulationServiceClient, ArbExecutionRequest,
+        simulation_service_client::SimulationServiceClient, ArbExecutionsRequest,
         BatchSwapOutputDataRequest, LiquidityPositionByIdRequest, LiquidityPositionsByPriceRequest,
-        LiquidityPositionsRequest, SimulateTradeRequest, SwapExecutionRequest,
+        LiquidityPositionsRequest, MarketDepthRequest, RouteExplanation, SimulateTradeRequest,
+        SwapExecutionRequest,
     },
     shielded_pool::v1::{
         query_service_client::QueryServiceClient as ShieldedPoolQueryServiceClient,
@@ -25,6 +27,7 @@ use tonic::transport::Channel;
 
 use crate::{
     command::utils::{self, render_positions},
+    output::{print_json, OutputFormat},
     App,
 };
 
@@ -50,12 +53,15 @@ pub enum DexCmd {
         #[clap(value_name = "asset_1:asset_2")]
         trading_pair: DirectedTradingPair,
     },
-    /// Display information about an arb execution at a specific height.
+    /// Display information about the arb executions at a specific height.
     #[clap(visible_alias = "arb")]
     ArbExecution {
-        /// The height to query for the swap execution.
+        /// The height to query for the arb executions.
         #[clap(long)]
         height: u64,
+        /// Only display the arb execution against this arb token, e.g. "penumbra".
+        #[clap(long)]
+        arb_token: Option<String>,
     },
     /// Display information about all liquidity positions known to the chain.
     #[clap(display_order(900))]
@@ -82,6 +88,20 @@ pub enum DexCmd {
         #[clap(long)]
         limit: Option<u64>,
     },
+    /// Display the liquidity available on a pair, aggregated by price level.
+    Depth {
+        /// The trading pair to query, with ordering determining direction of trade (1=>2).
+        /// Pairs must be specified with a colon separating them, e.g. "penumbra:test_usd".
+        #[clap(value_name = "asset_1:asset_2")]
+        trading_pair: DirectedTradingPair,
+        /// The width of the price levels, in units of asset 1 per unit of asset 2.
+        /// If unset, each distinct position price is its own level.
+        #[clap(long)]
+        tick_size: Option<f64>,
+        /// A limit on the number of price levels to display.
+        #[clap(long)]
+        limit: Option<u32>,
+    },
     /// Simulates execution of a trade against the current DEX state.
     Simulate {
         /// The input amount to swap, written as a typed value 1.87penumbra, 12cubes, etc.
@@ -89,10 +109,19 @@ pub enum DexCmd {
         /// The denomination to swap the input into, e.g. `gm`
         #[clap(long, display_order = 100)]
         into: String,
+        /// Also display the paths explored by the router, the fills along each
+        /// route, and the circuit-breaker counters.
+        #[clap(long, display_order = 200)]
+        explain: bool,
     },
 }
 
 impl DexCmd {
+    /// Determine if this command can print its results with `--output json`.
+    pub fn supports_json(&self) -> bool {
+        matches!(self, DexCmd::Depth { .. })
+    }
+
     pub async fn get_batch_outputs(
         &self,
         app: &mut App,
@@ -133,19 +162,32 @@ impl DexCmd {
             .context("cannot parse batch swap output data")
     }
 
-    pub async fn get_arb_execution(&self, app: &mut App, height: &u64) -> Result<SwapExecution> {
+    /// Gets the arb executions at `height`, one per arb token that surfaced a surplus.
+    pub async fn get_arb_executions(
+        &self,
+        app: &mut App,
+        height: u64,
+        arb_token: Option<asset::Id>,
+    ) -> Result<Vec<SwapExecution>> {
         let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
         client
-            .arb_execution(ArbExecutionRequest {
-                height: *height,
-                ..Default::default()
+            .arb_executions(ArbExecutionsRequest {
+                start_height: height,
+                end_height: height,
+                arb_token: arb_token.map(Into::into),
             })
             .await?
             .into_inner()
-            .swap_execution
-            .ok_or_else(|| anyhow::anyhow!("proto response missing arb execution"))?
-            .try_into()
-            .context("cannot parse batch swap output data")
+            .map_err(anyhow::Error::from)
+            .and_then(|response| async move {
+                response
+                    .swap_execution
+                    .ok_or_else(|| anyhow::anyhow!("proto response missing arb execution"))?
+                    .try_into()
+                    .context("cannot parse arb execution")
+            })
+            .try_collect()
+            .await
     }
 
     pub async fn get_simulated_execution(
@@ -153,25 +195,29 @@ impl DexCmd {
         app: &mut App,
         input: Value,
         output: asset::Id,
-    ) -> Result<SwapExecution> {
+        explain: bool,
+    ) -> Result<(SwapExecution, Option<RouteExplanation>)> {
         use penumbra_proto::core::component::dex::v1::simulate_trade_request::{
             routing::Setting, Routing,
         };
         let mut client = SimulationServiceClient::new(app.pd_channel().await?);
-        client
+        let response = client
             .simulate_trade(SimulateTradeRequest {
                 input: Some(input.into()),
                 output: Some(output.into()),
                 routing: Some(Routing {
                     setting: Some(Setting::Default(Default::default())),
                 }),
+                explain,
             })
             .await?
-            .into_inner()
+            .into_inner();
+        let swap_execution = response
             .output
             .ok_or_else(|| anyhow::anyhow!("proto response missing swap execution"))?
             .try_into()
-            .context("cannot parse simulation response")
+            .context("cannot parse simulation response")?;
+        Ok((swap_execution, response.explanation))
     }
 
     pub async fn get_all_liquidity_positions(
@@ -215,6 +261,71 @@ impl DexCmd {
             .boxed())
     }
 
+    pub async fn print_market_depth(
+        &self,
+        app: &mut App,
+        trading_pair: &DirectedTradingPair,
+        tick_size: Option<f64>,
+        limit: Option<u32>,
+    ) -> Result<()> {
+        let cache = app.view().assets().await?;
+        let unit_amount = |asset_id: &asset::Id| -> Result<f64> {
+            let metadata = cache
+                .get(asset_id)
+                .with_context(|| format!("unknown asset {asset_id}"))?;
+            Ok(metadata.default_unit().unit_amount().value() as f64)
+        };
+        // Prices are quoted by the chain in base units, convert them from and
+        // to display units.
+        let display_ratio = unit_amount(&trading_pair.end)? / unit_amount(&trading_pair.start)?;
+
+        let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
+        let depth = client
+            .market_depth(MarketDepthRequest {
+                trading_pair: Some((*trading_pair).into()),
+                tick_size: tick_size
+                    .map(|tick| tick / display_ratio)
+                    .unwrap_or_default(),
+                depth_limit: limit.unwrap_or_default(),
+            })
+            .await?
+            .into_inner();
+
+        if app.output == OutputFormat::Json {
+            return print_json(&depth);
+        }
+
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec!["Price", "Liquidity", "Cumulative", "Positions"]);
+        let format_liquidity = |amount: Amount| {
+            Value {
+                asset_id: trading_pair.end,
+                amount,
+            }
+            .format(&cache)
+        };
+        for level in depth.levels {
+            let liquidity: Amount = level
+                .liquidity
+                .context("missing liquidity in market depth level")?
+                .try_into()?;
+            let cumulative_liquidity: Amount = level
+                .cumulative_liquidity
+                .context("missing cumulative liquidity in market depth level")?
+                .try_into()?;
+            table.add_row(vec![
+                format!("{:.6}", level.price * display_ratio),
+                format_liquidity(liquidity),
+                format_liquidity(cumulative_liquidity),
+                level.position_count.to_string(),
+            ]);
+        }
+        println!("{}", table);
+
+        Ok(())
+    }
+
     pub async fn print_swap_execution(
         &self,
         app: &mut App,
@@ -298,6 +409,131 @@ impl DexCmd {
         Ok(())
     }
 
+    pub async fn print_route_explanation(
+        &self,
+        app: &mut App,
+        explanation: &RouteExplanation,
+    ) -> Result<()> {
+        let cache = app.view().assets().await?;
+        let parse_route = |route: &[penumbra_proto::core::asset::v1::AssetId]| {
+            route
+                .iter()
+                .cloned()
+                .map(asset::Id::try_from)
+                .collect::<Result<Vec<_>>>()
+        };
+        let format_route = |route: &[asset::Id]| {
+            route
+                .iter()
+                .map(|id| match cache.get(id) {
+                    Some(metadata) => metadata.default_unit().to_string(),
+                    None => id.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(" => ")
+        };
+        // The chain quotes prices in base units of the start asset per base
+        // unit of the end asset, convert them to display units.
+        let format_price = |price: f64, start: &asset::Id, end: &asset::Id| {
+            let unit_amount = |id: &asset::Id| {
+                cache
+                    .get(id)
+                    .map(|metadata| metadata.default_unit().unit_amount().value() as f64)
+                    .unwrap_or(1.0)
+            };
+            format!("{:.6}", price * unit_amount(end) / unit_amount(start))
+        };
+
+        for (i, split) in explanation.splits.iter().enumerate() {
+            let route = parse_route(&split.route)?;
+            match (route.first(), route.last()) {
+                (Some(start), Some(end)) => {
+                    let spill_price = if split.spill_price == 0.0 {
+                        "none".to_string()
+                    } else {
+                        format_price(split.spill_price, start, end)
+                    };
+                    println!(
+                        "\nSplit {}: {} (spill price: {})",
+                        i + 1,
+                        format_route(&route),
+                        spill_price
+                    );
+                }
+                _ => println!("\nSplit {}: no route found", i + 1),
+            }
+
+            if !split.candidates.is_empty() {
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec!["Candidate", "Estimated Price", ""]);
+                for candidate in &split.candidates {
+                    let candidate_route = parse_route(&candidate.route)?;
+                    let (Some(start), Some(end)) =
+                        (candidate_route.first(), candidate_route.last())
+                    else {
+                        continue;
+                    };
+                    let status = if candidate.spill {
+                        "spill"
+                    } else if candidate_route == route {
+                        "selected"
+                    } else {
+                        ""
+                    };
+                    table.add_row(vec![
+                        format_route(&candidate_route),
+                        format_price(candidate.estimated_price, start, end),
+                        status.to_string(),
+                    ]);
+                }
+                println!("{}", table);
+            }
+
+            if !split.fills.is_empty() {
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec!["Fill", "Positions", "Trace"]);
+                for (j, fill) in split.fills.iter().enumerate() {
+                    let position_ids = fill
+                        .position_ids
+                        .iter()
+                        .cloned()
+                        .map(|id| position::Id::try_from(id).map(|id| id.to_string()))
+                        .collect::<Result<Vec<_>>>()?;
+                    let trace = fill
+                        .trace
+                        .as_ref()
+                        .map(|trace| trace.value.as_slice())
+                        .unwrap_or_default()
+                        .iter()
+                        .cloned()
+                        .map(|value| Value::try_from(value).map(|value| value.format(&cache)))
+                        .collect::<Result<Vec<_>>>()?;
+                    table.add_row(vec![
+                        (j + 1).to_string(),
+                        position_ids.join("\n"),
+                        trace.join(" => "),
+                    ]);
+                }
+                println!("{}", table);
+            }
+        }
+
+        println!(
+            "\n{}/{} path searches, {}/{} executions",
+            explanation.path_searches,
+            explanation.max_path_searches,
+            explanation.executions,
+            explanation.max_executions
+        );
+        if !explanation.stop_reason.is_empty() {
+            println!("Routing stopped because {}.", explanation.stop_reason);
+        }
+
+        Ok(())
+    }
+
     pub async fn print_batch_outputs(
         &self,
         app: &mut App,
@@ -382,17 +618,45 @@ impl DexCmd {
 
                 self.print_swap_execution(app, &swap_execution).await?;
             }
-            DexCmd::ArbExecution { height } => {
-                let swap_execution = self.get_arb_execution(app, height).await?;
-
-                self.print_swap_execution(app, &swap_execution).await?;
+            DexCmd::ArbExecution { height, arb_token } => {
+                let arb_token = arb_token
+                    .as_ref()
+                    .map(|unit| asset::REGISTRY.parse_unit(unit).base().id());
+                let arb_executions = self.get_arb_executions(app, *height, arb_token).await?;
+
+                if arb_executions.is_empty() {
+                    println!("no arb executions at height {height}");
+                }
+                for arb_execution in &arb_executions {
+                    self.print_swap_execution(app, arb_execution).await?;
+                }
             }
-            DexCmd::Simulate { input, into } => {
+            DexCmd::Depth {
+                trading_pair,
+                tick_size,
+                limit,
+            } => {
+                self.print_market_depth(app, trading_pair, *tick_size, *limit)
+                    .await?;
+            }
+            DexCmd::Simulate {
+                input,
+                into,
+                explain,
+            } => {
                 let input = input.parse::<Value>()?;
                 let into = asset::REGISTRY.parse_unit(into.as_str()).base();
 
-                let swap_execution = self.get_simulated_execution(app, input, into.id()).await?;
+                let (swap_execution, explanation) = self
+                    .get_simulated_execution(app, input, into.id(), *explain)
+                    .await?;
                 self.print_swap_execution(app, &swap_execution).await?;
+                if *explain {
+                    let explanation = explanation.context(
+                        "the node did not explain the simulation, it may be running an older version",
+                    )?;
+                    self.print_route_explanation(app, &explanation).await?;
+                }
             }
             DexCmd::AllPositions { include_closed } => {
                 let client = DexQueryServiceClient::new(app.pd_channel().await?);
@@ -5,14 +5,14 @@ use penumbra_transaction::Transaction;
 
 use crate::App;
 
-use super::OutputFormat;
-
 /// Queries the chain for a transaction by hash.
+///
+/// The transaction is printed as JSON, regardless of `--output`.
 #[derive(Debug, clap::Args)]
 pub struct Tx {
-    /// The format to output the transaction in.
-    #[clap(short, long, value_enum, default_value_t)]
-    output: OutputFormat,
+    /// Print the transaction as base64-encoded bytes instead of JSON.
+    #[clap(long)]
+    base64: bool,
     /// The hex-formatted transaction hash to query.
     hash: String,
 }
@@ -31,15 +31,12 @@ impl Tx {
         let rsp = rsp.into_inner();
         let tx = Transaction::decode(rsp.tx.as_slice())?;
 
-        match self.output {
-            OutputFormat::Json => {
-                let tx_json = serde_json::to_string_pretty(&tx)?;
-                println!("{}", tx_json.to_colored_json_auto()?);
-            }
-            OutputFormat::Base64 => {
-                use base64::{display::Base64Display, engine::general_purpose::STANDARD};
-                println!("{}", Base64Display::new(&rsp.tx, &STANDARD));
-            }
+        if self.base64 {
+            use base64::{display::Base64Display, engine::general_purpose::STANDARD};
+            println!("{}", Base64Display::new(&rsp.tx, &STANDARD));
+        } else {
+            let tx_json = serde_json::to_string_pretty(&tx)?;
+            println!("{}", tx_json.to_colored_json_auto()?);
         }
 
         Ok(())
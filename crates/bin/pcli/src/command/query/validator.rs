@@ -30,7 +30,10 @@ use penumbra_stake::{
     IdentityKey, Uptime, BPS_SQUARED_SCALING_FACTOR,
 };
 
-use crate::App;
+use crate::{
+    output::{print_json, OutputFormat, UptimeDocument},
+    App,
+};
 
 // TODO: replace this with something more standard for the `query` subcommand
 #[derive(Debug, clap::Subcommand)]
@@ -102,6 +105,10 @@ impl ValidatorCmd {
                     bv.cmp(&av)
                 });
 
+                if app.output == OutputFormat::Json {
+                    return print_json(&validators);
+                }
+
                 let total_voting_power = validators
                     .iter()
                     .filter_map(|v| {
@@ -199,16 +206,20 @@ impl ValidatorCmd {
                     .await?
                     .into_inner();
 
-                // Coerce the validator information into TOML, or return an error if it was not
-                // found within the client's response.
-                let serialize = |v| toml::to_string_pretty(&v).map_err(Error::from);
-                let toml = validator_info
+                let validator = validator_info
                     .ok_or_else(|| anyhow!("response did not include validator info"))?
                     .try_into()
                     .context("parsing validator info")
-                    .map(|Info { validator, .. }| validator)
-                    .map(ValidatorToml::from)
-                    .and_then(serialize)?;
+                    .map(|Info { validator, .. }| validator)?;
+
+                // The definition file is always TOML, so that it can be edited and uploaded again.
+                if file.is_none() && app.output == OutputFormat::Json {
+                    return print_json(&validator);
+                }
+
+                // Coerce the validator information into TOML.
+                let serialize = |v| toml::to_string_pretty(&v).map_err(Error::from);
+                let toml = serialize(ValidatorToml::from(validator))?;
 
                 // Write to a file if an output file was specified, otherwise print to stdout.
                 if let Some(file) = file {
@@ -249,6 +260,14 @@ impl ValidatorCmd {
                 let state = status.state;
                 let active = matches!(state, validator::State::Active);
 
+                if app.output == OutputFormat::Json {
+                    return print_json(&UptimeDocument {
+                        identity_key,
+                        state: state.to_string(),
+                        uptime,
+                    });
+                }
+
                 // Get the chain parameters
                 let mut client = AppQueryServiceClient::new(app.pd_channel().await?);
                 let params: AppParameters = client
@@ -370,11 +389,15 @@ impl ValidatorCmd {
 
                 // Parse the validator status, or return an error if it was not found within the
                 // client's response.
-                let info = validator_info
+                let info: Info = validator_info
                     .ok_or_else(|| anyhow!("response did not include validator info"))?
                     .try_into()
                     .context("parsing validator info")?;
 
+                if app.output == OutputFormat::Json {
+                    return print_json(&info);
+                }
+
                 // Initialize a table, add a header and insert this validator's information.
                 let mut table = Table::new();
                 table
@@ -0,0 +1,74 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use dialoguer::Confirm;
+use penumbra_custody::{AuthorizeRequest, CustodyClient};
+use penumbra_transaction::{AuthorizationData, TransactionPlan};
+
+use crate::{
+    output::{print_json, TransactionResult},
+    App,
+};
+
+#[derive(Debug, clap::Parser)]
+pub struct SignCmd {
+    /// A JSON file containing the transaction plan to sign.
+    plan: Utf8PathBuf,
+    /// The file to write the authorization data to.
+    #[clap(long, default_value = "authorization.json")]
+    auth: Utf8PathBuf,
+    /// Sign the plan without asking for confirmation.
+    #[clap(long, short)]
+    yes: bool,
+}
+
+impl SignCmd {
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let plan: TransactionPlan = serde_json::from_str(
+            &fs::read_to_string(&self.plan)
+                .with_context(|| format!("failed to read transaction plan {}", self.plan))?,
+        )
+        .with_context(|| format!("failed to parse transaction plan {}", self.plan))?;
+
+        let view = app.display_plan(&plan).await?;
+
+        if !self.yes {
+            // In JSON mode the view is only printed on stdout once the plan is
+            // signed, so show it on stderr before asking.
+            if let Some(view) = &view {
+                eprintln!("{}", serde_json::to_string_pretty(view)?);
+            }
+            if !Confirm::new()
+                .with_prompt("Do you want to sign this transaction?")
+                .interact()?
+            {
+                return Ok(());
+            }
+        }
+
+        let auth_data: AuthorizationData = CustodyClient::authorize(
+            &mut app.custody,
+            AuthorizeRequest {
+                plan,
+                pre_authorizations: Vec::new(),
+            },
+        )
+        .await?
+        .data
+        .ok_or_else(|| anyhow::anyhow!("empty AuthorizeResponse message"))?
+        .try_into()?;
+
+        fs::write(&self.auth, serde_json::to_vec_pretty(&auth_data)?)
+            .with_context(|| format!("could not write authorization data to {}", self.auth))?;
+        match view {
+            None => println!("wrote authorization data to {}", self.auth),
+            Some(view) => print_json(&TransactionResult::Signed {
+                authorization: self.auth.clone(),
+                view: Box::new(view),
+            })?,
+        }
+
+        Ok(())
+    }
+}
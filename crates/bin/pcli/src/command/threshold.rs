@@ -1,8 +1,18 @@
-use anyhow::Result;
-use penumbra_custody::threshold::Terminal;
+use std::{collections::HashSet, net::SocketAddr};
+
+use anyhow::{anyhow, Result};
+use camino::Utf8Path;
+use ed25519_consensus::VerificationKey;
+use penumbra_custody::threshold::{self, Coordinator, NetworkTerminal, Terminal};
+use penumbra_proto::custody::threshold::v1::{
+    coordinator_service_client::CoordinatorServiceClient,
+    coordinator_service_server::CoordinatorServiceServer,
+};
+use tonic::transport::{Channel, ClientTlsConfig};
+use url::Url;
 
 use crate::{
-    config::{CustodyConfig, GovernanceCustodyConfig},
+    config::{CustodyConfig, GovernanceCustodyConfig, PcliConfig},
     terminal::ActualTerminal,
     App,
 };
@@ -10,13 +20,30 @@ use crate::{
 #[derive(Debug, clap::Subcommand)]
 pub enum ThresholdCmd {
     /// Contribute to signing a transaction with threshold custody
-    Sign,
+    Sign {
+        /// Exchange messages through the coordinator at this URL, instead of
+        /// copying and pasting them.
+        #[clap(long, requires = "session")]
+        coordinator: Option<Url>,
+        /// The name of the signing session to join on the coordinator.
+        #[clap(long, requires = "coordinator")]
+        session: Option<String>,
+    },
+    /// Run a coordinator relaying threshold signing and DKG messages between participants
+    Coordinate(CoordinateCmd),
+    /// Redistribute the shares of this wallet's threshold key to a new set of participants
+    ///
+    /// The wallet keeps the same key and full viewing key, but the threshold, the number
+    /// of participants, and the participants themselves can change.
+    Reshare(ReshareCmd),
 }
 
 impl ThresholdCmd {
     pub fn offline(&self) -> bool {
         match self {
-            ThresholdCmd::Sign => true,
+            ThresholdCmd::Sign { .. } => true,
+            ThresholdCmd::Coordinate(_) => true,
+            ThresholdCmd::Reshare(_) => true,
         }
     }
 
@@ -38,10 +65,103 @@ impl ThresholdCmd {
             _ => None,              // If not threshold, we can't sign using governance config
         };
         match self {
-            ThresholdCmd::Sign => {
+            ThresholdCmd::Sign {
+                coordinator: Some(coordinator),
+                session: Some(session),
+            } => {
+                // We identify ourselves to the coordinator with the key of our spend config,
+                // unless we only have a governance config.
+                let identity = config
+                    .as_ref()
+                    .or(governance_config.as_ref())
+                    .ok_or(anyhow!(
+                        "cannot threshold sign using a non-threshold custody backend"
+                    ))?;
+                let participants = config
+                    .iter()
+                    .chain(governance_config.iter())
+                    .flat_map(|config| config.verification_keys())
+                    .collect::<HashSet<_>>();
+                let terminal = NetworkTerminal::follower(
+                    ActualTerminal,
+                    coordinator_client(coordinator)?,
+                    session.clone(),
+                    *identity.signing_key(),
+                    participants,
+                );
                 penumbra_custody::threshold::follow(
                     config.as_ref(),
                     governance_config.as_ref(),
+                    &terminal,
+                )
+                .await
+            }
+            ThresholdCmd::Sign { .. } => {
+                penumbra_custody::threshold::follow(
+                    config.as_ref(),
+                    governance_config.as_ref(),
+                    &ActualTerminal,
+                )
+                .await
+            }
+            ThresholdCmd::Coordinate(_) => unreachable!("coordinate command already executed"),
+            ThresholdCmd::Reshare(_) => unreachable!("reshare command already executed"),
+        }
+    }
+}
+
+/// The parameters of a resharing, which every participant needs to agree on.
+#[derive(Debug, Clone, clap::Args)]
+pub struct ReshareParams {
+    /// The minimum number of new signers required to make a signature (>= 2).
+    #[clap(short, long)]
+    threshold: u16,
+    /// The number of participants who will hold a share after resharing.
+    #[clap(short, long)]
+    num_participants: u16,
+    /// The number of current participants dealing their shares, at least the current threshold.
+    #[clap(short, long)]
+    dealers: u16,
+    /// Exchange messages through the coordinator at this URL, instead of
+    /// copying and pasting them.
+    #[clap(long, requires = "session")]
+    coordinator: Option<Url>,
+    /// The name of the resharing session to join on the coordinator.
+    #[clap(long, requires = "coordinator")]
+    session: Option<String>,
+}
+
+impl ReshareParams {
+    /// Take part in resharing, returning our new config if we're `member`.
+    pub async fn run(
+        &self,
+        old: Option<&threshold::Config>,
+        member: bool,
+    ) -> Result<Option<threshold::Config>> {
+        match (&self.coordinator, &self.session) {
+            (Some(coordinator), Some(session)) => {
+                let terminal = NetworkTerminal::peer(
+                    ActualTerminal,
+                    coordinator_client(coordinator)?,
+                    session.clone(),
+                );
+                threshold::reshare(
+                    old,
+                    member,
+                    self.threshold,
+                    self.num_participants,
+                    self.dealers,
+                    &terminal,
+                )
+                .await
+            }
+            _ => {
+                threshold::reshare(
+                    old,
+                    member,
+                    self.threshold,
+                    self.num_participants,
+                    self.dealers,
                     &ActualTerminal,
                 )
                 .await
@@ -49,3 +169,107 @@ impl ThresholdCmd {
         }
     }
 }
+
+#[derive(Debug, clap::Parser)]
+pub struct ReshareCmd {
+    #[clap(flatten)]
+    params: ReshareParams,
+    /// Deal our share without receiving a new one, leaving this wallet view-only.
+    #[clap(long)]
+    leave: bool,
+}
+
+impl ReshareCmd {
+    /// Reshare the key of the wallet in `home`, replacing its threshold config.
+    ///
+    /// This takes the home directory directly, since it rewrites the config file.
+    pub async fn exec(&self, home: &Utf8Path) -> Result<()> {
+        let config_path = home.join(crate::CONFIG_FILE_NAME);
+        let mut config = PcliConfig::load(&config_path)?;
+        let (old, password) = match config.custody.clone() {
+            CustodyConfig::Threshold(old) => (old, None),
+            CustodyConfig::Encrypted(encrypted) => {
+                let password = ActualTerminal.get_password().await?;
+                let old = encrypted
+                    .convert_to_threshold(&password)?
+                    .ok_or(anyhow!("cannot reshare a non-threshold custody backend"))?;
+                (old, Some(password))
+            }
+            _ => anyhow::bail!("cannot reshare a non-threshold custody backend"),
+        };
+
+        let new = self.params.run(Some(&old), !self.leave).await?;
+        config.custody = match (new, password) {
+            (Some(new), Some(password)) => {
+                CustodyConfig::Encrypted(penumbra_custody::encrypted::Config::create(
+                    &password,
+                    penumbra_custody::encrypted::InnerConfig::Threshold(new),
+                )?)
+            }
+            (Some(new), None) => CustodyConfig::Threshold(new),
+            (None, _) => CustodyConfig::ViewOnly,
+        };
+        println!("Writing reshared config to {}", config_path);
+        config.save(&config_path)?;
+        if let CustodyConfig::Threshold(new) = &config.custody {
+            println!("The new shares belong to key epoch {}.", new.key_epoch());
+        }
+        println!("Participants refuse to sign with shares from an earlier key epoch, so any backups of the old config are useless with the new participants, and should be deleted.");
+        Ok(())
+    }
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct CoordinateCmd {
+    /// The address to listen for participants on.
+    #[clap(long, default_value = "127.0.0.1:8085")]
+    bind: SocketAddr,
+    /// The hex-encoded verification key of a participant allowed to send messages.
+    ///
+    /// May be given several times. If omitted, anyone can send messages.
+    #[clap(long = "participant")]
+    participants: Vec<String>,
+}
+
+impl CoordinateCmd {
+    /// Run the coordinator until interrupted.
+    ///
+    /// This doesn't take an `App`, since relaying messages doesn't need a
+    /// wallet, and participants running a DKG don't have one yet.
+    pub async fn exec(&self) -> Result<()> {
+        let participants = if self.participants.is_empty() {
+            None
+        } else {
+            Some(
+                self.participants
+                    .iter()
+                    .map(|participant| {
+                        let bytes = hex::decode(participant)?;
+                        Ok(VerificationKey::try_from(bytes.as_slice())?)
+                    })
+                    .collect::<Result<HashSet<_>>>()?,
+            )
+        };
+
+        println!("Relaying threshold messages on {}", self.bind);
+        tonic::transport::Server::builder()
+            .add_service(CoordinatorServiceServer::new(Coordinator::new(
+                participants,
+            )))
+            .serve(self.bind)
+            .await?;
+        Ok(())
+    }
+}
+
+/// Build a client for the threshold coordinator at `url`.
+///
+/// The connection is only made once the client is first used.
+pub fn coordinator_client(url: &Url) -> Result<CoordinatorServiceClient<Channel>> {
+    let endpoint = match url.scheme() {
+        "http" => Channel::from_shared(url.to_string())?,
+        "https" => Channel::from_shared(url.to_string())?.tls_config(ClientTlsConfig::new())?,
+        other => anyhow::bail!("unknown url scheme {other}"),
+    };
+    Ok(CoordinatorServiceClient::new(endpoint.connect_lazy()))
+}
@@ -8,6 +8,7 @@ use std::{
 
 use anyhow::{ensure, Context, Result};
 use ark_ff::UniformRand;
+use camino::Utf8PathBuf;
 use decaf377::{Fq, Fr};
 use ibc_proto::ibc::core::client::v1::{
     query_client::QueryClient as IbcClientQueryClient, QueryClientStateRequest,
@@ -27,16 +28,16 @@ use regex::Regex;
 
 use liquidity_position::PositionCmd;
 use penumbra_asset::{asset, asset::Metadata, Value, STAKING_TOKEN_ASSET_ID};
-use penumbra_dex::{lp::position, swap_claim::SwapClaimPlan};
+use penumbra_dex::{lp::position, swap_claim::SwapClaimPlan, SwapExecution};
 use penumbra_fee::FeeTier;
 use penumbra_governance::{proposal::ProposalToml, proposal_state::State as ProposalState, Vote};
 use penumbra_keys::{keys::AddressIndex, Address};
-use penumbra_num::Amount;
+use penumbra_num::{fixpoint::U128x128, Amount};
 use penumbra_proto::{
     core::component::{
         dex::v1::{
             query_service_client::QueryServiceClient as DexQueryServiceClient,
-            LiquidityPositionByIdRequest, PositionId,
+            LiquidityPositionByIdRequest, PositionId, PositionRewardsRequest,
         },
         governance::v1::{
             query_service_client::QueryServiceClient as GovernanceQueryServiceClient,
@@ -60,14 +61,61 @@ use penumbra_transaction::gas::swap_claim_gas_cost;
 use penumbra_view::{SpendableNoteRecord, ViewClient};
 use penumbra_wallet::plan::{self, Planner};
 use proposal::ProposalCmd;
+use send_batch::SendBatchCmd;
 
 use crate::command::tx::auction::AuctionCmd;
-use crate::App;
+use crate::{output::UsageError, App};
 
 mod auction;
 mod liquidity_position;
 mod proposal;
 mod replicate;
+mod send_batch;
+
+#[derive(Debug, clap::Args)]
+pub struct TransactionCmd {
+    /// Write the transaction plan to this file instead of building and
+    /// submitting the transaction.
+    ///
+    /// The plan can then be signed on an offline machine with `pcli sign`,
+    /// and submitted with `pcli broadcast`.
+    #[clap(long, global = true, value_name = "FILE")]
+    pub plan_only: Option<Utf8PathBuf>,
+    #[clap(subcommand)]
+    pub cmd: TxCmd,
+}
+
+impl TransactionCmd {
+    /// Determine if this command can run in "offline" mode.
+    pub fn offline(&self) -> bool {
+        self.cmd.offline()
+    }
+
+    /// Determine if this command can print its results with `--output json`.
+    pub fn supports_json(&self) -> bool {
+        // The proposal template is printed as TOML.
+        !matches!(self.cmd, TxCmd::Proposal(ProposalCmd::Template { .. }))
+    }
+
+    /// Check that `--plan-only` is only used with commands that build a single transaction.
+    fn check_plan_only(&self) -> Result<()> {
+        if self.plan_only.is_none() {
+            return Ok(());
+        }
+        match self.cmd.plan_only_unsupported() {
+            Some(reason) => {
+                Err(UsageError(format!("{reason}, and does not support --plan-only")).into())
+            }
+            None => Ok(()),
+        }
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        self.check_plan_only()?;
+        app.plan_only = self.plan_only.clone();
+        self.cmd.exec(app).await
+    }
+}
 
 #[derive(Debug, clap::Subcommand)]
 pub enum TxCmd {
@@ -92,6 +140,9 @@ pub enum TxCmd {
         #[clap(short, long, default_value_t)]
         fee_tier: FeeTier,
     },
+    /// Send funds to many recipients, as listed in a CSV file.
+    #[clap(display_order = 110)]
+    SendBatch(SendBatchCmd),
     /// Deposit stake into a validator's delegation pool.
     #[clap(display_order = 200)]
     Delegate {
@@ -141,6 +192,13 @@ pub enum TxCmd {
         /// The denomination to swap the input into, e.g. `gm`
         #[clap(long, display_order = 100)]
         into: String,
+        /// Only execute the swap at a price at most this many percent worse than
+        /// a simulated execution against the current DEX state, e.g. `0.5`.
+        ///
+        /// Input that can't be filled within that price is returned when the
+        /// swap is claimed.
+        #[clap(long, display_order = 200)]
+        max_slippage: Option<f64>,
         /// Only spend funds originally received by the given account.
         #[clap(long, default_value = "0", display_order = 300)]
         source: u32,
@@ -270,6 +328,7 @@ impl TxCmd {
     pub fn offline(&self) -> bool {
         match self {
             TxCmd::Send { .. } => false,
+            TxCmd::SendBatch(cmd) => cmd.offline(),
             TxCmd::Sweep { .. } => false,
             TxCmd::Swap { .. } => false,
             TxCmd::Delegate { .. } => false,
@@ -284,6 +343,32 @@ impl TxCmd {
         }
     }
 
+    /// If this command can't write its plan with `--plan-only`, say why.
+    ///
+    /// Only one plan file is written, so commands which build several transactions, or which
+    /// need to see one transaction on chain before planning the next, can't be planned ahead.
+    fn plan_only_unsupported(&self) -> Option<&'static str> {
+        match self {
+            TxCmd::SendBatch(_) => Some("send-batch may produce several transactions"),
+            TxCmd::Sweep => Some("sweep may produce several transactions"),
+            TxCmd::UndelegateClaim { .. } => {
+                Some("undelegate-claim produces a transaction per unbonding token")
+            }
+            TxCmd::Swap { .. } => Some(
+                "swap is claimed by a second transaction, which can only be planned once the swap is on chain",
+            ),
+            TxCmd::Send { .. }
+            | TxCmd::Delegate { .. }
+            | TxCmd::Undelegate { .. }
+            | TxCmd::Vote { .. }
+            | TxCmd::Proposal(_)
+            | TxCmd::CommunityPoolDeposit { .. }
+            | TxCmd::Position(_)
+            | TxCmd::Withdraw { .. }
+            | TxCmd::Auction(_) => None,
+        }
+    }
+
     pub async fn exec(&self, app: &mut App) -> Result<()> {
         // TODO: use a command line flag to determine the fee token,
         // and pull the appropriate GasPrices out of this rpc response,
@@ -336,6 +421,7 @@ impl TxCmd {
                     .context("can't build send transaction")?;
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::SendBatch(cmd) => cmd.exec(app).await?,
             TxCmd::CommunityPoolDeposit {
                 values,
                 source,
@@ -374,17 +460,19 @@ impl TxCmd {
                 let num_plans = plans.len();
 
                 for (i, plan) in plans.into_iter().enumerate() {
-                    println!("building sweep {i} of {num_plans}");
+                    app.output
+                        .progress(format!("building sweep {i} of {num_plans}"));
                     app.build_and_submit_transaction(plan).await?;
                 }
                 if num_plans == 0 {
-                    println!("finished sweeping");
+                    app.output.progress("finished sweeping");
                     break;
                 }
             },
             TxCmd::Swap {
                 input,
                 into,
+                max_slippage,
                 source,
                 fee_tier,
             } => {
@@ -392,6 +480,13 @@ impl TxCmd {
                 let into = asset::REGISTRY.parse_unit(into.as_str()).base();
                 let fee_tier: FeeTier = (*fee_tier).into();
 
+                let min_output = match max_slippage {
+                    Some(max_slippage) => {
+                        Some(min_swap_output(app, input, &into, *max_slippage).await?)
+                    }
+                    None => None,
+                };
+
                 let fvk = app.config.full_viewing_key.clone();
 
                 // If a source address was specified, use it for the swap, otherwise,
@@ -410,7 +505,13 @@ impl TxCmd {
                     .fee(&swap_claim_gas_cost())
                     .apply_tier(fee_tier.into());
 
-                planner.swap(input, into.id(), estimated_claim_fee, claim_address)?;
+                planner.swap(
+                    input,
+                    into.id(),
+                    estimated_claim_fee,
+                    claim_address,
+                    min_output,
+                )?;
 
                 let plan = planner
                     .plan(app.view(), AddressIndex::new(*source))
@@ -440,8 +541,8 @@ impl TxCmd {
                 let pro_rata_outputs = swap_record
                     .output_data
                     .pro_rata_outputs((swap_plaintext.delta_1_i, swap_plaintext.delta_2_i));
-                println!("Swap submitted and batch confirmed!");
-                println!(
+                app.output.progress("Swap submitted and batch confirmed!");
+                app.output.progress(format!(
                     "You will receive outputs of {} and {}. Claiming now...",
                     Value {
                         amount: pro_rata_outputs.0,
@@ -453,7 +554,7 @@ impl TxCmd {
                         asset_id: swap_record.output_data.trading_pair.asset_2(),
                     }
                     .format(&asset_cache),
-                );
+                ));
 
                 let params = app
                     .view
@@ -644,7 +745,8 @@ impl TxCmd {
 
                 for (address_index, notes_by_asset) in notes.into_iter() {
                     for (token, notes) in notes_by_asset.into_iter() {
-                        println!("claiming {}", token.denom().default_unit());
+                        app.output
+                            .progress(format!("claiming {}", token.denom().default_unit()));
 
                         let validator_identity = token.validator();
                         let unbonding_start_height = token.unbonding_start_height();
@@ -1108,7 +1210,8 @@ impl TxCmd {
                     .await?;
 
                 if owned_position_ids.is_empty() {
-                    println!("No open positions are available to close.");
+                    app.output
+                        .progress("No open positions are available to close.");
                     return Ok(());
                 }
 
@@ -1147,7 +1250,8 @@ impl TxCmd {
                     .await?;
 
                 if owned_position_ids.is_empty() {
-                    println!("No closed positions are available to withdraw.");
+                    app.output
+                        .progress("No closed positions are available to withdraw.");
                     return Ok(());
                 }
 
@@ -1248,8 +1352,75 @@ impl TxCmd {
 
                 app.build_and_submit_transaction(plan).await?;
             }
-            TxCmd::Position(PositionCmd::RewardClaim {}) => {
-                unimplemented!("deprecated, remove this")
+            TxCmd::Position(PositionCmd::RewardClaim {
+                source,
+                position_ids,
+                fee_tier,
+            }) => {
+                let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
+
+                let mut planner = Planner::new(OsRng);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .set_fee_tier((*fee_tier).into());
+
+                let mut claims = 0;
+                for position_id in position_ids {
+                    let position: position::Position = client
+                        .liquidity_position_by_id(LiquidityPositionByIdRequest {
+                            position_id: Some(PositionId::from(*position_id)),
+                        })
+                        .await?
+                        .into_inner()
+                        .data
+                        .context("missing position metadata")?
+                        .try_into()?;
+
+                    // Rewards can only be claimed once the position has been withdrawn,
+                    // and each claim advances the position's sequence number.
+                    let position::State::Withdrawn { sequence } = position.state else {
+                        anyhow::bail!(
+                            "position {} is {}, and must be withdrawn before claiming its rewards",
+                            position_id,
+                            position.state
+                        );
+                    };
+
+                    let rewards: Amount = client
+                        .position_rewards(PositionRewardsRequest {
+                            position_id: Some(PositionId::from(*position_id)),
+                        })
+                        .await?
+                        .into_inner()
+                        .rewards
+                        .context("missing position rewards")?
+                        .try_into()?;
+                    if rewards == Amount::zero() {
+                        app.output.progress(format!(
+                            "Position {position_id} has no rewards to claim, skipping."
+                        ));
+                        continue;
+                    }
+
+                    planner.position_reward_claim(*position_id, sequence + 1, rewards);
+                    claims += 1;
+                }
+
+                if claims == 0 {
+                    app.output.progress("No rewards are available to claim.");
+                    return Ok(());
+                }
+
+                let plan = planner
+                    .plan(
+                        app.view
+                            .as_mut()
+                            .context("view service must be initialized")?,
+                        AddressIndex::new(*source),
+                    )
+                    .await?;
+
+                app.build_and_submit_transaction(plan).await?;
             }
             TxCmd::Position(PositionCmd::Replicate(replicate_cmd)) => {
                 replicate_cmd.exec(app).await?;
@@ -1261,3 +1432,139 @@ impl TxCmd {
         Ok(())
     }
 }
+
+/// Computes the minimum acceptable output for swapping `input` into `into`,
+/// allowing the execution price to be at most `max_slippage` percent worse than
+/// a simulated execution against the current DEX state.
+async fn min_swap_output(
+    app: &mut App,
+    input: Value,
+    into: &Metadata,
+    max_slippage: f64,
+) -> Result<Amount> {
+    use penumbra_proto::core::component::dex::v1::{
+        simulate_trade_request::{routing::Setting, Routing},
+        simulation_service_client::SimulationServiceClient,
+        SimulateTradeRequest,
+    };
+
+    ensure!(
+        (0.0..100.0).contains(&max_slippage),
+        "--max-slippage must be a percentage between 0 and 100"
+    );
+
+    let mut client = SimulationServiceClient::new(app.pd_channel().await?);
+    let execution: SwapExecution = client
+        .simulate_trade(SimulateTradeRequest {
+            input: Some(input.into()),
+            output: Some(into.id().into()),
+            routing: Some(Routing {
+                setting: Some(Setting::Default(Default::default())),
+            }),
+            explain: false,
+        })
+        .await?
+        .into_inner()
+        .output
+        .ok_or_else(|| anyhow::anyhow!("proto response missing swap execution"))?
+        .try_into()
+        .context("cannot parse simulation response")?;
+
+    let cache = app.view().assets().await?;
+    ensure!(
+        execution.input.amount > Amount::zero(),
+        "there is no liquidity to swap {} into {}",
+        input.format(&cache),
+        into,
+    );
+
+    // If the simulation could only fill part of the input, extrapolate the
+    // output from the price of the filled part.
+    let expected_output = U128x128::ratio(execution.output.amount, execution.input.amount)?
+        .apply_to_amount(&input.amount)?;
+
+    // Apply the slippage in basis points, to keep the computation exact.
+    let max_slippage_bps = (max_slippage * 100.0).round() as u64;
+    let min_output =
+        U128x128::ratio(10_000 - max_slippage_bps, 10_000)?.apply_to_amount(&expected_output)?;
+    ensure!(
+        min_output > Amount::zero(),
+        "the expected output of the swap is too small to apply --max-slippage"
+    );
+
+    app.output.progress(format!(
+        "expected output is {}, requiring at least {}",
+        Value {
+            amount: expected_output,
+            asset_id: into.id(),
+        }
+        .format(&cache),
+        Value {
+            amount: min_output,
+            asset_id: into.id(),
+        }
+        .format(&cache),
+    ));
+
+    Ok(min_output)
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+    use crate::{opt::Opt, Command};
+
+    fn check(args: &[&str]) -> Result<()> {
+        let opt = Opt::try_parse_from(
+            ["pcli", "tx", "--plan-only", "plan.json"]
+                .iter()
+                .chain(args),
+        )?;
+        let Command::Transaction(cmd) = opt.cmd else {
+            panic!("expected a transaction command");
+        };
+        cmd.check_plan_only()
+    }
+
+    #[test]
+    fn plan_only_is_rejected_for_commands_with_several_transactions() {
+        for args in [
+            &["swap", "1penumbra", "--into", "gm"][..],
+            &["sweep"],
+            &["undelegate-claim"],
+            &["send-batch", "payments.csv"],
+        ] {
+            let error = check(args).expect_err("--plan-only should be rejected");
+            assert!(
+                error.to_string().contains("does not support --plan-only"),
+                "{args:?}: {error}"
+            );
+        }
+    }
+
+    #[test]
+    fn plan_only_is_allowed_for_single_transactions() {
+        check(&["send", "1penumbra", "--to", "penumbra1abc"]).unwrap();
+        check(&["position", "close-all"]).unwrap();
+    }
+
+    #[test]
+    fn json_output_is_only_accepted_where_supported() {
+        let supports_json = |args: &[&str]| {
+            Opt::try_parse_from(["pcli", "--output", "json"].iter().chain(args))
+                .unwrap()
+                .cmd
+                .supports_json()
+        };
+        assert!(supports_json(&["tx", "sweep"]));
+        assert!(supports_json(&["view", "balance"]));
+        assert!(supports_json(&["query", "dex", "depth", "gm:gn"]));
+        assert!(!supports_json(&["tx", "proposal", "template", "signaling"]));
+        assert!(!supports_json(&["view", "address", "0"]));
+        assert!(!supports_json(&[
+            "query", "dex", "simulate", "1gm", "--into", "gn"
+        ]));
+    }
+}
@@ -1,7 +1,7 @@
 use std::path::Path;
 
 use crate::command::tx::FeeTier;
-use crate::App;
+use crate::{output::OutputFormat, App};
 use anyhow::Result;
 use anyhow::{anyhow, bail, Context};
 use clap::Subcommand;
@@ -281,7 +281,8 @@ impl DutchCmd {
                 fee_tier,
                 debug,
             } => {
-                println!("Gradual dutch auction prototype");
+                let output = app.output;
+                output.progress("Gradual dutch auction prototype");
 
                 let input = input_str.parse::<Value>()?;
                 let max_output = max_output_str.parse::<Value>()?;
@@ -304,10 +305,10 @@ impl DutchCmd {
                 let max_output_fmt = max_output.format(&asset_cache);
                 let min_output_fmt = min_output.format(&asset_cache);
 
-                println!("total to auction: {input_fmt}");
-                println!("start price: {max_output_fmt}");
-                println!("end price: {min_output_fmt}");
-                display_auction_description(&asset_cache, auction_descriptions.clone());
+                output.progress(format!("total to auction: {input_fmt}"));
+                output.progress(format!("start price: {max_output_fmt}"));
+                output.progress(format!("end price: {min_output_fmt}"));
+                display_auction_description(output, &asset_cache, auction_descriptions.clone());
 
                 let mut planner = Planner::new(OsRng);
                 planner
@@ -355,7 +356,7 @@ impl DutchCmd {
                     .0
                     .format(&asset_cache);
 
-                println!("Total fee: {fee_fmt}");
+                output.progress(format!("Total fee: {fee_fmt}"));
 
                 if !yes {
                     Confirm::new()
@@ -449,7 +450,11 @@ async fn auctions_to_withdraw(
     Ok(auction_ids)
 }
 
-fn display_auction_description(asset_cache: &Cache, auctions: Vec<DutchAuctionDescription>) {
+fn display_auction_description(
+    output: OutputFormat,
+    asset_cache: &Cache,
+    auctions: Vec<DutchAuctionDescription>,
+) {
     let mut tally_max_output = Amount::zero();
     let mut tally_min_output = Amount::zero();
     let mut tally_input = Amount::zero();
@@ -505,7 +510,7 @@ fn display_auction_description(asset_cache: &Cache, auctions: Vec<DutchAuctionDe
         ]);
     }
 
-    println!("{}", table);
+    output.progress(table);
 
     let tally_input_fmt = Value {
         asset_id: input_id,
@@ -525,7 +530,7 @@ fn display_auction_description(asset_cache: &Cache, auctions: Vec<DutchAuctionDe
     }
     .format(&asset_cache);
 
-    println!("Total auctioned: {tally_input_fmt}");
-    println!("Total max output: {tally_output_max_fmt}");
-    println!("Total min output: {tally_output_min_fmt}");
+    output.progress(format!("Total auctioned: {tally_input_fmt}"));
+    output.progress(format!("Total max output: {tally_output_max_fmt}"));
+    output.progress(format!("Total min output: {tally_output_min_fmt}"));
 }
@@ -64,9 +64,17 @@ pub enum PositionCmd {
         fee_tier: FeeTier,
     },
 
-    /// Debits a withdrawn position NFT and credits a claimed position NFT and any liquidity incentives.
-    #[clap(hide(true))] // remove when reward claims exist
-    RewardClaim {},
+    /// Debits withdrawn position NFTs and credits withdrawn position NFTs with the next sequence number and the liquidity rewards accrued by the positions.
+    RewardClaim {
+        /// Only spend funds originally received by the given address index.
+        #[clap(long, default_value = "0")]
+        source: u32,
+        /// The list of [`position::Id`] of the positions to claim the rewards of.
+        position_ids: Vec<position::Id>,
+        /// The selected fee tier to multiply the fee amount by.
+        #[clap(short, long, default_value_t)]
+        fee_tier: FeeTier,
+    },
     /// Replicate a trading function
     #[clap(subcommand)]
     Replicate(ReplicateCmd),
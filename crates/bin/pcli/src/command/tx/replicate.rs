@@ -19,19 +19,34 @@ use penumbra_view::{Planner, ViewClient};
 
 use crate::dex_utils;
 use crate::dex_utils::replicate::debug;
-use crate::{warning, App};
+use crate::{output::OutputFormat, warning, App};
+
+mod ladder;
 
 /// Queries the chain for a transaction by hash.
 #[derive(Debug, clap::Subcommand)]
 pub enum ReplicateCmd {
     #[clap(visible_alias = "xyk")]
     ConstantProduct(ConstantProduct),
+    /// Replicate concentrated liquidity over a price range.
+    Concentrated(ladder::Concentrated),
+    /// Replicate a StableSwap curve, for pegged assets.
+    #[clap(name = "stableswap")]
+    StableSwap(ladder::StableSwap),
+    /// Replicate a linear range order, selling evenly across a price range.
+    Linear(ladder::Linear),
+    /// Close a ladder of positions and reopen it around a new price.
+    Rebalance(ladder::Rebalance),
 }
 
 impl ReplicateCmd {
     pub async fn exec(&self, app: &mut App) -> anyhow::Result<()> {
         match self {
             ReplicateCmd::ConstantProduct(xyk_cmd) => xyk_cmd.exec(app).await?,
+            ReplicateCmd::Concentrated(cmd) => ladder::open(app, cmd, &[]).await?,
+            ReplicateCmd::StableSwap(cmd) => ladder::open(app, cmd, &[]).await?,
+            ReplicateCmd::Linear(cmd) => ladder::open(app, cmd, &[]).await?,
+            ReplicateCmd::Rebalance(cmd) => cmd.exec(app).await?,
         };
         Ok(())
     }
@@ -39,6 +54,10 @@ impl ReplicateCmd {
     pub fn offline(&self) -> bool {
         match self {
             ReplicateCmd::ConstantProduct(_) => false,
+            ReplicateCmd::Concentrated(_) => false,
+            ReplicateCmd::StableSwap(_) => false,
+            ReplicateCmd::Linear(_) => false,
+            ReplicateCmd::Rebalance(_) => false,
         }
     }
 }
@@ -106,31 +125,36 @@ impl ConstantProduct {
         {
             return Ok(());
         }
-        println!("\nso it shall be...\n\n");
-        println!(
-            "#################################################################################"
+        let output = app.output;
+        output.progress("\nso it shall be...\n\n");
+        output.progress(
+            "#################################################################################",
         );
-        println!(
-            "########################### LIQUIDITY SUMMARY ###################################"
+        output.progress(
+            "########################### LIQUIDITY SUMMARY ###################################",
         );
-        println!(
-            "#################################################################################"
+        output.progress(
+            "#################################################################################",
         );
-        println!("\nYou want to provide liquidity on the pair {}", pair);
-        println!("You will need:",);
-        println!(" -> {amount_start}{}", pair.start);
-        println!(" -> {amount_end}{}", pair.end);
+        output.progress(format!(
+            "\nYou want to provide liquidity on the pair {}",
+            pair
+        ));
+        output.progress("You will need:");
+        output.progress(format!(" -> {amount_start}{}", pair.start));
+        output.progress(format!(" -> {amount_end}{}", pair.end));
         // TODO(erwan): would be nice to print current balance?
 
-        println!("You will create the following pools:");
+        output.progress("You will create the following pools:");
         let asset_cache = app.view().assets().await?;
-        println!(
-            "{}",
-            crate::command::utils::render_positions(&asset_cache, &positions),
-        );
+        output.progress(crate::command::utils::render_positions(
+            &asset_cache,
+            &positions,
+        ));
 
         if let Some(debug_file) = &self.debug_file {
             Self::write_debug_data(
+                output,
                 debug_file.clone(),
                 self.pair.clone(),
                 self.input.clone(),
@@ -173,8 +197,10 @@ impl ConstantProduct {
                 AddressIndex::new(self.source),
             )
             .await?;
-        let tx_id = app.build_and_submit_transaction(plan).await?;
-        println!("posted with transaction id: {tx_id}");
+        if let Some(tx_id) = app.build_and_submit_transaction(plan).await? {
+            app.output
+                .progress(format!("posted with transaction id: {tx_id}"));
+        }
 
         Ok(())
     }
@@ -226,6 +252,7 @@ impl ConstantProduct {
     }
 
     pub(crate) fn write_debug_data(
+        output: OutputFormat,
         file: PathBuf,
         pair: DirectedUnitPair,
         input: Value,
@@ -254,9 +281,9 @@ impl ConstantProduct {
 
         let r2 = r1 * current_price;
         let total_k = r1 * r2;
-        println!("Entry R1: {r1}");
-        println!("Entry R2: {r2}");
-        println!("total K: {total_k}");
+        output.progress(format!("Entry R1: {r1}"));
+        output.progress(format!("Entry R2: {r2}"));
+        output.progress(format!("total K: {total_k}"));
 
         let debug_positions: Vec<debug::PayoffPositionEntry> = positions
             .iter()
@@ -0,0 +1,548 @@
+use std::collections::BTreeSet;
+
+use anyhow::{Context, Result};
+use dialoguer::Confirm;
+use rand_core::OsRng;
+
+use penumbra_asset::Value;
+use penumbra_dex::{
+    lp::position::{self, Position},
+    DirectedUnitPair,
+};
+use penumbra_fee::GasPrices;
+use penumbra_keys::keys::AddressIndex;
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_proto::{
+    core::component::dex::v1::{
+        query_service_client::QueryServiceClient as DexQueryServiceClient,
+        LiquidityPositionByIdRequest, SpreadRequest,
+    },
+    view::v1::GasPricesRequest,
+};
+use penumbra_view::{Planner, ViewClient};
+
+use crate::dex_utils::replicate;
+use crate::{warning, App};
+
+/// The parameters shared by all the ladder strategies.
+#[derive(Debug, Clone, clap::Args)]
+pub struct LadderArgs {
+    pub pair: DirectedUnitPair,
+    /// The liquidity to supply, in either asset of the pair. The amount of the
+    /// other asset is determined by the strategy and the current price.
+    pub input: Value,
+
+    /// The price of the start asset, in units of the end asset. Defaults to
+    /// the price of the best position on the market.
+    #[clap(short, long)]
+    pub current_price: Option<f64>,
+
+    #[clap(short, long, default_value_t = 0u32)]
+    pub fee_bps: u32,
+    /// The number of positions the ladder is made of.
+    #[clap(short, long, default_value_t = 16)]
+    pub num_positions: usize,
+    /// `--yes` means all prompt interaction are skipped and agreed.
+    #[clap(short, long)]
+    pub yes: bool,
+
+    #[clap(long, default_value = "0", hide(true))]
+    pub source: u32,
+}
+
+impl LadderArgs {
+    fn validate(&self) -> Result<()> {
+        if self.input.asset_id != self.pair.start.id() && self.input.asset_id != self.pair.end.id()
+        {
+            anyhow::bail!("you must supply liquidity with an asset that's part of the market")
+        } else if self.input.amount == 0u64.into() {
+            anyhow::bail!("the quantity of liquidity supplied must be non-zero.",)
+        } else if self.fee_bps > 5000 {
+            anyhow::bail!("the maximum fee is 5000bps (50%)")
+        } else if self.num_positions == 0 {
+            anyhow::bail!("the ladder must have at least one position")
+        } else if self.current_price.is_some()
+            && self.current_price.expect("current price is Some") <= 0.0
+        {
+            anyhow::bail!("the supplied current price must be positive")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// A trading curve that is replicated with a ladder of positions.
+pub trait LadderStrategy {
+    fn ladder(&self) -> &LadderArgs;
+
+    /// Checks the strategy's own parameters.
+    fn validate(&self) -> Result<()>;
+
+    /// Computes the positions of the ladder at the given price.
+    fn replicate(&self, current_price: f64) -> Result<Vec<Position>>;
+}
+
+/// Concentrated liquidity over a price range.
+#[derive(Debug, Clone, clap::Args)]
+pub struct Concentrated {
+    #[clap(flatten)]
+    pub ladder: LadderArgs,
+    /// The lowest price of the range, in units of the end asset.
+    #[clap(long)]
+    pub lower: f64,
+    /// The highest price of the range, in units of the end asset.
+    #[clap(long)]
+    pub upper: f64,
+}
+
+impl LadderStrategy for Concentrated {
+    fn ladder(&self) -> &LadderArgs {
+        &self.ladder
+    }
+
+    fn validate(&self) -> Result<()> {
+        validate_range(self.lower, self.upper)
+    }
+
+    fn replicate(&self, current_price: f64) -> Result<Vec<Position>> {
+        replicate::concentrated::replicate(
+            &self.ladder.pair,
+            &self.ladder.input,
+            current_price,
+            replicate::concentrated::Concentrated {
+                lower: self.lower,
+                upper: self.upper,
+            },
+            self.ladder.num_positions,
+            self.ladder.fee_bps,
+        )
+    }
+}
+
+/// A StableSwap curve, for pegged assets.
+#[derive(Debug, Clone, clap::Args)]
+pub struct StableSwap {
+    #[clap(flatten)]
+    pub ladder: LadderArgs,
+    /// The amplification of the curve. The higher it is, the more liquidity
+    /// is concentrated around the peg.
+    #[clap(long, default_value_t = 100.0)]
+    pub amplification: f64,
+    /// The price the assets are pegged at, in units of the end asset.
+    #[clap(long, default_value_t = 1.0)]
+    pub peg: f64,
+    /// How far from the peg the ladder extends, in basis points of the peg.
+    #[clap(long, default_value_t = 200u32)]
+    pub range_bps: u32,
+}
+
+impl LadderStrategy for StableSwap {
+    fn ladder(&self) -> &LadderArgs {
+        &self.ladder
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.amplification <= 0.0 {
+            anyhow::bail!("the amplification must be positive")
+        } else if self.peg <= 0.0 {
+            anyhow::bail!("the peg must be positive")
+        } else if self.range_bps == 0 || self.range_bps >= 10_000 {
+            anyhow::bail!("the range must be between 1 and 9999bps")
+        } else {
+            Ok(())
+        }
+    }
+
+    fn replicate(&self, current_price: f64) -> Result<Vec<Position>> {
+        replicate::stableswap::replicate(
+            &self.ladder.pair,
+            &self.ladder.input,
+            current_price,
+            replicate::stableswap::StableSwap {
+                amplification: self.amplification,
+                peg: self.peg,
+            },
+            self.range_bps as f64 / 10_000.0,
+            self.ladder.num_positions,
+            self.ladder.fee_bps,
+        )
+    }
+}
+
+/// A linear range order, selling the start asset evenly across a price range.
+#[derive(Debug, Clone, clap::Args)]
+pub struct Linear {
+    #[clap(flatten)]
+    pub ladder: LadderArgs,
+    /// The lowest price of the range, in units of the end asset.
+    #[clap(long)]
+    pub lower: f64,
+    /// The highest price of the range, in units of the end asset.
+    #[clap(long)]
+    pub upper: f64,
+}
+
+impl LadderStrategy for Linear {
+    fn ladder(&self) -> &LadderArgs {
+        &self.ladder
+    }
+
+    fn validate(&self) -> Result<()> {
+        validate_range(self.lower, self.upper)
+    }
+
+    fn replicate(&self, current_price: f64) -> Result<Vec<Position>> {
+        replicate::linear::replicate(
+            &self.ladder.pair,
+            &self.ladder.input,
+            current_price,
+            replicate::linear::Linear {
+                lower: self.lower,
+                upper: self.upper,
+            },
+            self.ladder.num_positions,
+            self.ladder.fee_bps,
+        )
+    }
+}
+
+fn validate_range(lower: f64, upper: f64) -> Result<()> {
+    if lower <= 0.0 {
+        anyhow::bail!("the lower price must be positive")
+    } else if upper <= lower {
+        anyhow::bail!("the upper price must be greater than the lower price")
+    } else {
+        Ok(())
+    }
+}
+
+/// The strategies a ladder can be rebalanced into.
+#[derive(Debug, Clone, clap::Subcommand)]
+pub enum Strategy {
+    Concentrated(Concentrated),
+    #[clap(name = "stableswap")]
+    StableSwap(StableSwap),
+    Linear(Linear),
+}
+
+impl Strategy {
+    fn as_ladder_strategy(&self) -> &dyn LadderStrategy {
+        match self {
+            Strategy::Concentrated(strategy) => strategy,
+            Strategy::StableSwap(strategy) => strategy,
+            Strategy::Linear(strategy) => strategy,
+        }
+    }
+}
+
+/// Closes a ladder and reopens it around a new price.
+///
+/// Positions can only be withdrawn once their closure has been processed at the
+/// end of a block, so rebalancing takes two transactions: the first closes the
+/// ladder, and once it is confirmed, the second withdraws the closed positions
+/// and opens the new ladder with their reserves.
+#[derive(Debug, Clone, clap::Args)]
+pub struct Rebalance {
+    /// The positions of the ladder to close, which must be opened positions on
+    /// the pair owned by the wallet.
+    #[clap(long = "close", required = true)]
+    pub position_ids: Vec<position::Id>,
+    #[clap(subcommand)]
+    pub strategy: Strategy,
+}
+
+impl Rebalance {
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let strategy = self.strategy.as_ladder_strategy();
+        let pair = strategy
+            .ladder()
+            .pair
+            .into_directed_trading_pair()
+            .to_canonical();
+
+        if app.plan_only.is_some() {
+            anyhow::bail!("rebalancing takes two transactions, so it cannot be planned ahead");
+        }
+
+        let opened = app
+            .view()
+            .owned_position_ids(Some(position::State::Opened), Some(pair))
+            .await?;
+        check_positions_to_close(&self.position_ids, &opened)?;
+
+        open(app, strategy, &self.position_ids).await
+    }
+}
+
+/// Checks that the positions to close are distinct, and among the `opened`
+/// positions owned by the wallet on the pair.
+fn check_positions_to_close(to_close: &[position::Id], opened: &[position::Id]) -> Result<()> {
+    let mut seen = BTreeSet::new();
+    for position_id in to_close {
+        if !seen.insert(position_id) {
+            anyhow::bail!("position {position_id} is listed more than once");
+        }
+        if !opened.contains(position_id) {
+            anyhow::bail!("position {position_id} is not an opened position of yours on the pair");
+        }
+    }
+    Ok(())
+}
+
+/// Opens the ladder of `strategy`.
+///
+/// If `to_close` is not empty, those positions are closed first, in a separate
+/// transaction, and once it is confirmed, they are withdrawn in the transaction
+/// that opens the ladder.
+pub async fn open(
+    app: &mut App,
+    strategy: &dyn LadderStrategy,
+    to_close: &[position::Id],
+) -> Result<()> {
+    let args = strategy.ladder();
+    args.validate()?;
+    strategy.validate()?;
+
+    let pair = args.pair.clone();
+    let current_price = match args.current_price {
+        Some(user_supplied_price) => user_supplied_price,
+        None => market_price(app, &pair).await?,
+    };
+    let positions = strategy.replicate(current_price)?;
+
+    let (amount_start, amount_end) =
+        positions
+            .iter()
+            .fold((Amount::zero(), Amount::zero()), |acc, pos| {
+                (
+                    acc.0
+                        + pos
+                            .reserves_for(pair.start.id())
+                            .expect("start is part of position"),
+                    acc.1
+                        + pos
+                            .reserves_for(pair.end.id())
+                            .expect("end is part of position"),
+                )
+            });
+    let amount_start = pair.start.format_value(amount_start);
+    let amount_end = pair.end.format_value(amount_end);
+
+    warning::rmm();
+
+    let output = app.output;
+    output.progress(format!(
+        "\nYou want to provide liquidity on the pair {pair} around the price {current_price}"
+    ));
+    if !to_close.is_empty() {
+        output.progress(format!(
+            "You will first close {} positions, then withdraw them to fund the new ones.",
+            to_close.len()
+        ));
+    }
+    output.progress("You will need:");
+    output.progress(format!(" -> {amount_start}{}", pair.start));
+    output.progress(format!(" -> {amount_end}{}", pair.end));
+
+    output.progress("You will create the following pools:");
+    let asset_cache = app.view().assets().await?;
+    output.progress(crate::command::utils::render_positions(
+        &asset_cache,
+        &positions,
+    ));
+
+    if !args.yes
+        && !Confirm::new()
+            .with_prompt("Do you want to open those liquidity positions on-chain?")
+            .interact()?
+    {
+        return Ok(());
+    }
+
+    let gas_prices: GasPrices = app
+        .view
+        .as_mut()
+        .context("view service must be initialized")?
+        .gas_prices(GasPricesRequest {})
+        .await?
+        .into_inner()
+        .gas_prices
+        .expect("gas prices must be available")
+        .try_into()?;
+
+    let mut planner = Planner::new(OsRng);
+    planner.set_gas_prices(gas_prices);
+
+    if !to_close.is_empty() {
+        let mut close_planner = Planner::new(OsRng);
+        close_planner.set_gas_prices(gas_prices);
+        for position_id in to_close {
+            close_planner.position_close(*position_id);
+        }
+        let plan = close_planner
+            .plan(
+                app.view
+                    .as_mut()
+                    .context("view service must be initialized")?,
+                AddressIndex::new(args.source),
+            )
+            .await?;
+        if let Some(tx_id) = app.build_and_submit_transaction(plan).await? {
+            app.output.progress(format!(
+                "closed {} positions with transaction id: {tx_id}",
+                to_close.len()
+            ));
+        }
+
+        // The closing transaction has been confirmed, so the closures have
+        // been processed and the final reserves are known.
+        let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
+        for position_id in to_close {
+            let position: Position = client
+                .liquidity_position_by_id(LiquidityPositionByIdRequest {
+                    position_id: Some((*position_id).into()),
+                })
+                .await?
+                .into_inner()
+                .data
+                .context("missing position metadata")?
+                .try_into()?;
+            planner.position_withdraw(*position_id, position.reserves, position.phi.pair);
+        }
+    }
+
+    positions.iter().for_each(|position| {
+        planner.position_open(position.clone());
+    });
+
+    let plan = planner
+        .plan(
+            app.view
+                .as_mut()
+                .context("view service must be initialized")?,
+            AddressIndex::new(args.source),
+        )
+        .await?;
+    if let Some(tx_id) = app.build_and_submit_transaction(plan).await? {
+        app.output
+            .progress(format!("posted with transaction id: {tx_id}"));
+    }
+
+    Ok(())
+}
+
+/// Fetches the price of the start asset, in units of the end asset, as the
+/// midpoint between the best prices to sell and buy it on the market.
+async fn market_price(app: &mut App, pair: &DirectedUnitPair) -> Result<f64> {
+    let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
+    let spread_data = client
+        .spread(SpreadRequest {
+            trading_pair: Some(pair.into_directed_trading_pair().to_canonical().into()),
+        })
+        .await?
+        .into_inner();
+
+    tracing::debug!(?spread_data, %pair, "fetched spread for pair");
+
+    let (Some(best_1_to_2), Some(best_2_to_1)) = (
+        spread_data.best_1_to_2_position,
+        spread_data.best_2_to_1_position,
+    ) else {
+        anyhow::bail!("couldn't find a market price for the specified assets, you can manually specify a price using --current-price <price>")
+    };
+
+    // The exchange rate from the start asset to the end asset, in base units.
+    let rate = |position: Position, start: &penumbra_asset::asset::Id| -> Result<f64> {
+        let rate = position
+            .phi
+            .orient_start(*start)
+            .context("position is on the pair")?
+            .effective_price_inv();
+        Ok(rate.into())
+    };
+    let (best_1_to_2, best_2_to_1): (Position, Position) =
+        (best_1_to_2.try_into()?, best_2_to_1.try_into()?);
+    let (sell, buy) =
+        if pair.start.id() == pair.into_directed_trading_pair().to_canonical().asset_1() {
+            (best_1_to_2, best_2_to_1)
+        } else {
+            (best_2_to_1, best_1_to_2)
+        };
+    let sell_price = rate(sell, &pair.start.id())?;
+    let buy_price = 1.0 / rate(buy, &pair.end.id())?;
+
+    // Scale the price from base units into display units.
+    let scale: f64 = U128x128::ratio(
+        pair.start.unit_amount().value(),
+        pair.end.unit_amount().value(),
+    )?
+    .into();
+    Ok((sell_price + buy_price) / 2.0 * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(byte: u8) -> position::Id {
+        position::Id([byte; 32])
+    }
+
+    #[test]
+    fn rebalance_closes_opened_positions() {
+        let opened = [id(1), id(2), id(3)];
+        assert!(check_positions_to_close(&[id(1), id(3)], &opened).is_ok());
+        assert!(check_positions_to_close(&opened, &opened).is_ok());
+    }
+
+    #[test]
+    fn rebalance_rejects_positions_not_opened_on_pair() {
+        let opened = [id(1), id(2)];
+        assert!(check_positions_to_close(&[id(1), id(4)], &opened).is_err());
+        assert!(check_positions_to_close(&[id(1)], &[]).is_err());
+    }
+
+    #[test]
+    fn rebalance_rejects_duplicate_positions() {
+        let opened = [id(1), id(2)];
+        assert!(check_positions_to_close(&[id(2), id(1), id(2)], &opened).is_err());
+    }
+
+    #[test]
+    fn rebalance_requires_positions_to_close() {
+        use clap::Parser;
+
+        #[derive(Debug, clap::Parser)]
+        struct Cmd {
+            #[clap(subcommand)]
+            rebalance: RebalanceCmd,
+        }
+
+        #[derive(Debug, clap::Subcommand)]
+        enum RebalanceCmd {
+            Rebalance(Rebalance),
+        }
+
+        let strategy = [
+            "concentrated",
+            "penumbra:test_usd",
+            "100penumbra",
+            "--lower",
+            "0.8",
+            "--upper",
+            "1.25",
+        ];
+        let close = format!("--close={}", id(1));
+
+        let without_close = ["pcli", "rebalance"].into_iter().chain(strategy);
+        assert!(Cmd::try_parse_from(without_close).is_err());
+
+        let with_close = ["pcli", "rebalance", close.as_str()]
+            .into_iter()
+            .chain(strategy);
+        let Cmd {
+            rebalance: RebalanceCmd::Rebalance(rebalance),
+        } = Cmd::try_parse_from(with_close).unwrap();
+        assert_eq!(rebalance.position_ids, vec![id(1)]);
+    }
+}
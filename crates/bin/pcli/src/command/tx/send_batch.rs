@@ -0,0 +1,441 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use anyhow::{anyhow, Context, Result};
+use camino::Utf8PathBuf;
+use dialoguer::Confirm;
+use rand_core::OsRng;
+use serde::{Deserialize, Serialize};
+
+use penumbra_asset::{asset, Value};
+use penumbra_fee::{Fee, FeeTier, Gas, GasPrices};
+use penumbra_keys::{keys::AddressIndex, Address};
+use penumbra_num::Amount;
+use penumbra_proto::view::v1::GasPricesRequest;
+use penumbra_transaction::gas::{output_gas_cost, spend_gas_cost};
+use penumbra_view::{Planner, ViewClient};
+
+use crate::{output::OutputFormat, App};
+
+/// Send funds to many recipients, as listed in a CSV file.
+///
+/// Payments are grouped into as few transactions as possible. Since a
+/// transaction has a single memo and spends from a single account, rows with
+/// different memos or source accounts are always sent in separate
+/// transactions.
+#[derive(Debug, Clone, clap::Args)]
+pub struct SendBatchCmd {
+    /// A CSV file with `address` and `value` columns, and optional `memo` and
+    /// `source` columns.
+    pub payments: Utf8PathBuf,
+    /// The file to write the transaction hash of each payment to.
+    #[clap(long, default_value = "payments-report.csv")]
+    pub report: Utf8PathBuf,
+    /// The maximum number of payments to include in a single transaction.
+    #[clap(long, default_value_t = 32)]
+    pub max_outputs: usize,
+    /// The maximum number of actions in a single transaction.
+    ///
+    /// Batches whose plan exceeds this limit, e.g. because many small notes
+    /// must be spent, are split in half and planned again. A single payment
+    /// whose plan exceeds it is an error.
+    #[clap(long, default_value_t = 64)]
+    pub max_actions: usize,
+    /// The selected fee tier to multiply the fee amount by.
+    #[clap(short, long, default_value_t)]
+    pub fee_tier: FeeTier,
+    /// Skip the confirmation prompt.
+    #[clap(short, long)]
+    pub yes: bool,
+}
+
+/// A row of the payments file.
+#[derive(Debug, Clone, Deserialize)]
+struct PaymentRow {
+    address: String,
+    value: String,
+    #[serde(default)]
+    memo: Option<String>,
+    #[serde(default)]
+    source: Option<u32>,
+}
+
+/// A row of the report file.
+#[derive(Debug, Clone, Serialize)]
+struct ReportRow {
+    row: usize,
+    address: String,
+    value: String,
+    memo: String,
+    source: u32,
+    transaction_id: String,
+}
+
+/// A parsed payment, along with its row number in the payments file.
+#[derive(Debug, Clone)]
+struct Payment {
+    row: usize,
+    to: Address,
+    value: Value,
+}
+
+/// A set of payments that will be made in one transaction.
+#[derive(Debug, Clone)]
+struct Batch {
+    source: u32,
+    memo: String,
+    payments: Vec<Payment>,
+}
+
+impl Batch {
+    /// Estimate the fee for this batch, assuming that each asset sent is
+    /// covered by a single note, leaving one change output per asset.
+    fn estimated_fee(&self, gas_prices: &GasPrices, fee_tier: FeeTier) -> Fee {
+        let assets = self
+            .payments
+            .iter()
+            .map(|payment| payment.value.asset_id)
+            .collect::<BTreeSet<_>>()
+            .len();
+        let gas = std::iter::repeat_with(output_gas_cost)
+            .take(self.payments.len() + assets)
+            .chain(std::iter::repeat_with(spend_gas_cost).take(assets))
+            .sum::<Gas>();
+        gas_prices.fee(&gas).apply_tier(fee_tier)
+    }
+}
+
+impl SendBatchCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        anyhow::ensure!(self.max_outputs > 0, "--max-outputs must be positive");
+
+        let rows = self.read_payments()?;
+        let mut report = rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| ReportRow {
+                row: i + 1,
+                address: row.address.clone(),
+                value: row.value.clone(),
+                memo: row.memo.clone().unwrap_or_default(),
+                source: row.source.unwrap_or_default(),
+                transaction_id: String::new(),
+            })
+            .collect::<Vec<_>>();
+        let mut batches = self.batches(&rows)?;
+
+        let gas_prices: GasPrices = app
+            .view()
+            .gas_prices(GasPricesRequest {})
+            .await?
+            .into_inner()
+            .gas_prices
+            .expect("gas prices must be available")
+            .try_into()?;
+
+        let asset_cache = app.view().assets().await?;
+        self.print_summary(app.output, &batches, &asset_cache, &gas_prices);
+        if !self.yes
+            && !Confirm::new()
+                .with_prompt("Do you want to send these payments?")
+                .interact()?
+        {
+            return Ok(());
+        }
+
+        // Each batch is planned only once the previous one has been confirmed,
+        // so that the view service doesn't select the same notes twice.
+        while let Some(batch) = batches.pop_front() {
+            let mut planner = Planner::new(OsRng);
+            planner
+                .set_gas_prices(gas_prices)
+                .set_fee_tier(self.fee_tier.into());
+            for payment in &batch.payments {
+                planner.output(payment.value.clone(), payment.to.clone());
+            }
+            let plan = planner
+                .memo(batch.memo.clone())
+                .plan(app.view(), AddressIndex::new(batch.source))
+                .await
+                .with_context(|| {
+                    format!(
+                        "can't build transaction for rows {}",
+                        rows_list(&batch.payments)
+                    )
+                })?;
+
+            if plan.actions.len() > self.max_actions {
+                anyhow::ensure!(
+                    batch.payments.len() > 1,
+                    "the payment on row {} needs {} actions, more than --max-actions {}, \
+                     so it can't be sent in a single transaction",
+                    batch.payments[0].row,
+                    plan.actions.len(),
+                    self.max_actions,
+                );
+                let (first, second) = batch.payments.split_at(batch.payments.len() / 2);
+                for payments in [second, first] {
+                    batches.push_front(Batch {
+                        payments: payments.to_vec(),
+                        ..batch.clone()
+                    });
+                }
+                continue;
+            }
+
+            app.output.progress(format!(
+                "sending {} payments (rows {}) for a fee of {}...",
+                batch.payments.len(),
+                rows_list(&batch.payments),
+                plan.transaction_parameters.fee.format(&asset_cache)
+            ));
+            let tx_id = app.build_and_submit_transaction(plan).await?;
+            if let Some(tx_id) = tx_id {
+                for payment in &batch.payments {
+                    report[payment.row - 1].transaction_id = tx_id.to_string();
+                }
+            }
+            // Rewrite the report after every transaction, so that it is
+            // accurate even if a later transaction fails.
+            self.write_report(&report)?;
+        }
+
+        app.output
+            .progress(format!("wrote payment report to {}", self.report));
+        Ok(())
+    }
+
+    fn read_payments(&self) -> Result<Vec<PaymentRow>> {
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_path(&self.payments)
+            .with_context(|| format!("failed to read payments file {}", self.payments))?;
+        reader
+            .deserialize()
+            .enumerate()
+            .map(|(i, row)| row.with_context(|| format!("invalid payment on row {}", i + 1)))
+            .collect()
+    }
+
+    /// Group the payments by source account and memo, splitting groups that
+    /// have more than `max_outputs` payments.
+    fn batches(&self, rows: &[PaymentRow]) -> Result<VecDeque<Batch>> {
+        let mut groups = BTreeMap::<(u32, String), Vec<Payment>>::new();
+        for (i, row) in rows.iter().enumerate() {
+            let row_number = i + 1;
+            let to = row
+                .address
+                .parse::<Address>()
+                .map_err(|_| anyhow!("address on row {} is invalid", row_number))?;
+            let value = row
+                .value
+                .parse::<Value>()
+                .with_context(|| format!("value on row {} is invalid", row_number))?;
+            groups
+                .entry((
+                    row.source.unwrap_or_default(),
+                    row.memo.clone().unwrap_or_default(),
+                ))
+                .or_default()
+                .push(Payment {
+                    row: row_number,
+                    to,
+                    value,
+                });
+        }
+
+        let mut batches = VecDeque::new();
+        for ((source, memo), payments) in groups {
+            for chunk in payments.chunks(self.max_outputs) {
+                batches.push_back(Batch {
+                    source,
+                    memo: memo.clone(),
+                    payments: chunk.to_vec(),
+                });
+            }
+        }
+        Ok(batches)
+    }
+
+    fn print_summary(
+        &self,
+        output: OutputFormat,
+        batches: &VecDeque<Batch>,
+        asset_cache: &asset::Cache,
+        gas_prices: &GasPrices,
+    ) {
+        let payments = batches.iter().map(|b| b.payments.len()).sum::<usize>();
+        output.progress(format!(
+            "{} payments will be sent in at least {} transactions:",
+            payments,
+            batches.len()
+        ));
+
+        let mut table = comfy_table::Table::new();
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec!["Source", "Memo", "Payments", "Total", "Estimated fee"]);
+        for batch in batches {
+            let mut totals = BTreeMap::<asset::Id, Amount>::new();
+            for payment in &batch.payments {
+                *totals.entry(payment.value.asset_id).or_default() += payment.value.amount;
+            }
+            let totals = totals
+                .into_iter()
+                .map(|(asset_id, amount)| Value { amount, asset_id }.format(asset_cache))
+                .collect::<Vec<_>>()
+                .join(", ");
+            table.add_row(vec![
+                batch.source.to_string(),
+                batch.memo.clone(),
+                batch.payments.len().to_string(),
+                totals,
+                batch
+                    .estimated_fee(gas_prices, self.fee_tier.into())
+                    .format(asset_cache),
+            ]);
+        }
+        output.progress(table);
+    }
+
+    fn write_report(&self, report: &[ReportRow]) -> Result<()> {
+        let mut writer = csv::Writer::from_path(&self.report)
+            .with_context(|| format!("failed to write payment report {}", self.report))?;
+        for row in report {
+            writer.serialize(row)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// The row numbers of a list of payments, for display.
+fn rows_list(payments: &[Payment]) -> String {
+    payments
+        .iter()
+        .map(|p| p.row.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_keys::test_keys::{ADDRESS_0_STR, ADDRESS_1_STR};
+
+    use super::*;
+
+    fn cmd(max_outputs: usize) -> SendBatchCmd {
+        SendBatchCmd {
+            payments: "payments.csv".into(),
+            report: "payments-report.csv".into(),
+            max_outputs,
+            max_actions: 64,
+            fee_tier: FeeTier::default(),
+            yes: true,
+        }
+    }
+
+    fn row(address: &str, value: &str, memo: Option<&str>, source: Option<u32>) -> PaymentRow {
+        PaymentRow {
+            address: address.to_owned(),
+            value: value.to_owned(),
+            memo: memo.map(ToOwned::to_owned),
+            source,
+        }
+    }
+
+    fn rows(batch: &Batch) -> Vec<usize> {
+        batch.payments.iter().map(|p| p.row).collect()
+    }
+
+    #[test]
+    fn batches_group_by_source_and_memo() {
+        let batches = cmd(32)
+            .batches(&[
+                row(ADDRESS_0_STR, "1penumbra", None, None),
+                row(ADDRESS_1_STR, "2penumbra", Some("rent"), None),
+                row(ADDRESS_0_STR, "3penumbra", None, Some(1)),
+                row(ADDRESS_1_STR, "4penumbra", None, Some(0)),
+                row(ADDRESS_0_STR, "5penumbra", Some("rent"), None),
+            ])
+            .unwrap();
+
+        let summary = batches
+            .iter()
+            .map(|b| (b.source, b.memo.as_str(), rows(b)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                (0, "", vec![1, 4]),
+                (0, "rent", vec![2, 5]),
+                (1, "", vec![3]),
+            ]
+        );
+    }
+
+    #[test]
+    fn batches_are_split_at_max_outputs() {
+        let payments = (0..5)
+            .map(|_| row(ADDRESS_0_STR, "1penumbra", None, None))
+            .collect::<Vec<_>>();
+        let batches = cmd(2).batches(&payments).unwrap();
+        assert_eq!(
+            batches.iter().map(rows).collect::<Vec<_>>(),
+            vec![vec![1, 2], vec![3, 4], vec![5]]
+        );
+    }
+
+    #[test]
+    fn batches_report_invalid_rows() {
+        let error = cmd(32)
+            .batches(&[
+                row(ADDRESS_0_STR, "1penumbra", None, None),
+                row("penumbra1nope", "1penumbra", None, None),
+            ])
+            .unwrap_err();
+        assert_eq!(error.to_string(), "address on row 2 is invalid");
+
+        let error = cmd(32)
+            .batches(&[row(ADDRESS_0_STR, "lots", None, None)])
+            .unwrap_err();
+        assert_eq!(error.to_string(), "value on row 1 is invalid");
+    }
+
+    #[test]
+    fn estimated_fee_grows_with_the_batch() {
+        let gas_prices = GasPrices {
+            block_space_price: 1_000,
+            compact_block_space_price: 1_000,
+            verification_price: 1_000,
+            execution_price: 1_000,
+            ..Default::default()
+        };
+        let payments = [
+            row(ADDRESS_0_STR, "1penumbra", None, None),
+            row(ADDRESS_1_STR, "1penumbra", None, None),
+        ];
+        let single =
+            cmd(1).batches(&payments).unwrap()[0].estimated_fee(&gas_prices, FeeTier::default());
+        let pair =
+            cmd(2).batches(&payments).unwrap()[0].estimated_fee(&gas_prices, FeeTier::default());
+        assert!(single.amount() > Amount::zero());
+        assert!(single.amount() < pair.amount());
+    }
+
+    #[test]
+    fn rows_list_joins_row_numbers() {
+        let batches = cmd(32)
+            .batches(&[
+                row(ADDRESS_0_STR, "1penumbra", None, None),
+                row(ADDRESS_1_STR, "1penumbra", Some("memo"), None),
+                row(ADDRESS_1_STR, "1penumbra", None, None),
+            ])
+            .unwrap();
+        assert_eq!(rows_list(&batches[0].payments), "1, 3");
+        assert_eq!(rows_list(&batches[1].payments), "2");
+        assert_eq!(rows_list(&[]), "");
+    }
+}
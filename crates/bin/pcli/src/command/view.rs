@@ -2,6 +2,9 @@ use anyhow::Result;
 
 use address::AddressCmd;
 use balance::BalanceCmd;
+use history::HistoryCmd;
+use pending::PendingCmd;
+use position_history::PositionHistoryCmd;
 use staked::StakedCmd;
 use transaction_hashes::TransactionHashesCmd;
 use tx::TxCmd;
@@ -14,6 +17,9 @@ use self::auction::AuctionCmd;
 mod address;
 mod auction;
 mod balance;
+mod history;
+mod pending;
+mod position_history;
 mod staked;
 mod wallet_id;
 
@@ -44,6 +50,12 @@ pub enum ViewCmd {
     ListTransactionHashes(TransactionHashesCmd),
     /// Displays a transaction's details by hash.
     Tx(TxCmd),
+    /// Reports the gains realized by your disposals, based on the cost of the lots they consumed.
+    History(HistoryCmd),
+    /// Lists transactions that were broadcast but haven't yet been detected on chain.
+    Pending(PendingCmd),
+    /// Reports the executions against your liquidity positions, and their realized PnL.
+    PositionHistory(PositionHistoryCmd),
 }
 
 impl ViewCmd {
@@ -58,6 +70,27 @@ impl ViewCmd {
             ViewCmd::Sync => false,
             ViewCmd::ListTransactionHashes(transactions_cmd) => transactions_cmd.offline(),
             ViewCmd::Tx(tx_cmd) => tx_cmd.offline(),
+            ViewCmd::History(history_cmd) => history_cmd.offline(),
+            ViewCmd::Pending(pending_cmd) => pending_cmd.offline(),
+            ViewCmd::PositionHistory(position_history_cmd) => position_history_cmd.offline(),
+        }
+    }
+
+    /// Determine if this command can print its results with `--output json`.
+    pub fn supports_json(&self) -> bool {
+        match self {
+            ViewCmd::Balance(_)
+            | ViewCmd::Staked(_)
+            | ViewCmd::Tx(_)
+            | ViewCmd::PositionHistory(_) => true,
+            ViewCmd::Auction(_)
+            | ViewCmd::WalletId(_)
+            | ViewCmd::Address(_)
+            | ViewCmd::Reset(_)
+            | ViewCmd::Sync
+            | ViewCmd::ListTransactionHashes(_)
+            | ViewCmd::History(_)
+            | ViewCmd::Pending(_) => false,
         }
     }
 
@@ -92,14 +125,33 @@ impl ViewCmd {
                 address_cmd.exec(&full_viewing_key)?;
             }
             ViewCmd::Balance(balance_cmd) => {
+                let output = app.output;
+                let view_client = app.view();
+                balance_cmd.exec(view_client, output).await?;
+            }
+            ViewCmd::History(history_cmd) => {
+                let channel = app.pd_channel().await?;
                 let view_client = app.view();
-                balance_cmd.exec(view_client).await?;
+                history_cmd.exec(view_client, channel).await?;
+            }
+            ViewCmd::Pending(pending_cmd) => {
+                let view_client = app.view();
+                pending_cmd.exec(view_client).await?;
+            }
+            ViewCmd::PositionHistory(position_history_cmd) => {
+                let channel = app.pd_channel().await?;
+                let output = app.output;
+                let view_client = app.view();
+                position_history_cmd
+                    .exec(view_client, channel, output)
+                    .await?;
             }
             ViewCmd::Staked(staked_cmd) => {
                 let channel = app.pd_channel().await?;
+                let output = app.output;
                 let view_client = app.view();
                 staked_cmd
-                    .exec(&full_viewing_key, view_client, channel)
+                    .exec(&full_viewing_key, view_client, channel, output)
                     .await?;
             }
         }
@@ -5,6 +5,8 @@ use penumbra_keys::AddressView;
 use penumbra_sct::CommitmentSource;
 use penumbra_view::ViewClient;
 
+use crate::output::{print_json, BalanceEntry, NoteEntry, OutputFormat};
+
 #[derive(Debug, clap::Args)]
 pub struct BalanceCmd {
     #[clap(long)]
@@ -17,7 +19,7 @@ impl BalanceCmd {
         false
     }
 
-    pub async fn exec<V: ViewClient>(&self, view: &mut V) -> Result<()> {
+    pub async fn exec<V: ViewClient>(&self, view: &mut V, output: OutputFormat) -> Result<()> {
         let asset_cache = view.assets().await?;
 
         // Initialize the table
@@ -53,6 +55,19 @@ impl BalanceCmd {
                  */
                 ;
 
+            if output == OutputFormat::Json {
+                let entries = rows
+                    .map(|(index, value, source, return_address)| NoteEntry {
+                        account: index,
+                        value,
+                        display: value.format(&asset_cache),
+                        source,
+                        return_address,
+                    })
+                    .collect::<Vec<_>>();
+                return print_json(&entries);
+            }
+
             for (index, value, source, return_address) in rows {
                 table.add_row(vec![
                     format!("# {}", index),
@@ -86,6 +101,17 @@ impl BalanceCmd {
                     Some(denom) => !denom.is_withdrawn_position_nft(),
                 });
 
+            if output == OutputFormat::Json {
+                let entries = rows
+                    .map(|(index, value)| BalanceEntry {
+                        account: index,
+                        value,
+                        display: value.format(&asset_cache),
+                    })
+                    .collect::<Vec<_>>();
+                return print_json(&entries);
+            }
+
             for (index, value) in rows {
                 table.add_row(vec![format!("# {}", index), value.format(&asset_cache)]);
             }
@@ -0,0 +1,232 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+use comfy_table::{presets, Table};
+use tonic::transport::Channel;
+
+use penumbra_asset::{asset, Value};
+use penumbra_dex::{CandlestickData, DirectedTradingPair};
+use penumbra_num::Amount;
+use penumbra_proto::core::component::dex::v1::{
+    query_service_client::QueryServiceClient as DexQueryServiceClient, CandlestickDataRequest,
+};
+use penumbra_view::{
+    cost_basis::{self, AccountingMethod, RealizedGain, TaxDisposal, TaxLot},
+    ViewClient,
+};
+
+/// How far back to look for a candlestick when pricing a lot or disposal, in blocks.
+const CANDLESTICK_LOOKBACK: u64 = 20_000;
+
+#[derive(Clone, clap::ValueEnum, Debug, Default)]
+pub enum HistoryFormat {
+    #[default]
+    Table,
+    Csv,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct HistoryCmd {
+    /// The accounting method used to match disposals against acquisition lots.
+    #[clap(long, default_value = "fifo")]
+    pub method: AccountingMethod,
+    /// The denomination that costs and proceeds are measured in.
+    ///
+    /// Lots acquired, or disposals made, in exchange for other assets, or for
+    /// nothing, are valued at the DEX closing price of the last block that
+    /// traded them against the numeraire, and reported with an unknown basis or
+    /// proceeds if there is none.
+    #[clap(long, default_value = "upenumbra")]
+    pub numeraire: String,
+    /// Don't estimate unknown costs and proceeds from DEX market prices.
+    #[clap(long)]
+    pub no_market_prices: bool,
+    /// Only report gains realized on this denomination.
+    #[clap(long)]
+    pub asset: Option<String>,
+    /// The format to print the realized gains in.
+    #[clap(long, value_enum, default_value_t)]
+    pub format: HistoryFormat,
+}
+
+impl HistoryCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec<V: ViewClient>(&self, view: &mut V, pd_channel: Channel) -> Result<()> {
+        let numeraire = asset::REGISTRY.parse_unit(&self.numeraire).base();
+        let asset_filter = self
+            .asset
+            .as_ref()
+            .map(|denom| asset::REGISTRY.parse_unit(denom).id());
+
+        let asset_cache = view.assets().await?;
+        let mut lots = view.tax_lots(asset_filter).await?;
+        let mut disposals = view.tax_disposals(asset_filter).await?;
+
+        if !self.no_market_prices {
+            let prices = market_prices(pd_channel, &lots, &disposals, numeraire.id()).await?;
+            cost_basis::apply_market_prices(
+                &mut lots,
+                &mut disposals,
+                numeraire.id(),
+                |asset_id, height| prices.get(&(asset_id, height)).copied(),
+            );
+        }
+
+        let gains = cost_basis::realized_gains(lots, disposals, self.method, numeraire.id())?;
+
+        let denom = |id: &asset::Id| {
+            asset_cache
+                .get(id)
+                .map(|metadata| metadata.base_denom().denom)
+                .unwrap_or_else(|| id.to_string())
+        };
+        let optional = |amount: Option<Amount>| amount.map(|a| a.to_string()).unwrap_or_default();
+
+        match self.format {
+            HistoryFormat::Csv => {
+                println!(
+                    "asset,amount,height_acquired,height_disposed,basis,proceeds,gain,numeraire"
+                );
+                for gain in &gains {
+                    println!(
+                        "{},{},{},{},{},{},{},{}",
+                        denom(&gain.asset_id),
+                        gain.amount,
+                        gain.height_acquired
+                            .map(|h| h.to_string())
+                            .unwrap_or_default(),
+                        gain.height_disposed,
+                        optional(gain.basis),
+                        optional(gain.proceeds),
+                        gain.gain().map(|g| g.to_string()).unwrap_or_default(),
+                        numeraire.base_denom().denom,
+                    );
+                }
+            }
+            HistoryFormat::Table => {
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec![
+                    "Disposed", "Acquired", "Value", "Basis", "Proceeds", "Gain",
+                ]);
+
+                let format_numeraire = |amount: Option<Amount>| {
+                    amount
+                        .map(|amount| {
+                            Value {
+                                amount,
+                                asset_id: numeraire.id(),
+                            }
+                            .format(&asset_cache)
+                        })
+                        .unwrap_or_else(|| "unknown".to_string())
+                };
+
+                for gain in &gains {
+                    table.add_row(vec![
+                        gain.height_disposed.to_string(),
+                        gain.height_acquired
+                            .map(|h| h.to_string())
+                            .unwrap_or_else(|| "unknown".to_string()),
+                        Value {
+                            amount: gain.amount,
+                            asset_id: gain.asset_id,
+                        }
+                        .format(&asset_cache),
+                        format_numeraire(gain.basis),
+                        format_numeraire(gain.proceeds),
+                        format_gain(gain, &numeraire),
+                    ]);
+                }
+
+                println!("{table}");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Fetches the market prices, in units of the numeraire, of the assets that
+/// the costs and proceeds of `lots` and `disposals` were paid in, at the heights
+/// they were paid at.
+async fn market_prices(
+    pd_channel: Channel,
+    lots: &[TaxLot],
+    disposals: &[TaxDisposal],
+    numeraire: asset::Id,
+) -> Result<BTreeMap<(asset::Id, u64), f64>> {
+    let wanted: BTreeSet<(asset::Id, u64)> = lots
+        .iter()
+        .map(|lot| (lot.cost.unwrap_or(lot.value).asset_id, lot.height_acquired))
+        .chain(disposals.iter().map(|disposal| {
+            (
+                disposal.proceeds.unwrap_or(disposal.value).asset_id,
+                disposal.height_disposed,
+            )
+        }))
+        .filter(|(asset_id, _)| *asset_id != numeraire)
+        .collect();
+
+    let mut client = DexQueryServiceClient::new(pd_channel);
+    let mut prices = BTreeMap::new();
+    for (asset_id, height) in wanted {
+        // Prefer trades selling the asset for the numeraire, and fall back to
+        // the inverse of trades buying it.
+        let direct = cost_basis::candlestick_price(
+            &candlesticks(&mut client, asset_id, numeraire, height).await?,
+            height,
+        );
+        let price = match direct {
+            Some(price) => Some(price),
+            None => cost_basis::candlestick_price(
+                &candlesticks(&mut client, numeraire, asset_id, height).await?,
+                height,
+            )
+            .filter(|price| *price > 0.0)
+            .map(|price| 1.0 / price),
+        };
+        if let Some(price) = price {
+            prices.insert((asset_id, height), price);
+        }
+    }
+
+    Ok(prices)
+}
+
+/// Fetches the per-block candlesticks of the pair selling `start` for `end`,
+/// in the window of [`CANDLESTICK_LOOKBACK`] blocks ending at `height`.
+async fn candlesticks(
+    client: &mut DexQueryServiceClient<Channel>,
+    start: asset::Id,
+    end: asset::Id,
+    height: u64,
+) -> Result<Vec<CandlestickData>> {
+    client
+        .candlestick_data(CandlestickDataRequest {
+            pair: Some(DirectedTradingPair::new(start, end).into()),
+            limit: CANDLESTICK_LOOKBACK,
+            start_height: height.saturating_sub(CANDLESTICK_LOOKBACK - 1).max(1),
+            ..Default::default()
+        })
+        .await?
+        .into_inner()
+        .data
+        .into_iter()
+        .map(CandlestickData::try_from)
+        .collect()
+}
+
+fn format_gain(gain: &RealizedGain, numeraire: &asset::Metadata) -> String {
+    match gain.gain() {
+        None => "unknown".to_string(),
+        Some(g) if g < 0 => format!(
+            "-{}",
+            numeraire.format_value(Amount::from(g.unsigned_abs()))
+        ),
+        Some(g) => numeraire.format_value(Amount::from(g.unsigned_abs())),
+    }
+}
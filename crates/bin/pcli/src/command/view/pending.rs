@@ -0,0 +1,43 @@
+use anyhow::Result;
+use comfy_table::{presets, Table};
+use penumbra_view::ViewClient;
+
+#[derive(Debug, clap::Args)]
+pub struct PendingCmd {}
+
+impl PendingCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec<V: ViewClient>(&self, view: &mut V) -> Result<()> {
+        let pending = view.pending_transactions().await?;
+
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec![
+            "Transaction Hash",
+            "Submitted",
+            "Last Broadcast",
+            "Expires",
+        ]);
+
+        for pending_transaction in pending {
+            // Transactions that never expire are only tracked until they time out.
+            let expiry_height = match pending_transaction.expiry_height() {
+                0 => format!("{} (timeout)", pending_transaction.timeout_height()),
+                height => height.to_string(),
+            };
+            table.add_row(vec![
+                pending_transaction.id().to_string(),
+                pending_transaction.submitted_height.to_string(),
+                pending_transaction.last_broadcast_height.to_string(),
+                expiry_height,
+            ]);
+        }
+
+        println!("{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use comfy_table::{presets, Table};
+use futures::TryStreamExt;
+use tonic::transport::Channel;
+
+use penumbra_asset::{asset, Value};
+use penumbra_dex::lp::{
+    position::{self, Position},
+    PositionExecution,
+};
+use penumbra_num::Amount;
+use penumbra_proto::core::component::dex::v1::{
+    query_service_client::QueryServiceClient as DexQueryServiceClient,
+    LiquidityPositionByIdRequest, PositionExecutionsRequest,
+};
+use penumbra_view::ViewClient;
+
+use crate::output::{print_json, OutputFormat, PositionExecutionEntry, PositionHistoryDocument};
+
+#[derive(Debug, clap::Args)]
+pub struct PositionHistoryCmd {
+    /// The positions to report on. Defaults to all the positions opened by the wallet.
+    pub position_ids: Vec<position::Id>,
+    /// Only report executions occurring at or after this height.
+    #[clap(long, default_value = "0")]
+    pub start_height: u64,
+}
+
+impl PositionHistoryCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(
+        &self,
+        view_client: &mut impl ViewClient,
+        pd_channel: Channel,
+        output: OutputFormat,
+    ) -> Result<()> {
+        let asset_cache = view_client.assets().await?;
+        let position_ids = if self.position_ids.is_empty() {
+            view_client.owned_position_ids(None, None).await?
+        } else {
+            self.position_ids.clone()
+        };
+
+        let mut client = DexQueryServiceClient::new(pd_channel);
+        let mut documents = Vec::new();
+        for position_id in position_ids {
+            let position: Position = client
+                .liquidity_position_by_id(LiquidityPositionByIdRequest {
+                    position_id: Some(position_id.into()),
+                })
+                .await?
+                .into_inner()
+                .data
+                .context("position not found")?
+                .try_into()?;
+
+            let executions = client
+                .position_executions(PositionExecutionsRequest {
+                    position_id: Some(position_id.into()),
+                    start_height: self.start_height,
+                    end_height: 0,
+                })
+                .await?
+                .into_inner()
+                .map_err(anyhow::Error::from)
+                .and_then(|response| async move {
+                    PositionExecution::try_from(
+                        response
+                            .execution
+                            .context("missing execution in response")?,
+                    )
+                })
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            documents.push(position_history(&asset_cache, &position, &executions)?);
+        }
+
+        if output == OutputFormat::Json {
+            return print_json(&documents);
+        }
+
+        for document in documents {
+            println!("Position {}", document.position_id);
+            if document.executions.is_empty() {
+                println!("  no executions");
+                continue;
+            }
+
+            let mut table = Table::new();
+            table.load_preset(presets::NOTHING);
+            table.set_header(vec!["Height", "Input", "Output", "Fee"]);
+            for execution in &document.executions {
+                table.add_row(vec![
+                    execution.height.to_string(),
+                    execution.input.format(&asset_cache),
+                    execution.output.format(&asset_cache),
+                    execution.fee.format(&asset_cache),
+                ]);
+            }
+            println!("{table}");
+
+            let fees = document
+                .fees
+                .iter()
+                .map(|fee| fee.format(&asset_cache))
+                .collect::<Vec<_>>()
+                .join(", ");
+            println!("Fees earned:   {fees}");
+            println!(
+                "Reserves:      {}, {}",
+                document.reserves_change_display[0], document.reserves_change_display[1]
+            );
+            println!("Realized PnL:  {}", document.realized_pnl_display);
+            println!();
+        }
+
+        Ok(())
+    }
+}
+
+/// Summarizes the executions against a position.
+///
+/// The realized PnL is the change in the position's reserves, valued in asset 1
+/// at the position's own price. Since a position trades at a fixed price, this
+/// is the value of the fees it has earned.
+fn position_history(
+    asset_cache: &asset::Cache,
+    position: &Position,
+    executions: &[PositionExecution],
+) -> Result<PositionHistoryDocument> {
+    let pair = position.phi.pair;
+    let mut fees = BTreeMap::<asset::Id, Amount>::new();
+    let mut change_1 = 0i128;
+    let mut change_2 = 0i128;
+    let mut entries = Vec::new();
+
+    for execution in executions {
+        let fee = Value {
+            amount: execution.fee,
+            asset_id: execution.direction.start,
+        };
+        *fees.entry(fee.asset_id).or_default() += fee.amount;
+
+        change_1 += signed(execution.reserves.r1)? - signed(execution.prev_reserves.r1)?;
+        change_2 += signed(execution.reserves.r2)? - signed(execution.prev_reserves.r2)?;
+
+        entries.push(PositionExecutionEntry {
+            height: execution.height,
+            input: execution.input(),
+            output: execution.output(),
+            fee,
+        });
+    }
+
+    // With the trading function `p * r1 + q * r2`, a unit of asset 2 is worth
+    // `q / p` units of asset 1.
+    let p = signed(position.phi.component.p)?;
+    let q = signed(position.phi.component.q)?;
+    let realized_pnl = change_2
+        .checked_mul(q)
+        .map(|value| change_1 + value / p)
+        .context("overflow computing realized PnL")?;
+
+    Ok(PositionHistoryDocument {
+        position_id: position.id(),
+        executions: entries,
+        fees: fees
+            .into_iter()
+            .map(|(asset_id, amount)| Value { amount, asset_id })
+            .collect(),
+        reserves_change: [change_1.to_string(), change_2.to_string()],
+        reserves_change_display: [
+            format_signed(asset_cache, change_1, pair.asset_1()),
+            format_signed(asset_cache, change_2, pair.asset_2()),
+        ],
+        realized_pnl: realized_pnl.to_string(),
+        realized_pnl_display: format_signed(asset_cache, realized_pnl, pair.asset_1()),
+    })
+}
+
+fn signed(amount: Amount) -> Result<i128> {
+    i128::try_from(amount.value()).context("amount too large")
+}
+
+fn format_signed(asset_cache: &asset::Cache, amount: i128, asset_id: asset::Id) -> String {
+    let value = Value {
+        amount: Amount::from(amount.unsigned_abs()),
+        asset_id,
+    };
+    if amount < 0 {
+        format!("-{}", value.format(asset_cache))
+    } else {
+        value.format(asset_cache)
+    }
+}
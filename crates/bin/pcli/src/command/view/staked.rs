@@ -13,6 +13,8 @@ use penumbra_proto::core::component::stake::v1::{
 use penumbra_stake::{validator, DelegationToken};
 use penumbra_view::ViewClient;
 
+use crate::output::{print_json, DelegationEntry, OutputFormat, StakedDocument};
+
 #[derive(Debug, clap::Parser)]
 pub struct StakedCmd {}
 
@@ -26,6 +28,7 @@ impl StakedCmd {
         _fvk: &FullViewingKey,
         view_client: &mut impl ViewClient,
         pd_channel: Channel,
+        output: OutputFormat,
     ) -> Result<()> {
         let asset_cache = view_client.assets().await?;
 
@@ -46,6 +49,7 @@ impl StakedCmd {
 
         let notes = view_client.unspent_notes_by_asset_and_address().await?;
         let mut total = 0u128;
+        let mut delegations = Vec::new();
 
         let mut table = Table::new();
         table.load_preset(presets::NOTHING);
@@ -79,6 +83,14 @@ impl StakedCmd {
             {
                 Some(info) => info,
                 None => {
+                    delegations.push(DelegationEntry {
+                        identity_key: dt.validator(),
+                        name: None,
+                        tokens: delegation,
+                        unbonded: None,
+                        exchange_rate: None,
+                        display: delegation.format(&asset_cache),
+                    });
                     table.add_row(vec![
                         "missing data".to_string(),
                         "missing data".to_string(),
@@ -103,6 +115,14 @@ impl StakedCmd {
                 validator_exchange_rate / 1_0000_0000.0
             };
 
+            delegations.push(DelegationEntry {
+                identity_key: dt.validator(),
+                name: Some(info.validator.name.clone()),
+                tokens: delegation,
+                unbonded: Some(unbonded),
+                exchange_rate: Some(rate),
+                display: unbonded.format(&asset_cache),
+            });
             table.add_row(vec![
                 info.validator.name.clone(),
                 unbonded.format(&asset_cache),
@@ -138,6 +158,15 @@ impl StakedCmd {
             asset_id: *STAKING_TOKEN_ASSET_ID,
         };
 
+        if output == OutputFormat::Json {
+            return print_json(&StakedDocument {
+                delegations,
+                unbonded,
+                total,
+                total_display: total.format(&asset_cache),
+            });
+        }
+
         table.add_row(vec![
             "Total".to_string(),
             total.format(&asset_cache),
@@ -3,7 +3,10 @@ use penumbra_proto::{util::tendermint_proxy::v1::GetTxRequest, DomainType};
 use penumbra_transaction::Transaction;
 use penumbra_view::{TransactionInfo, ViewClient};
 
-use crate::App;
+use crate::{
+    output::{print_json, OutputFormat, TransactionDocument},
+    App,
+};
 
 /// Queries the chain for a transaction by hash.
 #[derive(Debug, clap::Args)]
@@ -31,7 +34,7 @@ impl TxCmd {
         let tx_info = if let Ok(tx_info) = app.view().transaction_info_by_hash(hash).await {
             tx_info
         } else {
-            if !self.raw {
+            if !self.raw && app.output == OutputFormat::Text {
                 println!("Transaction not found in view service, fetching from fullnode...");
             } else {
                 tracing::info!("Transaction not found in view service, fetching from fullnode...");
@@ -59,7 +62,13 @@ impl TxCmd {
             }
         };
 
-        if self.raw {
+        if app.output == OutputFormat::Json {
+            print_json(&TransactionDocument {
+                hash: tx_info.id.to_string(),
+                height: tx_info.height,
+                view: tx_info.view,
+            })?;
+        } else if self.raw {
             use colored_json::prelude::*;
             println!(
                 "{}",
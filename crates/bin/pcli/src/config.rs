@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use penumbra_stake::GovernanceKey;
@@ -7,8 +7,8 @@ use serde_with::{serde_as, DisplayFromStr};
 use url::Url;
 
 use penumbra_custody::{
-    encrypted::Config as EncryptedConfig, soft_kms::Config as SoftKmsConfig,
-    threshold::Config as ThresholdConfig,
+    encrypted::Config as EncryptedConfig, external::Config as ExternalConfig,
+    soft_kms::Config as SoftKmsConfig, threshold::Config as ThresholdConfig,
 };
 use penumbra_keys::FullViewingKey;
 
@@ -30,6 +30,14 @@ pub struct PcliConfig {
     pub custody: CustodyConfig,
     /// The governance custody backend to use.
     pub governance_custody: Option<GovernanceCustodyConfig>,
+    /// If set, exchange threshold signing messages through this coordinator,
+    /// rather than by copying and pasting them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold_coordinator: Option<Url>,
+    /// If set, record the outcome of every threshold signing request in an
+    /// audit log at this path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold_audit_log: Option<PathBuf>,
 }
 
 impl PcliConfig {
@@ -73,6 +81,8 @@ pub enum CustodyConfig {
     Threshold(ThresholdConfig),
     /// An encrypted custody service.
     Encrypted(EncryptedConfig),
+    /// A separate signer process, reached over a Unix socket or stdio.
+    External(ExternalConfig),
 }
 
 /// The governance custody backend to use.
@@ -123,6 +133,8 @@ mod tests {
                 penumbra_keys::test_keys::SPEND_KEY.clone(),
             )),
             governance_custody: None,
+            threshold_coordinator: None,
+            threshold_audit_log: None,
         };
 
         let mut config2 = config.clone();
@@ -2,6 +2,10 @@
 /// The acceptable amount of difference between a value and its approximation.
 const APPROXIMATION_TOLERANCE: f64 = 1e-8;
 
+pub mod concentrated;
+pub mod ladder;
+pub mod linear;
+pub mod stableswap;
 pub mod xyk;
 pub mod balancer {}
 pub mod volatility {}
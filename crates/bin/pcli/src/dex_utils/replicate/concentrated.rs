@@ -0,0 +1,59 @@
+use penumbra_asset::Value;
+use penumbra_dex::{lp::position::Position, DirectedUnitPair};
+
+use crate::dex_utils::replicate::{
+    ladder::{self, Curve},
+    math_utils,
+};
+
+/// Concentrated liquidity, as provided by a constant-product market whose
+/// reserves are entirely traded across the price range `[lower, upper]`.
+///
+/// With unit liquidity, the reserves at a price `P` in the range are
+/// `1/sqrt(P) - 1/sqrt(upper)` of the start asset and `sqrt(P) - sqrt(lower)`
+/// of the end asset.
+#[derive(Clone, Copy, Debug)]
+pub struct Concentrated {
+    pub lower: f64,
+    pub upper: f64,
+}
+
+impl Concentrated {
+    /// Splits the range into `num_positions` bins of equal relative width.
+    pub fn boundaries(&self, num_positions: usize) -> Vec<f64> {
+        math_utils::sample_geometric(self.lower, self.upper, num_positions)
+    }
+
+    /// The curve's invariant, which is zero for reserves on the curve.
+    pub fn invariant(&self, r1: f64, r2: f64, liquidity: f64) -> f64 {
+        (r1 + liquidity / self.upper.sqrt()) * (r2 + liquidity * self.lower.sqrt())
+            - liquidity * liquidity
+    }
+
+    fn clamp(&self, price: f64) -> f64 {
+        price.clamp(self.lower, self.upper)
+    }
+}
+
+impl Curve for Concentrated {
+    fn reserves_1(&self, price: f64) -> f64 {
+        1.0 / self.clamp(price).sqrt() - 1.0 / self.upper.sqrt()
+    }
+
+    fn reserves_2(&self, price: f64) -> f64 {
+        self.clamp(price).sqrt() - self.lower.sqrt()
+    }
+}
+
+#[tracing::instrument(name = "replicate_concentrated")]
+pub fn replicate(
+    pair: &DirectedUnitPair,
+    input: &Value,
+    current_price: f64,
+    curve: Concentrated,
+    num_positions: usize,
+    fee_bps: u32,
+) -> anyhow::Result<Vec<Position>> {
+    let boundaries = curve.boundaries(num_positions);
+    ladder::replicate(&curve, &boundaries, pair, input, current_price, fee_bps)
+}
@@ -0,0 +1,196 @@
+//! Replication of arbitrary trading curves with ladders of limit orders.
+//!
+//! A curve is described by the reserves it holds at each price. Between two
+//! prices, the curve trades the difference in its reserves at an average price,
+//! so a ladder of positions, one per price bin, holds exactly the same reserves
+//! as the curve whenever the market price sits on a bin boundary.
+use anyhow::{anyhow, Context};
+use penumbra_asset::Value;
+use penumbra_dex::{
+    lp::{position::Position, Reserves},
+    DirectedUnitPair,
+};
+use penumbra_num::{fixpoint::U128x128, Amount};
+use rand_core::OsRng;
+
+/// A trading curve, described by the reserves it holds at each price.
+///
+/// Prices are quoted in units of the end asset per unit of the start asset, and
+/// reserves are in display units. Curves are only defined up to a scale factor.
+pub trait Curve {
+    /// The reserves of the start asset held at `price`, which decrease as the
+    /// price rises and the curve sells the start asset.
+    fn reserves_1(&self, price: f64) -> f64;
+
+    /// The reserves of the end asset held at `price`, which increase as the
+    /// price rises.
+    fn reserves_2(&self, price: f64) -> f64;
+}
+
+/// A rung of a ladder, in display units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tick {
+    /// The price of the start asset, in units of the end asset.
+    pub price: f64,
+    /// The reserves of the start asset, sold as the price rises past the tick.
+    pub r1: f64,
+    /// The reserves of the end asset, used to buy the start asset as the price
+    /// falls past the tick.
+    pub r2: f64,
+}
+
+/// Computes the ladder replicating `curve` at `current_price`, with one tick per
+/// bin between consecutive `boundaries`.
+///
+/// Bins above the current price sell the start asset, and bins below buy it,
+/// with the bin containing the current price split in two. Each tick is priced
+/// at the average price the curve trades at across its bin. The ladder does not
+/// hold the reserves the curve trades outside of the boundaries.
+pub fn ticks(curve: &impl Curve, boundaries: &[f64], current_price: f64) -> Vec<Tick> {
+    let mut boundaries = boundaries.to_vec();
+    boundaries.sort_by(f64::total_cmp);
+    boundaries.dedup();
+
+    // The current price splits the bin containing it, but doesn't extend the ladder.
+    let (lowest, highest) = (boundaries[0], boundaries[boundaries.len() - 1]);
+    if lowest < current_price && current_price < highest {
+        boundaries.push(current_price);
+        boundaries.sort_by(f64::total_cmp);
+        boundaries.dedup();
+    }
+
+    boundaries
+        .windows(2)
+        .filter_map(|bin| {
+            let (low, high) = (bin[0], bin[1]);
+            let r1 = curve.reserves_1(low) - curve.reserves_1(high);
+            let r2 = curve.reserves_2(high) - curve.reserves_2(low);
+            if r1 <= 0.0 || r2 <= 0.0 {
+                return None;
+            }
+
+            // The average price is inside the bin, up to rounding errors.
+            let price = (r2 / r1).clamp(low, high);
+            if low >= current_price {
+                Some(Tick { price, r1, r2: 0.0 })
+            } else {
+                Some(Tick { price, r1: 0.0, r2 })
+            }
+        })
+        .collect()
+}
+
+/// Computes the reserves held by a ladder once the market price has moved to `price`.
+///
+/// Ticks above `price` hold the start asset, and ticks below it hold the end asset.
+pub fn holdings(ticks: &[Tick], price: f64) -> (f64, f64) {
+    ticks.iter().fold((0.0, 0.0), |(r1, r2), tick| {
+        if tick.price > price {
+            (r1 + tick.r1 + tick.r2 / tick.price, r2)
+        } else {
+            (r1, r2 + tick.r2 + tick.r1 * tick.price)
+        }
+    })
+}
+
+/// Replicates `curve` around `current_price`, sized so that the ladder holds
+/// `input` of one of the assets of `pair`.
+///
+/// The ladder's other reserves are whatever the curve holds of the other asset
+/// at the current price.
+pub fn replicate(
+    curve: &impl Curve,
+    boundaries: &[f64],
+    pair: &DirectedUnitPair,
+    input: &Value,
+    current_price: f64,
+    fee_bps: u32,
+) -> anyhow::Result<Vec<Position>> {
+    let ticks = ticks(curve, boundaries, current_price);
+    let (r1, r2): (f64, f64) = (
+        ticks.iter().map(|tick| tick.r1).sum(),
+        ticks.iter().map(|tick| tick.r2).sum(),
+    );
+
+    let (held, unit) = if input.asset_id == pair.start.id() {
+        (r1, &pair.start)
+    } else if input.asset_id == pair.end.id() {
+        (r2, &pair.end)
+    } else {
+        anyhow::bail!("the supplied liquidity must be on the pair")
+    };
+    if held <= 0.0 {
+        anyhow::bail!(
+            "at the current price, the ladder holds no {}, supply liquidity in the other asset of the pair",
+            unit
+        );
+    }
+
+    let amount: f64 = U128x128::ratio(input.amount.value(), unit.unit_amount().value())
+        .context("denom unit is not 0")?
+        .into();
+    let scale = amount / held;
+
+    let ticks: Vec<Tick> = ticks
+        .into_iter()
+        .map(|tick| Tick {
+            price: tick.price,
+            r1: tick.r1 * scale,
+            r2: tick.r2 * scale,
+        })
+        .collect();
+
+    positions(pair, &ticks, fee_bps)
+}
+
+/// Converts the ticks of a ladder into liquidity positions.
+///
+/// Ticks whose reserves round down to zero are dropped.
+pub fn positions(
+    pair: &DirectedUnitPair,
+    ticks: &[Tick],
+    fee_bps: u32,
+) -> anyhow::Result<Vec<Position>> {
+    let unit_start = pair.start.unit_amount();
+    let unit_end = pair.end.unit_amount();
+
+    let mut positions = Vec::with_capacity(ticks.len());
+    for tick in ticks {
+        let r1 = to_amount(tick.r1, unit_start)?;
+        let r2 = to_amount(tick.r2, unit_end)?;
+        if r1 == Amount::zero() && r2 == Amount::zero() {
+            continue;
+        }
+
+        // As with the xyk replication, the trading function `p * r1 + q * r2`
+        // quotes the start asset at `p / q` units of the end asset.
+        let p = to_amount(tick.price, unit_end)?;
+        if p == Amount::zero() {
+            anyhow::bail!("the price {} is too small to be represented", tick.price);
+        }
+        let q = unit_start;
+
+        positions.push(Position::new(
+            OsRng,
+            pair.into_directed_trading_pair(),
+            fee_bps,
+            p,
+            q,
+            Reserves { r1, r2 },
+        ));
+    }
+
+    Ok(positions)
+}
+
+/// Scales a quantity in display units into base units, rounding down.
+fn to_amount(value: f64, unit_amount: Amount) -> anyhow::Result<Amount> {
+    let value: U128x128 = value
+        .try_into()
+        .map_err(|_| anyhow!("cannot represent {value} as a fixed-point number"))?;
+    let scaled = (value * U128x128::from(unit_amount)).context("overflow scaling quantity")?;
+    scaled
+        .round_down()
+        .try_into()
+        .map_err(|_| anyhow!("{value} is too large to be represented as an amount"))
+}
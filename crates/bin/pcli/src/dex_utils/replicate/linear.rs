@@ -0,0 +1,55 @@
+use penumbra_asset::Value;
+use penumbra_dex::{lp::position::Position, DirectedUnitPair};
+
+use crate::dex_utils::replicate::{
+    ladder::{self, Curve},
+    math_utils,
+};
+
+/// A linear range order, which sells the start asset at a constant rate as the
+/// price rises from `lower` to `upper`.
+///
+/// With unit reserves, the reserves at a price `P` in the range are
+/// `(upper - P) / (upper - lower)` of the start asset and
+/// `(P^2 - lower^2) / (2 * (upper - lower))` of the end asset.
+#[derive(Clone, Copy, Debug)]
+pub struct Linear {
+    pub lower: f64,
+    pub upper: f64,
+}
+
+impl Linear {
+    /// Splits the range into `num_positions` bins of equal width, each trading
+    /// the same amount of the start asset.
+    pub fn boundaries(&self, num_positions: usize) -> Vec<f64> {
+        math_utils::sample_linear(self.lower, self.upper, num_positions)
+    }
+
+    fn clamp(&self, price: f64) -> f64 {
+        price.clamp(self.lower, self.upper)
+    }
+}
+
+impl Curve for Linear {
+    fn reserves_1(&self, price: f64) -> f64 {
+        (self.upper - self.clamp(price)) / (self.upper - self.lower)
+    }
+
+    fn reserves_2(&self, price: f64) -> f64 {
+        let price = self.clamp(price);
+        (price * price - self.lower * self.lower) / (2.0 * (self.upper - self.lower))
+    }
+}
+
+#[tracing::instrument(name = "replicate_linear")]
+pub fn replicate(
+    pair: &DirectedUnitPair,
+    input: &Value,
+    current_price: f64,
+    curve: Linear,
+    num_positions: usize,
+    fee_bps: u32,
+) -> anyhow::Result<Vec<Position>> {
+    let boundaries = curve.boundaries(num_positions);
+    ladder::replicate(&curve, &boundaries, pair, input, current_price, fee_bps)
+}
@@ -63,3 +63,40 @@ pub(crate) fn sample_to_upper(upper: f64, num_points: usize) -> Vec<f64> {
 
     (1..=num_points).map(|i| (i as f64) * step).collect()
 }
+
+/// Sample `num_points + 1` evenly spaced points from `lower` to `upper`, inclusive.
+pub(crate) fn sample_linear(lower: f64, upper: f64, num_points: usize) -> Vec<f64> {
+    let step = (upper - lower) / (num_points as f64);
+
+    (0..=num_points)
+        .map(|i| lower + (i as f64) * step)
+        .collect()
+}
+
+/// Sample `num_points + 1` geometrically spaced points from `lower` to `upper`, inclusive.
+pub(crate) fn sample_geometric(lower: f64, upper: f64, num_points: usize) -> Vec<f64> {
+    let ratio = (upper / lower).powf(1.0 / (num_points as f64));
+
+    (0..=num_points)
+        .map(|i| lower * ratio.powi(i as i32))
+        .collect()
+}
+
+/// Finds a root of the monotonic function `f` between `lower` and `upper` by bisection.
+///
+/// If `f` has no root in the interval, this returns the endpoint closest to one.
+pub(crate) fn bisect(f: impl Fn(f64) -> f64, lower: f64, upper: f64, iterations: usize) -> f64 {
+    let (mut lo, mut hi) = (lower, upper);
+    let increasing = f(lo) < f(hi);
+
+    for _ in 0..iterations {
+        let mid = (lo + hi) / 2.0;
+        if (f(mid) < 0.0) == increasing {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    (lo + hi) / 2.0
+}
@@ -0,0 +1,105 @@
+use penumbra_asset::Value;
+use penumbra_dex::{lp::position::Position, DirectedUnitPair};
+
+use crate::dex_utils::replicate::{
+    ladder::{self, Curve},
+    math_utils,
+};
+
+/// The number of bisection steps used to find the reserves at a given price.
+const BISECTION_ITERATIONS: usize = 200;
+
+/// A two-asset StableSwap curve, for assets pegged at `peg` units of the end
+/// asset per unit of the start asset.
+///
+/// With `x` the reserves of the start asset, `y` the reserves of the end asset
+/// in units of the start asset, and `D = 1`, the curve's invariant is
+/// `4A(x + y) + D = 4AD + D^3 / (4xy)`, where `A` is the amplification. The
+/// larger the amplification, the more the curve's liquidity is concentrated
+/// around the peg.
+#[derive(Clone, Copy, Debug)]
+pub struct StableSwap {
+    pub amplification: f64,
+    pub peg: f64,
+}
+
+impl StableSwap {
+    /// Splits the prices within `range` of the peg, as a fraction of the peg,
+    /// into `num_positions` bins of equal relative width.
+    pub fn boundaries(&self, range: f64, num_positions: usize) -> Vec<f64> {
+        math_utils::sample_geometric(
+            self.peg * (1.0 - range),
+            self.peg * (1.0 + range),
+            num_positions,
+        )
+    }
+
+    /// The curve's invariant, which is zero for reserves on the curve.
+    ///
+    /// Here, `y` is in units of the start asset, rather than the end asset.
+    pub fn invariant(&self, x: f64, y: f64) -> f64 {
+        let a = 4.0 * self.amplification;
+        a * (x + y) + 1.0 - a - 1.0 / (4.0 * x * y)
+    }
+
+    /// Solves the invariant for `y`, in units of the start asset, given `x`.
+    pub fn y(&self, x: f64) -> f64 {
+        // Multiplying the invariant by `4xy` gives a quadratic in `y`, with a
+        // single positive root, computed so as to avoid cancellation.
+        let a = 16.0 * self.amplification * x;
+        let b = 16.0 * self.amplification * x * x + 4.0 * x - 16.0 * self.amplification * x;
+        let discriminant = (b * b + 4.0 * a).sqrt();
+        if b >= 0.0 {
+            2.0 / (b + discriminant)
+        } else {
+            (discriminant - b) / (2.0 * a)
+        }
+    }
+
+    /// The marginal price of the start asset, in units of the end asset, when
+    /// the curve holds `x` of the start asset.
+    pub fn price(&self, x: f64) -> f64 {
+        let y = self.y(x);
+        let a = 4.0 * self.amplification;
+        let d_x = a + 1.0 / (4.0 * x * x * y);
+        let d_y = a + 1.0 / (4.0 * x * y * y);
+        self.peg * d_x / d_y
+    }
+
+    /// The reserves of the start asset at which the marginal price is `price`.
+    fn x(&self, price: f64) -> f64 {
+        // The price decreases as the reserves of the start asset increase, so we
+        // bisect over the logarithm of the reserves.
+        let ln_x = math_utils::bisect(
+            |ln_x| self.price(ln_x.exp()) - price,
+            -30.0,
+            10.0,
+            BISECTION_ITERATIONS,
+        );
+        ln_x.exp()
+    }
+}
+
+impl Curve for StableSwap {
+    fn reserves_1(&self, price: f64) -> f64 {
+        self.x(price)
+    }
+
+    fn reserves_2(&self, price: f64) -> f64 {
+        self.peg * self.y(self.x(price))
+    }
+}
+
+#[tracing::instrument(name = "replicate_stableswap")]
+pub fn replicate(
+    pair: &DirectedUnitPair,
+    input: &Value,
+    current_price: f64,
+    curve: StableSwap,
+    range: f64,
+    num_positions: usize,
+    fee_bps: u32,
+) -> anyhow::Result<Vec<Position>> {
+    let boundaries = curve.boundaries(range, num_positions);
+    ladder::replicate(&curve, &boundaries, pair, input, current_price, fee_bps)
+}
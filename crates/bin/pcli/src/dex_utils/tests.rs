@@ -1,5 +1,18 @@
-use crate::dex_utils::replicate::xyk;
+use penumbra_asset::Value;
+use penumbra_dex::DirectedUnitPair;
+use penumbra_num::Amount;
+use proptest::prelude::*;
+
+use crate::dex_utils::replicate::{
+    concentrated::{self, Concentrated},
+    ladder::{self, Curve, Tick},
+    linear::{self, Linear},
+    stableswap::{self, StableSwap},
+    xyk,
+};
 const PRECISION_BOUND: f64 = 0.0001;
+/// The acceptable difference between a ladder and the curve it replicates.
+const LADDER_PRECISION_BOUND: f64 = 1e-9;
 
 fn approx_eq(a: f64, b: f64) -> bool {
     let ab_abs_diff = f64::abs(a - b);
@@ -35,3 +48,175 @@ fn test_xyk_solver() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Checks that every tick is priced inside the range it replicates, sells the
+/// start asset above the current price, and buys it below.
+fn check_ticks(ticks: &[Tick], boundaries: &[f64], current_price: f64) {
+    let (lower, upper) = (boundaries[0], boundaries[boundaries.len() - 1]);
+    for tick in ticks {
+        assert!(lower <= tick.price && tick.price <= upper);
+        if tick.r1 > 0.0 {
+            assert_eq!(tick.r2, 0.0);
+            assert!(tick.price >= current_price);
+        } else {
+            assert!(tick.r2 > 0.0);
+            assert!(tick.price <= current_price);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    /// The reserves of a concentrated liquidity ladder satisfy the invariant of
+    /// the range at every bin boundary.
+    fn concentrated_ladder_follows_invariant(
+        lower in 0.01f64..100.0,
+        width in 1.01f64..100.0,
+        current_price_factor in 0.5f64..2.0,
+        num_positions in 1usize..64,
+    ) {
+        let curve = Concentrated { lower, upper: lower * width };
+        let current_price = lower * width.sqrt() * current_price_factor;
+        let boundaries = curve.boundaries(num_positions);
+        let ticks = ladder::ticks(&curve, &boundaries, current_price);
+        check_ticks(&ticks, &boundaries, current_price);
+
+        // The ladder is built with unit liquidity.
+        for price in boundaries {
+            let (r1, r2) = ladder::holdings(&ticks, price);
+            prop_assert!(curve.invariant(r1, r2, 1.0).abs() <= LADDER_PRECISION_BOUND);
+        }
+    }
+
+    #[test]
+    /// The reserves of a StableSwap ladder, together with the reserves the curve
+    /// holds outside the ladder's range, satisfy the StableSwap invariant at
+    /// every bin boundary.
+    fn stableswap_ladder_follows_invariant(
+        amplification in 1f64..1000.0,
+        peg in 0.1f64..10.0,
+        current_price_factor in 0.97f64..1.03,
+        range in 0.001f64..0.1,
+        num_positions in 1usize..64,
+    ) {
+        let curve = StableSwap { amplification, peg };
+        let current_price = peg * current_price_factor;
+        let boundaries = curve.boundaries(range, num_positions);
+        let ticks = ladder::ticks(&curve, &boundaries, current_price);
+        check_ticks(&ticks, &boundaries, current_price);
+
+        let tail_1 = curve.reserves_1(boundaries[boundaries.len() - 1]);
+        let tail_2 = curve.reserves_2(boundaries[0]);
+        for price in boundaries {
+            let (r1, r2) = ladder::holdings(&ticks, price);
+            let (x, y) = (r1 + tail_1, (r2 + tail_2) / peg);
+            prop_assert!(curve.invariant(x, y).abs() <= 1e-6);
+        }
+    }
+
+    #[test]
+    /// A linear range order sells the same amount of the start asset in every
+    /// bin, so that its reserves decrease linearly with the price.
+    fn linear_ladder_is_linear(
+        lower in 0.01f64..100.0,
+        width in 1.01f64..10.0,
+        current_price_factor in 0.5f64..2.0,
+        num_positions in 1usize..64,
+    ) {
+        let curve = Linear { lower, upper: lower * width };
+        let current_price = lower * width.sqrt() * current_price_factor;
+        let boundaries = curve.boundaries(num_positions);
+        let ticks = ladder::ticks(&curve, &boundaries, current_price);
+        check_ticks(&ticks, &boundaries, current_price);
+
+        for price in boundaries {
+            let (r1, _) = ladder::holdings(&ticks, price);
+            let expected = (curve.upper - price) / (curve.upper - curve.lower);
+            prop_assert!((r1 - expected).abs() <= LADDER_PRECISION_BOUND);
+        }
+    }
+}
+
+#[test]
+/// The replicated positions hold the supplied liquidity, up to rounding.
+fn replicated_positions_hold_input() -> anyhow::Result<()> {
+    let pair: DirectedUnitPair = "penumbra:test_usd".parse()?;
+    let input: Value = "1000penumbra".parse()?;
+    let num_positions = 16;
+
+    let strategies = [
+        concentrated::replicate(
+            &pair,
+            &input,
+            1.5,
+            Concentrated {
+                lower: 1.0,
+                upper: 2.0,
+            },
+            num_positions,
+            0,
+        )?,
+        linear::replicate(
+            &pair,
+            &input,
+            0.5,
+            Linear {
+                lower: 1.0,
+                upper: 2.0,
+            },
+            num_positions,
+            0,
+        )?,
+        stableswap::replicate(
+            &pair,
+            &input,
+            1.0,
+            StableSwap {
+                amplification: 100.0,
+                peg: 1.0,
+            },
+            0.02,
+            num_positions,
+            0,
+        )?,
+    ];
+
+    for positions in strategies {
+        assert!(positions.len() <= num_positions + 1);
+        let r1: Amount = positions
+            .iter()
+            .map(|position| {
+                position
+                    .reserves_for(pair.start.id())
+                    .expect("start is part of position")
+            })
+            .sum();
+        assert!(r1 <= input.amount);
+        assert!(r1 + Amount::from(positions.len() as u64) >= input.amount);
+    }
+
+    Ok(())
+}
+
+#[test]
+/// A ladder cannot be funded with an asset it doesn't hold at the current price.
+fn replicate_rejects_unheld_input() -> anyhow::Result<()> {
+    let pair: DirectedUnitPair = "penumbra:test_usd".parse()?;
+    let input: Value = "1000test_usd".parse()?;
+
+    // Below the range, a linear range order only holds the start asset.
+    let result = linear::replicate(
+        &pair,
+        &input,
+        0.5,
+        Linear {
+            lower: 1.0,
+            upper: 2.0,
+        },
+        16,
+        0,
+    );
+    assert!(result.is_err());
+
+    Ok(())
+}
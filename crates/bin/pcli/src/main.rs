@@ -4,6 +4,7 @@
 use std::fs;
 
 use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
 use clap::Parser;
 use futures::StreamExt;
 use penumbra_proto::{
@@ -12,13 +13,19 @@ use penumbra_proto::{
 };
 use penumbra_view::ViewClient;
 
-use crate::{command::*, config::PcliConfig, opt::Opt};
+use crate::{
+    command::*,
+    config::PcliConfig,
+    opt::Opt,
+    output::{ErrorClass, ErrorDocument, OutputFormat, UsageError},
+};
 
 mod command;
 mod config;
 mod dex_utils;
 mod network;
 mod opt;
+mod output;
 mod terminal;
 mod transaction_view_ext;
 mod warning;
@@ -35,6 +42,11 @@ pub struct App {
     pub custody: CustodyServiceClient<BoxGrpcService>,
     pub governance_custody: CustodyServiceClient<BoxGrpcService>,
     pub config: PcliConfig,
+    /// When set, transaction commands write their plan to this file instead of
+    /// building and submitting the transaction.
+    pub plan_only: Option<Utf8PathBuf>,
+    /// The format to print results in.
+    pub output: OutputFormat,
 }
 
 impl App {
@@ -63,7 +75,10 @@ impl App {
         use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
         let progress_bar = ProgressBar::with_draw_target(
             initial_status.latest_known_block_height - initial_status.full_sync_height,
-            ProgressDrawTarget::stdout(),
+            match self.output {
+                OutputFormat::Text => ProgressDrawTarget::stdout(),
+                OutputFormat::Json => ProgressDrawTarget::stderr(),
+            },
         )
         .with_style(
             ProgressStyle::default_bar()
@@ -93,6 +108,23 @@ async fn main() -> Result<()> {
     // that tracing is set up even for wallet commands that don't build the `App`.
     opt.init_tracing();
 
+    let output = opt.output;
+    if let Err(error) = run(opt).await {
+        let class = ErrorClass::of(&error);
+        match output {
+            OutputFormat::Text => eprintln!("Error: {error:?}"),
+            OutputFormat::Json => output::print_json(&ErrorDocument::new(&error))?,
+        }
+        std::process::exit(class.exit_code());
+    }
+    Ok(())
+}
+
+async fn run(opt: Opt) -> Result<()> {
+    if opt.output == OutputFormat::Json && !opt.cmd.supports_json() {
+        return Err(UsageError("this command does not support --output json".to_owned()).into());
+    }
+
     //Ensure that the data_path exists, in case this is a cold start
     fs::create_dir_all(&opt.home)
         .with_context(|| format!("Failed to create home directory {}", opt.home))?;
@@ -118,6 +150,18 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
+    // The threshold coordinator only relays messages, so it doesn't need a wallet.
+    if let Command::Threshold(ThresholdCmd::Coordinate(coordinate_cmd)) = &opt.cmd {
+        coordinate_cmd.exec().await?;
+        return Ok(());
+    }
+
+    // Resharing rewrites the config file, so it takes the home dir directly.
+    if let Command::Threshold(ThresholdCmd::Reshare(reshare_cmd)) = &opt.cmd {
+        reshare_cmd.exec(opt.home.as_path()).await?;
+        return Ok(());
+    }
+
     let (mut app, cmd) = opt.into_app().await?;
 
     if !cmd.offline() {
@@ -132,11 +176,14 @@ async fn main() -> Result<()> {
         Command::Init(_) => unreachable!("init command already executed"),
         Command::Debug(_) => unreachable!("debug command already executed"),
         Command::Transaction(tx_cmd) => tx_cmd.exec(&mut app).await?,
+        Command::Sign(cmd) => cmd.exec(&mut app).await?,
+        Command::Broadcast(cmd) => cmd.exec(&mut app).await?,
         Command::View(view_cmd) => view_cmd.exec(&mut app).await?,
         Command::Validator(cmd) => cmd.exec(&mut app).await?,
         Command::Query(cmd) => cmd.exec(&mut app).await?,
         Command::Ceremony(cmd) => cmd.exec(&mut app).await?,
         Command::Threshold(cmd) => cmd.exec(&mut app).await?,
+        Command::Policy(cmd) => cmd.exec(&mut app).await?,
     }
 
     Ok(())
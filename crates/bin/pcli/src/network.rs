@@ -1,6 +1,7 @@
 use anyhow::Context;
 use decaf377_rdsa::{Signature, SpendAuth};
 use futures::{FutureExt, TryStreamExt};
+use penumbra_asset::asset;
 use penumbra_governance::ValidatorVoteBody;
 use penumbra_proto::{
     custody::v1::{AuthorizeValidatorDefinitionRequest, AuthorizeValidatorVoteRequest},
@@ -9,32 +10,84 @@ use penumbra_proto::{
     DomainType,
 };
 use penumbra_stake::validator::Validator;
-use penumbra_transaction::{txhash::TransactionId, Transaction, TransactionPlan};
+use penumbra_transaction::{txhash::TransactionId, Transaction, TransactionPlan, TransactionView};
 use penumbra_view::ViewClient;
 use std::future::Future;
 use tonic::transport::{Channel, ClientTlsConfig};
 use tracing::instrument;
 
-use crate::App;
+use crate::{
+    output::{print_json, OutputFormat, TransactionResult},
+    App,
+};
 
 impl App {
+    /// Build and submit a transaction, or, if `--plan-only` was given, write
+    /// the plan to disk for offline signing and return `None`.
     pub async fn build_and_submit_transaction(
         &mut self,
         plan: TransactionPlan,
-    ) -> anyhow::Result<TransactionId> {
+    ) -> anyhow::Result<Option<TransactionId>> {
+        if let Some(path) = self.plan_only.clone() {
+            let view = self.display_plan(&plan).await?;
+            std::fs::write(&path, serde_json::to_vec_pretty(&plan)?)
+                .with_context(|| format!("could not write transaction plan to {path}"))?;
+            match view {
+                None => println!("wrote transaction plan to {path}"),
+                Some(view) => print_json(&TransactionResult::Planned {
+                    plan: path,
+                    view: Box::new(view),
+                })?,
+            }
+            return Ok(None);
+        }
         let transaction = self.build_transaction(plan).await?;
-        self.submit_transaction(transaction).await
+        let id = self.submit_transaction(transaction).await?;
+        if self.output == OutputFormat::Json {
+            print_json(&TransactionResult::submitted(id, None))?;
+        }
+        Ok(Some(id))
+    }
+
+    /// Print the [`TransactionView`] a plan will produce, so that it can be
+    /// checked before it is signed.
+    ///
+    /// In JSON mode, the view is returned instead, to be embedded in the
+    /// command's result so that stdout holds a single document.
+    ///
+    /// This works without a view service, in which case only the known assets
+    /// are available to render amounts.
+    pub async fn display_plan(
+        &mut self,
+        plan: &TransactionPlan,
+    ) -> anyhow::Result<Option<TransactionView>> {
+        use crate::transaction_view_ext::{plan_view, TransactionViewExt};
+
+        let denoms = match self.view.as_mut() {
+            Some(view) => view.assets().await?,
+            None => asset::Cache::with_known_assets(),
+        };
+        let view = plan_view(plan, &self.config.full_viewing_key, denoms)?;
+
+        match self.output {
+            OutputFormat::Text => {
+                view.render_terminal();
+                Ok(None)
+            }
+            OutputFormat::Json => Ok(Some(view)),
+        }
     }
 
     pub fn build_transaction(
         &mut self,
         plan: TransactionPlan,
     ) -> impl Future<Output = anyhow::Result<Transaction>> + '_ {
-        println!(
+        let output = self.output;
+        output.progress(format!(
             "building transaction [{} actions, {} proofs]...",
             plan.actions.len(),
             plan.num_proofs(),
-        );
+        ));
         let start = std::time::Instant::now();
         let tx = penumbra_wallet::build_transaction(
             &self.config.full_viewing_key,
@@ -45,14 +98,14 @@ impl App {
         async move {
             let tx = tx.await?;
             let elapsed = start.elapsed();
-            println!(
+            output.progress(format!(
                 "finished proving in {}.{:03} seconds [{} actions, {} proofs, {} bytes]",
                 elapsed.as_secs(),
                 elapsed.subsec_millis(),
                 tx.actions().count(),
                 tx.num_proofs(),
                 tx.encode_to_vec().len()
-            );
+            ));
             Ok(tx)
         }
     }
@@ -99,7 +152,8 @@ impl App {
         &mut self,
         transaction: Transaction,
     ) -> anyhow::Result<TransactionId> {
-        println!("broadcasting transaction and awaiting confirmation...");
+        let output = self.output;
+        output.progress("broadcasting transaction and awaiting confirmation...");
         let mut rsp = self.view().broadcast_transaction(transaction, true).await?;
 
         let id = async move {
@@ -107,22 +161,25 @@ impl App {
                 match rsp.status {
                     Some(status) => match status {
                         BroadcastStatus::BroadcastSuccess(bs) => {
-                            println!(
+                            output.progress(format!(
                                 "transaction broadcast successfully: {}",
                                 TransactionId::try_from(
                                     bs.id.expect("detected transaction missing id")
                                 )?
-                            );
+                            ));
                         }
                         BroadcastStatus::Confirmed(c) => {
                             let id = c.id.expect("detected transaction missing id").try_into()?;
                             if c.detection_height != 0 {
-                                println!(
+                                output.progress(format!(
                                     "transaction confirmed and detected: {} @ height {}",
                                     id, c.detection_height
-                                );
+                                ));
                             } else {
-                                println!("transaction confirmed and detected: {}", id);
+                                output.progress(format!(
+                                    "transaction confirmed and detected: {}",
+                                    id
+                                ));
                             }
                             return Ok(id);
                         }
@@ -154,7 +211,8 @@ impl App {
         &mut self,
         transaction: Transaction,
     ) -> anyhow::Result<()> {
-        println!("broadcasting transaction without confirmation...");
+        self.output
+            .progress("broadcasting transaction without confirmation...");
         self.view()
             .broadcast_transaction(transaction, false)
             .await?;
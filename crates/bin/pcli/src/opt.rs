@@ -1,5 +1,7 @@
 use crate::{
+    command::coordinator_client,
     config::{CustodyConfig, GovernanceCustodyConfig, PcliConfig},
+    output::OutputFormat,
     terminal::ActualTerminal,
     App, Command,
 };
@@ -7,8 +9,12 @@ use anyhow::Result;
 use camino::Utf8PathBuf;
 use clap::Parser;
 use directories::ProjectDirs;
-use penumbra_custody::soft_kms::SoftKms;
-use penumbra_proto::box_grpc_svc;
+use penumbra_custody::{
+    audit::AuditLog,
+    soft_kms::SoftKms,
+    threshold::{Config as ThresholdConfig, NetworkTerminal, Threshold},
+};
+use penumbra_proto::box_grpc_svc::{self, BoxGrpcService};
 use penumbra_proto::{
     custody::v1::{
         custody_service_client::CustodyServiceClient, custody_service_server::CustodyServiceServer,
@@ -16,8 +22,9 @@ use penumbra_proto::{
     view::v1::{view_service_client::ViewServiceClient, view_service_server::ViewServiceServer},
 };
 use penumbra_view::ViewServer;
-use std::io::IsTerminal as _;
+use std::{io::IsTerminal as _, path::Path};
 use tracing_subscriber::EnvFilter;
+use url::Url;
 
 #[derive(Debug, Parser)]
 #[clap(name = "pcli", about = "The Penumbra command-line interface.", version)]
@@ -27,6 +34,13 @@ pub struct Opt {
     /// The home directory used to store configuration and data.
     #[clap(long, default_value_t = default_home(), env = "PENUMBRA_PCLI_HOME")]
     pub home: Utf8PathBuf,
+    /// The format to print results in.
+    ///
+    /// With `json`, results are printed as JSON documents on stdout, progress
+    /// messages are printed on stderr, and errors are printed as JSON documents
+    /// with a non-zero exit code.
+    #[clap(long, global = true, value_enum, default_value_t)]
+    pub output: OutputFormat,
 }
 
 impl Opt {
@@ -69,13 +83,11 @@ impl Opt {
                 let custody_svc = CustodyServiceServer::new(soft_kms);
                 CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
             }
-            CustodyConfig::Threshold(config) => {
-                tracing::info!("using manual threshold custody service");
-                let threshold_kms =
-                    penumbra_custody::threshold::Threshold::new(config.clone(), ActualTerminal);
-                let custody_svc = CustodyServiceServer::new(threshold_kms);
-                CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
-            }
+            CustodyConfig::Threshold(threshold_config) => threshold_custody(
+                threshold_config,
+                config.threshold_coordinator.as_ref(),
+                config.threshold_audit_log.as_deref(),
+            )?,
             CustodyConfig::Encrypted(config) => {
                 tracing::info!("using encrypted custody service");
                 let encrypted_kms =
@@ -83,6 +95,12 @@ impl Opt {
                 let custody_svc = CustodyServiceServer::new(encrypted_kms);
                 CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
             }
+            CustodyConfig::External(config) => {
+                tracing::info!(?config, "using external custody service");
+                let external = penumbra_custody::external::External::new(config.clone());
+                let custody_svc = CustodyServiceServer::new(external);
+                CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
+            }
         };
 
         // Build the governance custody service...
@@ -96,14 +114,13 @@ impl Opt {
                     let custody_svc = CustodyServiceServer::new(soft_kms);
                     CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
                 }
-                GovernanceCustodyConfig::Threshold(config) => {
-                    tracing::info!(
-                        "using separate manual threshold custody service for validator voting"
-                    );
-                    let threshold_kms =
-                        penumbra_custody::threshold::Threshold::new(config.clone(), ActualTerminal);
-                    let custody_svc = CustodyServiceServer::new(threshold_kms);
-                    CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
+                GovernanceCustodyConfig::Threshold(threshold_config) => {
+                    tracing::info!("using separate threshold custody service for validator voting");
+                    threshold_custody(
+                        threshold_config,
+                        config.threshold_coordinator.as_ref(),
+                        config.threshold_audit_log.as_deref(),
+                    )?
                 }
                 GovernanceCustodyConfig::Encrypted { config, .. } => {
                     tracing::info!("using separate encrypted custody service for validator voting");
@@ -150,11 +167,55 @@ impl Opt {
             custody,
             governance_custody,
             config,
+            plan_only: None,
+            output: self.output,
         };
         Ok((app, self.cmd))
     }
 }
 
+/// Build a threshold custody service, exchanging messages through the
+/// coordinator if one is configured, and through the terminal otherwise.
+///
+/// If an audit log is configured, the outcome of every request is recorded in it.
+fn threshold_custody(
+    config: &ThresholdConfig,
+    coordinator: Option<&Url>,
+    audit_log: Option<&Path>,
+) -> Result<CustodyServiceClient<BoxGrpcService>> {
+    let client = match coordinator {
+        Some(coordinator) => {
+            tracing::info!(%coordinator, "using networked threshold custody service");
+            let terminal = NetworkTerminal::coordinator(
+                ActualTerminal,
+                coordinator_client(coordinator)?,
+                config,
+            );
+            let threshold_kms = with_audit_log(Threshold::new(config.clone(), terminal), audit_log);
+            let custody_svc = CustodyServiceServer::new(threshold_kms);
+            CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
+        }
+        None => {
+            tracing::info!("using manual threshold custody service");
+            let threshold_kms =
+                with_audit_log(Threshold::new(config.clone(), ActualTerminal), audit_log);
+            let custody_svc = CustodyServiceServer::new(threshold_kms);
+            CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
+        }
+    };
+    Ok(client)
+}
+
+fn with_audit_log<T>(threshold: Threshold<T>, audit_log: Option<&Path>) -> Threshold<T> {
+    match audit_log {
+        Some(path) => {
+            tracing::info!(?path, "recording threshold signing requests in audit log");
+            threshold.with_audit_log(AuditLog::new(path))
+        }
+        None => threshold,
+    }
+}
+
 fn default_home() -> Utf8PathBuf {
     let path = ProjectDirs::from("zone", "penumbra", "pcli")
         .expect("Failed to get platform data dir")
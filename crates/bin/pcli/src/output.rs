@@ -0,0 +1,384 @@
+//! Machine-readable output.
+//!
+//! With `--output json`, commands print one of the documents defined here
+//! instead of a table. Domain types are embedded using their serde encodings,
+//! which are the proto3 JSON encodings of the corresponding protobuf messages,
+//! so these documents stay stable as long as the protobuf definitions do.
+//! Each document also carries human-readable renderings of its values, for
+//! convenience.
+//!
+//! Progress messages are written to stderr in JSON mode, so that stdout only
+//! contains JSON documents.
+
+use anyhow::Result;
+use camino::Utf8PathBuf;
+use colored_json::ToColoredJson;
+use serde::Serialize;
+
+use penumbra_asset::Value;
+use penumbra_dex::lp::position;
+use penumbra_keys::AddressView;
+use penumbra_sct::CommitmentSource;
+use penumbra_stake::{IdentityKey, Uptime};
+use penumbra_transaction::{txhash::TransactionId, TransactionView};
+
+/// The format `pcli` writes its results in.
+#[derive(Clone, Copy, clap::ValueEnum, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text and tables.
+    Text,
+    /// JSON documents, one per result.
+    Json,
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        Self::Text
+    }
+}
+
+impl OutputFormat {
+    /// Print a progress message, on stderr in JSON mode so that stdout only
+    /// contains JSON documents.
+    pub fn progress(self, message: impl std::fmt::Display) {
+        match self {
+            OutputFormat::Text => println!("{message}"),
+            OutputFormat::Json => eprintln!("{message}"),
+        }
+    }
+}
+
+/// Print a JSON document on stdout.
+pub fn print_json<T: Serialize>(document: &T) -> Result<()> {
+    let json = serde_json::to_string_pretty(document)?;
+    println!("{}", json.to_colored_json_auto()?);
+    Ok(())
+}
+
+/// The balance of one asset in one account, as printed by `pcli view balance`.
+#[derive(Debug, Serialize)]
+pub struct BalanceEntry {
+    pub account: u32,
+    pub value: Value,
+    pub display: String,
+}
+
+/// A single note, as printed by `pcli view balance --by-note`.
+#[derive(Debug, Serialize)]
+pub struct NoteEntry {
+    pub account: u32,
+    pub value: Value,
+    pub display: String,
+    pub source: CommitmentSource,
+    pub return_address: Option<AddressView>,
+}
+
+/// The delegations of the wallet, as printed by `pcli view staked`.
+#[derive(Debug, Serialize)]
+pub struct StakedDocument {
+    pub delegations: Vec<DelegationEntry>,
+    /// The staking tokens that are not delegated.
+    pub unbonded: Value,
+    /// The total value of the delegations and the unbonded staking tokens.
+    pub total: Value,
+    pub total_display: String,
+}
+
+/// The delegations of the wallet to a single validator.
+#[derive(Debug, Serialize)]
+pub struct DelegationEntry {
+    pub identity_key: IdentityKey,
+    /// The name of the validator, if its definition could be found.
+    pub name: Option<String>,
+    /// The delegation tokens held.
+    pub tokens: Value,
+    /// The value of the delegation tokens in staking tokens, if the
+    /// validator's exchange rate could be found.
+    pub unbonded: Option<Value>,
+    pub exchange_rate: Option<f64>,
+    pub display: String,
+}
+
+/// The executions against a liquidity position, as printed by `pcli view position-history`.
+#[derive(Debug, Serialize)]
+pub struct PositionHistoryDocument {
+    pub position_id: position::Id,
+    pub executions: Vec<PositionExecutionEntry>,
+    /// The fees earned by the position, per asset.
+    pub fees: Vec<Value>,
+    /// The net change in the reserves of asset 1 and asset 2, in base units.
+    pub reserves_change: [String; 2],
+    pub reserves_change_display: [String; 2],
+    /// The realized PnL of the position, in base units of asset 1.
+    pub realized_pnl: String,
+    pub realized_pnl_display: String,
+}
+
+/// A single execution against a liquidity position.
+#[derive(Debug, Serialize)]
+pub struct PositionExecutionEntry {
+    pub height: u64,
+    /// The amount the position received.
+    pub input: Value,
+    /// The amount the position gave out.
+    pub output: Value,
+    /// The fee earned by the position, included in the input.
+    pub fee: Value,
+}
+
+/// A transaction, as printed by `pcli view tx`.
+#[derive(Debug, Serialize)]
+pub struct TransactionDocument {
+    /// The hex-encoded transaction hash.
+    pub hash: String,
+    pub height: u64,
+    pub view: TransactionView,
+}
+
+/// The result of a transaction command, printed once per transaction.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum TransactionResult {
+    /// The transaction was submitted and confirmed.
+    Submitted {
+        /// The hex-encoded transaction hash.
+        transaction_id: String,
+        /// The view of the submitted plan, for `pcli broadcast`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        view: Option<Box<TransactionView>>,
+    },
+    /// The transaction plan was written to a file, with `--plan-only`.
+    Planned {
+        plan: Utf8PathBuf,
+        view: Box<TransactionView>,
+    },
+    /// The authorization data for a plan was written to a file, by `pcli sign`.
+    Signed {
+        authorization: Utf8PathBuf,
+        view: Box<TransactionView>,
+    },
+}
+
+impl TransactionResult {
+    pub fn submitted(id: TransactionId, view: Option<TransactionView>) -> Self {
+        Self::Submitted {
+            transaction_id: id.to_string(),
+            view: view.map(Box::new),
+        }
+    }
+}
+
+/// The uptime of a validator, as printed by `pcli query validator uptime`.
+#[derive(Debug, Serialize)]
+pub struct UptimeDocument {
+    pub identity_key: IdentityKey,
+    pub state: String,
+    pub uptime: Uptime,
+}
+
+/// An error, printed on stdout when a command fails in JSON mode.
+#[derive(Debug, Serialize)]
+pub struct ErrorDocument {
+    pub error: ErrorBody,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ErrorBody {
+    /// The outermost error message.
+    pub message: String,
+    /// The underlying causes of the error, outermost first.
+    pub causes: Vec<String>,
+    /// What kind of failure this was.
+    pub class: ErrorClass,
+    /// The process exit code.
+    pub exit_code: i32,
+}
+
+impl ErrorDocument {
+    pub fn new(error: &anyhow::Error) -> Self {
+        let class = ErrorClass::of(error);
+        Self {
+            error: ErrorBody {
+                message: error.to_string(),
+                causes: error.chain().skip(1).map(ToString::to_string).collect(),
+                class,
+                exit_code: class.exit_code(),
+            },
+        }
+    }
+}
+
+/// A command was given options it can't be used with.
+///
+/// Unlike invalid arguments, which are rejected while parsing the command
+/// line, these are only detected once the command is known.
+#[derive(Debug)]
+pub struct UsageError(pub String);
+
+impl std::fmt::Display for UsageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for UsageError {}
+
+/// The kind of a command failure, which determines the process exit code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorClass {
+    /// Any failure not covered by a more specific class.
+    Other,
+    /// The command was used with options it doesn't support.
+    Usage,
+    /// A request to `pd` or the view service failed.
+    Network,
+    /// A file could not be read or written.
+    Io,
+}
+
+impl ErrorClass {
+    /// Classify an error by the first cause, outermost first, that has a known class.
+    pub fn of(error: &anyhow::Error) -> Self {
+        for cause in error.chain() {
+            if cause.is::<UsageError>() {
+                return ErrorClass::Usage;
+            }
+            if cause.is::<tonic::Status>() || cause.is::<tonic::transport::Error>() {
+                return ErrorClass::Network;
+            }
+            if cause.is::<std::io::Error>() {
+                return ErrorClass::Io;
+            }
+        }
+        ErrorClass::Other
+    }
+
+    /// The process exit code for this class of failure.
+    ///
+    /// Invalid command-line arguments are rejected by the argument parser,
+    /// which also exits with code 2.
+    pub fn exit_code(self) -> i32 {
+        match self {
+            ErrorClass::Other => 1,
+            ErrorClass::Usage => 2,
+            ErrorClass::Network => 3,
+            ErrorClass::Io => 4,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+    use penumbra_asset::{asset, STAKING_TOKEN_ASSET_ID};
+    use penumbra_keys::test_keys::FULL_VIEWING_KEY;
+    use penumbra_transaction::TransactionPlan;
+    use serde_json::json;
+
+    use crate::transaction_view_ext::plan_view;
+
+    fn staking_value(amount: u128) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+    }
+
+    #[test]
+    fn balance_entry_schema() {
+        let value = staking_value(1_000_000);
+        let entry = BalanceEntry {
+            account: 1,
+            value,
+            display: "1penumbra".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_value(&entry).unwrap(),
+            json!({
+                "account": 1,
+                "value": serde_json::to_value(value).unwrap(),
+                "display": "1penumbra",
+            })
+        );
+        // The value uses the proto3 JSON encoding of `penumbra.core.asset.v1.Value`.
+        assert_eq!(
+            serde_json::to_value(value).unwrap()["amount"],
+            json!({ "lo": "1000000" })
+        );
+    }
+
+    #[test]
+    fn transaction_result_schema() {
+        let id = TransactionId([0xab; 32]);
+        assert_eq!(
+            serde_json::to_value(TransactionResult::submitted(id, None)).unwrap(),
+            json!({ "transaction_id": "ab".repeat(32) })
+        );
+
+        let view = plan_view(
+            &TransactionPlan::default(),
+            &FULL_VIEWING_KEY,
+            asset::Cache::with_known_assets(),
+        )
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(TransactionResult::Planned {
+                plan: "plan.json".into(),
+                view: Box::new(view.clone()),
+            })
+            .unwrap(),
+            json!({ "plan": "plan.json", "view": serde_json::to_value(&view).unwrap() })
+        );
+        assert_eq!(
+            serde_json::to_value(TransactionResult::submitted(id, Some(view.clone()))).unwrap(),
+            json!({
+                "transaction_id": "ab".repeat(32),
+                "view": serde_json::to_value(&view).unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn error_schema() {
+        let error = Err::<(), _>(anyhow::anyhow!("connection refused"))
+            .context("could not connect to the view service")
+            .unwrap_err();
+        assert_eq!(
+            serde_json::to_value(ErrorDocument::new(&error)).unwrap(),
+            json!({
+                "error": {
+                    "message": "could not connect to the view service",
+                    "causes": ["connection refused"],
+                    "class": "other",
+                    "exit_code": 1,
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn error_classes() {
+        let class = |error: anyhow::Error| {
+            let class = ErrorClass::of(&error);
+            (class, class.exit_code())
+        };
+        assert_eq!(
+            class(anyhow::anyhow!("no such auction")),
+            (ErrorClass::Other, 1)
+        );
+        assert_eq!(
+            class(anyhow::Error::new(UsageError("not supported".into())).context("sweep")),
+            (ErrorClass::Usage, 2)
+        );
+        assert_eq!(
+            class(anyhow::Error::new(tonic::Status::unavailable("down")).context("querying")),
+            (ErrorClass::Network, 3)
+        );
+        let missing = std::fs::read("/nonexistent/pcli/config.toml")
+            .context("could not read config")
+            .unwrap_err();
+        assert_eq!(class(missing), (ErrorClass::Io, 4));
+    }
+}
@@ -1,15 +1,22 @@
 use comfy_table::presets;
 use comfy_table::Table;
-use penumbra_asset::asset::Id;
-use penumbra_asset::ValueView;
+use penumbra_asset::asset::{self, Id, Metadata};
+use penumbra_asset::{Value, ValueView};
+use penumbra_auction::auction::dutch::actions::view::{
+    ActionDutchAuctionScheduleView, ActionDutchAuctionWithdrawView,
+};
+use penumbra_auction::auction::dutch::ActionDutchAuctionEnd;
+use penumbra_auction::auction::AuctionId;
 use penumbra_dex::swap::SwapView;
 use penumbra_dex::swap_claim::SwapClaimView;
 use penumbra_fee::Fee;
-use penumbra_keys::AddressView;
+use penumbra_keys::{AddressView, FullViewingKey};
 use penumbra_num::Amount;
 use penumbra_shielded_pool::SpendView;
 use penumbra_transaction::view::action_view::OutputView;
-use penumbra_transaction::TransactionView;
+use penumbra_transaction::{
+    ActionPlan, ActionView, TransactionPerspective, TransactionPlan, TransactionView,
+};
 
 // Issues identified:
 // TODO: FeeView
@@ -140,19 +147,192 @@ fn value_view_amount(value_view: &ValueView) -> Amount {
     }
 }
 
+// Resolve an amount of an asset through the metadata carried by a view, if any.
+fn format_amount(amount: Amount, asset_id: Id, metadata: &Option<Metadata>) -> String {
+    let cache: asset::Cache = metadata.iter().cloned().collect();
+    format_value_view(&Value { amount, asset_id }.view_with_cache(&cache))
+}
+
+// The number of `output` units exchanged for one `input` unit, in display units.
+fn format_price(
+    output: Amount,
+    output_metadata: &Option<Metadata>,
+    input: Amount,
+    input_metadata: &Option<Metadata>,
+) -> String {
+    let display_amount = |amount: Amount, metadata: &Option<Metadata>| {
+        let exponent = metadata
+            .as_ref()
+            .map(|m| m.default_unit().exponent())
+            .unwrap_or_default();
+        amount.value() as f64 / 10f64.powi(exponent as i32)
+    };
+    let input = display_amount(input, input_metadata);
+    if input == 0.0 {
+        return "-".to_string();
+    }
+    let price = format!("{:.6}", display_amount(output, output_metadata) / input);
+    price
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+fn format_unit(asset_id: &Id, metadata: &Option<Metadata>) -> String {
+    match metadata {
+        Some(metadata) => metadata.default_unit().to_string(),
+        None => format_asset_id(asset_id),
+    }
+}
+
+// Dutch auctions record their state in the sequence number of the auction NFT.
+fn format_auction_state(seq: u64) -> &'static str {
+    match seq {
+        0 => "opened",
+        1 => "closed",
+        _ => "withdrawn",
+    }
+}
+
+fn format_auction_transition(auction_id: &AuctionId, from: Option<u64>, to: u64) -> String {
+    match from {
+        Some(from) => format!(
+            "Auction {}: {} -> {}",
+            auction_id,
+            format_auction_state(from),
+            format_auction_state(to)
+        ),
+        None => format!("Auction {}: {}", auction_id, format_auction_state(to)),
+    }
+}
+
+fn format_dutch_auction_schedule(view: &ActionDutchAuctionScheduleView) -> String {
+    let description = &view.action.description;
+    let input = &description.input;
+    let output_unit = format_unit(&description.output_id, &view.output_metadata);
+    let input_unit = format_unit(&input.asset_id, &view.input_metadata);
+    format!(
+        "Sell {} for {} to {}\nPrice: {} to {} {} per {}\n{} steps from height {} to {}\n{}",
+        format_amount(input.amount, input.asset_id, &view.input_metadata),
+        format_amount(
+            description.max_output,
+            description.output_id,
+            &view.output_metadata
+        ),
+        format_amount(
+            description.min_output,
+            description.output_id,
+            &view.output_metadata
+        ),
+        format_price(
+            description.max_output,
+            &view.output_metadata,
+            input.amount,
+            &view.input_metadata
+        ),
+        format_price(
+            description.min_output,
+            &view.output_metadata,
+            input.amount,
+            &view.input_metadata
+        ),
+        output_unit,
+        input_unit,
+        description.step_count,
+        description.start_height,
+        description.end_height,
+        format_auction_transition(&view.auction_id, None, 0),
+    )
+}
+
+fn format_dutch_auction_end(end: &ActionDutchAuctionEnd) -> String {
+    format_auction_transition(&end.auction_id, Some(0), 1)
+}
+
+fn format_dutch_auction_withdraw(view: &ActionDutchAuctionWithdrawView) -> String {
+    let transition = format_auction_transition(
+        &view.action.auction_id,
+        Some(view.action.seq.saturating_sub(1)),
+        view.action.seq,
+    );
+    if view.reserves.is_empty() {
+        return format!("{}\nReserves: not visible", transition);
+    }
+    let reserves = view
+        .reserves
+        .iter()
+        .map(format_value_view)
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{}\nReserves: {}", transition, reserves)
+}
+
+/// The [`TransactionView`] a plan will produce, as seen by the wallet that made it.
+///
+/// Only the assets in `denoms` are available to render amounts.
+pub fn plan_view(
+    plan: &TransactionPlan,
+    fvk: &FullViewingKey,
+    denoms: asset::Cache,
+) -> anyhow::Result<TransactionView> {
+    let tx = plan.build_stub(fvk)?;
+
+    let mut txp = TransactionPerspective {
+        payload_keys: tx.payload_keys(fvk)?,
+        transaction_id: tx.id(),
+        denoms,
+        ..Default::default()
+    };
+    for spend in plan.spend_plans() {
+        txp.spend_nullifiers
+            .insert(spend.nullifier(fvk), spend.note.clone());
+        txp.address_views
+            .push(fvk.view_address(spend.note.address()));
+    }
+    for output in plan.output_plans() {
+        txp.address_views
+            .push(fvk.view_address(output.dest_address.clone()));
+    }
+
+    let mut view = tx.view_from_perspective(&txp);
+    // Auction withdrawals only commit to their reserves, but the plan has them in the clear.
+    for action_view in view.body_view.action_views.iter_mut() {
+        if let ActionView::ActionDutchAuctionWithdraw(withdraw) = action_view {
+            let reserves = plan.actions.iter().find_map(|action| match action {
+                ActionPlan::ActionDutchAuctionWithdraw(p)
+                    if p.auction_id == withdraw.action.auction_id =>
+                {
+                    Some([p.reserves_input, p.reserves_output])
+                }
+                _ => None,
+            });
+            if let Some(reserves) = reserves {
+                withdraw.reserves = reserves.map(|v| txp.view_value(v)).to_vec();
+            }
+        }
+    }
+    Ok(view)
+}
+
 pub trait TransactionViewExt {
+    /// Render this transaction view as text.
+    fn render(&self) -> String;
+
     /// Render this transaction view on stdout.
-    fn render_terminal(&self);
+    fn render_terminal(&self) {
+        print!("{}", self.render());
+    }
 }
 
 impl TransactionViewExt for TransactionView {
-    fn render_terminal(&self) {
+    fn render(&self) -> String {
+        let mut out = String::new();
         let fee = &self.body_view.transaction_parameters.fee;
         // the denomination should be visible here... does a FeeView exist?
-        println!("Fee: {}", format_fee(&fee));
+        out += &format!("Fee: {}\n", format_fee(&fee));
 
-        println!(
-            "Expiration Height: {}",
+        out += &format!(
+            "Expiration Height: {}\n",
             &self.body_view.transaction_parameters.expiry_height
         );
 
@@ -162,11 +342,14 @@ impl TransactionViewExt for TransactionView {
                     plaintext,
                     ciphertext: _,
                 } => {
-                    println!("Memo Sender: {}", &plaintext.return_address.address());
-                    println!("Memo Text: \n{}\n", &plaintext.text);
+                    out += &format!("Memo Sender: {}\n", &plaintext.return_address.address());
+                    out += &format!("Memo Text: \n{}\n\n", &plaintext.text);
                 }
                 penumbra_transaction::MemoView::Opaque { ciphertext } => {
-                    println!("Encrypted Memo: \n{}\n", format_opaque_bytes(&ciphertext.0));
+                    out += &format!(
+                        "Encrypted Memo: \n{}\n\n",
+                        format_opaque_bytes(&ciphertext.0)
+                    );
                 }
             }
         }
@@ -339,6 +522,10 @@ impl TransactionViewExt for TransactionView {
                 penumbra_transaction::ActionView::PositionWithdraw(_) => {
                     ["Withdraw Liquitity Position", ""]
                 }
+                penumbra_transaction::ActionView::PositionRewardClaim(position_reward_claim) => {
+                    action = format!("ID: {}", position_reward_claim.position_id);
+                    ["Claim Liquidity Position Rewards", &action]
+                }
                 penumbra_transaction::ActionView::ProposalDepositClaim(proposal_deposit_claim) => {
                     action = format!(
                         "Claim Deposit for Governance Proposal #{}",
@@ -378,12 +565,17 @@ impl TransactionViewExt for TransactionView {
                 penumbra_transaction::ActionView::Delegate(_) => ["Delegation", ""],
                 penumbra_transaction::ActionView::Undelegate(_) => ["Undelegation", ""],
                 penumbra_transaction::ActionView::UndelegateClaim(_) => ["Undelegation Claim", ""],
-                penumbra_transaction::ActionView::ActionDutchAuctionSchedule(_) => todo!(),
-                penumbra_transaction::ActionView::ActionDutchAuctionEnd(_) => {
-                    todo!()
+                penumbra_transaction::ActionView::ActionDutchAuctionSchedule(schedule) => {
+                    action = format_dutch_auction_schedule(schedule);
+                    ["Dutch Auction Schedule", &action]
+                }
+                penumbra_transaction::ActionView::ActionDutchAuctionEnd(end) => {
+                    action = format_dutch_auction_end(end);
+                    ["Dutch Auction End", &action]
                 }
-                penumbra_transaction::ActionView::ActionDutchAuctionWithdraw(_) => {
-                    todo!()
+                penumbra_transaction::ActionView::ActionDutchAuctionWithdraw(withdraw) => {
+                    action = format_dutch_auction_withdraw(withdraw);
+                    ["Dutch Auction Withdraw", &action]
                 }
             };
 
@@ -391,6 +583,289 @@ impl TransactionViewExt for TransactionView {
         }
 
         // Print table of actions and their descriptions
-        println!("{actions_table}");
+        out += &format!("{actions_table}\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_auction::auction::dutch::{
+        ActionDutchAuctionSchedule, ActionDutchAuctionWithdraw, DutchAuctionDescription,
+    };
+    use penumbra_dex::{
+        lp::{action::PositionOpen, position::Position, Reserves},
+        DirectedTradingPair,
+    };
+    use penumbra_keys::{keys::AddressIndex, test_keys::FULL_VIEWING_KEY};
+    use penumbra_shielded_pool::{Note, OutputPlan, SpendPlan};
+    use penumbra_transaction::{
+        memo::MemoPlaintext, plan::MemoPlan, Action, TransactionParameters,
+    };
+    use rand_core::OsRng;
+
+    fn gn() -> Metadata {
+        asset::Cache::with_known_assets()
+            .get_unit("gn")
+            .expect("gn is a known asset")
+            .base()
+    }
+
+    fn schedule_view(output_metadata: Option<Metadata>) -> ActionDutchAuctionScheduleView {
+        let description = DutchAuctionDescription {
+            input: Value {
+                amount: 100_000_000u128.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+            output_id: gn().id(),
+            max_output: 250_000_000u128.into(),
+            min_output: 150_000_000u128.into(),
+            start_height: 1000,
+            end_height: 2000,
+            step_count: 100,
+            nonce: [0u8; 32],
+        };
+        ActionDutchAuctionScheduleView {
+            auction_id: description.id(),
+            action: ActionDutchAuctionSchedule { description },
+            input_metadata: asset::Cache::with_known_assets().get_by_id(*STAKING_TOKEN_ASSET_ID),
+            output_metadata,
+        }
+    }
+
+    #[test]
+    fn renders_dutch_auction_schedule() {
+        let view = schedule_view(Some(gn()));
+        assert_eq!(
+            format_dutch_auction_schedule(&view),
+            format!(
+                "Sell 100penumbra for 250gn to 150gn\n\
+                 Price: 2.5 to 1.5 gn per penumbra\n\
+                 100 steps from height 1000 to 2000\n\
+                 Auction {}: opened",
+                view.auction_id
+            )
+        );
+    }
+
+    #[test]
+    fn renders_dutch_auction_schedule_with_unknown_output() {
+        let view = schedule_view(None);
+        let output_id = view.action.description.output_id;
+        assert_eq!(
+            format_dutch_auction_schedule(&view),
+            format!(
+                "Sell 100penumbra for 250000000{output_id} to 150000000{output_id}\n\
+                 Price: 2500000 to 1500000 {} per penumbra\n\
+                 100 steps from height 1000 to 2000\n\
+                 Auction {}: opened",
+                format_asset_id(&output_id),
+                view.auction_id
+            )
+        );
+    }
+
+    #[test]
+    fn renders_dutch_auction_end_and_withdraw() {
+        let auction_id = schedule_view(None).auction_id;
+        assert_eq!(
+            format_dutch_auction_end(&ActionDutchAuctionEnd { auction_id }),
+            format!("Auction {auction_id}: opened -> closed")
+        );
+
+        let action = ActionDutchAuctionWithdraw {
+            auction_id,
+            seq: 2,
+            reserves_commitment: Default::default(),
+        };
+        let cache = asset::Cache::with_known_assets();
+        let view = ActionDutchAuctionWithdrawView {
+            action: action.clone(),
+            reserves: vec![
+                Value {
+                    amount: 0u128.into(),
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                }
+                .view_with_cache(&cache),
+                Value {
+                    amount: 180_000_000u128.into(),
+                    asset_id: gn().id(),
+                }
+                .view_with_cache(&cache),
+            ],
+        };
+        assert_eq!(
+            format_dutch_auction_withdraw(&view),
+            format!("Auction {auction_id}: closed -> withdrawn\nReserves: 0penumbra, 180gn")
+        );
+
+        let view = ActionDutchAuctionWithdrawView {
+            action,
+            reserves: vec![],
+        };
+        assert_eq!(
+            format_dutch_auction_withdraw(&view),
+            format!("Auction {auction_id}: closed -> withdrawn\nReserves: not visible")
+        );
+    }
+
+    /// The rendered lines of a transaction view, with runs of whitespace collapsed,
+    /// so that the snapshots don't depend on the table's column widths.
+    fn rendered_lines(view: &TransactionView) -> Vec<String> {
+        view.render()
+            .lines()
+            .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|line| !line.is_empty())
+            .collect()
+    }
+
+    fn penumbra(amount: u128) -> Value {
+        Value {
+            amount: (amount * 1_000_000).into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+    }
+
+    /// A payment of 100penumbra from account 0 to account 1, with a memo.
+    fn send_plan() -> TransactionPlan {
+        let fvk = &*FULL_VIEWING_KEY;
+        let account_0 = fvk.payment_address(AddressIndex::new(0)).0;
+        let account_1 = fvk.payment_address(AddressIndex::new(1)).0;
+        let note = Note::generate(&mut OsRng, &account_0, penumbra(1000));
+        TransactionPlan {
+            actions: vec![
+                ActionPlan::Spend(SpendPlan::new(&mut OsRng, note, 0u64.into())),
+                ActionPlan::Output(OutputPlan::new(&mut OsRng, penumbra(100), account_1)),
+                ActionPlan::Output(OutputPlan::new(
+                    &mut OsRng,
+                    penumbra(900),
+                    account_0.clone(),
+                )),
+            ],
+            transaction_parameters: TransactionParameters {
+                expiry_height: 100,
+                chain_id: "penumbra-testnet".to_owned(),
+                fee: Fee::from_staking_token_amount(0u64.into()),
+            },
+            detection_data: None,
+            memo: Some(MemoPlan::new(
+                &mut OsRng,
+                MemoPlaintext::new(account_0, "rent".to_owned()).unwrap(),
+            )),
+        }
+    }
+
+    #[test]
+    fn renders_own_send() {
+        let plan = send_plan();
+        let view = plan_view(&plan, &FULL_VIEWING_KEY, asset::Cache::with_known_assets()).unwrap();
+        let sender = FULL_VIEWING_KEY.payment_address(AddressIndex::new(0)).0;
+        assert_eq!(
+            rendered_lines(&view),
+            vec![
+                "Fee: 0".to_owned(),
+                "Expiration Height: 100".to_owned(),
+                format!("Memo Sender: {sender}"),
+                "Memo Text:".to_owned(),
+                "rent".to_owned(),
+                "Tx Action Description".to_owned(),
+                "Spend [account 0] -> 1000penumbra".to_owned(),
+                "Output 100penumbra -> [account 1]".to_owned(),
+                "Output 900penumbra -> [account 0]".to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_someone_elses_send() {
+        let plan = send_plan();
+        let tx = plan.build_stub(&FULL_VIEWING_KEY).unwrap();
+        // Without any keys, nothing but the public parameters can be seen.
+        let view = tx.view_from_perspective(&TransactionPerspective {
+            transaction_id: tx.id(),
+            denoms: asset::Cache::with_known_assets(),
+            ..Default::default()
+        });
+
+        let mut expected = vec![
+            "Fee: 0".to_owned(),
+            "Expiration Height: 100".to_owned(),
+            "Encrypted Memo:".to_owned(),
+            format_opaque_bytes(&tx.transaction_body().memo.unwrap().0),
+            "Tx Action Description".to_owned(),
+        ];
+        for action in tx.actions() {
+            expected.push(match action {
+                Action::Spend(spend) => format!(
+                    "Spend {}",
+                    format_opaque_bytes(&spend.body.nullifier.to_bytes())
+                ),
+                Action::Output(output) => format!(
+                    "Output {}",
+                    format_opaque_bytes(&output.body.note_payload.encrypted_note.0)
+                ),
+                _ => unreachable!("the plan only spends and outputs"),
+            });
+        }
+        assert_eq!(rendered_lines(&view), expected);
+    }
+
+    #[test]
+    fn renders_trading_actions() {
+        let schedule = schedule_view(None).action;
+        let auction_id = schedule.description.id();
+        let position = Position::new(
+            OsRng,
+            DirectedTradingPair::new(*STAKING_TOKEN_ASSET_ID, gn().id()),
+            30,
+            1u64.into(),
+            2u64.into(),
+            Reserves {
+                r1: 0u64.into(),
+                r2: 5_000_000u64.into(),
+            },
+        );
+        let plan = TransactionPlan {
+            actions: vec![
+                ActionPlan::PositionOpen(PositionOpen {
+                    position: position.clone(),
+                }),
+                ActionPlan::ActionDutchAuctionSchedule(schedule),
+                ActionPlan::ActionDutchAuctionEnd(ActionDutchAuctionEnd { auction_id }),
+            ],
+            transaction_parameters: TransactionParameters {
+                expiry_height: 0,
+                chain_id: "penumbra-testnet".to_owned(),
+                fee: Fee::from_staking_token_amount(2_000u64.into()),
+            },
+            detection_data: None,
+            memo: None,
+        };
+        let view = plan_view(&plan, &FULL_VIEWING_KEY, asset::Cache::with_known_assets()).unwrap();
+
+        let pair = position.phi.pair;
+        assert_eq!(
+            rendered_lines(&view),
+            vec![
+                "Fee: 2000".to_owned(),
+                "Expiration Height: 0".to_owned(),
+                "Tx Action Description".to_owned(),
+                format!(
+                    "Open Liquidity Position Reserves: ({} {}, {} {}) Fee: 30 ID: {}",
+                    position.reserves.r1,
+                    format_asset_id(&pair.asset_1()),
+                    position.reserves.r2,
+                    format_asset_id(&pair.asset_2()),
+                    position.id(),
+                ),
+                "Dutch Auction Schedule Sell 100penumbra for 250gn to 150gn".to_owned(),
+                "Price: 2.5 to 1.5 gn per penumbra".to_owned(),
+                "100 steps from height 1000 to 2000".to_owned(),
+                format!("Auction {auction_id}: opened"),
+                format!("Dutch Auction End Auction {auction_id}: opened -> closed"),
+            ]
+        );
     }
 }
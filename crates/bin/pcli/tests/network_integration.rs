@@ -221,6 +221,87 @@ fn transaction_send_from_addr_0_to_addr_1() {
         .timeout(std::time::Duration::from_secs(TIMEOUT_COMMAND_SECONDS));
 }
 
+/// Parse the stdout of a command run with `--output json`, checking that it
+/// holds exactly one JSON document.
+fn single_json_document(stdout: &[u8]) -> Value {
+    let documents = serde_json::Deserializer::from_slice(stdout)
+        .into_iter::<Value>()
+        .collect::<Result<Vec<_>, _>>()
+        .expect("stdout is valid JSON");
+    assert_eq!(
+        documents.len(),
+        1,
+        "stdout should hold exactly one JSON document: {documents:?}"
+    );
+    documents.into_iter().next().unwrap()
+}
+
+#[ignore]
+#[test]
+fn offline_signing_json_output() {
+    let tmpdir = load_wallet_into_tmpdir();
+    let home = tmpdir.path().to_str().unwrap();
+    let plan = tmpdir.path().join("plan.json");
+    let auth = tmpdir.path().join("authorization.json");
+
+    let mut plan_cmd = Command::cargo_bin("pcli").unwrap();
+    plan_cmd
+        .args([
+            "--home",
+            home,
+            "--output",
+            "json",
+            "tx",
+            "--plan-only",
+            plan.to_str().unwrap(),
+            "send",
+            TEST_ASSET,
+            "--to",
+            ADDRESS_1_STR,
+        ])
+        .timeout(std::time::Duration::from_secs(TIMEOUT_COMMAND_SECONDS));
+    let planned = single_json_document(&plan_cmd.unwrap().stdout);
+    assert_eq!(planned["plan"], plan.to_str().unwrap());
+    assert!(planned["view"].is_object());
+
+    let mut sign_cmd = Command::cargo_bin("pcli").unwrap();
+    sign_cmd
+        .args([
+            "--home",
+            home,
+            "--output",
+            "json",
+            "sign",
+            plan.to_str().unwrap(),
+            "--auth",
+            auth.to_str().unwrap(),
+            "--yes",
+        ])
+        .timeout(std::time::Duration::from_secs(TIMEOUT_COMMAND_SECONDS));
+    let signed = single_json_document(&sign_cmd.unwrap().stdout);
+    assert_eq!(signed["authorization"], auth.to_str().unwrap());
+    // Signing is offline, so fewer assets may be known than when planning.
+    assert!(signed["view"].is_object());
+
+    let mut broadcast_cmd = Command::cargo_bin("pcli").unwrap();
+    broadcast_cmd
+        .args([
+            "--home",
+            home,
+            "--output",
+            "json",
+            "broadcast",
+            "--plan",
+            plan.to_str().unwrap(),
+            "--auth",
+            auth.to_str().unwrap(),
+        ])
+        .timeout(std::time::Duration::from_secs(TIMEOUT_COMMAND_SECONDS));
+    let submitted = single_json_document(&broadcast_cmd.unwrap().stdout);
+    assert!(submitted["transaction_id"].is_string());
+    assert_eq!(submitted["view"], planned["view"]);
+}
+
 #[ignore]
 #[test]
 fn transaction_sweep() {
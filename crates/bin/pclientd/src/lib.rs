@@ -8,8 +8,10 @@ use anyhow::{Context, Result};
 use camino::Utf8PathBuf;
 use clap::Parser;
 use directories::ProjectDirs;
+use penumbra_custody::audit::AuditLog;
 use penumbra_custody::policy::{AuthPolicy, PreAuthorizationPolicy};
 use penumbra_custody::soft_kms::{self, SoftKms};
+use penumbra_custody::AuthorizeRequest;
 use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
 use penumbra_keys::FullViewingKey;
 use penumbra_proto::{
@@ -113,6 +115,33 @@ pub enum Command {
     Start {},
     /// Delete `pclientd` storage to reset local state.
     Reset {},
+    /// Check whether the configured authorization policy would allow a transaction plan.
+    ///
+    /// This is a dry run: stateful policies, like spend limits, are evaluated
+    /// against their current state but don't record the plan, and
+    /// pre-authorization policies are evaluated as if none were provided.
+    CheckPolicy {
+        /// A JSON file containing the transaction plan to check.
+        #[clap(long, display_order = 100)]
+        plan: Utf8PathBuf,
+    },
+    /// Inspect the audit log of custody authorization decisions.
+    #[clap(subcommand)]
+    Audit(AuditCommand),
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum AuditCommand {
+    /// Check that the audit log's hash chain is intact.
+    ///
+    /// Removing entries from the end of the log can't be detected this way,
+    /// so the printed hash of the last entry should be compared against
+    /// previously recorded values.
+    Verify {
+        /// Write the verified entries to this file, as JSON.
+        #[clap(long, display_order = 100)]
+        export: Option<Utf8PathBuf>,
+    },
 }
 
 impl Opt {
@@ -128,6 +157,12 @@ impl Opt {
         path
     }
 
+    fn audit_log_path(&self) -> Utf8PathBuf {
+        let mut path = self.home.clone();
+        path.push("audit.jsonl");
+        path
+    }
+
     fn check_home_nonempty(&self) -> Result<()> {
         if self.home.exists() {
             if !self.home.is_dir() {
@@ -183,6 +218,64 @@ impl Opt {
 
                 Ok(())
             }
+            Command::CheckPolicy { plan } => {
+                let config = PclientdConfig::load(opt.config_path()).context(
+                    "Failed to load pclientd config file. Have you run `pclientd init`?",
+                )?;
+                let kms_config = config
+                    .kms_config
+                    .ok_or_else(|| anyhow::anyhow!("pclientd is not configured in custody mode"))?;
+
+                let plan = serde_json::from_str(
+                    &fs::read_to_string(plan)
+                        .with_context(|| format!("Failed to read transaction plan {}", plan))?,
+                )
+                .with_context(|| format!("Failed to parse transaction plan {}", plan))?;
+                let request = AuthorizeRequest {
+                    plan,
+                    pre_authorizations: Vec::new(),
+                };
+
+                AuthPolicy::All {
+                    policies: kms_config.auth_policy,
+                }
+                .dry_run(&request)
+                .context("transaction plan is not allowed by the configured policy")?;
+                println!("Transaction plan is allowed by the configured policy.");
+
+                Ok(())
+            }
+            Command::Audit(AuditCommand::Verify { export }) => {
+                let config = PclientdConfig::load(opt.config_path()).context(
+                    "Failed to load pclientd config file. Have you run `pclientd init`?",
+                )?;
+                let audit_log = config
+                    .kms_config
+                    .and_then(|kms_config| kms_config.audit_log)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("pclientd is not configured with an audit log")
+                    })?;
+
+                let records = AuditLog::verify(&audit_log)
+                    .with_context(|| format!("Audit log {} is corrupted", audit_log.display()))?;
+                match records.last() {
+                    Some(last) => println!(
+                        "Verified {} entries in {}. The hash of the last entry is {}.",
+                        records.len(),
+                        audit_log.display(),
+                        last.hash
+                    ),
+                    None => println!("The audit log {} is empty.", audit_log.display()),
+                }
+
+                if let Some(export) = export {
+                    fs::write(export, serde_json::to_string_pretty(&records)?)
+                        .with_context(|| format!("Failed to write {}", export))?;
+                    println!("Exported the entries to {}.", export);
+                }
+
+                Ok(())
+            }
             Command::Init {
                 view,
                 custody,
@@ -266,6 +359,7 @@ impl Opt {
                     soft_kms::Config {
                         spend_key,
                         auth_policy,
+                        audit_log: Some(opt.audit_log_path().into()),
                     }
                 });
 
@@ -319,9 +413,10 @@ impl Opt {
 
                 let view_service =
                     ViewServiceServer::new(ViewServer::new(storage, config.grpc_url).await?);
-                let custody_service = config.kms_config.as_ref().map(|kms_config| {
-                    CustodyServiceServer::new(SoftKms::new(kms_config.spend_key.clone().into()))
-                });
+                let custody_service = config
+                    .kms_config
+                    .as_ref()
+                    .map(|kms_config| CustodyServiceServer::new(SoftKms::new(kms_config.clone())));
 
                 let server = Server::builder()
                     .accept_http1(true)
@@ -41,6 +41,7 @@ fn generate_config() -> anyhow::Result<PclientdConfig> {
         kms_config: Some(soft_kms::Config {
             spend_key: test_keys::SPEND_KEY.clone(),
             auth_policy: Vec::new(),
+            audit_log: None,
         }),
     })
 }
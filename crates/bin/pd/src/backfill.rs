@@ -0,0 +1,125 @@
+//! Backfilling of derived, nonverifiable data on existing nodes.
+
+use std::{collections::BTreeMap, path::PathBuf, str::FromStr};
+
+use anyhow::{anyhow, Context};
+use cnidarium::{StateDelta, StateRead, StateWrite, Storage};
+use futures::TryStreamExt;
+use penumbra_app::SUBSTORE_PREFIXES;
+use penumbra_asset::asset;
+use penumbra_dex::{
+    component::Chandelier, state_key::candlesticks, CandlestickData, DirectedTradingPair,
+};
+use penumbra_proto::DomainType;
+use penumbra_sct::component::clock::EpochRead;
+use tendermint_rpc::{Client, HttpClient};
+use url::Url;
+
+/// The number of block headers CometBFT returns per `blockchain` request.
+const HEADERS_PER_REQUEST: u64 = 20;
+
+/// Rebuilds the rolled-up candlesticks of every trading pair from the
+/// per-block candlesticks recorded in the node's state.
+///
+/// Block timestamps aren't kept in the application state, so they are read
+/// from the CometBFT RPC at `cometbft_addr`, which can be any node of the
+/// same chain. The existing rollups are discarded and rebuilt, so this can be
+/// run again safely. The node must be stopped while this runs.
+pub async fn backfill_candlesticks(pd_home: PathBuf, cometbft_addr: Url) -> anyhow::Result<()> {
+    let rocksdb_dir = pd_home.join("rocksdb");
+    let storage = Storage::load(rocksdb_dir, SUBSTORE_PREFIXES.to_vec())
+        .await
+        .context("error loading store for candlestick backfill")?;
+    let snapshot = storage.latest_snapshot();
+    let client = HttpClient::new(cometbft_addr.to_string().as_str())
+        .context("failed to create CometBFT RPC client")?;
+
+    // Collect the per-block candlesticks. The keys are ordered by pair, then by
+    // height, which is the order in which they must be rolled up.
+    let block_candlesticks: Vec<(DirectedTradingPair, CandlestickData)> = snapshot
+        .nonverifiable_prefix_raw(candlesticks::data::prefix().as_bytes())
+        .and_then(|(key, value)| async move {
+            let pair = parse_pair(&key)?;
+            let candlestick = CandlestickData::decode(value.as_ref())
+                .context("error deserializing candlestick")?;
+            Ok((pair, candlestick))
+        })
+        .try_collect()
+        .await?;
+    tracing::info!(
+        count = block_candlesticks.len(),
+        "loaded per-block candlesticks"
+    );
+
+    let mut delta = StateDelta::new(snapshot.clone());
+
+    // Discard the existing rollups, which are about to be rebuilt.
+    let stale_keys: Vec<Vec<u8>> = snapshot
+        .nonverifiable_prefix_raw(candlesticks::rollup::prefix().as_bytes())
+        .map_ok(|(key, _)| key)
+        .try_collect()
+        .await?;
+    for key in stale_keys {
+        delta.nonverifiable_delete(key);
+    }
+
+    let mut timestamps = BTreeMap::<u64, u64>::new();
+    for (pair, candlestick) in &block_candlesticks {
+        let height = candlestick.height;
+        if !timestamps.contains_key(&height) {
+            fetch_timestamps(&client, height, &mut timestamps).await?;
+        }
+        let timestamp = timestamps
+            .get(&height)
+            .copied()
+            .ok_or_else(|| anyhow!("CometBFT did not return a header for height {height}"))?;
+        let epoch_index = snapshot.get_epoch_by_height(height).await?.index;
+
+        delta
+            .roll_up_candlestick(pair, candlestick, Some(timestamp), epoch_index)
+            .await?;
+    }
+
+    storage.commit_in_place(delta).await?;
+    storage.release().await;
+    tracing::info!("candlestick backfill completed");
+    Ok(())
+}
+
+/// Parses the trading pair out of a per-block candlestick key.
+fn parse_pair(key: &[u8]) -> anyhow::Result<DirectedTradingPair> {
+    let key = std::str::from_utf8(key).context("candlestick key is not UTF-8")?;
+    let mut parts = key
+        .strip_prefix(candlesticks::data::prefix())
+        .ok_or_else(|| anyhow!("unexpected candlestick key {key}"))?
+        .split('/');
+    let (Some(start), Some(end)) = (parts.next(), parts.next()) else {
+        anyhow::bail!("unexpected candlestick key {key}");
+    };
+    Ok(DirectedTradingPair::new(
+        asset::Id::from_str(start)?,
+        asset::Id::from_str(end)?,
+    ))
+}
+
+/// Fetches the timestamps of a range of blocks starting at `height`.
+async fn fetch_timestamps(
+    client: &HttpClient,
+    height: u64,
+    timestamps: &mut BTreeMap<u64, u64>,
+) -> anyhow::Result<()> {
+    let response = client
+        .blockchain(
+            tendermint::block::Height::try_from(height)?,
+            tendermint::block::Height::try_from(height + HEADERS_PER_REQUEST - 1)?,
+        )
+        .await
+        .with_context(|| format!("failed to fetch block headers from height {height}"))?;
+    for meta in response.block_metas {
+        timestamps.insert(
+            meta.header.height.value(),
+            meta.header.time.unix_timestamp().try_into()?,
+        );
+    }
+    Ok(())
+}
@@ -145,6 +145,19 @@ pub enum RootCommand {
         #[clap(long, display_order = 1000)]
         ready_to_start: bool,
     },
+    /// Rebuild the rolled-up DEX candlesticks from the per-block candlesticks.
+    ///
+    /// Nodes that were running before candlestick rollups were introduced only
+    /// have per-block candlesticks. The node must be stopped while this runs.
+    BackfillCandlesticks {
+        /// The home directory of the full node.
+        #[clap(long, env = "PENUMBRA_PD_HOME", display_order = 100)]
+        home: PathBuf,
+        /// The JSON-RPC address of a CometBFT node of the same chain, used to
+        /// look up block timestamps.
+        #[clap(long, display_order = 200, default_value = "http://127.0.0.1:26657")]
+        cometbft_addr: Url,
+    },
 }
 
 #[derive(Debug, Subcommand)]
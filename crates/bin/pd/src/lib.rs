@@ -7,6 +7,7 @@
 
 mod metrics;
 
+pub mod backfill;
 pub mod cli;
 pub mod migrate;
 pub mod testnet;
@@ -12,7 +12,7 @@ use cnidarium::Storage;
 use metrics_exporter_prometheus::PrometheusBuilder;
 use pd::{
     cli::{Opt, RootCommand, TestnetCommand},
-    migrate::Migration::{ReadyToStart, Testnet77},
+    migrate::Migration::{ReadyToStart, Testnet78},
     testnet::{
         config::{get_testnet_dir, parse_tm_address, url_has_necessary_parts},
         generate::TestnetConfig,
@@ -458,12 +458,20 @@ async fn main() -> anyhow::Result<()> {
 
             let genesis_start = pd::migrate::last_block_timestamp(pd_home.clone()).await?;
             tracing::info!(?genesis_start, "last block timestamp");
-            Testnet77
+            Testnet78
                 .migrate(pd_home.clone(), comet_home, Some(genesis_start), force)
                 .instrument(pd_migrate_span)
                 .await
                 .context("failed to upgrade state")?;
         }
+        RootCommand::BackfillCandlesticks {
+            home,
+            cometbft_addr,
+        } => {
+            pd::backfill::backfill_candlesticks(home, cometbft_addr)
+                .await
+                .context("failed to backfill candlesticks")?;
+        }
     }
     Ok(())
 }
@@ -0,0 +1,113 @@
+//! Contains functions related to the migration script of Testnet78.
+//! The Testnet 78 release moved the DEX routing limits into the DEX parameters,
+//! so the migration sets them to their defaults, in addition to resetting the halt bit.
+use anyhow::Context;
+use cnidarium::{Snapshot, StateDelta, Storage};
+use jmt::RootHash;
+use penumbra_app::app::StateReadExt as _;
+use penumbra_dex::component::{StateReadExt as _, StateWriteExt as _};
+use penumbra_dex::DexParameters;
+use penumbra_governance::StateWriteExt;
+use penumbra_sct::component::clock::EpochManager;
+use penumbra_sct::component::clock::EpochRead;
+use std::path::PathBuf;
+use tracing::instrument;
+
+use crate::testnet::generate::TestnetConfig;
+
+/// Set the routing limits added to the DEX parameters to their defaults,
+/// keeping the rest of the parameters as they are.
+async fn write_dex_routing_limits(delta: &mut StateDelta<Snapshot>) -> anyhow::Result<()> {
+    let defaults = DexParameters::default();
+    let dex_params = DexParameters {
+        max_path_searches: defaults.max_path_searches,
+        max_executions: defaults.max_executions,
+        max_block_path_searches: defaults.max_block_path_searches,
+        max_block_executions: defaults.max_block_executions,
+        arb_extra_hops: defaults.arb_extra_hops,
+        ..delta.get_dex_params().await?
+    };
+    delta.put_dex_params(dex_params);
+    Ok(())
+}
+
+/// Run the full migration, given an export path and a start time for genesis.
+///
+/// Menu:
+/// - Set the DEX routing limits to their defaults.
+#[instrument]
+pub async fn migrate(
+    storage: Storage,
+    pd_home: PathBuf,
+    genesis_start: Option<tendermint::time::Time>,
+) -> anyhow::Result<()> {
+    // Setup:
+    let initial_state = storage.latest_snapshot();
+    let chain_id = initial_state.get_chain_id().await?;
+    let root_hash = initial_state
+        .root_hash()
+        .await
+        .expect("chain state has a root hash");
+    let pre_upgrade_root_hash: RootHash = root_hash.into();
+    let pre_upgrade_height = initial_state
+        .get_block_height()
+        .await
+        .expect("chain state has a block height");
+    let post_upgrade_height = pre_upgrade_height.wrapping_add(1);
+
+    let mut delta = StateDelta::new(initial_state);
+    write_dex_routing_limits(&mut delta)
+        .await
+        .context("failed to set dex routing limits")?;
+
+    // Set halt bit to 0, so chain can start again.
+    delta.ready_to_start();
+    delta.put_block_height(0u64);
+    let post_upgrade_root_hash = storage
+        .commit_in_place(delta)
+        .await
+        .context("failed to commit migration")?;
+    tracing::info!(?post_upgrade_root_hash, "post-migration root hash");
+    storage.release().await;
+
+    // The migration is complete, now we need to generate a genesis file. To do this, we need
+    // to lookup a validator view from the chain, and specify the post-upgrade app hash and
+    // initial height.
+    let app_state = penumbra_app::genesis::Content {
+        chain_id,
+        ..Default::default()
+    };
+    let mut genesis = TestnetConfig::make_genesis(app_state.clone()).expect("can make genesis");
+    genesis.app_hash = post_upgrade_root_hash
+        .0
+        .to_vec()
+        .try_into()
+        .expect("infallible conversion");
+
+    genesis.initial_height = post_upgrade_height as i64;
+    genesis.genesis_time = genesis_start.unwrap_or_else(|| {
+        let now = tendermint::time::Time::now();
+        tracing::info!(%now, "no genesis time provided, detecting a testing setup");
+        now
+    });
+    let checkpoint = post_upgrade_root_hash.0.to_vec();
+    let genesis = TestnetConfig::make_checkpoint(genesis, Some(checkpoint));
+    let genesis_json = serde_json::to_string(&genesis).expect("can serialize genesis");
+    tracing::info!("genesis: {}", genesis_json);
+    let genesis_path = pd_home.join("genesis.json");
+    std::fs::write(genesis_path, genesis_json).expect("can write genesis");
+
+    let validator_state_path = pd_home.join("priv_validator_state.json");
+    let fresh_validator_state = crate::testnet::generate::TestnetValidator::initial_state();
+    std::fs::write(validator_state_path, fresh_validator_state).expect("can write validator state");
+
+    tracing::info!(
+        pre_upgrade_height,
+        post_upgrade_height,
+        ?pre_upgrade_root_hash,
+        ?post_upgrade_root_hash,
+        "successful migration!"
+    );
+
+    Ok(())
+}
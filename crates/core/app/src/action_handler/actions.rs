@@ -31,6 +31,7 @@ impl AppActionHandler for Action {
             Action::PositionClose(action) => action.check_stateless(()).await,
             Action::PositionOpen(action) => action.check_stateless(()).await,
             Action::PositionWithdraw(action) => action.check_stateless(()).await,
+            Action::PositionRewardClaim(action) => action.check_stateless(()).await,
             Action::ProposalSubmit(action) => action.check_stateless(()).await,
             Action::ProposalWithdraw(action) => action.check_stateless(()).await,
             Action::ProposalDepositClaim(action) => action.check_stateless(()).await,
@@ -64,6 +65,7 @@ impl AppActionHandler for Action {
             Action::PositionClose(action) => action.check_historical(state).await,
             Action::PositionOpen(action) => action.check_historical(state).await,
             Action::PositionWithdraw(action) => action.check_historical(state).await,
+            Action::PositionRewardClaim(action) => action.check_historical(state).await,
             Action::ProposalSubmit(action) => action.check_historical(state).await,
             Action::ProposalWithdraw(action) => action.check_historical(state).await,
             Action::ProposalDepositClaim(action) => action.check_historical(state).await,
@@ -99,6 +101,7 @@ impl AppActionHandler for Action {
             Action::PositionClose(action) => action.check_and_execute(state).await,
             Action::PositionOpen(action) => action.check_and_execute(state).await,
             Action::PositionWithdraw(action) => action.check_and_execute(state).await,
+            Action::PositionRewardClaim(action) => action.check_and_execute(state).await,
             Action::ProposalSubmit(action) => action.check_and_execute(state).await,
             Action::ProposalWithdraw(action) => action.check_and_execute(state).await,
             Action::ProposalDepositClaim(action) => action.check_and_execute(state).await,
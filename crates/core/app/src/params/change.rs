@@ -1,7 +1,8 @@
-use std::fmt::Display;
+use std::{collections::BTreeSet, fmt::Display};
 
 use anyhow::Context;
 use anyhow::Result;
+use penumbra_asset::STAKING_TOKEN_ASSET_ID;
 use penumbra_auction::params::AuctionParameters;
 use penumbra_community_pool::params::CommunityPoolParameters;
 use penumbra_dex::DexParameters;
@@ -68,13 +69,17 @@ impl AppParameters {
             distributions_params:
                 DistributionsParameters {
                     staking_issuance_per_block: _,
+                    liquidity_reward_bps: _,
                 },
             fee_params:
                 FeeParameters {
                     fixed_gas_prices: _,
                     fixed_alt_gas_prices: _,
                 },
-            funding_params: FundingParameters {},
+            funding_params:
+                FundingParameters {
+                    liquidity_reward_pairs: _,
+                },
             governance_params:
                 GovernanceParameters {
                     proposal_voting_blocks: _,
@@ -108,6 +113,12 @@ impl AppParameters {
                     fixed_candidates: _,
                     max_hops: _,
                     max_positions_per_pair: _,
+                    arb_tokens: _,
+                    max_path_searches: _,
+                    max_executions: _,
+                    max_block_path_searches: _,
+                    max_block_executions: _,
+                    arb_extra_hops: _,
                 },
             // IMPORTANT: Don't use `..` here! We want to ensure every single field is verified!
         } = self;
@@ -163,13 +174,17 @@ impl AppParameters {
             distributions_params:
                 DistributionsParameters {
                     staking_issuance_per_block: _,
+                    liquidity_reward_bps,
                 },
             fee_params:
                 FeeParameters {
                     fixed_gas_prices: _,
                     fixed_alt_gas_prices: _,
                 },
-            funding_params: FundingParameters {},
+            funding_params:
+                FundingParameters {
+                    liquidity_reward_pairs,
+                },
             governance_params:
                 GovernanceParameters {
                     proposal_voting_blocks,
@@ -203,6 +218,12 @@ impl AppParameters {
                     fixed_candidates: _,
                     max_hops: _,
                     max_positions_per_pair: _,
+                    arb_tokens,
+                    max_path_searches,
+                    max_executions,
+                    max_block_path_searches,
+                    max_block_executions,
+                    arb_extra_hops,
                 },
             // IMPORTANT: Don't use `..` here! We want to ensure every single field is verified!
         } = self;
@@ -278,6 +299,61 @@ impl AppParameters {
                 *min_validator_stake >= 1_000_000u128.into(),
                 "the minimum validator stake must be at least 1penumbra",
             ),
+            (
+                arb_tokens
+                    .iter()
+                    .map(|token| token.asset_id)
+                    .collect::<BTreeSet<_>>()
+                    .len()
+                    == arb_tokens.len(),
+                "arb tokens must be unique",
+            ),
+            (
+                *max_path_searches >= 1,
+                "max path searches must be at least 1",
+            ),
+            (
+                *max_path_searches <= 1_024,
+                "max path searches must be at most 1,024",
+            ),
+            (*max_executions >= 1, "max executions must be at least 1"),
+            (
+                *max_executions <= 1_024,
+                "max executions must be at most 1,024",
+            ),
+            (
+                *max_block_path_searches >= *max_path_searches,
+                "max block path searches must be at least max path searches",
+            ),
+            (
+                *max_block_path_searches <= 65_536,
+                "max block path searches must be at most 65,536",
+            ),
+            (
+                *max_block_executions >= *max_executions,
+                "max block executions must be at least max executions",
+            ),
+            (
+                *max_block_executions <= 65_536,
+                "max block executions must be at most 65,536",
+            ),
+            (*arb_extra_hops <= 4, "arb extra hops must be at most 4"),
+            (
+                *liquidity_reward_bps <= 10_000,
+                "liquidity reward share must be at most 10,000 basis points",
+            ),
+            (
+                liquidity_reward_pairs.iter().collect::<BTreeSet<_>>().len()
+                    == liquidity_reward_pairs.len(),
+                "liquidity reward pairs must be unique",
+            ),
+            (
+                liquidity_reward_pairs.iter().all(|pair| {
+                    pair.asset_1() == *STAKING_TOKEN_ASSET_ID
+                        || pair.asset_2() == *STAKING_TOKEN_ASSET_ID
+                }),
+                "liquidity reward pairs must include the staking token",
+            ),
             // TODO(erwan): add a `max_positions_per_pair` check
         ])
     }
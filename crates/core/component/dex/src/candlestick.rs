@@ -1,4 +1,6 @@
-use anyhow::Result;
+use std::{fmt, str::FromStr};
+
+use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 
 use penumbra_proto::{core::component::dex::v1 as pb, DomainType};
@@ -7,6 +9,8 @@ use penumbra_proto::{core::component::dex::v1 as pb, DomainType};
 #[serde(try_from = "pb::CandlestickData", into = "pb::CandlestickData")]
 pub struct CandlestickData {
     /// The block height of the candlestick data.
+    ///
+    /// For rolled-up candlesticks, this is the last height included in the candlestick.
     pub height: u64,
     /// The first observed price during the block execution.
     pub open: f64,
@@ -20,6 +24,33 @@ pub struct CandlestickData {
     pub direct_volume: f64,
     /// The volume that traded as part of swaps, which could have traversed multiple routes.
     pub swap_volume: f64,
+    /// The first height included in the candlestick, for rolled-up candlesticks.
+    pub start_height: u64,
+    /// The bucket covered by the candlestick, for rolled-up candlesticks.
+    ///
+    /// See [`CandlestickResolution::bucket`].
+    pub bucket: u64,
+}
+
+impl CandlestickData {
+    /// Starts a rolled-up candlestick for `bucket` from a per-block candlestick.
+    pub fn rollup(block: &CandlestickData, bucket: u64) -> Self {
+        Self {
+            start_height: block.height,
+            bucket,
+            ..*block
+        }
+    }
+
+    /// Extends this candlestick with a later candlestick.
+    pub fn merge(&mut self, later: &CandlestickData) {
+        self.height = later.height;
+        self.close = later.close;
+        self.high = self.high.max(later.high);
+        self.low = self.low.min(later.low);
+        self.direct_volume += later.direct_volume;
+        self.swap_volume += later.swap_volume;
+    }
 }
 
 impl DomainType for CandlestickData {
@@ -36,6 +67,8 @@ impl From<CandlestickData> for pb::CandlestickData {
             low: cd.low,
             direct_volume: cd.direct_volume,
             swap_volume: cd.swap_volume,
+            start_height: cd.start_height,
+            bucket: cd.bucket,
         }
     }
 }
@@ -51,6 +84,110 @@ impl TryFrom<pb::CandlestickData> for CandlestickData {
             low: cd.low,
             direct_volume: cd.direct_volume,
             swap_volume: cd.swap_volume,
+            start_height: cd.start_height,
+            bucket: cd.bucket,
         })
     }
 }
+
+/// The resolution of candlestick data.
+///
+/// Per-block candlesticks are recorded by the chandelier at the end of every
+/// block, and rolled up into candlesticks at the coarser resolutions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CandlestickResolution {
+    Block,
+    Minute,
+    Hour,
+    Day,
+    Epoch,
+}
+
+impl CandlestickResolution {
+    /// The resolutions that are rolled up from the per-block candlesticks.
+    pub const ROLLUPS: [Self; 4] = [Self::Minute, Self::Hour, Self::Day, Self::Epoch];
+
+    /// The width of a bucket in seconds, for time-based resolutions.
+    pub fn bucket_seconds(&self) -> Option<u64> {
+        match self {
+            Self::Minute => Some(60),
+            Self::Hour => Some(60 * 60),
+            Self::Day => Some(24 * 60 * 60),
+            Self::Block | Self::Epoch => None,
+        }
+    }
+
+    /// The bucket containing a block with the given height, UNIX timestamp and
+    /// epoch index.
+    ///
+    /// Buckets of time-based resolutions are identified by the UNIX timestamp
+    /// of their start, and epoch buckets by the epoch index.
+    pub fn bucket(&self, height: u64, timestamp: u64, epoch_index: u64) -> u64 {
+        match self {
+            Self::Block => height,
+            Self::Epoch => epoch_index,
+            Self::Minute | Self::Hour | Self::Day => {
+                let width = self.bucket_seconds().expect("time-based resolution");
+                timestamp - timestamp % width
+            }
+        }
+    }
+
+    /// The name of the resolution, as used in state keys and on the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Block => "block",
+            Self::Minute => "1m",
+            Self::Hour => "1h",
+            Self::Day => "1d",
+            Self::Epoch => "epoch",
+        }
+    }
+}
+
+impl fmt::Display for CandlestickResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for CandlestickResolution {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "block" => Ok(Self::Block),
+            "1m" => Ok(Self::Minute),
+            "1h" => Ok(Self::Hour),
+            "1d" => Ok(Self::Day),
+            "epoch" => Ok(Self::Epoch),
+            _ => Err(anyhow!(
+                "unknown candlestick resolution {s}, expected one of block, 1m, 1h, 1d, epoch"
+            )),
+        }
+    }
+}
+
+impl From<CandlestickResolution> for pb::CandlestickResolution {
+    fn from(resolution: CandlestickResolution) -> Self {
+        match resolution {
+            CandlestickResolution::Block => pb::CandlestickResolution::Unspecified,
+            CandlestickResolution::Minute => pb::CandlestickResolution::Minute,
+            CandlestickResolution::Hour => pb::CandlestickResolution::Hour,
+            CandlestickResolution::Day => pb::CandlestickResolution::Day,
+            CandlestickResolution::Epoch => pb::CandlestickResolution::Epoch,
+        }
+    }
+}
+
+impl From<pb::CandlestickResolution> for CandlestickResolution {
+    fn from(resolution: pb::CandlestickResolution) -> Self {
+        match resolution {
+            pb::CandlestickResolution::Unspecified => CandlestickResolution::Block,
+            pb::CandlestickResolution::Minute => CandlestickResolution::Minute,
+            pb::CandlestickResolution::Hour => CandlestickResolution::Hour,
+            pb::CandlestickResolution::Day => CandlestickResolution::Day,
+            pb::CandlestickResolution::Epoch => CandlestickResolution::Epoch,
+        }
+    }
+}
@@ -1,3 +1,4 @@
 mod close;
 mod open;
+mod reward_claim;
 mod withdraw;
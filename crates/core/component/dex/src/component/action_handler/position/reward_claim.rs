@@ -0,0 +1,50 @@
+use anyhow::Result;
+use ark_ff::Zero;
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use cnidarium_component::ActionHandler;
+use decaf377::Fr;
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+
+use crate::{component::PositionManager, lp::action::PositionRewardClaim};
+
+#[async_trait]
+/// Debits a withdrawn position NFT and credits a withdrawn position NFT with the
+/// next sequence number and the rewards accrued by the position.
+impl ActionHandler for PositionRewardClaim {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        // A claim consumes a withdrawn position NFT, so it cannot be the first
+        // withdrawn state of the position.
+        anyhow::ensure!(
+            self.sequence > 0,
+            "reward claims must have a sequence number of at least 1"
+        );
+        Ok(())
+    }
+
+    async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // As with withdrawals, we check the rewards at the moment we execute the
+        // claim, to prevent any possibility of TOCTOU attacks.
+        let rewards = state
+            .claim_position_rewards(self.position_id, self.sequence)
+            .await?;
+
+        // Check that the commitment to the rewards the user is claiming is correct.
+        let expected_rewards_commitment = Value {
+            amount: rewards,
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+        .commit(Fr::zero());
+
+        if self.rewards_commitment != expected_rewards_commitment {
+            anyhow::bail!(
+                "rewards commitment {:?} is incorrect, expected {:?}",
+                self.rewards_commitment,
+                expected_rewards_commitment
+            );
+        }
+
+        Ok(())
+    }
+}
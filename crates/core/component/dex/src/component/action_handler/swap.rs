@@ -23,6 +23,18 @@ impl ActionHandler for Swap {
             anyhow::bail!("Trading pair must be distinct");
         }
 
+        // A minimum output only makes sense for a swap with a single input.
+        if let Some(min_output) = self.body.min_output {
+            ensure!(
+                min_output > 0u64.into(),
+                "minimum output must be nonzero, or omitted"
+            );
+            ensure!(
+                (self.body.delta_1_i == 0u64.into()) != (self.body.delta_2_i == 0u64.into()),
+                "swaps with a minimum output must have exactly one nonzero input"
+            );
+        }
+
         self.proof.verify(
             &SWAP_PROOF_VERIFICATION_KEY,
             SwapProofPublic {
@@ -55,6 +67,16 @@ impl ActionHandler for Swap {
         swap_flow.0 += swap.body.delta_1_i;
         swap_flow.1 += swap.body.delta_2_i;
 
+        // Record this swap's minimum output, if any, so that its direction of
+        // the batch is only executed if the minimum is met.
+        if let Some(min_output) = swap.body.min_output {
+            if swap.body.delta_1_i > 0u64.into() {
+                swap_flow.limit_swap_1_for_2(swap.body.delta_1_i, min_output);
+            } else {
+                swap_flow.limit_swap_2_for_1(swap.body.delta_2_i, min_output);
+            }
+        }
+
         // Set the batch swap flow for the trading pair.
         state
             .put_swap_flow(&swap.body.trading_pair, swap_flow)
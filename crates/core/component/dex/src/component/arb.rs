@@ -4,14 +4,15 @@ use crate::component::metrics;
 use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateDelta, StateWrite};
-use penumbra_asset::{asset, Value};
+use penumbra_asset::Value;
+use penumbra_community_pool::component::StateWriteExt as _;
 use penumbra_proto::StateWriteProto as _;
 use penumbra_sct::component::clock::EpochRead;
 use tracing::instrument;
 
 use crate::{
     component::{ExecutionCircuitBreaker, InternalDexWrite, ValueCircuitBreaker},
-    event, SwapExecution,
+    event, ArbSurplusDestination, ArbToken, SwapExecution,
 };
 
 use super::router::{RouteAndFill, RoutingParams};
@@ -20,16 +21,24 @@ use super::router::{RouteAndFill, RoutingParams};
 pub trait Arbitrage: StateWrite + Sized {
     /// Attempts to extract as much as possible of the `arb_token` from the available
     /// liquidity positions, and returns the amount of `arb_token` extracted.
-    #[instrument(skip(self, arb_token, routing_params))]
+    ///
+    /// The search counts against the `execution_budget`, so that several arb
+    /// tokens can share the same execution budget.
+    #[instrument(skip(self, arb_token, routing_params, execution_budget))]
     async fn arbitrage(
         self: &mut Arc<Self>,
-        arb_token: asset::Id,
+        arb_token: ArbToken,
         routing_params: RoutingParams,
+        execution_budget: &mut ExecutionCircuitBreaker,
     ) -> Result<Option<Value>>
     where
         Self: 'static,
     {
         tracing::debug!(?arb_token, ?routing_params, "beginning arb search");
+        let ArbToken {
+            asset_id: arb_token,
+            surplus_destination,
+        } = arb_token;
         let arb_start = std::time::Instant::now();
 
         // Work in a new `StateDelta`, so we can transactionally apply any state
@@ -43,14 +52,13 @@ pub trait Arbitrage: StateWrite + Sized {
             amount: u64::MAX.into(),
         };
 
-        let execution_circuit_breaker = ExecutionCircuitBreaker::default();
         let swap_execution = this
             .route_and_fill(
                 arb_token,
                 arb_token,
                 flash_loan.amount,
                 routing_params,
-                execution_circuit_breaker,
+                execution_budget,
             )
             .await?;
         let filled_input = swap_execution.input.amount;
@@ -120,20 +128,26 @@ pub trait Arbitrage: StateWrite + Sized {
         };
         self_mut.set_arb_execution(height, se.clone());
 
+        let surplus = Value {
+            amount: arb_profit,
+            asset_id: arb_token,
+        };
+
         // Deduct the input surplus from the dex's VCB.
-        self_mut
-            .dex_vcb_debit(Value {
-                amount: arb_profit,
-                asset_id: arb_token,
-            })
-            .await?;
+        self_mut.dex_vcb_debit(surplus).await?;
+
+        // The surplus has left the DEX; either it is burned, or it goes to the
+        // community pool.
+        match surplus_destination {
+            ArbSurplusDestination::Burn => {}
+            ArbSurplusDestination::CommunityPool => {
+                self_mut.community_pool_deposit(surplus).await?;
+            }
+        }
 
         // Emit an ABCI event detailing the arb execution.
         self_mut.record_proto(event::arb_execution(height, se));
-        return Ok(Some(Value {
-            amount: arb_profit,
-            asset_id: arb_token,
-        }));
+        return Ok(Some(surplus));
     }
 }
 
@@ -8,9 +8,12 @@ use penumbra_proto::{DomainType, StateReadProto, StateWriteProto};
 use penumbra_sct::component::clock::EpochRead as _;
 use tonic::async_trait;
 
-use crate::{lp::position::Position, state_key::candlesticks, DirectedTradingPair, SwapExecution};
+use crate::{
+    component::InternalDexWrite as _, lp::position::Position, state_key::candlesticks,
+    DirectedTradingPair, SwapExecution,
+};
 
-use crate::CandlestickData;
+use crate::{CandlestickData, CandlestickResolution};
 
 #[async_trait]
 pub trait CandlestickRead: StateRead {
@@ -52,6 +55,56 @@ pub trait CandlestickRead: StateRead {
             .try_collect()
             .await
     }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn get_rollup_candlestick(
+        &self,
+        resolution: CandlestickResolution,
+        trading_pair: &DirectedTradingPair,
+        bucket: u64,
+    ) -> Result<Option<CandlestickData>> {
+        self.nonverifiable_get(
+            candlesticks::rollup::by_resolution_pair_and_bucket(resolution, trading_pair, bucket)
+                .as_bytes(),
+        )
+        .await
+    }
+
+    /// Returns up to `limit` rolled-up candlesticks, starting from `start_bucket`
+    /// and ending before `end_bucket`, if set.
+    async fn rollup_candlesticks(
+        &self,
+        resolution: CandlestickResolution,
+        trading_pair: &DirectedTradingPair,
+        start_bucket: u64,
+        end_bucket: Option<u64>,
+        limit: usize,
+    ) -> Result<Vec<CandlestickData>> {
+        let prefix = candlesticks::rollup::by_resolution_and_pair(resolution, trading_pair);
+        let start_bucket_key = format!("{:020}", start_bucket).as_bytes().to_vec();
+        tracing::trace!(
+            ?prefix,
+            ?start_bucket,
+            ?end_bucket,
+            "searching for rolled-up candlesticks from starting bucket"
+        );
+
+        let range = self
+            .nonverifiable_range_raw(Some(prefix.as_bytes()), start_bucket_key..)
+            .context("error forming range query")?;
+
+        range
+            .and_then(|(_k, v)| async move {
+                CandlestickData::decode(v.as_ref()).context("error deserializing candlestick")
+            })
+            .try_take_while(|candlestick| {
+                let in_range = end_bucket.map_or(true, |end| candlestick.bucket < end);
+                futures::future::ready(Ok(in_range))
+            })
+            .take(limit)
+            .try_collect()
+            .await
+    }
 }
 impl<T: StateRead + ?Sized> CandlestickRead for T {}
 
@@ -147,6 +200,14 @@ pub trait Chandelier: StateWrite {
     #[tracing::instrument(level = "debug", skip(self))]
     async fn finalize_block_candlesticks(&mut self) -> Result<()> {
         let height = self.get_block_height().await?;
+        let epoch_index = self.get_current_epoch().await?.index;
+        // The block timestamp is only missing in tests that don't run the full
+        // block lifecycle, in which case only per-epoch candlesticks are rolled up.
+        let timestamp = self
+            .get_block_timestamp()
+            .await
+            .ok()
+            .map(|time| time.unix_timestamp().try_into().unwrap_or_default());
 
         // Fetch all the executions for the block.
         let block_executions = self.block_executions();
@@ -199,6 +260,8 @@ pub trait Chandelier: StateWrite {
                 low,
                 direct_volume,
                 swap_volume,
+                start_height: height,
+                bucket: height,
             };
             tracing::debug!(
                 ?height,
@@ -210,6 +273,59 @@ pub trait Chandelier: StateWrite {
                 candlesticks::data::by_pair_and_height(&trading_pair, height).into(),
                 candlestick,
             );
+            self.roll_up_candlestick(trading_pair, &candlestick, timestamp, epoch_index)
+                .await?;
+
+            // The closing price of the block also feeds the pair's price accumulator,
+            // from which time-weighted average prices are computed. Only the closing
+            // price is sampled, not the prices of the block's earlier executions.
+            if let Some((close, _, _)) = block_executions.last() {
+                self.update_price_accumulator(trading_pair, height, *close)
+                    .await;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Merges a per-block candlestick into the rolled-up candlesticks for
+    /// each resolution in [`CandlestickResolution::ROLLUPS`].
+    ///
+    /// If the block's UNIX `timestamp` is unknown, only the per-epoch
+    /// candlestick is updated.
+    #[tracing::instrument(level = "debug", skip(self, candlestick))]
+    async fn roll_up_candlestick(
+        &mut self,
+        trading_pair: &DirectedTradingPair,
+        candlestick: &CandlestickData,
+        timestamp: Option<u64>,
+        epoch_index: u64,
+    ) -> Result<()> {
+        for resolution in CandlestickResolution::ROLLUPS {
+            let timestamp = match (resolution.bucket_seconds(), timestamp) {
+                (Some(_), None) => continue,
+                (_, timestamp) => timestamp.unwrap_or_default(),
+            };
+            let bucket = resolution.bucket(candlestick.height, timestamp, epoch_index);
+            let rollup = match self
+                .get_rollup_candlestick(resolution, trading_pair, bucket)
+                .await?
+            {
+                Some(mut rollup) => {
+                    rollup.merge(candlestick);
+                    rollup
+                }
+                None => CandlestickData::rollup(candlestick, bucket),
+            };
+            self.nonverifiable_put(
+                candlesticks::rollup::by_resolution_pair_and_bucket(
+                    resolution,
+                    trading_pair,
+                    bucket,
+                )
+                .into(),
+                rollup,
+            );
         }
 
         Ok(())
@@ -271,7 +387,7 @@ mod tests {
     use crate::{
         component::{
             router::create_buy, tests::TempStorageExt as _, Dex, PositionManager as _,
-            SwapDataRead, SwapDataWrite,
+            StateReadExt as _, SwapDataRead, SwapDataWrite,
         },
         DirectedUnitPair,
     };
@@ -288,6 +404,8 @@ mod tests {
         let mut state_tx = state.try_begin_transaction().unwrap();
 
         state_tx.put_block_height(0);
+        state_tx
+            .put_block_timestamp(tendermint::Time::from_unix_timestamp(1_700_000_040, 0).unwrap());
         state_tx.put_epoch_by_height(
             0,
             penumbra_sct::epoch::Epoch {
@@ -351,6 +469,8 @@ mod tests {
         let mut state_tx = state.try_begin_transaction().unwrap();
         let height = 1u64;
         state_tx.put_block_height(height);
+        state_tx
+            .put_block_timestamp(tendermint::Time::from_unix_timestamp(1_700_000_045, 0).unwrap());
         state_tx.put_epoch_by_height(
             height,
             Epoch {
@@ -471,6 +591,57 @@ mod tests {
             two_gn,
             "swap volume is 2 gn"
         );
+
+        // Both blocks are in the same minute and epoch, so the rolled-up
+        // candlesticks cover both of them.
+        let three_gn = gn.value(3u32.into());
+        for (resolution, bucket) in [
+            (CandlestickResolution::Minute, 1_700_000_040),
+            (CandlestickResolution::Epoch, 0),
+        ] {
+            let cs = state
+                .get_rollup_candlestick(
+                    resolution,
+                    &pair_gn_penumbra.into_directed_trading_pair(),
+                    bucket,
+                )
+                .await?
+                .expect("rolled-up candlestick exists");
+
+            let swap_volume: U128x128 = cs.swap_volume.try_into().unwrap();
+            assert_eq!(cs.bucket, bucket, "bucket is {bucket}");
+            assert_eq!(cs.start_height, 0u64, "start height is 0");
+            assert_eq!(cs.height, 1u64, "end height is 1");
+            assert_eq!(cs.open, 2.0, "open price is 2.0");
+            assert_eq!(cs.close, 1.5, "close price is 1.5");
+            assert_eq!(cs.high, 2.0, "high price is 2.0");
+            assert_eq!(cs.low, 1.0, "low price is 1.0");
+            assert_eq!(
+                base_gn.value(swap_volume.try_into().unwrap()),
+                three_gn,
+                "swap volume is 3 gn"
+            );
+        }
+
+        // The closing prices of both blocks were recorded in the price accumulator.
+        let pair = pair_gn_penumbra.into_directed_trading_pair();
+        for (start_height, end_height, expected) in [(0, 0, 2.0), (1, 1, 1.5), (0, 1, 1.75)] {
+            let twap: f64 = state
+                .time_weighted_average_price(&pair, start_height, end_height)
+                .await?
+                .into();
+            assert_eq!(
+                twap, expected,
+                "twap over {start_height}..={end_height} is {expected}"
+            );
+        }
+
+        // The latest accumulator is part of the verifiable state.
+        let latest = state
+            .latest_price_accumulator(&pair)
+            .await?
+            .expect("the pair traded");
+        assert_eq!(latest.height, 1);
         Ok(())
     }
 }
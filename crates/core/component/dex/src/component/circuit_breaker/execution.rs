@@ -1,3 +1,5 @@
+use crate::DexParameters;
+
 const MAX_PATH_SEARCHES: u32 = 64;
 const MAX_EXECUTIONS: u32 = 64;
 
@@ -8,6 +10,8 @@ const MAX_EXECUTIONS: u32 = 64;
 /// to the search and execution limits managed by the circuit breaker.
 ///
 /// The circuit breaker ensures the swap will not use unbounded time complexity.
+/// The same structure is used to track a budget shared by several swaps, such
+/// as all the batch swaps of a block.
 #[derive(Debug, Clone)]
 pub(crate) struct ExecutionCircuitBreaker {
     /// The maximum number of times to perform path searches before stopping.
@@ -21,7 +25,6 @@ pub(crate) struct ExecutionCircuitBreaker {
 }
 
 impl ExecutionCircuitBreaker {
-    #[allow(dead_code)]
     pub fn new(max_path_searches: u32, max_executions: u32) -> Self {
         Self {
             max_path_searches,
@@ -31,19 +34,21 @@ impl ExecutionCircuitBreaker {
         }
     }
 
+    /// The budget for routing all of a block's batch swaps.
+    pub fn block_budget(params: &DexParameters) -> Self {
+        Self::new(params.max_block_path_searches, params.max_block_executions)
+    }
+
     pub fn exceeded_limits(&self) -> bool {
         self.current_path_searches > self.max_path_searches
             || self.current_executions > self.max_executions
     }
 }
 
+/// The default budget is that of a single trade. Budgets shared by several
+/// trades, like [`ExecutionCircuitBreaker::block_budget`], must be named explicitly.
 impl Default for ExecutionCircuitBreaker {
     fn default() -> Self {
-        Self {
-            max_path_searches: MAX_PATH_SEARCHES,
-            current_path_searches: 0,
-            max_executions: MAX_EXECUTIONS,
-            current_executions: 0,
-        }
+        Self::new(MAX_PATH_SEARCHES, MAX_EXECUTIONS)
     }
 }
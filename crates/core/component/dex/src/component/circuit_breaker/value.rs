@@ -64,13 +64,15 @@ mod tests {
 
     use crate::component::position_manager::price_index::PositionByPriceIndex;
     use crate::component::router::HandleBatchSwaps as _;
-    use crate::component::{InternalDexWrite, StateReadExt as _, SwapDataRead, SwapDataWrite};
+    use crate::component::{
+        ExecutionCircuitBreaker, InternalDexWrite, StateReadExt as _, SwapDataRead, SwapDataWrite,
+    };
     use crate::lp::plan::PositionWithdrawPlan;
     use crate::{
         component::{router::create_buy, tests::TempStorageExt},
         state_key, DirectedUnitPair,
     };
-    use crate::{BatchSwapOutputData, PositionOpen};
+    use crate::{BatchSwapOutputData, DexParameters, PositionOpen};
     use cnidarium::{ArcStateDeltaExt as _, StateDelta, TempStorage};
     use cnidarium_component::ActionHandler as _;
     use penumbra_asset::asset;
@@ -265,7 +267,13 @@ mod tests {
         let routing_params = state.routing_params().await.unwrap();
         // This call should panic due to the outflow of gn not being covered by the circuit breaker.
         state
-            .handle_batch_swaps(trading_pair, swap_flow, 0, routing_params)
+            .handle_batch_swaps(
+                trading_pair,
+                swap_flow,
+                0,
+                routing_params,
+                &mut ExecutionCircuitBreaker::block_budget(&DexParameters::default()),
+            )
             .await
             .expect("unable to process batch swaps");
     }
@@ -1,27 +1,33 @@
 use std::collections::BTreeSet;
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{anyhow, ensure, Result};
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
 use cnidarium_component::Component;
+use futures::{StreamExt, TryStreamExt};
 use penumbra_asset::asset;
 use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_num::fixpoint::U128x128;
 use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_sct::component::clock::EpochRead as _;
 use tendermint::v0_37::abci;
 use tracing::instrument;
 
+use crate::lp::{position::Position, PositionExecution};
 use crate::state_key::block_scoped;
 use crate::{
     component::SwapDataRead, component::SwapDataWrite, event, genesis, state_key,
-    BatchSwapOutputData, DexParameters, DirectedTradingPair, SwapExecution, TradingPair,
+    BatchSwapOutputData, DexParameters, DirectedTradingPair, PriceAccumulator, SwapExecution,
+    TradingPair, PRICE_HISTORY_RETENTION,
 };
 
 use super::eviction_manager::EvictionManager;
 use super::{
     chandelier::Chandelier,
     router::{HandleBatchSwaps, RoutingParams},
-    Arbitrage, PositionManager, PositionRead as _, ValueCircuitBreaker,
+    Arbitrage, ExecutionCircuitBreaker, PositionManager, PositionRead as _, RewardManager as _,
+    ValueCircuitBreaker,
 };
 
 pub struct Dex {}
@@ -56,7 +62,11 @@ impl Component for Dex {
         // This has already happened in the action handlers for each `PositionOpen` action.
 
         // 2. For each batch swap during the block, calculate clearing prices and set in the JMT.
-        let routing_params = state.routing_params().await.expect("dex params are set");
+        let dex_params = state.get_dex_params().await.expect("dex params are set");
+        let routing_params = RoutingParams::from(dex_params.clone());
+
+        // All of the block's batch swaps share a single routing budget.
+        let mut execution_budget = ExecutionCircuitBreaker::block_budget(&dex_params);
 
         for (trading_pair, swap_flows) in state.swap_flows() {
             let batch_start = std::time::Instant::now();
@@ -72,6 +82,7 @@ impl Component for Dex {
                     routing_params
                         .clone()
                         .with_extra_candidates([trading_pair.asset_1(), trading_pair.asset_2()]),
+                    &mut execution_budget,
                 )
                 .await
                 .expect("handling batch swaps is infaillible");
@@ -81,8 +92,8 @@ impl Component for Dex {
 
         // 3. Perform arbitrage to ensure all prices are consistent post-execution:
 
-        // For arbitrage, we extend the path search by 2 hops to allow a path out of the
-        // staking token and back.
+        // For arbitrage, we extend the path search by a few hops to allow a path out of
+        // the arb token and back.
 
         // Extend the fixed candidate set to include recently accessed assets, to have
         // more arbitrage execution against newly opened positions.
@@ -98,23 +109,35 @@ impl Component for Dex {
         );
 
         let arb_routing_params = RoutingParams {
-            max_hops: routing_params.max_hops + 2,
+            max_hops: routing_params.max_hops + dex_params.arb_extra_hops as usize,
             fixed_candidates,
             price_limit: Some(1u64.into()),
+            ..routing_params
         };
 
-        match state
-            .arbitrage(*STAKING_TOKEN_ASSET_ID, arb_routing_params)
-            .await
-        {
-            // The arb search completed successfully, and surfaced some surplus.
-            Ok(Some(v)) => tracing::info!(surplus = ?v, "arbitrage successful!"),
-            // The arb completed without errors, but resulted in no surplus, so
-            // the state fork was discarded.
-            Ok(None) => tracing::debug!("no arbitrage found"),
-            // The arbitrage search should not error, but if it does, we should
-            // simply not perform arbitrage, rather than halting the entire chain.
-            Err(e) => tracing::warn!(?e, "error processing arb, this is a bug"),
+        // The arb tokens are searched in the order set by governance, and share the
+        // execution budget of a single trade, so that adding arb tokens does not
+        // increase the worst-case time spent in arbitrage.
+        let mut arb_execution_budget =
+            ExecutionCircuitBreaker::new(dex_params.max_path_searches, dex_params.max_executions);
+        for arb_token in dex_params.arb_tokens() {
+            match state
+                .arbitrage(
+                    arb_token,
+                    arb_routing_params.clone(),
+                    &mut arb_execution_budget,
+                )
+                .await
+            {
+                // The arb search completed successfully, and surfaced some surplus.
+                Ok(Some(v)) => tracing::info!(surplus = ?v, "arbitrage successful!"),
+                // The arb completed without errors, but resulted in no surplus, so
+                // the state fork was discarded.
+                Ok(None) => tracing::debug!(?arb_token, "no arbitrage found"),
+                // The arbitrage search should not error, but if it does, we should
+                // simply not perform arbitrage, rather than halting the entire chain.
+                Err(e) => tracing::warn!(?e, ?arb_token, "error processing arb, this is a bug"),
+            }
         }
 
         // 4. Inspect trading pairs that saw new position opened during this block, and
@@ -142,7 +165,19 @@ impl Component for Dex {
     }
 
     #[instrument(name = "dex", skip(_state))]
-    async fn end_epoch<S: StateWrite + 'static>(mut _state: &mut Arc<S>) -> Result<()> {
+    async fn end_epoch<S: StateWrite + 'static>(state: &mut Arc<S>) -> Result<()> {
+        let state = Arc::get_mut(state).ok_or_else(|| anyhow!("state should be unique"))?;
+
+        // The funding component distributes the liquidity rewards of the ending
+        // epoch after us, so we only prune the reward tracking of earlier epochs.
+        let epoch_index = state.get_current_epoch().await?.index;
+        if let Err(error) = state.prune_reward_tracking(epoch_index).await {
+            tracing::error!(
+                ?error,
+                epoch_index,
+                "failed to prune liquidity reward tracking"
+            );
+        }
         Ok(())
     }
 }
@@ -180,8 +215,24 @@ pub trait StateReadExt: StateRead {
             .await
     }
 
-    async fn arb_execution(&self, height: u64) -> Result<Option<SwapExecution>> {
-        self.get(&state_key::arb_execution(height)).await
+    /// Gets the arb execution against `arb_token` at `height`, if any.
+    async fn arb_execution(
+        &self,
+        height: u64,
+        arb_token: asset::Id,
+    ) -> Result<Option<SwapExecution>> {
+        match self
+            .get(&state_key::arb_execution(height, &arb_token))
+            .await?
+        {
+            Some(execution) => Ok(Some(execution)),
+            // Before arb tokens were configurable, the staking token was the
+            // only arb token, and its executions were recorded by height alone.
+            None if arb_token == *STAKING_TOKEN_ASSET_ID => {
+                self.get(&state_key::legacy_arb_execution(height)).await
+            }
+            None => Ok(None),
+        }
     }
 
     /// Return a set of [`TradingPair`]s for which liquidity positions were opened
@@ -190,6 +241,79 @@ pub trait StateReadExt: StateRead {
         self.object_get(block_scoped::active::trading_pairs())
             .unwrap_or_default()
     }
+
+    /// Gets the latest price accumulator of a pair.
+    async fn latest_price_accumulator(
+        &self,
+        pair: &DirectedTradingPair,
+    ) -> Result<Option<PriceAccumulator>> {
+        self.get(&state_key::price_accumulators::latest(pair)).await
+    }
+
+    /// Gets the last price accumulator of a pair updated at or before `height`.
+    async fn price_accumulator(
+        &self,
+        pair: &DirectedTradingPair,
+        height: u64,
+    ) -> Result<Option<PriceAccumulator>> {
+        match self.latest_price_accumulator(pair).await? {
+            Some(latest) if latest.height <= height => return Ok(Some(latest)),
+            Some(_) => {}
+            None => return Ok(None),
+        }
+
+        // The history is ordered by height, and only covers the retention
+        // window, so this stops at the first update after `height`.
+        let mut history =
+            self.prefix::<PriceAccumulator>(&state_key::price_accumulators::by_pair(pair));
+        let mut found = None;
+        while let Some((_, accumulator)) = history.next().await.transpose()? {
+            if accumulator.height > height {
+                break;
+            }
+            found = Some(accumulator);
+        }
+        Ok(found)
+    }
+
+    /// Computes the time-weighted average price of a pair over the heights
+    /// `start_height..=end_height`, quoted like [`CandlestickData`](crate::CandlestickData) prices.
+    ///
+    /// The price at each height is the price of the pair's last execution in
+    /// the last block at or before that height in which it traded, so moving the
+    /// average requires moving the closing price of many blocks. Fails if the
+    /// pair never traded at or before `start_height`.
+    async fn time_weighted_average_price(
+        &self,
+        pair: &DirectedTradingPair,
+        start_height: u64,
+        end_height: u64,
+    ) -> Result<U128x128> {
+        ensure!(
+            start_height <= end_height,
+            "start height {start_height} is after end height {end_height}"
+        );
+        let no_price = || {
+            anyhow!(
+                "no price recorded for {pair:?} at or before height {start_height} \
+                 in the retained history"
+            )
+        };
+        let start = self
+            .price_accumulator(pair, start_height)
+            .await?
+            .ok_or_else(no_price)?;
+        let end = self
+            .price_accumulator(pair, end_height)
+            .await?
+            .ok_or_else(no_price)?;
+
+        let total = end
+            .cumulative_price_at(end_height + 1)?
+            .checked_sub(&start.cumulative_price_at(start_height)?)?;
+        let window = U128x128::from(end_height - start_height + 1);
+        Ok(total.checked_div(&window)?)
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
@@ -300,7 +424,95 @@ pub(crate) trait InternalDexWrite: StateWrite {
     }
 
     fn set_arb_execution(&mut self, height: u64, execution: SwapExecution) {
-        self.put(state_key::arb_execution(height), execution);
+        self.put(
+            state_key::arb_execution(height, &execution.input.asset_id),
+            execution,
+        );
+    }
+
+    /// Records the `price` of a pair at `height` in its price accumulator.
+    ///
+    /// This is called at most once per block, with the price of the pair's last
+    /// execution in the block: executions earlier in the block aren't sampled,
+    /// so the price only counts if it's still in effect at the end of the block.
+    ///
+    /// This runs in `end_block`, so it never fails: a sample that can't be
+    /// recorded, e.g. because the accumulator would overflow, is skipped.
+    async fn update_price_accumulator(
+        &mut self,
+        pair: &DirectedTradingPair,
+        height: u64,
+        price: U128x128,
+    ) {
+        let accumulator = match self.latest_price_accumulator(pair).await {
+            Ok(Some(latest)) => latest.update(height, price),
+            Ok(None) => Ok(PriceAccumulator::new(height, price)),
+            Err(e) => Err(e),
+        };
+        let accumulator = match accumulator {
+            Ok(accumulator) => accumulator,
+            Err(error) => {
+                tracing::warn!(
+                    ?error,
+                    ?pair,
+                    height,
+                    %price,
+                    "failed to update price accumulator, skipping sample"
+                );
+                return;
+            }
+        };
+        self.put(state_key::price_accumulators::latest(pair), accumulator);
+        self.put(
+            state_key::price_accumulators::by_pair_and_height(pair, height),
+            accumulator,
+        );
+
+        // Prune the history older than the retention window, keeping the last
+        // update before it, which is still in effect at the start of the window.
+        let cutoff = height.saturating_sub(PRICE_HISTORY_RETENTION);
+        let stale: Result<Vec<(String, PriceAccumulator)>> = self
+            .prefix::<PriceAccumulator>(&state_key::price_accumulators::by_pair(pair))
+            .try_take_while(|(_, accumulator)| {
+                futures::future::ready(Ok(accumulator.height <= cutoff))
+            })
+            .try_collect()
+            .await;
+        match stale {
+            Ok(mut stale) => {
+                stale.pop();
+                for (key, _) in stale {
+                    self.delete(key);
+                }
+            }
+            Err(error) => {
+                tracing::warn!(?error, ?pair, "failed to prune price accumulator history");
+            }
+        }
+    }
+
+    /// Indexes the execution that took a position from `prev_state` to `new_state`,
+    /// so that the position's history can be queried.
+    async fn index_position_execution(
+        &mut self,
+        prev_state: &Position,
+        new_state: &Position,
+    ) -> Result<()> {
+        let height = self.get_block_height().await?;
+        let execution = PositionExecution::new(height, prev_state, new_state)?;
+
+        // A position can be executed against several times in a block, so we
+        // order the executions within a block by a block-scoped counter.
+        let index: u32 = self
+            .object_get(block_scoped::position_executions::count())
+            .unwrap_or_default();
+        self.object_put(block_scoped::position_executions::count(), index + 1);
+
+        self.nonverifiable_put(
+            state_key::position_executions::key(&new_state.id(), height, index).into(),
+            execution,
+        );
+        Ok(())
     }
 }
 
@@ -6,18 +6,40 @@ use penumbra_num::Amount;
 // ((amount of asset 1 being exchanged for asset 2),
 //  (amount of asset 2 being exchanged for asset 1))
 #[derive(Default, Clone)]
-pub struct SwapFlow((Amount, Amount));
+pub struct SwapFlow {
+    flow: (Amount, Amount),
+    // The (input, minimum output) of each swap with a minimum output,
+    // for the 1 => 2 and 2 => 1 directions.
+    limited_swaps: (Vec<(Amount, Amount)>, Vec<(Amount, Amount)>),
+}
+
+impl SwapFlow {
+    /// The (input, minimum output) of each 1 => 2 and 2 => 1 swap with a minimum output.
+    pub fn limited_swaps(&self) -> (&[(Amount, Amount)], &[(Amount, Amount)]) {
+        (&self.limited_swaps.0, &self.limited_swaps.1)
+    }
+
+    /// Records a 1 => 2 swap of `input` that must output at least `min_output`.
+    pub fn limit_swap_1_for_2(&mut self, input: Amount, min_output: Amount) {
+        self.limited_swaps.0.push((input, min_output));
+    }
+
+    /// Records a 2 => 1 swap of `input` that must output at least `min_output`.
+    pub fn limit_swap_2_for_1(&mut self, input: Amount, min_output: Amount) {
+        self.limited_swaps.1.push((input, min_output));
+    }
+}
 
 impl Deref for SwapFlow {
     type Target = (Amount, Amount);
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.flow
     }
 }
 
 impl DerefMut for SwapFlow {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        &mut self.flow
     }
 }
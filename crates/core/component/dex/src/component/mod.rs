@@ -14,10 +14,13 @@ mod dex;
 mod eviction_manager;
 mod flow;
 mod position_manager;
+mod reward_manager;
 mod swap_manager;
 
+pub use chandelier::{CandlestickRead, Chandelier};
 pub use dex::{Dex, StateReadExt, StateWriteExt};
 pub use position_manager::PositionManager;
+pub use reward_manager::{RewardManager, RewardRead};
 
 // Read data from the Dex component;
 pub use position_manager::PositionRead;
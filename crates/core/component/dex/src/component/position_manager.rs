@@ -7,7 +7,8 @@ use async_trait::async_trait;
 use cnidarium::{EscapedByteSlice, StateRead, StateWrite};
 use futures::Stream;
 use futures::StreamExt;
-use penumbra_asset::{asset, Balance};
+use penumbra_asset::{asset, Balance, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_num::{fixpoint::U128x128, Amount};
 use penumbra_proto::DomainType;
 use penumbra_proto::{StateReadProto, StateWriteProto};
 use tap::Tap;
@@ -21,7 +22,7 @@ use crate::component::{
         price_index::PositionByPriceIndex,
     },
 };
-use crate::lp::Reserves;
+use crate::lp::{PositionExecution, Reserves};
 use crate::{
     component::position_manager::counter::PositionCounter,
     component::ValueCircuitBreaker,
@@ -32,6 +33,7 @@ use crate::{
 use crate::{event, state_key};
 
 use super::chandelier::Chandelier;
+use super::reward_manager::{RewardManager as _, RewardRead as _};
 
 const DYNAMIC_ASSET_LIMIT: usize = 10;
 
@@ -76,6 +78,40 @@ pub trait PositionRead: StateRead {
             .boxed()
     }
 
+    /// Returns a stream of [`position::Id`] with their effective price, ordered by
+    /// effective price.
+    fn position_prices(
+        &self,
+        pair: &DirectedTradingPair,
+    ) -> Pin<Box<dyn Stream<Item = Result<(position::Id, U128x128)>> + Send + 'static>> {
+        let prefix = engine::price_index::prefix(pair);
+        self.nonverifiable_prefix_raw(&prefix)
+            .map(|entry| {
+                let (k, _) = entry?;
+                let (price, id) = engine::price_index::parse_key(&k)?;
+                Ok((id, price))
+            })
+            .boxed()
+    }
+
+    /// Returns a stream of the executions against a position at or after
+    /// `start_height`, from the oldest to the newest.
+    fn position_executions(
+        &self,
+        id: &position::Id,
+        start_height: u64,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<PositionExecution>> + Send + 'static>>> {
+        let prefix = state_key::position_executions::by_id(id);
+        let start = state_key::position_executions::height_suffix(start_height).into_bytes();
+        Ok(self
+            .nonverifiable_range_raw(Some(prefix.as_bytes()), start..)?
+            .map(|entry| {
+                let (_, value) = entry?;
+                PositionExecution::decode(value.as_ref())
+            })
+            .boxed())
+    }
+
     async fn position_by_id(&self, id: &position::Id) -> Result<Option<position::Position>> {
         self.get(&state_key::position_by_id(id)).await
     }
@@ -374,6 +410,16 @@ pub trait PositionManager: StateWrite + PositionRead {
             .map_err(|e| tracing::warn!(?e, "failed to record position execution"))
             .ok();
 
+        // Likewise, index the execution for position history queries.
+        self.index_position_execution(&prev_state, &new_state)
+            .await
+            .map_err(|e| tracing::warn!(?e, "failed to index position execution"))
+            .ok();
+
+        // Unlike the indexes above, the volume determines liquidity rewards,
+        // so we do not ignore errors recording it.
+        self.record_position_volume(&prev_state, &new_state).await?;
+
         self.update_position(Some(prev_state), new_state).await
     }
 
@@ -454,6 +500,68 @@ pub trait PositionManager: StateWrite + PositionRead {
 
         Ok(reserves)
     }
+
+    /// Claim the rewards accrued by a withdrawn position, incrementing its sequence number.
+    ///
+    /// Updates the position's rewards to zero and returns the claimed rewards, in the staking token.
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn claim_position_rewards(
+        &mut self,
+        position_id: position::Id,
+        sequence: u64,
+    ) -> Result<Amount> {
+        let prev_state = self.position_by_id(&position_id).await?.ok_or_else(|| {
+            anyhow::anyhow!("claimed rewards of unknown position {}", position_id)
+        })?;
+
+        // As with withdrawals, this is redundant with the value balance mechanism,
+        // which requires the claim to consume the withdrawn position NFT of the
+        // previous sequence number.
+        match prev_state.state {
+            position::State::Withdrawn {
+                sequence: current_sequence,
+            } if current_sequence + 1 == sequence => {}
+            position::State::Withdrawn {
+                sequence: current_sequence,
+            } => anyhow::bail!(
+                "attempted to claim rewards of position {} with sequence {}, expected {}",
+                position_id,
+                sequence,
+                current_sequence + 1
+            ),
+            _ => anyhow::bail!(
+                "attempted to claim rewards of position {} with state {}, expected Withdrawn",
+                position_id,
+                prev_state.state
+            ),
+        }
+
+        let rewards = self.position_rewards(&position_id).await?;
+        ensure!(
+            rewards > Amount::zero(),
+            "position {} has no rewards to claim",
+            position_id
+        );
+        self.record_proto(event::position_reward_claim(position_id, sequence, rewards));
+
+        // Debit the DEX for the rewards, which were credited when they were distributed.
+        self.dex_vcb_debit(Value {
+            amount: rewards,
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        })
+        .await?;
+
+        // Zero out the rewards, to prevent double-claims, and update the sequence number.
+        self.put(state_key::position_rewards(&position_id), Amount::zero());
+        let new_state = {
+            let mut new_state = prev_state.clone();
+            new_state.state = position::State::Withdrawn { sequence };
+            new_state
+        };
+        self.update_position(Some(prev_state), new_state).await?;
+
+        Ok(rewards)
+    }
 }
 
 impl<T: StateWrite + ?Sized + Chandelier> PositionManager for T {}
@@ -485,6 +593,13 @@ trait Inner: StateWrite {
         self.update_trading_pair_position_counter(&prev_state, &new_state, &id)
             .await?;
 
+        // Track the liquidity the position provides, for liquidity rewards.
+        // Like the execution indexes, this must not prevent the update.
+        self.record_position_liquidity(&prev_state, &new_state)
+            .await
+            .map_err(|e| tracing::warn!(?e, "failed to record position liquidity"))
+            .ok();
+
         self.put(state_key::position_by_id(&id), new_state.clone());
         Ok(new_state)
     }
@@ -0,0 +1,310 @@
+use std::pin::Pin;
+
+use anyhow::{anyhow, Context as _, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use futures::{Stream, StreamExt as _, TryStreamExt as _};
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_proto::{DomainType, StateReadProto, StateWriteProto};
+use penumbra_sct::{component::clock::EpochRead as _, epoch::Epoch};
+
+use crate::{
+    component::{PositionRead as _, ValueCircuitBreaker},
+    lp::position::{self, Position},
+    state_key, TradingPair,
+};
+
+/// Provides read access to the liquidity rewards of positions.
+#[async_trait]
+pub trait RewardRead: StateRead {
+    /// Gets the unclaimed rewards accrued by a position, in the staking token.
+    async fn position_rewards(&self, id: &position::Id) -> Result<Amount> {
+        Ok(self
+            .get(&state_key::position_rewards(id))
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Returns a stream of the volume executed against each position of a
+    /// pair during an epoch, in units of the staking token.
+    fn position_volumes(
+        &self,
+        epoch_index: u64,
+        pair: &TradingPair,
+    ) -> Pin<Box<dyn Stream<Item = Result<(position::Id, Amount)>> + Send + 'static>> {
+        let prefix = state_key::position_volumes::by_epoch_and_pair(epoch_index, pair);
+        self.prefix_raw(&prefix)
+            .map(|entry| {
+                let (k, v) = entry?;
+                let id = k
+                    .rsplit('/')
+                    .next()
+                    .context("position volume key has a position id")?
+                    .parse()?;
+                Ok((id, Amount::decode(v.as_ref())?))
+            })
+            .boxed()
+    }
+
+    /// Gets the liquidity provided by a position of `pair` during an epoch, as
+    /// recorded so far: its reserves of the staking token, summed over every
+    /// block of the epoch in which it was open.
+    async fn position_liquidity(
+        &self,
+        epoch_index: u64,
+        pair: &TradingPair,
+        id: &position::Id,
+    ) -> Result<Amount> {
+        Ok(self
+            .get(&state_key::position_liquidity::key(epoch_index, pair, id))
+            .await?
+            .unwrap_or_default())
+    }
+}
+
+impl<T: StateRead + ?Sized> RewardRead for T {}
+
+/// Tracks the volume executed against positions and the liquidity they
+/// provide, and distributes liquidity rewards according to them.
+#[async_trait]
+pub trait RewardManager: StateWrite + RewardRead {
+    /// Adds the volume of the execution that took a position from `prev_state`
+    /// to `new_state` to the position's volume for the current epoch.
+    ///
+    /// The volume is measured in units of the staking token, whichever
+    /// direction the position was traded in, so only the positions of pairs
+    /// that include the staking token are tracked.
+    async fn record_position_volume(
+        &mut self,
+        prev_state: &Position,
+        new_state: &Position,
+    ) -> Result<()> {
+        let pair = &new_state.phi.pair;
+        let (Some(prev), Some(new)) = (staking_reserves(prev_state), staking_reserves(new_state))
+        else {
+            return Ok(());
+        };
+        let volume = if new > prev { new - prev } else { prev - new };
+        if volume == Amount::zero() {
+            return Ok(());
+        }
+
+        let epoch_index = self.get_current_epoch().await?.index;
+        let key = state_key::position_volumes::key(epoch_index, pair, &new_state.id());
+        let prev_volume: Amount = self.get(&key).await?.unwrap_or_default();
+        let new_volume = prev_volume
+            .checked_add(&volume)
+            .context("overflowed position volume")?;
+        self.put(key, new_volume);
+        Ok(())
+    }
+
+    /// Records the liquidity that a position provided up to the current block,
+    /// as it changes from `prev_state` to `new_state`.
+    ///
+    /// Like the volume, the liquidity is measured in units of the staking
+    /// token, so only the positions of pairs that include it are tracked.
+    async fn record_position_liquidity(
+        &mut self,
+        prev_state: &Option<Position>,
+        new_state: &Position,
+    ) -> Result<()> {
+        let pair = &new_state.phi.pair;
+        if staking_reserves(new_state).is_none() {
+            return Ok(());
+        }
+
+        let height = self.get_block_height().await?;
+        if let Some(prev_state) = prev_state {
+            if prev_state.state == position::State::Opened {
+                self.accrue_position_liquidity(prev_state, height).await?;
+            }
+        }
+
+        let key = state_key::position_liquidity::recorded_until(pair, &new_state.id());
+        if new_state.state == position::State::Opened {
+            self.put_proto(key, height);
+        } else {
+            self.delete(key);
+        }
+        Ok(())
+    }
+
+    /// Adds the liquidity that the open `position` provided from the height it
+    /// was last recorded at until `height` to its liquidity for the current epoch.
+    ///
+    /// Liquidity provided before the current epoch isn't counted: it was either
+    /// recorded when the previous epoch ended, or provided while the pair wasn't
+    /// rewarded.
+    async fn accrue_position_liquidity(&mut self, position: &Position, height: u64) -> Result<()> {
+        let pair = &position.phi.pair;
+        let id = position.id();
+        let epoch = self.get_current_epoch().await?;
+        let recorded_until: u64 = self
+            .get_proto(&state_key::position_liquidity::recorded_until(pair, &id))
+            .await?
+            .unwrap_or(height);
+        let blocks = height.saturating_sub(recorded_until.max(epoch.start_height));
+        let reserves = staking_reserves(position).unwrap_or_default();
+        if blocks == 0 || reserves == Amount::zero() {
+            return Ok(());
+        }
+
+        let key = state_key::position_liquidity::key(epoch.index, pair, &id);
+        let prev_liquidity = self.position_liquidity(epoch.index, pair, &id).await?;
+        let liquidity = reserves.value().saturating_mul(blocks.into());
+        self.put(
+            key,
+            Amount::from(prev_liquidity.value().saturating_add(liquidity)),
+        );
+        Ok(())
+    }
+
+    /// Splits `rewards` between the positions of `pair`, according to the
+    /// volume executed against them during the current epoch, `epoch`.
+    ///
+    /// Each position's volume is capped by its time-weighted liquidity, i.e. its
+    /// average reserves of the staking token over the epoch, so trading against
+    /// one's own position earns no more than keeping the same reserves open for
+    /// the whole epoch. Each reward is also capped by the fees the position
+    /// earned, which excludes positions without a fee.
+    ///
+    /// The rewards are issued into the DEX, to be claimed once each position is
+    /// withdrawn. A position whose reward can't be computed is skipped. Returns
+    /// the rewards actually distributed, which are rounded down and capped, and
+    /// zero if nothing traded on the pair.
+    async fn reward_liquidity_providers(
+        &mut self,
+        epoch: &Epoch,
+        pair: &TradingPair,
+        rewards: Amount,
+    ) -> Result<Amount> {
+        // Record the liquidity of the positions that are still open through the
+        // last block of the epoch.
+        let end_height = self.get_block_height().await? + 1;
+        let open_positions: Vec<String> = self
+            .prefix_keys(&state_key::position_liquidity::recorded_until_by_pair(pair))
+            .try_collect()
+            .await?;
+        for key in open_positions {
+            let position = match position_id_from_key(&key) {
+                Ok(id) => self.position_by_id(&id).await,
+                Err(error) => Err(error),
+            };
+            let accrued = match position {
+                Ok(Some(position)) => self.accrue_position_liquidity(&position, end_height).await,
+                Ok(None) => Err(anyhow!("position not found")),
+                Err(error) => Err(error),
+            };
+            match accrued {
+                Ok(()) => self.put_proto(key, end_height),
+                Err(error) => {
+                    tracing::warn!(?error, %key, "failed to record position liquidity, skipping position");
+                }
+            }
+        }
+
+        let epoch_blocks = u128::from(end_height.saturating_sub(epoch.start_height).max(1));
+        let volumes: Vec<(position::Id, Amount)> = self
+            .position_volumes(epoch.index, pair)
+            .try_collect()
+            .await?;
+        let mut weights = Vec::with_capacity(volumes.len());
+        for (id, volume) in volumes {
+            let liquidity = self.position_liquidity(epoch.index, pair, &id).await?;
+            let average_liquidity = Amount::from(liquidity.value() / epoch_blocks);
+            weights.push((id, volume, volume.min(average_liquidity)));
+        }
+        let total_weight: Amount = weights.iter().map(|(_, _, weight)| *weight).sum();
+        if total_weight == Amount::zero() {
+            return Ok(Amount::zero());
+        }
+
+        let mut distributed = Amount::zero();
+        for (id, volume, weight) in weights {
+            let fee = match self.position_by_id(&id).await {
+                Ok(Some(position)) => position.phi.component.fee,
+                Ok(None) => {
+                    tracing::warn!(%id, "rewarded position not found, skipping position");
+                    continue;
+                }
+                Err(error) => {
+                    tracing::warn!(?error, %id, "failed to fetch rewarded position, skipping position");
+                    continue;
+                }
+            };
+            let fees_earned = Amount::from(volume.value().saturating_mul(fee.into()) / 10_000);
+            let reward = match U128x128::ratio(weight, total_weight)
+                .and_then(|share| share.apply_to_amount(&rewards))
+            {
+                Ok(reward) => reward.min(fees_earned),
+                Err(error) => {
+                    tracing::warn!(?error, %id, "failed to compute liquidity reward, skipping position");
+                    continue;
+                }
+            };
+            if reward == Amount::zero() {
+                continue;
+            }
+            let prev_rewards = self.position_rewards(&id).await?;
+            self.put(state_key::position_rewards(&id), prev_rewards + reward);
+            distributed += reward;
+        }
+
+        self.dex_vcb_credit(Value {
+            amount: distributed,
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        })
+        .await?;
+
+        tracing::debug!(%pair, ?rewards, ?distributed, "rewarded liquidity providers");
+        Ok(distributed)
+    }
+
+    /// Deletes the position volumes and liquidity of the epochs before
+    /// `epoch_index`, whose rewards have already been distributed.
+    async fn prune_reward_tracking(&mut self, epoch_index: u64) -> Result<()> {
+        for (prefix, end) in [
+            (
+                state_key::position_volumes::prefix(),
+                state_key::position_volumes::by_epoch(epoch_index),
+            ),
+            (
+                state_key::position_liquidity::prefix(),
+                state_key::position_liquidity::by_epoch(epoch_index),
+            ),
+        ] {
+            let stale_keys: Vec<String> = self
+                .prefix_keys(prefix)
+                .try_take_while(|k| futures::future::ready(Ok(*k < end)))
+                .try_collect()
+                .await?;
+            for key in stale_keys {
+                self.delete(key);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> RewardManager for T {}
+
+/// The reserves of the staking token of a position, if its pair includes it.
+fn staking_reserves(position: &Position) -> Option<Amount> {
+    let pair = &position.phi.pair;
+    if pair.asset_1() == *STAKING_TOKEN_ASSET_ID {
+        Some(position.reserves.r1)
+    } else if pair.asset_2() == *STAKING_TOKEN_ASSET_ID {
+        Some(position.reserves.r2)
+    } else {
+        None
+    }
+}
+
+fn position_id_from_key(key: &str) -> Result<position::Id> {
+    key.rsplit('/')
+        .next()
+        .context("position key has a position id")?
+        .parse()
+}
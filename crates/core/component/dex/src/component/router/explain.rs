@@ -0,0 +1,138 @@
+use penumbra_asset::{asset, Value};
+use penumbra_num::fixpoint::U128x128;
+use penumbra_proto::core::component::dex::v1 as pb;
+
+use crate::lp::position;
+
+/// A record of how a trade was routed and filled, for debugging.
+///
+/// This is not used by consensus: it is only surfaced through the
+/// `SimulateTrade` RPC, to help liquidity providers understand why their
+/// positions were or were not routed through.
+#[derive(Debug, Clone, Default)]
+pub struct RouteExplanation {
+    /// The successive splits of the trade, in the order they were routed.
+    pub splits: Vec<RouteSplit>,
+    /// The number of path searches performed.
+    pub path_searches: u32,
+    /// The maximum number of path searches allowed by the circuit breaker.
+    pub max_path_searches: u32,
+    /// The number of executions performed.
+    pub executions: u32,
+    /// The maximum number of executions allowed by the circuit breaker.
+    pub max_executions: u32,
+    /// Why routing stopped.
+    pub stop_reason: Option<StopReason>,
+}
+
+/// A split of a trade: a path search, followed by filling along the route it
+/// selected, up to the spill price.
+#[derive(Debug, Clone, Default)]
+pub struct RouteSplit {
+    /// The paths explored by the path search.
+    pub candidates: Vec<RouteCandidate>,
+    /// The route selected by the path search, including the input asset, or
+    /// empty if no route was found.
+    pub route: Vec<asset::Id>,
+    /// The spill price the route was filled up to, if any.
+    pub spill_price: Option<U128x128>,
+    /// The fills along the route, in the order they were executed.
+    pub fills: Vec<RouteFill>,
+}
+
+/// A path explored by the path search: either the best known path to some
+/// asset, or the second-best one, whose price is the spill price.
+#[derive(Debug, Clone)]
+pub struct RouteCandidate {
+    /// The assets along the path, including the input asset.
+    pub route: Vec<asset::Id>,
+    /// The estimated price of the path, in units of the input asset per unit
+    /// of the asset it ends at.
+    pub estimated_price: U128x128,
+    /// Whether this is the second-best path to the asset it ends at.
+    pub spill: bool,
+}
+
+/// A fill along a route, executing against one position per hop.
+#[derive(Debug, Clone)]
+pub struct RouteFill {
+    /// The positions executed against, one per hop.
+    pub position_ids: Vec<position::Id>,
+    /// The amounts traded at each hop, starting with the input.
+    pub trace: Vec<Value>,
+}
+
+/// The condition that ended the routing of a trade.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    /// The execution circuit breaker was triggered.
+    CircuitBreaker,
+    /// The path search could not find a route under the price limit.
+    NoPath,
+    /// All of the input was filled.
+    Filled,
+    /// The last fill did not execute against any position.
+    NoExecution,
+    /// The last fill reached the price limit.
+    PriceLimit,
+}
+
+impl std::fmt::Display for StopReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            StopReason::CircuitBreaker => "the execution circuit breaker was triggered",
+            StopReason::NoPath => "no route was found under the price limit",
+            StopReason::Filled => "all of the input was filled",
+            StopReason::NoExecution => "the last fill did not execute against any position",
+            StopReason::PriceLimit => "the last fill reached the price limit",
+        })
+    }
+}
+
+impl From<RouteExplanation> for pb::RouteExplanation {
+    fn from(explanation: RouteExplanation) -> Self {
+        pb::RouteExplanation {
+            splits: explanation.splits.into_iter().map(Into::into).collect(),
+            path_searches: explanation.path_searches,
+            max_path_searches: explanation.max_path_searches,
+            executions: explanation.executions,
+            max_executions: explanation.max_executions,
+            stop_reason: explanation
+                .stop_reason
+                .map(|reason| reason.to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl From<RouteSplit> for pb::RouteSplit {
+    fn from(split: RouteSplit) -> Self {
+        pb::RouteSplit {
+            candidates: split.candidates.into_iter().map(Into::into).collect(),
+            route: split.route.into_iter().map(Into::into).collect(),
+            spill_price: split.spill_price.map(f64::from).unwrap_or_default(),
+            fills: split.fills.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<RouteCandidate> for pb::RouteCandidate {
+    fn from(candidate: RouteCandidate) -> Self {
+        pb::RouteCandidate {
+            route: candidate.route.into_iter().map(Into::into).collect(),
+            estimated_price: candidate.estimated_price.into(),
+            spill: candidate.spill,
+        }
+    }
+}
+
+impl From<RouteFill> for pb::RouteFill {
+    fn from(fill: RouteFill) -> Self {
+        pb::RouteFill {
+            position_ids: fill.position_ids.into_iter().map(Into::into).collect(),
+            trace: Some(pb::swap_execution::Trace {
+                value: fill.trace.into_iter().map(Into::into).collect(),
+            }),
+        }
+    }
+}
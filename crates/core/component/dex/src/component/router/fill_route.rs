@@ -23,6 +23,8 @@ use crate::{
     DirectedTradingPair, SwapExecution, TradingPair,
 };
 
+use super::RouteFill;
+
 /// An error that occurs during routing execution.
 #[derive(Debug, thiserror::Error)]
 pub enum FillError {
@@ -75,19 +77,25 @@ pub trait FillRoute: StateWrite + Sized {
         hops: &[asset::Id],
         spill_price: Option<U128x128>,
     ) -> Result<SwapExecution, FillError> {
-        fill_route_inner(self, input, hops, spill_price, true).await
+        let (swap_execution, _) =
+            fill_route_inner(self, input, hops, spill_price, true, false).await?;
+        Ok(swap_execution)
     }
 }
 
 impl<S: StateWrite> FillRoute for S {}
 
-async fn fill_route_inner<S: StateWrite + Sized>(
+/// Fills a trade along a route, as described in [`FillRoute::fill_route`], also
+/// returning the fills executed along the route, in order. The fills are only
+/// recorded if `explain` is set, and are empty otherwise.
+pub(super) async fn fill_route_inner<S: StateWrite + Sized>(
     state: S,
     mut input: Value,
     hops: &[asset::Id],
     spill_price: Option<U128x128>,
     ensure_progress: bool,
-) -> Result<SwapExecution, FillError> {
+    explain: bool,
+) -> Result<(SwapExecution, Vec<RouteFill>), FillError> {
     let fill_start = std::time::Instant::now();
 
     // Build a transaction for this execution, so if we error out at any
@@ -120,7 +128,7 @@ async fn fill_route_inner<S: StateWrite + Sized>(
             .ok_or(FillError::InvalidRoute(route.len()))?,
     };
 
-    let mut frontier = Frontier::load(&mut this, pairs).await?;
+    let mut frontier = Frontier::load(&mut this, pairs, explain).await?;
     tracing::debug!(?frontier, "assembled initial frontier");
 
     // Tracks whether we've already filled at least once, so we can skip the spill price check
@@ -237,6 +245,16 @@ async fn fill_route_inner<S: StateWrite + Sized>(
     let in_asset_id = frontier.pairs.first().expect("empty pairs").start;
     let out_asset_id = frontier.pairs.last().expect("empty pairs").end;
 
+    let fills = frontier
+        .trace
+        .iter()
+        .zip(frontier.trace_positions.iter())
+        .map(|(trace, position_ids)| RouteFill {
+            position_ids: position_ids.clone(),
+            trace: trace.clone(),
+        })
+        .collect();
+
     let swap_execution = SwapExecution {
         traces: std::mem::take(&mut frontier.trace),
         input: Value {
@@ -267,7 +285,7 @@ async fn fill_route_inner<S: StateWrite + Sized>(
     let fill_elapsed = fill_start.elapsed();
     metrics::histogram!(metrics::DEX_ROUTE_FILL_DURATION).record(fill_elapsed);
     // cleanup / finalization
-    Ok(swap_execution)
+    Ok((swap_execution, fills))
 }
 
 /// Breaksdown a route into a collection of `DirectedTradingPair`, this is mostly useful
@@ -307,6 +325,11 @@ struct Frontier<S> {
     pub positions_by_price: PositionsByPrice,
     /// A trace of the execution along the route.
     pub trace: Vec<Vec<Value>>,
+    /// The positions executed against in each entry of the trace, only
+    /// recorded if `explain` is set.
+    pub trace_positions: Vec<Vec<position::Id>>,
+    /// Whether to record the positions executed against.
+    pub explain: bool,
 }
 
 struct FrontierTx {
@@ -347,12 +370,18 @@ impl<S> std::fmt::Debug for Frontier<S> {
             .field("positions", &self.positions)
             .field("position_ids", &self.position_ids)
             .field("trace", &self.trace)
+            .field("trace_positions", &self.trace_positions)
+            .field("explain", &self.explain)
             .finish_non_exhaustive()
     }
 }
 
 impl<S: StateRead + StateWrite> Frontier<S> {
-    async fn load(state: S, pairs: Vec<DirectedTradingPair>) -> Result<Frontier<S>, FillError> {
+    async fn load(
+        state: S,
+        pairs: Vec<DirectedTradingPair>,
+        explain: bool,
+    ) -> Result<Frontier<S>, FillError> {
         let mut positions = Vec::new();
         let mut position_ids = BTreeSet::new();
 
@@ -397,6 +426,7 @@ impl<S: StateRead + StateWrite> Frontier<S> {
 
         // The current trace list along the route should be initialized as empty.
         let trace: Vec<Vec<Value>> = Vec::new();
+        let trace_positions: Vec<Vec<position::Id>> = Vec::new();
 
         Ok(Frontier {
             positions,
@@ -405,6 +435,8 @@ impl<S: StateRead + StateWrite> Frontier<S> {
             state,
             positions_by_price,
             trace,
+            trace_positions,
+            explain,
         })
     }
 
@@ -445,6 +477,10 @@ impl<S: StateRead + StateWrite> Frontier<S> {
 
         // Add the new trace
         self.trace.push(trace);
+        if self.explain {
+            self.trace_positions
+                .push(self.positions.iter().map(Position::id).collect());
+        }
 
         (
             changes
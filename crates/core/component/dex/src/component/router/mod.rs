@@ -1,3 +1,4 @@
+mod explain;
 mod fill_route;
 mod params;
 mod path;
@@ -8,11 +9,14 @@ mod route_and_fill;
 use path::Path;
 use path_cache::{PathCache, PathEntry, SharedPathCache};
 
+pub use explain::{RouteCandidate, RouteExplanation, RouteFill, RouteSplit, StopReason};
 pub use fill_route::FillRoute;
 pub use params::RoutingParams;
 pub use path_search::PathSearch;
 pub use route_and_fill::{HandleBatchSwaps, RouteAndFill};
 
+#[cfg(test)]
+mod proptests;
 #[cfg(test)]
 mod tests;
 
@@ -10,6 +10,10 @@ pub struct RoutingParams {
     pub price_limit: Option<U128x128>,
     pub fixed_candidates: Arc<Vec<asset::Id>>,
     pub max_hops: usize,
+    /// The maximum number of path searches performed while routing a single trade.
+    pub max_path_searches: u32,
+    /// The maximum number of executions against positions while routing a single trade.
+    pub max_executions: u32,
 }
 
 impl RoutingParams {
@@ -45,6 +49,8 @@ impl From<DexParameters> for RoutingParams {
         DexParameters {
             fixed_candidates,
             max_hops,
+            max_path_searches,
+            max_executions,
             ..
         }: DexParameters,
     ) -> Self {
@@ -52,6 +58,8 @@ impl From<DexParameters> for RoutingParams {
             fixed_candidates: Arc::new(fixed_candidates),
             max_hops: max_hops as usize,
             price_limit: None,
+            max_path_searches,
+            max_executions,
         }
     }
 }
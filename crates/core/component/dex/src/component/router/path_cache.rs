@@ -4,7 +4,7 @@ use cnidarium::{StateDelta, StateRead};
 use parking_lot::Mutex;
 use penumbra_asset::asset;
 
-use super::Path;
+use super::{Path, RouteCandidate};
 
 /// An entry in the path cache, representing a best known sub-path.
 pub(super) struct PathEntry<S: StateRead + 'static> {
@@ -98,6 +98,25 @@ impl<S: StateRead + 'static> PathCache<S> {
         })
     }
 
+    /// Lists the best and second-best paths found to each asset, for debugging.
+    pub fn candidates(&self) -> Vec<RouteCandidate> {
+        self.0
+            .values()
+            .flat_map(|entry| {
+                std::iter::once((&entry.path, false))
+                    .chain(entry.spill.iter().map(|spill| (spill, true)))
+            })
+            .filter(|(path, _)| !path.nodes.is_empty())
+            .map(|(path, spill)| RouteCandidate {
+                route: std::iter::once(path.start)
+                    .chain(path.nodes.iter().cloned())
+                    .collect(),
+                estimated_price: path.price,
+                spill,
+            })
+            .collect()
+    }
+
     /// Extract all active paths, marking their existing entries as inactive.
     pub fn extract_active(&mut self) -> Vec<Path<S>> {
         self.0
@@ -11,71 +11,102 @@ use tracing::{instrument, Instrument};
 
 use crate::component::PositionRead as _;
 
-use super::{Path, PathCache, PathEntry, RoutingParams, SharedPathCache};
+use super::{Path, PathCache, PathEntry, RouteCandidate, RoutingParams, SharedPathCache};
 
 #[async_trait]
 pub trait PathSearch: StateRead + Clone + 'static {
     /// Find the best route from `src` to `dst` with estimated price strictly less
     /// than `params.price_limit`, also returning the spill price for the next-best
     /// route, if one exists.
-    #[instrument(skip(self, params), fields(max_hops = params.max_hops), level = "debug", ret)]
     async fn path_search(
         &self,
         src: asset::Id,
         dst: asset::Id,
         params: RoutingParams,
     ) -> Result<(Option<Vec<asset::Id>>, Option<U128x128>)> {
-        let RoutingParams {
-            max_hops,
-            fixed_candidates,
-            price_limit,
-        } = params;
-
-        // Initialize some metrics for calculating time spent on path searching
-        // vs route filling. We use vecs so we can count across iterations of the loop.
-        let path_start = std::time::Instant::now();
-        tracing::debug!(?src, ?dst, ?max_hops, "searching for path");
-
-        // Work in a new stack of state changes, which we can completely discard
-        // at the end of routing
-        let state = StateDelta::new(self.clone());
-
-        let cache = PathCache::begin(src, state);
-        for i in 0..max_hops {
-            relax_active_paths(cache.clone(), fixed_candidates.clone()).await?;
-            tracing::trace!(i, "finished relaxing all active paths");
-        }
-
-        let entry = cache.lock().0.remove(&dst);
-        let Some(PathEntry { path, spill, .. }) = entry else {
-            return Ok((None, None));
-        };
-
-        let nodes = path.nodes;
-        let spill_price = spill.map(|p| p.price);
-        tracing::debug!(price = %path.price, spill_price = %spill_price.unwrap_or_else(|| 0u64.into()), ?src, ?nodes, "found path");
-        metrics::histogram!(crate::component::metrics::DEX_PATH_SEARCH_DURATION)
-            .record(path_start.elapsed());
-
-        match price_limit {
-            // Note: previously, this branch was a load-bearing termination condition, primarily
-            // exercised by the arbitrage logic. However, during the course of testnet 53,  we
-            // encountered two bugs that caused this predicate to not be exercised:
-            // 1. We treated the price limit as a inclusive bound, rather than an exclusive bound.
-            // 2. We relied on an estimate of the end-to-end path price which was lossy (`path.price`).
-            // The latter is an inherent information limitation, so we now have a redundant check in
-            // `route_and_fill` which uses the exact price of the route.
-            Some(price_limit) if path.price >= price_limit => {
-                tracing::debug!(price = %path.price, price_limit = %price_limit, "path too expensive");
-                Ok((None, None))
-            }
-            _ => Ok((Some(nodes), spill_price)),
-        }
+        let (path, spill_price, _) = search_paths(self.clone(), src, dst, params, false).await?;
+        Ok((path, spill_price))
     }
 }
 
 impl<S> PathSearch for S where S: StateRead + Clone + 'static {}
 
+/// Searches for the best route from `src` to `dst`, as described in
+/// [`PathSearch::path_search`], also listing the paths explored by the search
+/// if `explain` is set.
+#[instrument(
+    name = "path_search",
+    skip(state, params),
+    fields(max_hops = params.max_hops),
+    level = "debug",
+    ret
+)]
+pub(super) async fn search_paths<S: StateRead + Clone + 'static>(
+    state: S,
+    src: asset::Id,
+    dst: asset::Id,
+    params: RoutingParams,
+    explain: bool,
+) -> Result<(
+    Option<Vec<asset::Id>>,
+    Option<U128x128>,
+    Vec<RouteCandidate>,
+)> {
+    let RoutingParams {
+        max_hops,
+        fixed_candidates,
+        price_limit,
+        ..
+    } = params;
+
+    // Initialize some metrics for calculating time spent on path searching
+    // vs route filling. We use vecs so we can count across iterations of the loop.
+    let path_start = std::time::Instant::now();
+    tracing::debug!(?src, ?dst, ?max_hops, "searching for path");
+
+    // Work in a new stack of state changes, which we can completely discard
+    // at the end of routing
+    let state = StateDelta::new(state);
+
+    let cache = PathCache::begin(src, state);
+    for i in 0..max_hops {
+        relax_active_paths(cache.clone(), fixed_candidates.clone()).await?;
+        tracing::trace!(i, "finished relaxing all active paths");
+    }
+
+    let candidates = if explain {
+        cache.lock().candidates()
+    } else {
+        Vec::new()
+    };
+
+    let entry = cache.lock().0.remove(&dst);
+    let Some(PathEntry { path, spill, .. }) = entry else {
+        return Ok((None, None, candidates));
+    };
+
+    let nodes = path.nodes;
+    let spill_price = spill.map(|p| p.price);
+    tracing::debug!(price = %path.price, spill_price = %spill_price.unwrap_or_else(|| 0u64.into()), ?src, ?nodes, "found path");
+    metrics::histogram!(crate::component::metrics::DEX_PATH_SEARCH_DURATION)
+        .record(path_start.elapsed());
+
+    match price_limit {
+        // Note: previously, this branch was a load-bearing termination condition, primarily
+        // exercised by the arbitrage logic. However, during the course of testnet 53,  we
+        // encountered two bugs that caused this predicate to not be exercised:
+        // 1. We treated the price limit as a inclusive bound, rather than an exclusive bound.
+        // 2. We relied on an estimate of the end-to-end path price which was lossy (`path.price`).
+        // The latter is an inherent information limitation, so we now have a redundant check in
+        // `route_and_fill` which uses the exact price of the route.
+        Some(price_limit) if path.price >= price_limit => {
+            tracing::debug!(price = %path.price, price_limit = %price_limit, "path too expensive");
+            Ok((None, None, candidates))
+        }
+        _ => Ok((Some(nodes), spill_price, candidates)),
+    }
+}
+
 #[instrument(skip_all)]
 async fn relax_active_paths<S: StateRead + 'static>(
     cache: SharedPathCache<S>,
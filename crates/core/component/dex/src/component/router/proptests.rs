@@ -0,0 +1,437 @@
+//! Randomized tests of the router, against randomly generated position books.
+//!
+//! The first property checks invariants that must hold for any book and any
+//! sequence of swaps against it: value is conserved, positions never pay out
+//! more than their trading function allows, each hop of a route is filled from
+//! the best price to the worst, and routing is deterministic. The second one
+//! compares the router against a slow reference router on books with a single
+//! pair, for which the best execution is simply to fill the positions in order
+//! of price.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use cnidarium::{ArcStateDeltaExt, Snapshot, StateDelta, TempStorage};
+use penumbra_asset::{asset, Value};
+use penumbra_num::Amount;
+use proptest::prelude::*;
+
+use crate::{
+    component::{
+        router::{RouteAndFill, RouteExplanation},
+        tests::TempStorageExt,
+        ExecutionCircuitBreaker, PositionManager, PositionRead, StateReadExt,
+    },
+    lp::{
+        position::{self, Position},
+        Reserves,
+    },
+    DirectedTradingPair, SwapExecution,
+};
+
+/// The assets the position books are made of.
+const ASSETS: [&str; 3] = ["gm", "gn", "penumbra"];
+
+fn asset_id(index: usize) -> asset::Id {
+    asset::Cache::with_known_assets()
+        .get_unit(ASSETS[index])
+        .expect("asset is known")
+        .id()
+}
+
+/// A position of a randomly generated book, trading between two of [`ASSETS`].
+#[derive(Debug, Clone)]
+struct PositionSpec {
+    start: usize,
+    end: usize,
+    fee: u32,
+    p: u64,
+    q: u64,
+    r1: u64,
+    r2: u64,
+}
+
+impl PositionSpec {
+    /// Builds the position, with a nonce derived from its index in the book so
+    /// that the same book always has the same position IDs.
+    fn position(&self, index: usize) -> Position {
+        let mut nonce = [0u8; 32];
+        nonce[..8].copy_from_slice(&(index as u64).to_le_bytes());
+        Position::new_with_nonce(
+            nonce,
+            DirectedTradingPair::new(asset_id(self.start), asset_id(self.end)),
+            self.fee,
+            self.p.into(),
+            self.q.into(),
+            Reserves {
+                r1: self.r1.into(),
+                r2: self.r2.into(),
+            },
+        )
+    }
+}
+
+fn book(specs: &[PositionSpec]) -> Vec<Position> {
+    specs
+        .iter()
+        .enumerate()
+        .map(|(index, spec)| spec.position(index))
+        .collect()
+}
+
+/// Two distinct indices into [`ASSETS`].
+fn distinct_assets() -> impl Strategy<Value = (usize, usize)> {
+    (0..ASSETS.len(), 1..ASSETS.len())
+        .prop_map(|(start, offset)| (start, (start + offset) % ASSETS.len()))
+}
+
+/// A swap between two of [`ASSETS`].
+fn swap() -> impl Strategy<Value = (usize, usize, u64)> {
+    (distinct_assets(), 1u64..=50_000).prop_map(|((start, end), amount)| (start, end, amount))
+}
+
+/// A position with reserves on either or both sides.
+fn position_spec() -> impl Strategy<Value = PositionSpec> {
+    (
+        distinct_assets(),
+        0u32..=100,
+        1u64..=1_000,
+        1u64..=1_000,
+        0u64..=10_000,
+        0u64..=10_000,
+    )
+        .prop_filter("positions must have reserves", |(_, _, _, _, r1, r2)| {
+            r1 + r2 > 0
+        })
+        .prop_map(|((start, end), fee, p, q, r1, r2)| PositionSpec {
+            start,
+            end,
+            fee,
+            p,
+            q,
+            r1,
+            r2,
+        })
+}
+
+/// A book of positions on a single pair, all selling the end asset for the
+/// start asset.
+fn single_pair_book() -> impl Strategy<Value = (usize, usize, Vec<PositionSpec>)> {
+    distinct_assets().prop_flat_map(|(start, end)| {
+        let spec = (0u32..=100, 1u64..=1_000, 1u64..=1_000, 1u64..=10_000).prop_map(
+            move |(fee, p, q, r2)| PositionSpec {
+                start,
+                end,
+                fee,
+                p,
+                q,
+                r1: 0,
+                r2,
+            },
+        );
+        (Just(start), Just(end), prop::collection::vec(spec, 1..8))
+    })
+}
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("can build a runtime")
+        .block_on(future)
+}
+
+/// Opens the positions of `book` on top of the latest state of `storage`.
+async fn open_book(storage: &TempStorage, book: &[Position]) -> Arc<StateDelta<Snapshot>> {
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    for position in book {
+        state_tx.open_position(position.clone()).await.unwrap();
+    }
+    state_tx.apply();
+    state
+}
+
+/// Routes `input` into `output` against the positions opened in `state`,
+/// returning the execution, its explanation if `explain` is set, and the new
+/// state of the positions of `book`.
+async fn route(
+    state: &mut Arc<StateDelta<Snapshot>>,
+    book: &[Position],
+    input: Value,
+    output: asset::Id,
+    explain: bool,
+) -> (SwapExecution, Option<RouteExplanation>, Vec<Position>) {
+    let routing_params = state.routing_params().await.unwrap();
+    let mut execution_budget = ExecutionCircuitBreaker::default();
+
+    let (execution, explanation) = if explain {
+        let (execution, explanation) = state
+            .explain_route_and_fill(
+                input.asset_id,
+                output,
+                input.amount,
+                routing_params,
+                &mut execution_budget,
+            )
+            .await
+            .unwrap();
+        (execution, Some(explanation))
+    } else {
+        let execution = state
+            .route_and_fill(
+                input.asset_id,
+                output,
+                input.amount,
+                routing_params,
+                &mut execution_budget,
+            )
+            .await
+            .unwrap();
+        (execution, None)
+    };
+
+    let mut positions = Vec::with_capacity(book.len());
+    for position in book {
+        positions.push(
+            state
+                .position_by_id(&position.id())
+                .await
+                .unwrap()
+                .expect("position exists"),
+        );
+    }
+    (execution, explanation, positions)
+}
+
+/// The value of a position's reserves, valued by its own trading function.
+///
+/// Trading against a position can only increase it: fees are kept in the
+/// reserves, and rounding errors are always in the position's favor.
+fn valuation(position: &Position) -> u128 {
+    let phi = &position.phi.component;
+    phi.p.value() * position.reserves.r1.value() + phi.q.value() * position.reserves.r2.value()
+}
+
+/// Checks the invariants that must hold for any execution of `input` into
+/// `output` that took `before` to `after`.
+fn check_invariants(
+    before: &[Position],
+    after: &[Position],
+    input: Value,
+    output: asset::Id,
+    execution: &SwapExecution,
+) {
+    // The execution is consistent with its traces, and doesn't exceed the input.
+    assert_eq!(execution.input.asset_id, input.asset_id);
+    assert_eq!(execution.output.asset_id, output);
+    assert!(execution.input.amount <= input.amount);
+    for trace in &execution.traces {
+        assert!(trace.len() >= 2, "trace {trace:?} has no hops");
+        assert_eq!(trace[0].asset_id, input.asset_id);
+        assert_eq!(trace[trace.len() - 1].asset_id, output);
+    }
+    let traced_input: Amount = execution.traces.iter().map(|trace| trace[0].amount).sum();
+    let traced_output: Amount = execution
+        .traces
+        .iter()
+        .map(|trace| trace[trace.len() - 1].amount)
+        .sum();
+    assert_eq!(traced_input, execution.input.amount);
+    assert_eq!(traced_output, execution.output.amount);
+
+    // Value is conserved: the book gained exactly the input that was filled,
+    // paid out exactly the output, and broke even on every other asset.
+    for (index, name) in ASSETS.iter().enumerate() {
+        let id = asset_id(index);
+        let total = |positions: &[Position]| -> i128 {
+            positions
+                .iter()
+                .map(|position| position.reserves_for(id).unwrap_or_default().value() as i128)
+                .sum()
+        };
+        let expected = if id == input.asset_id {
+            execution.input.amount.value() as i128
+        } else if id == output {
+            -(execution.output.amount.value() as i128)
+        } else {
+            0
+        };
+        assert_eq!(
+            total(after) - total(before),
+            expected,
+            "reserves of {name} are not conserved"
+        );
+    }
+
+    // No position was overdrawn.
+    for (before, after) in before.iter().zip(after) {
+        assert!(
+            valuation(after) >= valuation(before),
+            "position {} was overdrawn: {:?} => {:?}",
+            before.id(),
+            before.reserves,
+            after.reserves
+        );
+    }
+}
+
+/// Checks that each hop of each route was filled against positions from the
+/// best price to the worst.
+fn check_fill_order(book: &[Position], explanation: &RouteExplanation) {
+    let positions: BTreeMap<position::Id, &Position> = book
+        .iter()
+        .map(|position| (position.id(), position))
+        .collect();
+
+    for split in &explanation.splits {
+        for hop in 0..split.route.len().saturating_sub(1) {
+            let end = split.route[hop + 1];
+            let prices: Vec<_> = split
+                .fills
+                .iter()
+                .map(|fill| {
+                    positions[&fill.position_ids[hop]]
+                        .phi
+                        .orient_end(end)
+                        .expect("position is on the pair of the hop")
+                        .effective_price()
+                })
+                .collect();
+            assert!(
+                prices.windows(2).all(|window| window[0] <= window[1]),
+                "hop {hop} of route {:?} was not filled in order of price",
+                split.route
+            );
+        }
+    }
+}
+
+/// A slow reference router for books with a single pair, where the best
+/// execution is to fill the positions one at a time, from the best price to
+/// the worst.
+///
+/// Returns the input filled, the output, and the final state of the book.
+fn reference_route(
+    book: &[Position],
+    input: Value,
+    output: asset::Id,
+) -> (Amount, Amount, Vec<Position>) {
+    let mut positions = book.to_vec();
+    let mut order: Vec<usize> = (0..positions.len())
+        .filter(|&i| positions[i].reserves_for(output) != Some(Amount::zero()))
+        .collect();
+    // Positions with the same price are filled in the order of their IDs, as
+    // they are in the price index.
+    order.sort_by_key(|&i| {
+        (
+            positions[i]
+                .phi
+                .orient_end(output)
+                .expect("position is on the pair")
+                .effective_price(),
+            positions[i].id(),
+        )
+    });
+
+    let mut unfilled = input;
+    let mut total_output = Amount::zero();
+    for i in order {
+        if unfilled.amount == Amount::zero() {
+            break;
+        }
+        let (remaining, reserves, lambda) = positions[i]
+            .phi
+            .fill(unfilled, &positions[i].reserves)
+            .expect("no overflow");
+        positions[i].reserves = reserves;
+        total_output += lambda.amount;
+        unfilled = remaining;
+    }
+
+    (input.amount - unfilled.amount, total_output, positions)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn route_and_fill_invariants(
+        specs in prop::collection::vec(position_spec(), 0..10),
+        swaps in prop::collection::vec(swap(), 1..4),
+    ) {
+        block_on(async {
+            let storage = TempStorage::new()
+                .await
+                .unwrap()
+                .apply_minimal_genesis()
+                .await
+                .unwrap();
+            let book = book(&specs);
+            let mut state = open_book(&storage, &book).await;
+            // Replays the same swaps against a copy of the book, explaining them.
+            let mut explained_state = open_book(&storage, &book).await;
+
+            let mut before = book.clone();
+            for (start, end, amount) in swaps {
+                let input = Value {
+                    amount: amount.into(),
+                    asset_id: asset_id(start),
+                };
+                let output = asset_id(end);
+
+                let (execution, _, after) = route(&mut state, &book, input, output, false).await;
+                check_invariants(&before, &after, input, output, &execution);
+
+                // Routing the same swap against the same book gives the same
+                // result, whether or not it is explained.
+                let (explained_execution, explanation, explained_after) =
+                    route(&mut explained_state, &book, input, output, true).await;
+                assert_eq!(execution, explained_execution);
+                assert_eq!(after, explained_after);
+
+                let explanation = explanation.expect("routing was explained");
+                check_fill_order(&before, &explanation);
+                let fills: Vec<Vec<Value>> = explanation
+                    .splits
+                    .iter()
+                    .flat_map(|split| split.fills.iter().map(|fill| fill.trace.clone()))
+                    .collect();
+                assert_eq!(fills, execution.traces);
+
+                before = after;
+            }
+        });
+    }
+
+    #[test]
+    fn route_and_fill_matches_reference_on_single_pair(
+        (start, end, specs) in single_pair_book(),
+        amount in 1u64..=50_000,
+    ) {
+        block_on(async {
+            let storage = TempStorage::new()
+                .await
+                .unwrap()
+                .apply_minimal_genesis()
+                .await
+                .unwrap();
+            let book = book(&specs);
+            let input = Value {
+                amount: amount.into(),
+                asset_id: asset_id(start),
+            };
+            let output = asset_id(end);
+
+            let mut state = open_book(&storage, &book).await;
+            let (execution, _, after) = route(&mut state, &book, input, output, false).await;
+            check_invariants(&book, &after, input, output, &execution);
+
+            let (filled, total_output, expected) = reference_route(&book, input, output);
+            assert_eq!(execution.input.amount, filled);
+            assert_eq!(execution.output.amount, total_output);
+            for (position, expected) in after.iter().zip(&expected) {
+                assert_eq!(position.reserves, expected.reserves);
+            }
+        });
+    }
+}
@@ -2,9 +2,9 @@ use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use cnidarium::StateWrite;
+use cnidarium::{StateDelta, StateWrite};
 use penumbra_asset::{asset, Value};
-use penumbra_num::Amount;
+use penumbra_num::{fixpoint::U128x128, Amount};
 use penumbra_sct::component::clock::EpochRead;
 use tracing::instrument;
 
@@ -12,14 +12,17 @@ use crate::{
     component::{
         chandelier::Chandelier,
         flow::SwapFlow,
-        router::{FillRoute, PathSearch, RoutingParams},
+        router::{RouteExplanation, RouteSplit, RoutingParams, StopReason},
         ExecutionCircuitBreaker, InternalDexWrite, PositionManager,
     },
     lp::position::MAX_RESERVE_AMOUNT,
     BatchSwapOutputData, SwapExecution, TradingPair,
 };
 
-use super::fill_route::FillError;
+use super::{
+    fill_route::{fill_route_inner, FillError},
+    path_search::search_paths,
+};
 
 /// Ties together the routing and filling logic, to process
 /// a block's batch swap flows.
@@ -33,47 +36,37 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
         // This will be read from the ABCI request
         block_height: u64,
         params: RoutingParams,
+        execution_budget: &mut ExecutionCircuitBreaker,
     ) -> Result<()>
     where
         Self: 'static,
     {
         let (delta_1, delta_2) = (batch_data.0, batch_data.1);
+        let (limited_swaps_1_for_2, limited_swaps_2_for_1) = batch_data.limited_swaps();
 
         tracing::debug!(?delta_1, ?delta_2, ?trading_pair, "decrypted batch swaps");
 
-        let execution_circuit_breaker = ExecutionCircuitBreaker::default();
-
-        let swap_execution_1_for_2 = if delta_1.value() > 0 {
-            Some(
-                self.route_and_fill(
-                    trading_pair.asset_1(),
-                    trading_pair.asset_2(),
-                    delta_1,
-                    params.clone(),
-                    execution_circuit_breaker.clone(),
-                )
-                .await?,
-            )
-        } else {
-            tracing::debug!("no input for asset 1, skipping 1=>2 routing and execution");
-            None
-        };
-
-        let swap_execution_2_for_1 = if delta_2.value() > 0 {
-            Some(
-                self.route_and_fill(
-                    trading_pair.asset_2(),
-                    trading_pair.asset_1(),
-                    delta_2,
-                    params.clone(),
-                    execution_circuit_breaker,
-                )
-                .await?,
-            )
-        } else {
-            tracing::debug!("no input for asset 2, skipping 2=>1 execution");
-            None
-        };
+        let swap_execution_1_for_2 = route_batch(
+            self,
+            trading_pair.asset_1(),
+            trading_pair.asset_2(),
+            delta_1,
+            limited_swaps_1_for_2,
+            params.clone(),
+            execution_budget,
+        )
+        .await?;
+
+        let swap_execution_2_for_1 = route_batch(
+            self,
+            trading_pair.asset_2(),
+            trading_pair.asset_1(),
+            delta_2,
+            limited_swaps_2_for_1,
+            params.clone(),
+            execution_budget,
+        )
+        .await?;
 
         let (lambda_2, unfilled_1) = match &swap_execution_1_for_2 {
             Some(swap_execution) => (
@@ -144,160 +137,355 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
 
 impl<T: PositionManager> HandleBatchSwaps for T {}
 
+/// Routes one direction of a batch, swapping `input` of `asset_1` for `asset_2`,
+/// as long as every swap in `limited_swaps` gets at least its minimum output.
+///
+/// The swap claim circuit pays every swap in a batch pro rata, so it can't refund
+/// only the swaps whose minimum wasn't met. Instead, a batch with limited swaps is
+/// routed in a fork of the state, and if any of them would get less than its
+/// minimum output, the fork is discarded and the whole direction is left unfilled,
+/// refunding every swap in it. This means that a single swap with an unrealistic
+/// minimum keeps its direction of the batch from executing in that block.
+///
+/// The path searches and executions of a discarded attempt are still charged to
+/// the execution budget, since the work was done either way.
+async fn route_batch<S: StateWrite + 'static>(
+    state: &mut Arc<S>,
+    asset_1: asset::Id,
+    asset_2: asset::Id,
+    input: Amount,
+    limited_swaps: &[(Amount, Amount)],
+    params: RoutingParams,
+    execution_budget: &mut ExecutionCircuitBreaker,
+) -> Result<Option<SwapExecution>> {
+    if input.value() == 0 {
+        tracing::debug!(?asset_1, "no input, skipping routing and execution");
+        return Ok(None);
+    }
+
+    if limited_swaps.is_empty() {
+        let (swap_execution, _) = route_and_fill_inner(
+            state,
+            asset_1,
+            asset_2,
+            input,
+            params,
+            execution_budget,
+            false,
+        )
+        .await?;
+        return Ok(Some(swap_execution));
+    }
+
+    let mut fork = Arc::new(StateDelta::new(state.clone()));
+    let (swap_execution, _) = route_and_fill_inner(
+        &mut fork,
+        asset_1,
+        asset_2,
+        input,
+        params,
+        execution_budget,
+        false,
+    )
+    .await?;
+    let (_, cache) = Arc::try_unwrap(fork)
+        .map_err(|_| ())
+        .expect("no more outstanding refs to state after routing")
+        .flatten();
+
+    let output = swap_execution.output.amount;
+    if let Some((swap_input, min_output)) = limited_swaps
+        .iter()
+        .find(|(swap_input, min_output)| pro_rata_output(*swap_input, input, output) < *min_output)
+    {
+        tracing::debug!(
+            ?swap_input,
+            ?min_output,
+            ?input,
+            ?output,
+            "minimum output not met, refunding the batch"
+        );
+        return Ok(None);
+    }
+
+    cache.apply_to(Arc::get_mut(state).expect("expected state to have no other refs"));
+    Ok(Some(swap_execution))
+}
+
+/// The output that a swap of `swap_input` gets from a batch that swapped `input`
+/// for `output`, computed as in `BatchSwapOutputData::pro_rata_outputs`.
+fn pro_rata_output(swap_input: Amount, input: Amount, output: Amount) -> Amount {
+    let pro_rata_input = (U128x128::from(swap_input) / U128x128::from(input)).unwrap_or_default();
+    (pro_rata_input * U128x128::from(output))
+        .unwrap_or_default()
+        .round_down()
+        .try_into()
+        .expect("rounded amount is integral")
+}
+
 /// Lower-level trait that ties together the routing and filling logic.
 #[async_trait]
 pub trait RouteAndFill: StateWrite + Sized {
-    #[instrument(skip(self, asset_1, asset_2, input, params, execution_circuit_breaker))]
+    #[instrument(skip(self, asset_1, asset_2, input, params, execution_budget))]
     async fn route_and_fill(
         self: &mut Arc<Self>,
         asset_1: asset::Id,
         asset_2: asset::Id,
         input: Amount,
         params: RoutingParams,
-        mut execution_circuit_breaker: ExecutionCircuitBreaker,
+        execution_budget: &mut ExecutionCircuitBreaker,
     ) -> Result<SwapExecution>
     where
         Self: 'static,
     {
-        tracing::debug!(?input, ?asset_1, ?asset_2, "starting route_and_fill");
-
-        // Unfilled output of asset 1
-        let mut total_unfilled_1 = input;
-        // Output of asset 2
-        let mut total_output_2 = 0u64.into();
-
-        // An ordered list of execution traces that were used to fill the trade.
-        let mut traces: Vec<Vec<Value>> = Vec::new();
+        let (swap_execution, _) = route_and_fill_inner(
+            self,
+            asset_1,
+            asset_2,
+            input,
+            params,
+            execution_budget,
+            false,
+        )
+        .await?;
+        Ok(swap_execution)
+    }
 
-        let max_delta_1: Amount = MAX_RESERVE_AMOUNT.into();
+    /// Routes and fills a trade like [`RouteAndFill::route_and_fill`], also
+    /// explaining how the trade was routed and filled.
+    ///
+    /// This is more expensive than `route_and_fill`, since it records every
+    /// path explored by the path searches, and is only meant for debugging.
+    #[instrument(skip(self, asset_1, asset_2, input, params, execution_budget))]
+    async fn explain_route_and_fill(
+        self: &mut Arc<Self>,
+        asset_1: asset::Id,
+        asset_2: asset::Id,
+        input: Amount,
+        params: RoutingParams,
+        execution_budget: &mut ExecutionCircuitBreaker,
+    ) -> Result<(SwapExecution, RouteExplanation)>
+    where
+        Self: 'static,
+    {
+        route_and_fill_inner(
+            self,
+            asset_1,
+            asset_2,
+            input,
+            params,
+            execution_budget,
+            true,
+        )
+        .await
+    }
+}
 
-        // Termination conditions:
-        // 1. We have no more delta_1 remaining
-        // 2. A path can no longer be found
-        // 3. We have reached the `RoutingParams` specified price limit
-        // 4. The execution circuit breaker has been triggered based on the number of path searches and executions
+impl<T: HandleBatchSwaps> RouteAndFill for T {}
 
-        loop {
-            // Check if we have exceeded the execution circuit breaker limits.
-            if execution_circuit_breaker.exceeded_limits() {
-                tracing::debug!("execution circuit breaker triggered, exiting route_and_fill");
-                break;
-            }
+/// Routes and fills a trade, recording how it was routed. The splits of the
+/// trade, with the paths explored and the positions filled, are only recorded
+/// if `explain` is set.
+async fn route_and_fill_inner<S: StateWrite + 'static>(
+    state: &mut Arc<S>,
+    asset_1: asset::Id,
+    asset_2: asset::Id,
+    input: Amount,
+    params: RoutingParams,
+    execution_budget: &mut ExecutionCircuitBreaker,
+    explain: bool,
+) -> Result<(SwapExecution, RouteExplanation)> {
+    tracing::debug!(?input, ?asset_1, ?asset_2, "starting route_and_fill");
+
+    // The trade is bounded both by its own limits, and by the budget it
+    // shares with the other trades routed alongside it.
+    let mut execution_circuit_breaker =
+        ExecutionCircuitBreaker::new(params.max_path_searches, params.max_executions);
+
+    // Unfilled output of asset 1
+    let mut total_unfilled_1 = input;
+    // Output of asset 2
+    let mut total_output_2 = 0u64.into();
+
+    // An ordered list of execution traces that were used to fill the trade.
+    let mut traces: Vec<Vec<Value>> = Vec::new();
+
+    let max_delta_1: Amount = MAX_RESERVE_AMOUNT.into();
+
+    let mut explanation = RouteExplanation {
+        max_path_searches: params.max_path_searches,
+        max_executions: params.max_executions,
+        ..Default::default()
+    };
+
+    // Termination conditions:
+    // 1. We have no more delta_1 remaining
+    // 2. A path can no longer be found
+    // 3. We have reached the `RoutingParams` specified price limit
+    // 4. The execution circuit breaker has been triggered based on the number of path searches and executions
+
+    loop {
+        // Check if we have exceeded the execution circuit breaker limits.
+        if execution_circuit_breaker.exceeded_limits() || execution_budget.exceeded_limits() {
+            tracing::debug!("execution circuit breaker triggered, exiting route_and_fill");
+            explanation.stop_reason = Some(StopReason::CircuitBreaker);
+            break;
+        }
 
-            // Find the best route between the two assets in the trading pair.
-            let (path, spill_price) = self
-                .path_search(asset_1, asset_2, params.clone())
+        // Find the best route between the two assets in the trading pair.
+        let (path, spill_price, candidates) =
+            search_paths(state.clone(), asset_1, asset_2, params.clone(), explain)
                 .await
                 .context("error finding best path")?;
+        if explain {
+            explanation.splits.push(RouteSplit {
+                candidates,
+                ..Default::default()
+            });
+        }
 
-            let Some(path) = path else {
-                tracing::debug!("no path found, exiting route_and_fill");
-                break;
-            };
+        let Some(path) = path else {
+            tracing::debug!("no path found, exiting route_and_fill");
+            explanation.stop_reason = Some(StopReason::NoPath);
+            break;
+        };
 
-            if path.is_empty() {
-                tracing::debug!("empty path found, exiting route_and_fill");
-                break;
-            }
+        if path.is_empty() {
+            tracing::debug!("empty path found, exiting route_and_fill");
+            explanation.stop_reason = Some(StopReason::NoPath);
+            break;
+        }
 
-            // Increment the execution circuit breaker path search counter.
-            execution_circuit_breaker.current_path_searches += 1;
+        // Increment the execution circuit breaker path search counter.
+        execution_circuit_breaker.current_path_searches += 1;
+        execution_budget.current_path_searches += 1;
 
-            let delta_1 = Value {
-                amount: total_unfilled_1.min(max_delta_1),
+        let delta_1 = Value {
+            amount: total_unfilled_1.min(max_delta_1),
+            asset_id: asset_1,
+        };
+
+        tracing::debug!(?path, delta_1 = ?delta_1.amount, "found path, filling up to spill price");
+
+        if explain {
+            let split = explanation
+                .splits
+                .last_mut()
+                .expect("a split was recorded for this path search");
+            split.route = std::iter::once(asset_1)
+                .chain(path.iter().cloned())
+                .collect();
+            split.spill_price = spill_price;
+        }
+
+        let execution = fill_route_inner(
+            Arc::get_mut(state).expect("expected state to have no other refs"),
+            delta_1,
+            &path,
+            spill_price,
+            true,
+            explain,
+        )
+        .await;
+
+        let execution = match execution {
+            Ok((execution, fills)) => {
+                if explain {
+                    explanation
+                        .splits
+                        .last_mut()
+                        .expect("a split was recorded for this path search")
+                        .fills = fills;
+                }
+                execution
+            }
+            Err(FillError::ExecutionOverflow(position_id)) => {
+                // We have encountered an overflow during the execution of the route.
+                // To route around this, we will close the position and try to route and fill again.
+                tracing::debug!(culprit = ?position_id, "overflow detected during routing execution");
+                Arc::get_mut(state)
+                    .expect("expected state to have no other refs")
+                    .close_position_by_id(&position_id)
+                    .await
+                    .expect("the position still exists");
+                continue;
+            }
+            Err(e) => {
+                // We have encountered an error during the execution of the route,
+                // there are no clear ways to route around this, so we propagate the error.
+                // `fill_route` is transactional and will have rolled back the state.
+                anyhow::bail!("error filling route: {:?}", e);
+            }
+        };
+
+        // Immediately track the execution in the state.
+        (total_output_2, total_unfilled_1) = {
+            let lambda_2 = execution.output;
+            let unfilled_1 = Value {
+                amount: total_unfilled_1
+                    .checked_sub(&execution.input.amount)
+                    .expect("unable to subtract unfilled input from total input"),
                 asset_id: asset_1,
             };
+            tracing::debug!(input = ?delta_1.amount, output = ?lambda_2.amount, unfilled = ?unfilled_1.amount, "filled along best path");
 
-            tracing::debug!(?path, delta_1 = ?delta_1.amount, "found path, filling up to spill price");
+            assert_eq!(lambda_2.asset_id, asset_2);
+            assert_eq!(unfilled_1.asset_id, asset_1);
 
-            let execution = Arc::get_mut(self)
-                .expect("expected state to have no other refs")
-                .fill_route(delta_1, &path, spill_price)
-                .await;
+            // Append the traces from this execution to the outer traces.
+            traces.append(&mut execution.traces.clone());
 
-            let execution = match execution {
-                Ok(execution) => execution,
-                Err(FillError::ExecutionOverflow(position_id)) => {
-                    // We have encountered an overflow during the execution of the route.
-                    // To route around this, we will close the position and try to route and fill again.
-                    tracing::debug!(culprit = ?position_id, "overflow detected during routing execution");
-                    Arc::get_mut(self)
-                        .expect("expected state to have no other refs")
-                        .close_position_by_id(&position_id)
-                        .await
-                        .expect("the position still exists");
-                    continue;
-                }
-                Err(e) => {
-                    // We have encountered an error during the execution of the route,
-                    // there are no clear ways to route around this, so we propagate the error.
-                    // `fill_route` is transactional and will have rolled back the state.
-                    anyhow::bail!("error filling route: {:?}", e);
-                }
-            };
+            (
+                total_output_2 + lambda_2.amount,
+                total_unfilled_1 - delta_1.amount + unfilled_1.amount,
+            )
+        };
 
-            // Immediately track the execution in the state.
-            (total_output_2, total_unfilled_1) = {
-                let lambda_2 = execution.output;
-                let unfilled_1 = Value {
-                    amount: total_unfilled_1
-                        .checked_sub(&execution.input.amount)
-                        .expect("unable to subtract unfilled input from total input"),
-                    asset_id: asset_1,
-                };
-                tracing::debug!(input = ?delta_1.amount, output = ?lambda_2.amount, unfilled = ?unfilled_1.amount, "filled along best path");
-
-                assert_eq!(lambda_2.asset_id, asset_2);
-                assert_eq!(unfilled_1.asset_id, asset_1);
-
-                // Append the traces from this execution to the outer traces.
-                traces.append(&mut execution.traces.clone());
-
-                (
-                    total_output_2 + lambda_2.amount,
-                    total_unfilled_1 - delta_1.amount + unfilled_1.amount,
-                )
-            };
+        // Increment the execution circuit breaker execution counter.
+        execution_circuit_breaker.current_executions += 1;
+        execution_budget.current_executions += 1;
 
-            // Increment the execution circuit breaker execution counter.
-            execution_circuit_breaker.current_executions += 1;
+        if total_unfilled_1.value() == 0 {
+            tracing::debug!("filled all input, exiting route_and_fill");
+            explanation.stop_reason = Some(StopReason::Filled);
+            break;
+        }
 
-            if total_unfilled_1.value() == 0 {
-                tracing::debug!("filled all input, exiting route_and_fill");
-                break;
-            }
+        // Ensure that we've actually executed, or else bail out.
+        let Some(accurate_max_price) = execution.max_price() else {
+            tracing::debug!("no traces in execution, exiting route_and_fill");
+            explanation.stop_reason = Some(StopReason::NoExecution);
+            break;
+        };
 
-            // Ensure that we've actually executed, or else bail out.
-            let Some(accurate_max_price) = execution.max_price() else {
-                tracing::debug!("no traces in execution, exiting route_and_fill");
+        // Check that the execution price is below the price limit, if one is set.
+        if let Some(price_limit) = params.price_limit {
+            if accurate_max_price >= price_limit {
+                tracing::debug!(
+                    ?accurate_max_price,
+                    ?price_limit,
+                    "execution price above price limit, exiting route_and_fill"
+                );
+                explanation.stop_reason = Some(StopReason::PriceLimit);
                 break;
-            };
-
-            // Check that the execution price is below the price limit, if one is set.
-            if let Some(price_limit) = params.price_limit {
-                if accurate_max_price >= price_limit {
-                    tracing::debug!(
-                        ?accurate_max_price,
-                        ?price_limit,
-                        "execution price above price limit, exiting route_and_fill"
-                    );
-                    break;
-                }
             }
         }
-
-        Ok(SwapExecution {
-            traces,
-            input: Value {
-                asset_id: asset_1,
-                amount: input - total_unfilled_1,
-            },
-            output: Value {
-                asset_id: asset_2,
-                amount: total_output_2,
-            },
-        })
     }
-}
 
-impl<T: HandleBatchSwaps> RouteAndFill for T {}
+    explanation.path_searches = execution_circuit_breaker.current_path_searches;
+    explanation.executions = execution_circuit_breaker.current_executions;
+
+    let swap_execution = SwapExecution {
+        traces,
+        input: Value {
+            asset_id: asset_1,
+            amount: input - total_unfilled_1,
+        },
+        output: Value {
+            asset_id: asset_2,
+            amount: total_output_2,
+        },
+    };
+
+    Ok((swap_execution, explanation))
+}
@@ -14,15 +14,15 @@ use crate::lp::SellOrder;
 use crate::DexParameters;
 use crate::{
     component::{
-        router::{FillRoute, HandleBatchSwaps, Path},
+        router::{FillRoute, HandleBatchSwaps, Path, RouteAndFill, StopReason},
         tests::TempStorageExt,
-        PositionManager, PositionRead, StateReadExt, StateWriteExt,
+        ExecutionCircuitBreaker, PositionManager, PositionRead, StateReadExt, StateWriteExt,
     },
     lp::{
         position::{self, Position},
         Reserves,
     },
-    DirectedTradingPair, DirectedUnitPair,
+    DirectedTradingPair, DirectedUnitPair, TradingPair,
 };
 
 use super::PathSearch;
@@ -1029,7 +1029,13 @@ async fn best_position_route_and_fill() -> anyhow::Result<()> {
         .unwrap();
     let routing_params = state.routing_params().await.unwrap();
     state
-        .handle_batch_swaps(trading_pair, swap_flow, 0u32.into(), routing_params)
+        .handle_batch_swaps(
+            trading_pair,
+            swap_flow,
+            0u32.into(),
+            routing_params,
+            &mut ExecutionCircuitBreaker::block_budget(&DexParameters::default()),
+        )
         .await
         .expect("unable to process batch swaps");
 
@@ -1052,6 +1058,122 @@ async fn best_position_route_and_fill() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+/// Test that a batch swap is left unfilled once the block's routing budget is spent.
+async fn exhausted_execution_budget_leaves_swap_unfilled() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+
+    let pair_1 = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+
+    // Create a single 1:1 gn:penumbra position (i.e. buy 1 gn at 1 penumbra).
+    let buy_1 = create_buy(pair_1.clone(), 1u64.into(), 1u64.into());
+    state_tx.open_position(buy_1).await.unwrap();
+    state_tx.apply();
+
+    let trading_pair = pair_1.into_directed_trading_pair().into();
+    let mut swap_flow = state.swap_flow(&trading_pair);
+    swap_flow.1 += 1u32.into();
+    Arc::get_mut(&mut state)
+        .unwrap()
+        .put_swap_flow(&trading_pair, swap_flow.clone())
+        .await
+        .unwrap();
+
+    // Earlier batch swaps in the block used up all of the path searches.
+    let mut execution_budget = ExecutionCircuitBreaker::new(4, 4);
+    execution_budget.current_path_searches = 5;
+
+    let routing_params = state.routing_params().await.unwrap();
+    state
+        .handle_batch_swaps(
+            trading_pair,
+            swap_flow,
+            0u32.into(),
+            routing_params,
+            &mut execution_budget,
+        )
+        .await
+        .expect("unable to process batch swaps");
+
+    // The 1 gn in is returned unfilled, even though the position could fill it.
+    let output_data = state.output_data(0, trading_pair).await?.unwrap();
+    assert_eq!(output_data.delta_2, 1u64.into());
+    assert_eq!(output_data.lambda_1, 0u64.into());
+    assert_eq!(output_data.unfilled_2, 1u64.into());
+    assert_eq!(execution_budget.current_executions, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that the explanation of a trade records the route, the positions it was
+/// filled against, and why routing stopped.
+async fn explain_route_and_fill() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+
+    let pair_1 = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+
+    // Create a single 1:1 gn:penumbra position (i.e. buy 1 gn at 1 penumbra).
+    let buy_1 = create_buy(pair_1.clone(), 1u64.into(), 1u64.into());
+    let buy_1_id = buy_1.id();
+    state_tx.open_position(buy_1).await.unwrap();
+    state_tx.apply();
+
+    // Swap more gn than the position can fill, so that routing runs out of paths.
+    let routing_params = state.routing_params().await.unwrap();
+    let max_path_searches = routing_params.max_path_searches;
+    let (swap_execution, explanation) = state
+        .explain_route_and_fill(
+            gn.id(),
+            penumbra.id(),
+            1_000_000_000_000u64.into(),
+            routing_params,
+            &mut ExecutionCircuitBreaker::default(),
+        )
+        .await?;
+
+    // The first split fills the position, and the second one finds no path.
+    assert_eq!(explanation.splits.len(), 2);
+    let split = &explanation.splits[0];
+    assert_eq!(split.route, vec![gn.id(), penumbra.id()]);
+    assert!(split
+        .candidates
+        .iter()
+        .any(|candidate| !candidate.spill && candidate.route == split.route));
+    assert!(split
+        .fills
+        .iter()
+        .all(|fill| fill.position_ids == vec![buy_1_id]));
+    let traces: Vec<Vec<Value>> = split.fills.iter().map(|fill| fill.trace.clone()).collect();
+    assert_eq!(traces, swap_execution.traces);
+    assert!(explanation.splits[1].route.is_empty());
+
+    assert_eq!(explanation.stop_reason, Some(StopReason::NoPath));
+    assert_eq!(explanation.path_searches, 1);
+    assert_eq!(explanation.executions, 1);
+    assert_eq!(explanation.max_path_searches, max_path_searches);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn multi_hop_route_and_fill() -> anyhow::Result<()> {
     let _ = tracing_subscriber::fmt::try_init();
@@ -1170,7 +1292,13 @@ async fn multi_hop_route_and_fill() -> anyhow::Result<()> {
         .unwrap();
     let routing_params = state.routing_params().await.unwrap();
     state
-        .handle_batch_swaps(trading_pair, swap_flow, 0u32.into(), routing_params)
+        .handle_batch_swaps(
+            trading_pair,
+            swap_flow,
+            0u32.into(),
+            routing_params,
+            &mut ExecutionCircuitBreaker::block_budget(&DexParameters::default()),
+        )
         .await
         .expect("unable to process batch swaps");
 
@@ -1201,6 +1329,118 @@ async fn multi_hop_route_and_fill() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Swaps 30 gm for at least `min_output` gn, against one position selling
+/// 10 gn at 1 gm each, and another selling 10 gn at 2 gm each.
+///
+/// Returns the output and the unfilled input of the batch, the reserves of gn
+/// left in the cheap position, and the execution budget used.
+async fn batch_swap_with_min_output(
+    min_output: Amount,
+) -> anyhow::Result<(Amount, Amount, Amount, ExecutionCircuitBreaker)> {
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+
+    let cheap = SellOrder::parse_str("10gn@10gm")
+        .unwrap()
+        .into_position(OsRng);
+    let expensive = SellOrder::parse_str("10gn@20gm")
+        .unwrap()
+        .into_position(OsRng);
+    let cheap_id = cheap.id();
+    state_tx.open_position(cheap).await.unwrap();
+    state_tx.open_position(expensive).await.unwrap();
+    state_tx.apply();
+
+    let input = Amount::from(30u64) * gm.unit_amount();
+    let trading_pair = TradingPair::new(gm.id(), gn.id());
+    let gm_is_asset_1 = trading_pair.asset_1() == gm.id();
+    let mut swap_flow = state.swap_flow(&trading_pair);
+    if gm_is_asset_1 {
+        swap_flow.0 += input;
+        swap_flow.limit_swap_1_for_2(input, min_output);
+    } else {
+        swap_flow.1 += input;
+        swap_flow.limit_swap_2_for_1(input, min_output);
+    }
+
+    Arc::get_mut(&mut state)
+        .unwrap()
+        .put_swap_flow(&trading_pair, swap_flow.clone())
+        .await
+        .unwrap();
+    let routing_params = state.routing_params().await.unwrap();
+    let mut execution_budget = ExecutionCircuitBreaker::block_budget(&DexParameters::default());
+    state
+        .handle_batch_swaps(
+            trading_pair,
+            swap_flow,
+            0u32.into(),
+            routing_params,
+            &mut execution_budget,
+        )
+        .await
+        .expect("unable to process batch swaps");
+
+    let output_data = state.output_data(0, trading_pair).await?.unwrap();
+    let (output, unfilled) = if gm_is_asset_1 {
+        (output_data.lambda_2, output_data.unfilled_1)
+    } else {
+        (output_data.lambda_1, output_data.unfilled_2)
+    };
+
+    let cheap = state.position_by_id(&cheap_id).await?.unwrap();
+    let cheap_gn = if cheap.phi.pair.asset_1() == gn.id() {
+        cheap.reserves.r1
+    } else {
+        cheap.reserves.r2
+    };
+
+    Ok((output, unfilled, cheap_gn, execution_budget))
+}
+
+#[tokio::test]
+/// Test that a batch swap is executed if every swap in it gets its minimum output.
+async fn batch_swap_meets_min_output() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+
+    let min_output = Amount::from(15u64) * gn.unit_amount();
+    let (output, _, cheap_gn, _) = batch_swap_with_min_output(min_output).await?;
+
+    // Both positions were used.
+    assert!(output >= min_output);
+    assert_eq!(cheap_gn, Amount::zero());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that a batch swap that can't meet a swap's minimum output is refunded
+/// in full, leaving the positions untouched, and that routing it still counts
+/// against the execution budget.
+async fn batch_swap_refunds_unmet_min_output() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+
+    // Only 20 gn are for sale.
+    let min_output = Amount::from(21u64) * gn.unit_amount();
+    let (output, unfilled, cheap_gn, execution_budget) =
+        batch_swap_with_min_output(min_output).await?;
+
+    assert_eq!(output, Amount::zero());
+    assert_eq!(unfilled, Amount::from(30u64) * gm.unit_amount());
+    assert_eq!(cheap_gn, Amount::from(10u64) * gn.unit_amount());
+    assert!(execution_budget.current_path_searches > 0);
+    assert!(execution_budget.current_executions > 0);
+
+    Ok(())
+}
+
 #[tokio::test]
 /// Reproduce the dust constraint creating `current_input = 0`
 async fn fill_dust_route() -> anyhow::Result<()> {
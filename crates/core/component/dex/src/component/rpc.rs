@@ -8,7 +8,8 @@ use tonic::Status;
 use tracing::instrument;
 
 use cnidarium::{StateDelta, Storage};
-use penumbra_asset::{asset, Value};
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_num::fixpoint::U128x128;
 use penumbra_proto::{
     core::component::dex::v1::{
         query_service_server::QueryService,
@@ -23,8 +24,11 @@ use penumbra_proto::{
         LiquidityPositionByIdRequest, LiquidityPositionByIdResponse, LiquidityPositionsByIdRequest,
         LiquidityPositionsByIdResponse, LiquidityPositionsByPriceRequest,
         LiquidityPositionsByPriceResponse, LiquidityPositionsRequest, LiquidityPositionsResponse,
+        MarketDepthRequest, MarketDepthResponse, PositionExecutionsRequest,
+        PositionExecutionsResponse, PositionRewardsRequest, PositionRewardsResponse,
         SimulateTradeRequest, SimulateTradeResponse, SpreadRequest, SpreadResponse,
         SwapExecutionRequest, SwapExecutionResponse, SwapExecutionsRequest, SwapExecutionsResponse,
+        TimeWeightedAveragePriceRequest, TimeWeightedAveragePriceResponse,
     },
     DomainType, StateReadProto,
 };
@@ -33,13 +37,20 @@ use super::ExecutionCircuitBreaker;
 use crate::{
     component::metrics,
     lp::position::{self, Position},
-    state_key, CandlestickData, DirectedTradingPair, SwapExecution, TradingPair,
+    state_key, CandlestickData, CandlestickResolution, DirectedTradingPair, SwapExecution,
+    TradingPair,
 };
 
-use super::{chandelier::CandlestickRead, router::RouteAndFill, PositionRead, StateReadExt};
+use super::{
+    chandelier::CandlestickRead, router::RouteAndFill, PositionRead, RewardRead, StateReadExt,
+};
 
+mod depth;
 pub mod stub;
 
+/// The maximum number of price levels returned by a market depth query.
+const MAX_MARKET_DEPTH_LEVELS: usize = 1_000;
+
 // TODO: Hide this and only expose a Router?
 pub struct Server {
     storage: Storage,
@@ -72,6 +83,9 @@ impl QueryService for Server {
         Pin<Box<dyn futures::Stream<Item = Result<ArbExecutionsResponse, tonic::Status>> + Send>>;
     type SwapExecutionsStream =
         Pin<Box<dyn futures::Stream<Item = Result<SwapExecutionsResponse, tonic::Status>> + Send>>;
+    type PositionExecutionsStream = Pin<
+        Box<dyn futures::Stream<Item = Result<PositionExecutionsResponse, tonic::Status>> + Send>,
+    >;
     type CandlestickDataStreamStream = Pin<
         Box<
             dyn futures::Stream<Item = Result<CandlestickDataStreamResponse, tonic::Status>> + Send,
@@ -86,9 +100,15 @@ impl QueryService for Server {
         let state = self.storage.latest_snapshot();
         let request_inner = request.into_inner();
         let height = request_inner.height;
+        let arb_token = match request_inner.arb_token {
+            Some(arb_token) => arb_token
+                .try_into()
+                .map_err(|_| Status::invalid_argument("invalid arb token"))?,
+            None => *STAKING_TOKEN_ASSET_ID,
+        };
 
         let arb_execution = state
-            .arb_execution(height)
+            .arb_execution(height, arb_token)
             .await
             .map_err(|e| tonic::Status::internal(e.to_string()))?;
 
@@ -110,6 +130,11 @@ impl QueryService for Server {
         let request_inner = request.into_inner();
         let start_height = request_inner.start_height;
         let end_height = request_inner.end_height;
+        let arb_token: Option<asset::Id> = request_inner
+            .arb_token
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|_| Status::invalid_argument("invalid arb token"))?;
 
         let s = state.prefix(state_key::arb_executions());
         Ok(tonic::Response::new(
@@ -125,11 +150,15 @@ impl QueryService for Server {
                     let (key, arb_execution) = i.expect("i is Ok");
                     let height = key
                         .split('/')
-                        .last()
-                        .expect("arb execution key has height as last part")
+                        .nth(2)
+                        .expect("arb execution key has height as third part")
                         .parse()
                         .expect("height is a number");
 
+                    if arb_token.is_some_and(|id| id != arb_execution.input.asset_id) {
+                        return None;
+                    }
+
                     // TODO: would be great to start iteration at start_height
                     // and stop at end_height rather than touching _every_
                     // key, but the current storage implementation doesn't make this
@@ -216,14 +245,6 @@ impl QueryService for Server {
         // Limit the number of candlesticks returned to 20,000 (approximately 1 day)
         // to prevent the server from being overwhelmed by a single request.
         let limit = std::cmp::min(request.get_ref().limit, 20_000u64);
-        let start_height = match request.get_ref().start_height {
-            0 => {
-                // If no start height is provided, go `limit` blocks back from now.
-                let current_height = state.version();
-                current_height.saturating_sub(limit)
-            }
-            start_height => start_height,
-        };
 
         let pair: DirectedTradingPair = request
             .get_ref()
@@ -233,6 +254,43 @@ impl QueryService for Server {
             .try_into()
             .map_err(|_| Status::invalid_argument("invalid trading_pair"))?;
 
+        let resolution: CandlestickResolution = request.get_ref().resolution().into();
+        if resolution != CandlestickResolution::Block {
+            let start_bucket = match request.get_ref().start_bucket {
+                0 => {
+                    // If no start bucket is provided, go `limit` buckets back from the current one.
+                    let current_bucket = current_candlestick_bucket(&state, resolution)
+                        .await
+                        .map_err(|e| tonic::Status::internal(e.to_string()))?;
+                    let width = resolution.bucket_seconds().unwrap_or(1);
+                    current_bucket.saturating_sub(limit.saturating_sub(1) * width)
+                }
+                start_bucket => start_bucket,
+            };
+            let end_bucket = match request.get_ref().end_bucket {
+                0 => None,
+                end_bucket => Some(end_bucket),
+            };
+
+            let candlesticks = state
+                .rollup_candlesticks(resolution, &pair, start_bucket, end_bucket, limit as usize)
+                .await
+                .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+            return Ok(tonic::Response::new(CandlestickDataResponse {
+                data: candlesticks.into_iter().map(Into::into).collect(),
+            }));
+        }
+
+        let start_height = match request.get_ref().start_height {
+            0 => {
+                // If no start height is provided, go `limit` blocks back from now.
+                let current_height = state.version();
+                current_height.saturating_sub(limit)
+            }
+            start_height => start_height,
+        };
+
         let candlesticks = state
             .candlesticks(&pair, start_height, limit as usize)
             .await
@@ -293,6 +351,49 @@ impl QueryService for Server {
         ))
     }
 
+    #[instrument(skip(self, request))]
+    async fn time_weighted_average_price(
+        &self,
+        request: tonic::Request<TimeWeightedAveragePriceRequest>,
+    ) -> Result<tonic::Response<TimeWeightedAveragePriceResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let pair: DirectedTradingPair = request
+            .pair
+            .ok_or_else(|| Status::invalid_argument("missing trading_pair"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid trading_pair"))?;
+
+        let current_height = state.version();
+        let end_height = match request.end_height {
+            0 => current_height,
+            end_height => end_height,
+        };
+        if end_height > current_height {
+            return Err(Status::invalid_argument(format!(
+                "end height {end_height} is after the current height {current_height}"
+            )));
+        }
+        if request.start_height > end_height {
+            return Err(Status::invalid_argument(format!(
+                "start height {} is after end height {end_height}",
+                request.start_height
+            )));
+        }
+
+        let price = state
+            .time_weighted_average_price(&pair, request.start_height, end_height)
+            .await
+            .map_err(|e| Status::not_found(e.to_string()))?;
+
+        Ok(tonic::Response::new(TimeWeightedAveragePriceResponse {
+            price: price.into(),
+            start_height: request.start_height,
+            end_height,
+        }))
+    }
+
     #[instrument(skip(self, request))]
     async fn swap_executions(
         &self,
@@ -476,6 +577,70 @@ impl QueryService for Server {
         Ok(tonic::Response::new(s.boxed()))
     }
 
+    #[instrument(skip(self, request))]
+    async fn market_depth(
+        &self,
+        request: tonic::Request<MarketDepthRequest>,
+    ) -> Result<tonic::Response<MarketDepthResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let pair: DirectedTradingPair = request
+            .trading_pair
+            .ok_or_else(|| Status::invalid_argument("missing directed trading pair"))?
+            .try_into()
+            .map_err(|e| {
+                Status::invalid_argument(format!("error parsing directed trading pair: {:#}", e))
+            })?;
+
+        let tick_size = match request.tick_size {
+            tick_size if tick_size == 0.0 => None,
+            tick_size => match U128x128::try_from(tick_size) {
+                Ok(tick) if tick != U128x128::default() => Some(tick),
+                _ => {
+                    return Err(Status::invalid_argument(format!(
+                        "invalid tick size {tick_size}"
+                    )))
+                }
+            },
+        };
+        // Limit the number of levels to prevent the server from being
+        // overwhelmed by a single request.
+        let depth_limit = match request.depth_limit {
+            0 => MAX_MARKET_DEPTH_LEVELS,
+            depth_limit => std::cmp::min(depth_limit as usize, MAX_MARKET_DEPTH_LEVELS),
+        };
+
+        let mut depth = depth::DepthAggregator::new(tick_size, depth_limit);
+        let mut positions = state.position_prices(&pair);
+        while let Some((id, price)) = positions
+            .try_next()
+            .await
+            .map_err(|e| Status::internal(format!("error reading price index: {:#}", e)))?
+        {
+            // The liquidity of a position is its reserves of the end asset. Positions
+            // are looked up as they are reached, so the work stops at the depth limit.
+            let liquidity = state
+                .position_by_id(&id)
+                .await
+                .map_err(|e| Status::internal(format!("error reading position {id}: {:#}", e)))?
+                .and_then(|position| position.reserves_for(pair.end))
+                .ok_or_else(|| {
+                    Status::internal(format!("position {id} in the price index was not found"))
+                })?;
+            if !depth
+                .add(price, liquidity)
+                .map_err(|e| Status::internal(format!("error aggregating depth: {:#}", e)))?
+            {
+                break;
+            }
+        }
+
+        Ok(tonic::Response::new(MarketDepthResponse {
+            levels: depth.into_levels(),
+        }))
+    }
+
     #[instrument(skip(self, request))]
     async fn liquidity_positions(
         &self,
@@ -585,6 +750,74 @@ impl QueryService for Server {
             .boxed(),
         ))
     }
+
+    #[instrument(skip(self, request))]
+    async fn position_executions(
+        &self,
+        request: tonic::Request<PositionExecutionsRequest>,
+    ) -> Result<tonic::Response<Self::PositionExecutionsStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let position_id: position::Id = request
+            .position_id
+            .ok_or_else(|| Status::invalid_argument("missing position id"))?
+            .try_into()
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::invalid_argument(format!("error converting position_id: {e}"))
+            })?;
+        let start_height = request.start_height;
+        let end_height = match request.end_height {
+            0 => u64::MAX,
+            end_height => end_height,
+        };
+
+        let s = state
+            .position_executions(&position_id, start_height)
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+        Ok(tonic::Response::new(
+            s.try_take_while(move |execution| {
+                futures::future::ready(Ok(execution.height <= end_height))
+            })
+            .map_ok(|execution| PositionExecutionsResponse {
+                execution: Some(execution.into()),
+            })
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!(
+                    "error getting position execution from storage: {e}"
+                ))
+            })
+            // TODO: how do we instrument a Stream
+            //.instrument(Span::current())
+            .boxed(),
+        ))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn position_rewards(
+        &self,
+        request: tonic::Request<PositionRewardsRequest>,
+    ) -> Result<tonic::Response<PositionRewardsResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let position_id: position::Id = request
+            .into_inner()
+            .position_id
+            .ok_or_else(|| Status::invalid_argument("missing position id"))?
+            .try_into()
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::invalid_argument(format!("error converting position_id: {e}"))
+            })?;
+
+        let rewards = state
+            .position_rewards(&position_id)
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        Ok(tonic::Response::new(PositionRewardsResponse {
+            rewards: Some(rewards.into()),
+        }))
+    }
 }
 
 #[tonic::async_trait]
@@ -636,16 +869,35 @@ impl SimulationService for Server {
         }
 
         let mut state_tx = Arc::new(StateDelta::new(state));
-        let execution_circuit_breaker = ExecutionCircuitBreaker::default();
-        let swap_execution = state_tx
-            .route_and_fill(
-                input.asset_id,
-                output_id,
-                input.amount,
-                routing_params,
-                execution_circuit_breaker,
-            )
-            .await
+        let mut execution_budget = ExecutionCircuitBreaker::new(
+            routing_params.max_path_searches,
+            routing_params.max_executions,
+        );
+        // Explaining the routing records every path explored, so it is only done on request.
+        let simulation = if request.explain {
+            state_tx
+                .explain_route_and_fill(
+                    input.asset_id,
+                    output_id,
+                    input.amount,
+                    routing_params,
+                    &mut execution_budget,
+                )
+                .await
+                .map(|(swap_execution, explanation)| (swap_execution, Some(explanation)))
+        } else {
+            state_tx
+                .route_and_fill(
+                    input.asset_id,
+                    output_id,
+                    input.amount,
+                    routing_params,
+                    &mut execution_budget,
+                )
+                .await
+                .map(|swap_execution| (swap_execution, None))
+        };
+        let (swap_execution, explanation) = simulation
             .map_err(|e| tonic::Status::internal(format!("error simulating trade: {:#}", e)))?;
 
         let unfilled = Value {
@@ -664,6 +916,7 @@ impl SimulationService for Server {
         let rsp = tonic::Response::new(SimulateTradeResponse {
             unfilled: Some(unfilled.into()),
             output: Some(swap_execution.into()),
+            explanation: explanation.map(Into::into),
         });
 
         let duration = start_time.elapsed();
@@ -673,3 +926,20 @@ impl SimulationService for Server {
         Ok(rsp)
     }
 }
+
+/// The bucket of the current block, at the given rolled-up resolution.
+async fn current_candlestick_bucket<S: cnidarium::StateRead>(
+    state: &S,
+    resolution: CandlestickResolution,
+) -> Result<u64> {
+    use penumbra_sct::component::clock::EpochRead;
+
+    let height = state.get_block_height().await?;
+    let epoch_index = state.get_current_epoch().await?.index;
+    let timestamp = state
+        .get_block_timestamp()
+        .await?
+        .unix_timestamp()
+        .try_into()?;
+    Ok(resolution.bucket(height, timestamp, epoch_index))
+}
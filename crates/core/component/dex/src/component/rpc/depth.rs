@@ -0,0 +1,127 @@
+use anyhow::Result;
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_proto::core::component::dex::v1::MarketDepthLevel;
+
+/// Aggregates the positions of a pair, visited from the best price to the
+/// worst, into price levels.
+pub(super) struct DepthAggregator {
+    tick_size: Option<U128x128>,
+    depth_limit: usize,
+    levels: Vec<Level>,
+}
+
+struct Level {
+    price: U128x128,
+    liquidity: Amount,
+    position_count: u32,
+}
+
+impl DepthAggregator {
+    /// Creates an aggregator producing at most `depth_limit` levels.
+    ///
+    /// Without a `tick_size`, each distinct price is its own level.
+    pub(super) fn new(tick_size: Option<U128x128>, depth_limit: usize) -> Self {
+        Self {
+            tick_size,
+            depth_limit,
+            levels: Vec::new(),
+        }
+    }
+
+    /// Adds a position with the given effective price and liquidity.
+    ///
+    /// Returns `false` if the position is past the last level, in which case
+    /// no worse-priced positions need to be added.
+    pub(super) fn add(&mut self, price: U128x128, liquidity: Amount) -> Result<bool> {
+        // Round the price up to the next tick, so that every position in a
+        // level trades at the level's price or better.
+        let level_price = match self.tick_size {
+            Some(tick_size) => price
+                .checked_div(&tick_size)?
+                .round_up()?
+                .checked_mul(&tick_size)?,
+            None => price,
+        };
+
+        match self.levels.last_mut() {
+            Some(level) if level.price == level_price => {
+                level.liquidity += liquidity;
+                level.position_count += 1;
+            }
+            _ if self.levels.len() >= self.depth_limit => return Ok(false),
+            _ => self.levels.push(Level {
+                price: level_price,
+                liquidity,
+                position_count: 1,
+            }),
+        }
+
+        Ok(true)
+    }
+
+    pub(super) fn into_levels(self) -> Vec<MarketDepthLevel> {
+        let mut cumulative_liquidity = Amount::zero();
+        self.levels
+            .into_iter()
+            .map(|level| {
+                cumulative_liquidity += level.liquidity;
+                MarketDepthLevel {
+                    price: level.price.into(),
+                    liquidity: Some(level.liquidity.into()),
+                    cumulative_liquidity: Some(cumulative_liquidity.into()),
+                    position_count: level.position_count,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn amount(level: &MarketDepthLevel) -> (u64, u64) {
+        let liquidity: Amount = level.liquidity.clone().unwrap().try_into().unwrap();
+        let cumulative: Amount = level
+            .cumulative_liquidity
+            .clone()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        (liquidity.value() as u64, cumulative.value() as u64)
+    }
+
+    #[test]
+    fn buckets_prices_by_tick() -> Result<()> {
+        let mut depth = DepthAggregator::new(Some(U128x128::ratio(1u64, 2u64)?), 2);
+        assert!(depth.add(U128x128::ratio(3u64, 10u64)?, 10u64.into())?);
+        assert!(depth.add(U128x128::ratio(1u64, 2u64)?, 20u64.into())?);
+        assert!(depth.add(U128x128::ratio(6u64, 10u64)?, 30u64.into())?);
+        // A third level is past the depth limit.
+        assert!(!depth.add(U128x128::ratio(11u64, 10u64)?, 40u64.into())?);
+
+        let levels = depth.into_levels();
+        assert_eq!(levels.len(), 2);
+        assert_eq!(levels[0].price, 0.5);
+        assert_eq!(levels[0].position_count, 2);
+        assert_eq!(amount(&levels[0]), (30, 30));
+        assert_eq!(levels[1].price, 1.0);
+        assert_eq!(levels[1].position_count, 1);
+        assert_eq!(amount(&levels[1]), (30, 60));
+        Ok(())
+    }
+
+    #[test]
+    fn distinct_prices_without_tick() -> Result<()> {
+        let mut depth = DepthAggregator::new(None, 10);
+        depth.add(1u64.into(), 5u64.into())?;
+        depth.add(1u64.into(), 5u64.into())?;
+        depth.add(U128x128::ratio(101u64, 100u64)?, 7u64.into())?;
+
+        let levels = depth.into_levels();
+        assert_eq!(levels.len(), 2);
+        assert_eq!(amount(&levels[0]), (10, 10));
+        assert_eq!(amount(&levels[1]), (7, 17));
+        Ok(())
+    }
+}
@@ -5,7 +5,9 @@ use async_trait::async_trait;
 use cnidarium::{ArcStateDeltaExt, StateDelta, TempStorage};
 use futures::StreamExt;
 use penumbra_asset::{asset, Value};
-use penumbra_num::Amount;
+use penumbra_community_pool::component::StateReadExt as _;
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_proto::StateReadProto as _;
 use rand_core::OsRng;
 
 use crate::component::{SwapDataRead, SwapDataWrite};
@@ -16,10 +18,12 @@ use crate::{
     component::{
         router::FillRoute,
         router::{create_buy, create_sell, HandleBatchSwaps, RoutingParams},
-        Arbitrage, PositionManager, PositionRead, StateReadExt, StateWriteExt,
+        Arbitrage, ExecutionCircuitBreaker, InternalDexWrite, PositionManager, PositionRead,
+        RewardManager, RewardRead, StateReadExt, StateWriteExt,
     },
     lp::{position::Position, Reserves},
-    BatchSwapOutputData, DirectedTradingPair, DirectedUnitPair,
+    state_key, ArbSurplusDestination, ArbToken, BatchSwapOutputData, DirectedTradingPair,
+    DirectedUnitPair, PriceAccumulator, TradingPair, PRICE_HISTORY_RETENTION,
 };
 
 #[async_trait]
@@ -523,6 +527,218 @@ async fn empty_order_fails() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+/// Fills three positions, rewards them in proportion to their volume up to the
+/// fees they earned, and claims the rewards of one of them once it is withdrawn.
+async fn liquidity_rewards_follow_volume() -> anyhow::Result<()> {
+    use crate::component::ValueCircuitBreaker as _;
+    use futures::TryStreamExt as _;
+    use penumbra_sct::component::clock::EpochRead as _;
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let pair = TradingPair::new(gm.id(), penumbra.id());
+
+    let position_1 = SellOrder::parse_str("150gm@1penumbra/100bps")?.into_position(OsRng);
+    let position_2 = SellOrder::parse_str("50gm@1penumbra/100bps")?.into_position(OsRng);
+    // A position without a fee earns no rewards, however much it trades.
+    let position_3 = SellOrder::parse_str("50gm@1penumbra")?.into_position(OsRng);
+    state_tx.open_position(position_1.clone()).await?;
+    state_tx.open_position(position_2.clone()).await?;
+    state_tx.open_position(position_3.clone()).await?;
+
+    // Exhaust all three positions. The DEX is credited with the input, as it
+    // would be by the swap.
+    let input = "300penumbra".parse::<Value>().unwrap();
+    state_tx.dex_vcb_credit(input).await?;
+    FillRoute::fill_route(&mut state_tx, input, &[gm.id()], None).await?;
+
+    // The volume is measured in the staking token, fees included.
+    let volumes: Vec<_> = state_tx.position_volumes(0, &pair).try_collect().await?;
+    assert_eq!(volumes.len(), 3);
+    let volume_of = |id| {
+        volumes
+            .iter()
+            .find(|(position_id, _)| *position_id == id)
+            .map(|(_, volume)| *volume)
+            .unwrap()
+    };
+    let volume_1 = volume_of(position_1.id());
+    let volume_2 = volume_of(position_2.id());
+    let volume_3 = volume_of(position_3.id());
+    assert_eq!(volume_3, 50_000_000u64.into());
+    assert!(volume_1 > volume_2 && volume_2 > volume_3);
+
+    // The positions held their reserves of the staking token for the whole
+    // epoch, one block long, so their volume isn't capped by their liquidity.
+    // Rewards below the fees earned are split in proportion to the volume,
+    // but the position without a fee gets nothing.
+    let epoch = state_tx.get_current_epoch().await?;
+    let rewards = Amount::from(4_000u64);
+    let total_volume = volume_1 + volume_2 + volume_3;
+    let reward_1 = U128x128::ratio(volume_1, total_volume)?.apply_to_amount(&rewards)?;
+    let reward_2 = U128x128::ratio(volume_2, total_volume)?.apply_to_amount(&rewards)?;
+    let distributed = state_tx
+        .reward_liquidity_providers(&epoch, &pair, rewards)
+        .await?;
+    assert_eq!(distributed, reward_1 + reward_2);
+    assert_eq!(state_tx.position_rewards(&position_1.id()).await?, reward_1);
+    assert_eq!(state_tx.position_rewards(&position_2.id()).await?, reward_2);
+    assert_eq!(
+        state_tx.position_rewards(&position_3.id()).await?,
+        Amount::zero()
+    );
+
+    // Rewards above the fees earned are capped by them.
+    let fees_1 = Amount::from(volume_1.value() * 100 / 10_000);
+    let fees_2 = Amount::from(volume_2.value() * 100 / 10_000);
+    let distributed = state_tx
+        .reward_liquidity_providers(&epoch, &pair, 1_000_000_000_000u64.into())
+        .await?;
+    assert_eq!(distributed, fees_1 + fees_2);
+    assert_eq!(
+        state_tx.position_rewards(&position_1.id()).await?,
+        reward_1 + fees_1
+    );
+
+    // Rewards can only be claimed once the position is withdrawn.
+    assert!(state_tx
+        .claim_position_rewards(position_1.id(), 1)
+        .await
+        .is_err());
+    state_tx.close_position_by_id(&position_1.id()).await?;
+    state_tx.withdraw_position(position_1.id(), 0).await?;
+    let claimed = state_tx.claim_position_rewards(position_1.id(), 1).await?;
+    assert_eq!(claimed, reward_1 + fees_1);
+    assert_eq!(
+        state_tx.position_rewards(&position_1.id()).await?,
+        Amount::zero()
+    );
+    assert_eq!(
+        state_tx
+            .position_by_id(&position_1.id())
+            .await?
+            .unwrap()
+            .state,
+        position::State::Withdrawn { sequence: 1 }
+    );
+
+    // The rewards cannot be claimed twice.
+    assert!(state_tx
+        .claim_position_rewards(position_1.id(), 2)
+        .await
+        .is_err());
+
+    // Once the epoch is over, its volumes and liquidity are pruned.
+    state_tx.prune_reward_tracking(1).await?;
+    let volumes: Vec<_> = state_tx.position_volumes(0, &pair).try_collect().await?;
+    assert!(volumes.is_empty());
+    assert_eq!(
+        state_tx
+            .position_liquidity(0, &pair, &position_2.id())
+            .await?,
+        Amount::zero()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Checks that volume executed against a position is only rewarded up to the
+/// liquidity the position provided over the epoch, so that a position opened
+/// just to trade against it earns nothing.
+async fn liquidity_rewards_are_capped_by_liquidity() -> anyhow::Result<()> {
+    use crate::component::ValueCircuitBreaker as _;
+    use penumbra_sct::component::clock::{EpochManager as _, EpochRead as _};
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let pair = TradingPair::new(gm.id(), penumbra.id());
+
+    // One position is open for the whole epoch, the other only at its end.
+    let early = SellOrder::parse_str("100penumbra@100gm/100bps")?.into_position(OsRng);
+    let late = SellOrder::parse_str("100penumbra@100gm/100bps")?.into_position(OsRng);
+    state_tx.open_position(early.clone()).await?;
+    let epoch = state_tx.get_current_epoch().await?;
+    state_tx.put_block_height(99);
+    state_tx.put_epoch_by_height(99, epoch.clone());
+    state_tx.open_position(late.clone()).await?;
+
+    // Both positions are exhausted, with the same volume.
+    let input = "300gm".parse::<Value>().unwrap();
+    state_tx.dex_vcb_credit(input).await?;
+    FillRoute::fill_route(&mut state_tx, input, &[penumbra.id()], None).await?;
+    assert_eq!(
+        state_tx
+            .position_volumes(0, &pair)
+            .collect::<Vec<_>>()
+            .await
+            .len(),
+        2
+    );
+
+    // The early position held its reserves for 99 of the epoch's 100 blocks,
+    // while the late one held them for none of them.
+    assert_eq!(
+        state_tx.position_liquidity(0, &pair, &early.id()).await?,
+        Amount::from(99u64) * penumbra.unit_amount() * Amount::from(100u64)
+    );
+    let distributed = state_tx
+        .reward_liquidity_providers(&epoch, &pair, 4_000u64.into())
+        .await?;
+    assert_eq!(state_tx.position_rewards(&early.id()).await?, distributed);
+    assert!(distributed > Amount::zero());
+    assert_eq!(state_tx.position_rewards(&late.id()).await?, Amount::zero());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Checks that executions on pairs without the staking token are not tracked
+/// for liquidity rewards.
+async fn liquidity_rewards_require_staking_token() -> anyhow::Result<()> {
+    use crate::component::ValueCircuitBreaker as _;
+    use futures::TryStreamExt as _;
+    use penumbra_sct::component::clock::EpochRead as _;
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let pair = TradingPair::new(gm.id(), gn.id());
+
+    let position = SellOrder::parse_str("50gm@1gn/100bps")?.into_position(OsRng);
+    state_tx.open_position(position).await?;
+
+    let input = "100gn".parse::<Value>().unwrap();
+    state_tx.dex_vcb_credit(input).await?;
+    FillRoute::fill_route(&mut state_tx, input, &[gm.id()], None).await?;
+
+    let volumes: Vec<_> = state_tx.position_volumes(0, &pair).try_collect().await?;
+    assert!(volumes.is_empty());
+    let epoch = state_tx.get_current_epoch().await?;
+    let distributed = state_tx
+        .reward_liquidity_providers(&epoch, &pair, 4_000u64.into())
+        .await?;
+    assert_eq!(distributed, Amount::zero());
+
+    Ok(())
+}
+
 #[tokio::test]
 /// Test that positions are created and returned as expected.
 async fn position_create_and_retrieve() -> anyhow::Result<()> {
@@ -633,7 +849,13 @@ async fn swap_execution_tests() -> anyhow::Result<()> {
         .unwrap();
     let routing_params = state.routing_params().await.unwrap();
     state
-        .handle_batch_swaps(trading_pair, swap_flow, 0, routing_params)
+        .handle_batch_swaps(
+            trading_pair,
+            swap_flow,
+            0,
+            routing_params,
+            &mut ExecutionCircuitBreaker::block_budget(&DexParameters::default()),
+        )
         .await
         .expect("unable to process batch swaps");
 
@@ -741,7 +963,13 @@ async fn swap_execution_tests() -> anyhow::Result<()> {
         .unwrap();
     let routing_params = state.routing_params().await.unwrap();
     state
-        .handle_batch_swaps(trading_pair, swap_flow, 0u32.into(), routing_params)
+        .handle_batch_swaps(
+            trading_pair,
+            swap_flow,
+            0u32.into(),
+            routing_params,
+            &mut ExecutionCircuitBreaker::block_budget(&DexParameters::default()),
+        )
         .await
         .expect("unable to process batch swaps");
 
@@ -845,10 +1073,21 @@ async fn basic_cycle_arb() -> anyhow::Result<()> {
         max_hops: 4 + 2,
         price_limit: Some(1u64.into()),
         fixed_candidates: Arc::new(vec![penumbra.id(), gm.id(), gn.id()]),
+        max_path_searches: 64,
+        max_executions: 64,
     };
-    state.arbitrage(penumbra.id(), routing_params).await?;
+    state
+        .arbitrage(
+            ArbToken::default(),
+            routing_params,
+            &mut ExecutionCircuitBreaker::default(),
+        )
+        .await?;
 
-    let arb_execution = state.arb_execution(0).await?.expect("arb was performed");
+    let arb_execution = state
+        .arb_execution(0, penumbra.id())
+        .await?
+        .expect("arb was performed");
     assert_eq!(
         arb_execution.traces,
         vec![vec![
@@ -862,6 +1101,221 @@ async fn basic_cycle_arb() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Opens positions forming a cycle `arb => via_1 => via_2 => arb`, which can be
+/// arbitraged from 10 `arb` into 20 `arb`, like in [`basic_cycle_arb`].
+async fn create_arb_cycle(
+    state: &mut Arc<StateDelta<cnidarium::Snapshot>>,
+    arb: &asset::Unit,
+    via_1: &asset::Unit,
+    via_2: &asset::Unit,
+) {
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    // Sell 10 `via_1` at 1 `arb` each.
+    state_tx
+        .open_position(create_sell(
+            DirectedUnitPair::new(via_1.clone(), arb.clone()),
+            10u64.into(),
+            1u64.into(),
+        ))
+        .await
+        .unwrap();
+    // Buy 100 `via_1` at 2 `via_2` each.
+    state_tx
+        .open_position(create_buy(
+            DirectedUnitPair::new(via_1.clone(), via_2.clone()),
+            100u64.into(),
+            2u64.into(),
+        ))
+        .await
+        .unwrap();
+    // Sell 100 `arb` at 1 `via_2` each.
+    state_tx
+        .open_position(create_sell(
+            DirectedUnitPair::new(arb.clone(), via_2.clone()),
+            100u64.into(),
+            1u64.into(),
+        ))
+        .await
+        .unwrap();
+    state_tx.apply();
+}
+
+/// Ends block 0, arbitraging against `arb_tokens` with a budget of `max_path_searches`.
+async fn end_block_with_arb_tokens(
+    state: &mut Arc<StateDelta<cnidarium::Snapshot>>,
+    arb_tokens: Vec<ArbToken>,
+    max_path_searches: u32,
+) {
+    use cnidarium_component::Component as _;
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_dex_params(DexParameters {
+        arb_tokens,
+        max_path_searches,
+        ..DexParameters::default()
+    });
+    state_tx.apply();
+
+    let end_block = tendermint::abci::request::EndBlock { height: 0 };
+    crate::component::Dex::end_block(state, &end_block).await;
+}
+
+fn burned_arb_token(asset: &asset::Unit) -> ArbToken {
+    ArbToken {
+        asset_id: asset.id(),
+        surplus_destination: ArbSurplusDestination::Burn,
+    }
+}
+
+#[tokio::test]
+/// Test that the surplus of an arb token can be sent to the community pool.
+async fn basic_cycle_arb_to_community_pool() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    create_arb_cycle(&mut state, &penumbra, &gn, &gm).await;
+
+    let routing_params = RoutingParams {
+        max_hops: 4 + 2,
+        price_limit: Some(1u64.into()),
+        fixed_candidates: Arc::new(vec![penumbra.id(), gm.id(), gn.id()]),
+        max_path_searches: 64,
+        max_executions: 64,
+    };
+    let arb_token = ArbToken {
+        asset_id: penumbra.id(),
+        surplus_destination: ArbSurplusDestination::CommunityPool,
+    };
+    state
+        .arbitrage(
+            arb_token,
+            routing_params,
+            &mut ExecutionCircuitBreaker::default(),
+        )
+        .await?;
+
+    // The 10penumbra surplus went to the community pool.
+    assert_eq!(
+        state.community_pool_asset_balance(penumbra.id()).await?,
+        penumbra.value(10u32.into()).amount
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that arb tokens are searched in order, so that the first arb token to
+/// reach a cycle takes its surplus.
+async fn arb_tokens_are_searched_in_order() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+
+    // Both penumbra and gm can go around the same cycle.
+    for (first, second) in [(&penumbra, &gm), (&gm, &penumbra)] {
+        let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+        let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+        create_arb_cycle(&mut state, &penumbra, &gn, &gm).await;
+
+        let arb_tokens = vec![burned_arb_token(first), burned_arb_token(second)];
+        end_block_with_arb_tokens(&mut state, arb_tokens, 64).await;
+
+        assert!(
+            state.arb_execution(0, first.id()).await?.is_some(),
+            "the first arb token takes the surplus"
+        );
+        assert!(
+            state.arb_execution(0, second.id()).await?.is_none(),
+            "no surplus is left for the second arb token"
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that the arb executions of several arb tokens are recorded separately.
+async fn arb_executions_are_recorded_per_token() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let cache = asset::Cache::with_known_assets();
+    let penumbra = cache.get_unit("penumbra").unwrap();
+    let gm = cache.get_unit("gm").unwrap();
+    let gn = cache.get_unit("gn").unwrap();
+    let test_usd = cache.get_unit("test_usd").unwrap();
+    let test_btc = cache.get_unit("test_btc").unwrap();
+    let test_atom = cache.get_unit("test_atom").unwrap();
+
+    // Two disjoint cycles, one for each arb token.
+    create_arb_cycle(&mut state, &penumbra, &gn, &gm).await;
+    create_arb_cycle(&mut state, &test_usd, &test_btc, &test_atom).await;
+
+    let arb_tokens = vec![burned_arb_token(&penumbra), burned_arb_token(&test_usd)];
+    end_block_with_arb_tokens(&mut state, arb_tokens, 64).await;
+
+    let penumbra_arb = state
+        .arb_execution(0, penumbra.id())
+        .await?
+        .expect("penumbra was arbitraged");
+    assert_eq!(penumbra_arb.input, penumbra.value(10u32.into()));
+    assert_eq!(penumbra_arb.output, penumbra.value(20u32.into()));
+
+    let test_usd_arb = state
+        .arb_execution(0, test_usd.id())
+        .await?
+        .expect("test_usd was arbitraged");
+    assert_eq!(test_usd_arb.input, test_usd.value(10u32.into()));
+    assert_eq!(test_usd_arb.output, test_usd.value(20u32.into()));
+
+    // Nothing was recorded for the assets that weren't arb tokens.
+    assert!(state.arb_execution(0, gm.id()).await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that the arb tokens share a single execution budget.
+async fn arb_tokens_share_execution_budget() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let cache = asset::Cache::with_known_assets();
+    let penumbra = cache.get_unit("penumbra").unwrap();
+    let gm = cache.get_unit("gm").unwrap();
+    let gn = cache.get_unit("gn").unwrap();
+    let test_usd = cache.get_unit("test_usd").unwrap();
+    let test_btc = cache.get_unit("test_btc").unwrap();
+    let test_atom = cache.get_unit("test_atom").unwrap();
+
+    create_arb_cycle(&mut state, &penumbra, &gn, &gm).await;
+    create_arb_cycle(&mut state, &test_usd, &test_btc, &test_atom).await;
+
+    // The budget is only exceeded once it's used past its maximum, so this
+    // allows a single path search, which the first arb token uses up.
+    let arb_tokens = vec![burned_arb_token(&penumbra), burned_arb_token(&test_usd)];
+    end_block_with_arb_tokens(&mut state, arb_tokens, 0).await;
+
+    assert!(state.arb_execution(0, penumbra.id()).await?.is_some());
+    assert!(
+        state.arb_execution(0, test_usd.id()).await?.is_none(),
+        "the second arb token has no budget left"
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 /// Reproduce the arbitrage loop bug that caused testnet 53 to stall.
 /// The issue was that we did not treat the spill price as a strict
@@ -944,11 +1398,17 @@ async fn reproduce_arbitrage_loop_testnet_53() -> anyhow::Result<()> {
         max_hops: 4 + 2,
         price_limit: Some(1u64.into()),
         fixed_candidates: Arc::new(vec![penumbra.id(), test_usd.id()]),
+        max_path_searches: 64,
+        max_executions: 64,
     };
 
     let arb_profit = tokio::time::timeout(
         tokio::time::Duration::from_secs(2),
-        state.arbitrage(penumbra.id(), routing_params),
+        state.arbitrage(
+            ArbToken::default(),
+            routing_params,
+            &mut ExecutionCircuitBreaker::default(),
+        ),
     )
     .await??;
 
@@ -958,7 +1418,10 @@ async fn reproduce_arbitrage_loop_testnet_53() -> anyhow::Result<()> {
     assert_eq!(arb_profit, Some(profit));
 
     tracing::info!("fetching the `ArbExecution`");
-    let arb_execution = state.arb_execution(0).await?.expect("arb was performed");
+    let arb_execution = state
+        .arb_execution(0, penumbra.id())
+        .await?
+        .expect("arb was performed");
     tracing::info!(?arb_execution, "fetched arb execution!");
 
     // Validate that the arb execution has the correct data:
@@ -1144,3 +1607,67 @@ async fn check_routable_asset_ordering() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+/// Test that the price accumulator history is pruned to the retention window,
+/// keeping the last update before it so that prices in the window can still
+/// be computed.
+async fn price_accumulator_history_is_bounded() -> anyhow::Result<()> {
+    let mut state = StateDelta::new(());
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let pair = DirectedTradingPair::new(gm.id(), gn.id());
+
+    let start = 10;
+    let end = start + 2 * PRICE_HISTORY_RETENTION;
+    for height in [start, start + 1, end - PRICE_HISTORY_RETENTION - 1, end] {
+        state
+            .update_price_accumulator(&pair, height, height.into())
+            .await;
+    }
+
+    // Only the last update before the window, and the one in it, are kept.
+    let history: Vec<u64> = state
+        .prefix::<PriceAccumulator>(&state_key::price_accumulators::by_pair(&pair))
+        .map(|entry| entry.map(|(_, accumulator)| accumulator.height))
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<anyhow::Result<_>>()?;
+    assert_eq!(history, vec![end - PRICE_HISTORY_RETENTION - 1, end]);
+
+    let window_start = end - PRICE_HISTORY_RETENTION;
+    let twap = state
+        .time_weighted_average_price(&pair, window_start, window_start)
+        .await?;
+    assert_eq!(twap, U128x128::from(window_start - 1));
+    assert!(state
+        .time_weighted_average_price(&pair, start + 1, end)
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that a price that would overflow the accumulator is skipped, rather
+/// than failing the block.
+async fn price_accumulator_skips_overflowing_samples() -> anyhow::Result<()> {
+    let mut state = StateDelta::new(());
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let pair = DirectedTradingPair::new(gm.id(), gn.id());
+
+    let huge = U128x128::from(u128::MAX);
+    state.update_price_accumulator(&pair, 1, huge).await;
+    state.update_price_accumulator(&pair, 3, 1u64.into()).await;
+
+    let latest = state
+        .latest_price_accumulator(&pair)
+        .await?
+        .expect("the first sample was recorded");
+    assert_eq!(latest.height, 1);
+    assert_eq!(latest.last_price, huge);
+
+    Ok(())
+}
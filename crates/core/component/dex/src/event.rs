@@ -76,6 +76,18 @@ pub fn position_withdraw(
     }
 }
 
+pub fn position_reward_claim(
+    position_id: position::Id,
+    sequence: u64,
+    rewards: Amount,
+) -> pb::EventPositionRewardClaim {
+    pb::EventPositionRewardClaim {
+        position_id: Some(position_id.into()),
+        sequence,
+        rewards: Some(rewards.into()),
+    }
+}
+
 pub fn position_execution(
     prev_state: &Position,
     new_state: &Position,
@@ -10,12 +10,14 @@ pub mod state_key;
 mod batch_swap_output_data;
 mod candlestick;
 mod params;
+mod price_accumulator;
 mod swap_execution;
 mod trading_pair;
 
 pub use batch_swap_output_data::BatchSwapOutputData;
-pub use candlestick::CandlestickData;
-pub use params::DexParameters;
+pub use candlestick::{CandlestickData, CandlestickResolution};
+pub use params::{ArbSurplusDestination, ArbToken, DexParameters};
+pub use price_accumulator::{PriceAccumulator, PRICE_HISTORY_RETENTION};
 pub use swap_execution::SwapExecution;
 pub use trading_pair::{DirectedTradingPair, DirectedUnitPair, TradingPair, TradingPairVar};
 
@@ -23,6 +25,6 @@ pub mod lp;
 pub mod swap;
 pub mod swap_claim;
 
-pub use lp::action::{PositionClose, PositionOpen, PositionWithdraw};
+pub use lp::action::{PositionClose, PositionOpen, PositionRewardClaim, PositionWithdraw};
 pub use swap::Swap;
 pub use swap_claim::SwapClaim;
@@ -1,3 +1,4 @@
+mod execution;
 mod nft;
 mod order;
 mod reserves;
@@ -7,6 +8,7 @@ pub mod action;
 pub mod plan;
 pub mod position;
 
+pub use execution::PositionExecution;
 pub use nft::LpNft;
 pub use order::{BuyOrder, SellOrder};
 pub use reserves::Reserves;
@@ -105,6 +105,29 @@ impl EffectingData for PositionWithdraw {
     }
 }
 
+/// A transaction action that claims the liquidity rewards accrued by a withdrawn position.
+///
+/// This action's contribution to the transaction's value balance is to consume a
+/// withdrawn position NFT and contribute a withdrawn position NFT with the next
+/// sequence number, as well as the rewards accrued by the position.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::PositionRewardClaim", into = "pb::PositionRewardClaim")]
+pub struct PositionRewardClaim {
+    pub position_id: position::Id,
+    /// A transparent (zero blinding factor) commitment to the rewards accrued by the position.
+    ///
+    /// The chain will check this commitment by recomputing it with the on-chain state.
+    pub rewards_commitment: balance::Commitment,
+    /// The sequence number of the withdrawn position NFT produced by the claim.
+    pub sequence: u64,
+}
+
+impl EffectingData for PositionRewardClaim {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
 impl DomainType for PositionOpen {
     type Proto = pb::PositionOpen;
 }
@@ -186,3 +209,35 @@ impl TryFrom<pb::PositionWithdraw> for PositionWithdraw {
         })
     }
 }
+
+impl DomainType for PositionRewardClaim {
+    type Proto = pb::PositionRewardClaim;
+}
+
+impl From<PositionRewardClaim> for pb::PositionRewardClaim {
+    fn from(value: PositionRewardClaim) -> Self {
+        Self {
+            position_id: Some(value.position_id.into()),
+            rewards_commitment: Some(value.rewards_commitment.into()),
+            sequence: value.sequence,
+        }
+    }
+}
+
+impl TryFrom<pb::PositionRewardClaim> for PositionRewardClaim {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::PositionRewardClaim) -> Result<Self, Self::Error> {
+        Ok(Self {
+            position_id: value
+                .position_id
+                .ok_or_else(|| anyhow::anyhow!("missing position_id"))?
+                .try_into()?,
+            rewards_commitment: value
+                .rewards_commitment
+                .ok_or_else(|| anyhow::anyhow!("missing rewards_commitment"))?
+                .try_into()?,
+            sequence: value.sequence,
+        })
+    }
+}
@@ -0,0 +1,181 @@
+use anyhow::{anyhow, Context, Result};
+use penumbra_asset::Value;
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
+
+use crate::DirectedTradingPair;
+
+use super::{position::Position, Reserves};
+
+/// A record of a single execution against a liquidity position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositionExecution {
+    /// The height at which the execution occurred.
+    pub height: u64,
+    /// The reserves of the position before the execution.
+    pub prev_reserves: Reserves,
+    /// The reserves of the position after the execution.
+    pub reserves: Reserves,
+    /// The direction of the trade: the position received `start`, and gave out `end`.
+    pub direction: DirectedTradingPair,
+    /// The fee earned by the position, in the `start` asset.
+    pub fee: Amount,
+}
+
+impl PositionExecution {
+    /// Describes the execution that took a position from `prev_state` to `new_state`.
+    pub fn new(height: u64, prev_state: &Position, new_state: &Position) -> Result<Self> {
+        // The position received the asset whose reserves increased.
+        let pair = prev_state.phi.pair;
+        let direction = if new_state.reserves.r1 > prev_state.reserves.r1 {
+            DirectedTradingPair::new(pair.asset_1(), pair.asset_2())
+        } else {
+            DirectedTradingPair::new(pair.asset_2(), pair.asset_1())
+        };
+
+        let input = new_state
+            .reserves_for(direction.start)
+            .context("missing reserves")?
+            .checked_sub(
+                &prev_state
+                    .reserves_for(direction.start)
+                    .context("missing reserves")?,
+            )
+            .ok_or_else(|| anyhow!("position execution did not increase input reserves"))?;
+
+        // The position keeps `fee / 10_000` of its input, and trades the rest.
+        let fee = Amount::from(input.value() * u128::from(prev_state.phi.component.fee) / 10_000);
+
+        Ok(Self {
+            height,
+            prev_reserves: prev_state.reserves.clone(),
+            reserves: new_state.reserves.clone(),
+            direction,
+            fee,
+        })
+    }
+
+    /// The reserves before and after the execution, oriented so that `r1` is
+    /// the input asset and `r2` the output asset.
+    fn oriented_reserves(&self) -> (Reserves, Reserves) {
+        if self.direction.start == self.direction.to_canonical().asset_1() {
+            (self.prev_reserves.clone(), self.reserves.clone())
+        } else {
+            (self.prev_reserves.flip(), self.reserves.flip())
+        }
+    }
+
+    /// The amount the position received.
+    pub fn input(&self) -> Value {
+        let (prev, new) = self.oriented_reserves();
+        Value {
+            amount: new.r1.saturating_sub(&prev.r1),
+            asset_id: self.direction.start,
+        }
+    }
+
+    /// The amount the position gave out.
+    pub fn output(&self) -> Value {
+        let (prev, new) = self.oriented_reserves();
+        Value {
+            amount: prev.r2.saturating_sub(&new.r2),
+            asset_id: self.direction.end,
+        }
+    }
+}
+
+impl DomainType for PositionExecution {
+    type Proto = pb::PositionExecution;
+}
+
+impl From<PositionExecution> for pb::PositionExecution {
+    fn from(execution: PositionExecution) -> Self {
+        Self {
+            height: execution.height,
+            prev_reserves: Some(execution.prev_reserves.into()),
+            reserves: Some(execution.reserves.into()),
+            direction: Some(execution.direction.into()),
+            fee: Some(execution.fee.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::PositionExecution> for PositionExecution {
+    type Error = anyhow::Error;
+
+    fn try_from(execution: pb::PositionExecution) -> Result<Self, Self::Error> {
+        Ok(Self {
+            height: execution.height,
+            prev_reserves: execution
+                .prev_reserves
+                .ok_or_else(|| anyhow!("missing prev_reserves"))?
+                .try_into()?,
+            reserves: execution
+                .reserves
+                .ok_or_else(|| anyhow!("missing reserves"))?
+                .try_into()?,
+            direction: execution
+                .direction
+                .ok_or_else(|| anyhow!("missing direction"))?
+                .try_into()?,
+            fee: execution
+                .fee
+                .ok_or_else(|| anyhow!("missing fee"))?
+                .try_into()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::Zero;
+    use decaf377::Fq;
+    use penumbra_asset::asset;
+    use rand_core::OsRng;
+
+    #[test]
+    fn execution_of_fill() {
+        let a = asset::Id(Fq::zero());
+        let b = asset::Id(Fq::from(1u64));
+
+        // A position selling 1000 B for A at a price of 1, with a 1% fee.
+        let prev_state = Position::new(
+            OsRng,
+            DirectedTradingPair::new(a, b),
+            100u32,
+            1u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: 0u64.into(),
+                r2: 1000u64.into(),
+            },
+        );
+
+        let input = Value {
+            amount: 100u64.into(),
+            asset_id: a,
+        };
+        let (unfilled, new_reserves, output) =
+            prev_state.phi.fill(input, &prev_state.reserves).unwrap();
+        assert_eq!(unfilled.amount, Amount::zero());
+        let mut new_state = prev_state.clone();
+        new_state.reserves = new_reserves;
+
+        let execution = PositionExecution::new(7, &prev_state, &new_state).unwrap();
+        assert_eq!(execution.height, 7);
+        assert_eq!(execution.direction, DirectedTradingPair::new(a, b));
+        assert_eq!(execution.input(), input);
+        assert_eq!(execution.output(), output);
+        assert_eq!(execution.fee, 1u64.into());
+
+        // Undoing the trade is an execution in the other direction.
+        let reverse = PositionExecution::new(8, &new_state, &prev_state).unwrap();
+        assert_eq!(reverse.direction, DirectedTradingPair::new(b, a));
+        assert_eq!(reverse.input(), output);
+        assert_eq!(reverse.output(), input);
+
+        let roundtrip = PositionExecution::decode(execution.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(roundtrip, execution);
+    }
+}
@@ -1,6 +1,7 @@
 use ark_ff::Zero;
 use decaf377::Fr;
-use penumbra_asset::{balance, Balance, Value};
+use penumbra_asset::{balance, Balance, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_num::Amount;
 use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
 use serde::{Deserialize, Serialize};
 
@@ -9,7 +10,7 @@ use crate::{
     TradingPair,
 };
 
-use super::action::PositionWithdraw;
+use super::action::{PositionRewardClaim, PositionWithdraw};
 
 /// A planned [`PositionWithdraw`](PositionWithdraw).
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -124,3 +125,99 @@ impl TryFrom<pb::PositionWithdrawPlan> for PositionWithdrawPlan {
         })
     }
 }
+
+/// A planned [`PositionRewardClaim`](PositionRewardClaim).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(
+    try_from = "pb::PositionRewardClaimPlan",
+    into = "pb::PositionRewardClaimPlan"
+)]
+pub struct PositionRewardClaimPlan {
+    pub position_id: position::Id,
+    pub sequence: u64,
+    /// The rewards accrued by the position, in the staking token.
+    pub rewards: Amount,
+}
+
+impl PositionRewardClaimPlan {
+    /// Convenience method to construct the [`PositionRewardClaim`] described by this [`PositionRewardClaimPlan`].
+    pub fn position_reward_claim(&self) -> PositionRewardClaim {
+        PositionRewardClaim {
+            position_id: self.position_id,
+            rewards_commitment: self.rewards_commitment(),
+            sequence: self.sequence,
+        }
+    }
+
+    fn rewards(&self) -> Value {
+        Value {
+            amount: self.rewards,
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+    }
+
+    pub fn rewards_commitment(&self) -> balance::Commitment {
+        self.rewards().commit(Fr::zero())
+    }
+
+    pub fn balance(&self) -> Balance {
+        // A claim consumes the withdrawn position NFT of the previous sequence
+        // number, and produces the rewards and a withdrawn position NFT of the
+        // next sequence number. A claim can never follow the closed state, so
+        // the sequence number is at least 1.
+        let mut balance = Balance::from(self.rewards());
+        balance -= Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(
+                self.position_id,
+                position::State::Withdrawn {
+                    sequence: self.sequence.saturating_sub(1),
+                },
+            )
+            .asset_id(),
+        };
+        balance += Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(
+                self.position_id,
+                position::State::Withdrawn {
+                    sequence: self.sequence,
+                },
+            )
+            .asset_id(),
+        };
+
+        balance
+    }
+}
+
+impl DomainType for PositionRewardClaimPlan {
+    type Proto = pb::PositionRewardClaimPlan;
+}
+
+impl From<PositionRewardClaimPlan> for pb::PositionRewardClaimPlan {
+    fn from(msg: PositionRewardClaimPlan) -> Self {
+        Self {
+            position_id: Some(msg.position_id.into()),
+            sequence: msg.sequence,
+            rewards: Some(msg.rewards.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::PositionRewardClaimPlan> for PositionRewardClaimPlan {
+    type Error = anyhow::Error;
+    fn try_from(msg: pb::PositionRewardClaimPlan) -> Result<Self, Self::Error> {
+        Ok(Self {
+            position_id: msg
+                .position_id
+                .ok_or_else(|| anyhow::anyhow!("missing position_id"))?
+                .try_into()?,
+            sequence: msg.sequence,
+            rewards: msg
+                .rewards
+                .ok_or_else(|| anyhow::anyhow!("missing rewards"))?
+                .try_into()?,
+        })
+    }
+}
@@ -11,6 +11,26 @@ pub struct DexParameters {
     pub fixed_candidates: Vec<asset::Id>,
     pub max_hops: u32,
     pub max_positions_per_pair: u32,
+    pub arb_tokens: Vec<ArbToken>,
+    pub max_path_searches: u32,
+    pub max_executions: u32,
+    pub max_block_path_searches: u32,
+    pub max_block_executions: u32,
+    pub arb_extra_hops: u32,
+}
+
+impl DexParameters {
+    /// The tokens to arbitrage against, in order.
+    ///
+    /// Falls back to the staking token, with its surplus burned, if no arb
+    /// tokens are configured.
+    pub fn arb_tokens(&self) -> Vec<ArbToken> {
+        if self.arb_tokens.is_empty() {
+            vec![ArbToken::default()]
+        } else {
+            self.arb_tokens.clone()
+        }
+    }
 }
 
 impl DomainType for DexParameters {
@@ -30,6 +50,16 @@ impl TryFrom<pb::DexParameters> for DexParameters {
                 .collect::<Result<_, _>>()?,
             max_hops: msg.max_hops,
             max_positions_per_pair: msg.max_positions_per_pair,
+            arb_tokens: msg
+                .arb_tokens
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            max_path_searches: msg.max_path_searches,
+            max_executions: msg.max_executions,
+            max_block_path_searches: msg.max_block_path_searches,
+            max_block_executions: msg.max_block_executions,
+            arb_extra_hops: msg.arb_extra_hops,
         })
     }
 }
@@ -45,6 +75,12 @@ impl From<DexParameters> for pb::DexParameters {
                 .collect(),
             max_hops: params.max_hops,
             max_positions_per_pair: params.max_positions_per_pair,
+            arb_tokens: params.arb_tokens.into_iter().map(Into::into).collect(),
+            max_path_searches: params.max_path_searches,
+            max_executions: params.max_executions,
+            max_block_path_searches: params.max_block_path_searches,
+            max_block_executions: params.max_block_executions,
+            arb_extra_hops: params.arb_extra_hops,
         }
     }
 }
@@ -68,6 +104,86 @@ impl Default for DexParameters {
             ],
             max_hops: 4,
             max_positions_per_pair: 1_000,
+            arb_tokens: vec![ArbToken::default()],
+            max_path_searches: 64,
+            max_executions: 64,
+            max_block_path_searches: 4_096,
+            max_block_executions: 4_096,
+            arb_extra_hops: 2,
+        }
+    }
+}
+
+/// Where the surplus of an arbitrage execution goes.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ArbSurplusDestination {
+    /// The surplus is burned.
+    #[default]
+    Burn,
+    /// The surplus is deposited into the community pool.
+    CommunityPool,
+}
+
+impl From<pb::ArbSurplusDestination> for ArbSurplusDestination {
+    fn from(destination: pb::ArbSurplusDestination) -> Self {
+        match destination {
+            pb::ArbSurplusDestination::Unspecified | pb::ArbSurplusDestination::Burn => Self::Burn,
+            pb::ArbSurplusDestination::CommunityPool => Self::CommunityPool,
+        }
+    }
+}
+
+impl From<ArbSurplusDestination> for pb::ArbSurplusDestination {
+    fn from(destination: ArbSurplusDestination) -> Self {
+        match destination {
+            ArbSurplusDestination::Burn => Self::Burn,
+            ArbSurplusDestination::CommunityPool => Self::CommunityPool,
+        }
+    }
+}
+
+/// A token the DEX arbitrages against at the end of each block.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::ArbToken", into = "pb::ArbToken")]
+pub struct ArbToken {
+    pub asset_id: asset::Id,
+    pub surplus_destination: ArbSurplusDestination,
+}
+
+impl DomainType for ArbToken {
+    type Proto = pb::ArbToken;
+}
+
+impl TryFrom<pb::ArbToken> for ArbToken {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::ArbToken) -> anyhow::Result<Self> {
+        Ok(ArbToken {
+            asset_id: msg
+                .asset_id
+                .ok_or_else(|| anyhow::anyhow!("missing arb token asset id"))?
+                .try_into()?,
+            surplus_destination: pb::ArbSurplusDestination::try_from(msg.surplus_destination)
+                .map_err(|_| anyhow::anyhow!("invalid arb surplus destination"))?
+                .into(),
+        })
+    }
+}
+
+impl From<ArbToken> for pb::ArbToken {
+    fn from(token: ArbToken) -> Self {
+        pb::ArbToken {
+            asset_id: Some(token.asset_id.into()),
+            surplus_destination: pb::ArbSurplusDestination::from(token.surplus_destination) as i32,
+        }
+    }
+}
+
+impl Default for ArbToken {
+    fn default() -> Self {
+        Self {
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+            surplus_destination: ArbSurplusDestination::Burn,
         }
     }
 }
@@ -0,0 +1,118 @@
+use anyhow::{ensure, Context, Result};
+use penumbra_num::fixpoint::U128x128;
+use penumbra_proto::{core::component::dex::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+/// The number of blocks of accumulator history kept for each pair, which bounds
+/// how far back a time-weighted average price can start.
+pub const PRICE_HISTORY_RETENTION: u64 = 17_280;
+
+/// A cumulative price accumulator for a directed trading pair.
+///
+/// The accumulator sums the price in effect at every height, where the price
+/// recorded at a height stays in effect until the next one is recorded. The
+/// time-weighted average price over a window is the difference between the
+/// cumulative prices at both ends of the window, divided by its length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::PriceAccumulator", into = "pb::PriceAccumulator")]
+pub struct PriceAccumulator {
+    /// The height at which the accumulator was last updated.
+    pub height: u64,
+    /// The price recorded at `height`.
+    pub last_price: U128x128,
+    /// The sum of the prices in effect at every height before `height`.
+    pub cumulative_price: U128x128,
+}
+
+impl PriceAccumulator {
+    /// Starts an accumulator with the first price recorded for a pair.
+    pub fn new(height: u64, price: U128x128) -> Self {
+        Self {
+            height,
+            last_price: price,
+            cumulative_price: U128x128::default(),
+        }
+    }
+
+    /// The sum of the prices in effect at every height before `height`.
+    pub fn cumulative_price_at(&self, height: u64) -> Result<U128x128> {
+        ensure!(
+            height >= self.height,
+            "cannot compute cumulative price at height {height} from accumulator at height {}",
+            self.height
+        );
+        let elapsed = U128x128::from(height - self.height);
+        let accrued = self.last_price.checked_mul(&elapsed)?;
+        Ok(self.cumulative_price.checked_add(&accrued)?)
+    }
+
+    /// Records a new `price` at `height`, which must not be before the last update.
+    pub fn update(&self, height: u64, price: U128x128) -> Result<Self> {
+        Ok(Self {
+            height,
+            last_price: price,
+            cumulative_price: self.cumulative_price_at(height)?,
+        })
+    }
+}
+
+impl DomainType for PriceAccumulator {
+    type Proto = pb::PriceAccumulator;
+}
+
+impl From<PriceAccumulator> for pb::PriceAccumulator {
+    fn from(acc: PriceAccumulator) -> Self {
+        Self {
+            height: acc.height,
+            last_price: acc.last_price.into(),
+            cumulative_price: acc.cumulative_price.into(),
+        }
+    }
+}
+
+impl TryFrom<pb::PriceAccumulator> for PriceAccumulator {
+    type Error = anyhow::Error;
+    fn try_from(acc: pb::PriceAccumulator) -> Result<Self, Self::Error> {
+        Ok(Self {
+            height: acc.height,
+            last_price: acc
+                .last_price
+                .as_slice()
+                .try_into()
+                .context("invalid last price")?,
+            cumulative_price: acc
+                .cumulative_price
+                .as_slice()
+                .try_into()
+                .context("invalid cumulative price")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accumulates_prices_in_effect() -> Result<()> {
+        // Price 2 from height 10, then price 4 from height 13.
+        let acc = PriceAccumulator::new(10, 2u64.into());
+        assert_eq!(acc.cumulative_price_at(10)?, U128x128::default());
+        assert_eq!(acc.cumulative_price_at(12)?, 4u64.into());
+
+        let acc = acc.update(13, 4u64.into())?;
+        assert_eq!(acc.cumulative_price, 6u64.into());
+        assert_eq!(acc.cumulative_price_at(15)?, 14u64.into());
+
+        assert!(acc.cumulative_price_at(12).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn round_trips_through_proto() -> Result<()> {
+        let acc = PriceAccumulator::new(7, U128x128::ratio(3u64, 2u64)?).update(9, 5u64.into())?;
+        let decoded = PriceAccumulator::decode(acc.encode_to_vec().as_slice())?;
+        assert_eq!(acc, decoded);
+        Ok(())
+    }
+}
@@ -58,6 +58,132 @@ pub mod candlesticks {
             format!("{}{}/{}/", prefix(), &pair.start, &pair.end)
         }
     }
+
+    pub mod rollup {
+        use crate::{CandlestickResolution, DirectedTradingPair};
+
+        pub fn prefix() -> &'static str {
+            "dex/candlesticks/rollup/"
+        }
+
+        pub fn by_resolution_pair_and_bucket(
+            resolution: CandlestickResolution,
+            pair: &DirectedTradingPair,
+            bucket: u64,
+        ) -> String {
+            format!(
+                "{}{resolution}/{}/{}/{bucket:020}",
+                prefix(),
+                &pair.start,
+                &pair.end
+            )
+        }
+
+        pub fn by_resolution_and_pair(
+            resolution: CandlestickResolution,
+            pair: &DirectedTradingPair,
+        ) -> String {
+            format!("{}{resolution}/{}/{}/", prefix(), &pair.start, &pair.end)
+        }
+    }
+}
+
+pub mod price_accumulators {
+    use crate::DirectedTradingPair;
+
+    pub fn prefix() -> &'static str {
+        "dex/price_accumulators/"
+    }
+
+    /// The latest accumulator of a pair, which is updated by each block it trades in.
+    pub fn latest(pair: &DirectedTradingPair) -> String {
+        format!("{}latest/{}/{}", prefix(), &pair.start, &pair.end)
+    }
+
+    /// The recent accumulators of a pair, keyed by the height they were updated at.
+    pub fn by_pair(pair: &DirectedTradingPair) -> String {
+        format!("{}history/{}/{}/", prefix(), &pair.start, &pair.end)
+    }
+
+    pub fn by_pair_and_height(pair: &DirectedTradingPair, height: u64) -> String {
+        // The height is zero-padded, so that the history is iterated in height order.
+        format!("{}{height:020}", by_pair(pair))
+    }
+}
+
+pub mod position_executions {
+    use crate::lp::position;
+
+    pub fn prefix() -> &'static str {
+        "dex/position_executions/"
+    }
+
+    pub fn by_id(id: &position::Id) -> String {
+        format!("{}{}/", prefix(), id)
+    }
+
+    pub fn height_suffix(height: u64) -> String {
+        format!("{height:020}")
+    }
+
+    /// The key of the `index`-th position execution recorded at `height`.
+    pub fn key(id: &position::Id, height: u64, index: u32) -> String {
+        format!("{}{}/{index:010}", by_id(id), height_suffix(height))
+    }
+}
+
+pub fn position_rewards(id: &position::Id) -> String {
+    format!("dex/position_rewards/{id}")
+}
+
+/// The volume executed against each position in an epoch, for liquidity rewards.
+pub mod position_volumes {
+    use crate::{lp::position, TradingPair};
+
+    pub fn prefix() -> &'static str {
+        "dex/position_volumes/"
+    }
+
+    pub fn by_epoch(epoch_index: u64) -> String {
+        format!("{}{epoch_index:020}/", prefix())
+    }
+
+    pub fn by_epoch_and_pair(epoch_index: u64, pair: &TradingPair) -> String {
+        format!("{}{pair}/", by_epoch(epoch_index))
+    }
+
+    pub fn key(epoch_index: u64, pair: &TradingPair, id: &position::Id) -> String {
+        format!("{}{id}", by_epoch_and_pair(epoch_index, pair))
+    }
+}
+
+pub mod position_liquidity {
+    use crate::{lp::position, TradingPair};
+
+    pub fn prefix() -> &'static str {
+        "dex/position_liquidity/"
+    }
+
+    pub fn by_epoch(epoch_index: u64) -> String {
+        format!("{}{epoch_index:020}/", prefix())
+    }
+
+    pub fn by_epoch_and_pair(epoch_index: u64, pair: &TradingPair) -> String {
+        format!("{}{pair}/", by_epoch(epoch_index))
+    }
+
+    pub fn key(epoch_index: u64, pair: &TradingPair, id: &position::Id) -> String {
+        format!("{}{id}", by_epoch_and_pair(epoch_index, pair))
+    }
+
+    /// The heights up to which the liquidity of the open positions of a pair was recorded.
+    pub fn recorded_until_by_pair(pair: &TradingPair) -> String {
+        format!("dex/position_liquidity_recorded_until/{pair}/")
+    }
+
+    pub fn recorded_until(pair: &TradingPair, id: &position::Id) -> String {
+        format!("{}{id}", recorded_until_by_pair(pair))
+    }
 }
 
 pub mod block_scoped {
@@ -66,6 +192,12 @@ pub mod block_scoped {
             "dex/block_scoped/active/trading_pairs"
         }
     }
+
+    pub mod position_executions {
+        pub fn count() -> &'static str {
+            "dex/block_scoped/position_executions/count"
+        }
+    }
 }
 
 pub fn output_data(height: u64, trading_pair: TradingPair) -> String {
@@ -88,7 +220,13 @@ pub fn swap_executions() -> &'static str {
     "dex/swap_execution/"
 }
 
-pub fn arb_execution(height: u64) -> String {
+pub fn arb_execution(height: u64, arb_token: &asset::Id) -> String {
+    format!("dex/arb_execution/{height:020}/{arb_token}")
+}
+
+/// The key arb executions against the staking token were recorded under
+/// before arb tokens were configurable.
+pub fn legacy_arb_execution(height: u64) -> String {
     format!("dex/arb_execution/{height:020}")
 }
 
@@ -194,6 +332,7 @@ pub(crate) mod engine {
     pub(crate) mod price_index {
 
         use super::*;
+        use penumbra_num::fixpoint::U128x128;
 
         pub(crate) fn prefix(pair: &DirectedTradingPair) -> [u8; 71] {
             let mut key = [0u8; 71];
@@ -215,6 +354,14 @@ pub(crate) mod engine {
             key[103..135].copy_from_slice(&id_bytes);
             key.to_vec()
         }
+
+        /// Parses the effective price and the position ID out of a price index key.
+        pub(crate) fn parse_key(key: &[u8]) -> anyhow::Result<(U128x128, position::Id)> {
+            anyhow::ensure!(key.len() == 135, "key must be 135 bytes");
+            let price: [u8; 32] = key[71..103].try_into()?;
+            let id: [u8; 32] = key[103..135].try_into()?;
+            Ok((U128x128::from_bytes(price), position::Id(id)))
+        }
     }
 }
 
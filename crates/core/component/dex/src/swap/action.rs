@@ -87,6 +87,10 @@ pub struct Body {
     pub delta_2_i: Amount,
     pub fee_commitment: balance::Commitment,
     pub payload: SwapPayload,
+    /// The minimum acceptable output of the swap, if any.
+    ///
+    /// Only one-directional swaps may set a minimum output.
+    pub min_output: Option<Amount>,
 }
 
 impl EffectingData for Body {
@@ -107,6 +111,7 @@ impl From<Body> for pb::SwapBody {
             delta_2_i: Some(s.delta_2_i.into()),
             fee_commitment: Some(s.fee_commitment.into()),
             payload: Some(s.payload.into()),
+            min_output: s.min_output.map(Into::into),
         }
     }
 }
@@ -137,6 +142,7 @@ impl TryFrom<pb::SwapBody> for Body {
                 .payload
                 .ok_or_else(|| anyhow::anyhow!("missing payload"))?
                 .try_into()?,
+            min_output: s.min_output.map(TryInto::try_into).transpose()?,
         })
     }
 }
@@ -4,6 +4,7 @@ use ark_ff::{UniformRand, Zero};
 use decaf377::{FieldExt, Fq, Fr};
 use penumbra_asset::{balance, Balance, Value};
 use penumbra_keys::FullViewingKey;
+use penumbra_num::Amount;
 use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
 use rand_core::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
@@ -21,6 +22,8 @@ pub struct SwapPlan {
     pub fee_blinding: Fr,
     pub proof_blinding_r: Fq,
     pub proof_blinding_s: Fq,
+    /// The minimum acceptable output of the swap, if any.
+    pub min_output: Option<Amount>,
 }
 
 impl SwapPlan {
@@ -33,6 +36,7 @@ impl SwapPlan {
             swap_plaintext,
             proof_blinding_r: Fq::rand(rng),
             proof_blinding_s: Fq::rand(rng),
+            min_output: None,
         }
     }
 
@@ -52,6 +56,7 @@ impl SwapPlan {
             delta_2_i: self.swap_plaintext.delta_2_i,
             fee_commitment: self.fee_commitment(),
             payload: self.swap_plaintext.encrypt(fvk.outgoing()),
+            min_output: self.min_output,
         }
     }
 
@@ -125,6 +130,7 @@ impl From<SwapPlan> for pb::SwapPlan {
             fee_blinding: msg.fee_blinding.to_bytes().to_vec(),
             proof_blinding_r: msg.proof_blinding_r.to_bytes().to_vec(),
             proof_blinding_s: msg.proof_blinding_s.to_bytes().to_vec(),
+            min_output: msg.min_output.map(Into::into),
         }
     }
 }
@@ -153,6 +159,7 @@ impl TryFrom<pb::SwapPlan> for SwapPlan {
                 .context("swap plaintext malformed")?,
             proof_blinding_r: Fq::from_bytes(proof_blinding_r_bytes)?,
             proof_blinding_s: Fq::from_bytes(proof_blinding_s_bytes)?,
+            min_output: msg.min_output.map(TryInto::try_into).transpose()?,
         })
     }
 }
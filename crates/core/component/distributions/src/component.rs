@@ -51,7 +51,7 @@ impl Component for Distributions {
         let state = Arc::get_mut(state).context("state should be unique")?;
         let new_issuance = state.compute_new_issuance().await?;
         tracing::debug!(?new_issuance, "computed new issuance for epoch");
-        Ok(state.distribute(new_issuance).await)
+        state.distribute(new_issuance).await
     }
 }
 
@@ -102,8 +102,30 @@ trait DistributionManager: StateWriteExt {
     }
 
     /// Update the object store with the new issuance of staking tokens for this epoch.
-    async fn distribute(&mut self, new_issuance: Amount) {
-        self.set_staking_token_issuance_for_epoch(new_issuance)
+    ///
+    /// The liquidity reward share is set aside first, so that the staking
+    /// base rate is computed from the remainder of the issuance.
+    async fn distribute(&mut self, new_issuance: Amount) -> Result<()> {
+        let liquidity_reward_bps = self.get_distributions_params().await?.liquidity_reward_bps;
+        let liquidity_reward_issuance = Amount::from(
+            new_issuance
+                .value()
+                .checked_mul(liquidity_reward_bps.into())
+                .expect("issuance is far below the overflow threshold")
+                / 10_000,
+        );
+        let staking_issuance = new_issuance
+            .checked_sub(&liquidity_reward_issuance)
+            .context("liquidity reward share is at most the full issuance")?;
+
+        tracing::debug!(
+            ?staking_issuance,
+            ?liquidity_reward_issuance,
+            "split issuance for epoch"
+        );
+        self.set_staking_token_issuance_for_epoch(staking_issuance);
+        self.set_liquidity_reward_issuance_for_epoch(liquidity_reward_issuance);
+        Ok(())
     }
 }
 
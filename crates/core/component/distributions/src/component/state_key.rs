@@ -3,6 +3,11 @@ pub fn staking_token_issuance_for_epoch() -> &'static str {
     "distributions/staking_token_issuance_for_epoch"
 }
 
+// The amount of staking tokens set aside for liquidity rewards this epoch.
+pub fn liquidity_reward_issuance_for_epoch() -> &'static str {
+    "distributions/liquidity_reward_issuance_for_epoch"
+}
+
 pub fn distributions_parameters() -> &'static str {
     "distributions/parameters"
 }
@@ -9,6 +9,9 @@ use serde::{Deserialize, Serialize};
 )]
 pub struct DistributionsParameters {
     pub staking_issuance_per_block: u64,
+    /// The share of each epoch's staking token issuance set aside for
+    /// liquidity provider rewards, in basis points.
+    pub liquidity_reward_bps: u64,
 }
 
 impl DomainType for DistributionsParameters {
@@ -21,6 +24,7 @@ impl TryFrom<pb::DistributionsParameters> for DistributionsParameters {
     fn try_from(msg: pb::DistributionsParameters) -> anyhow::Result<Self> {
         Ok(DistributionsParameters {
             staking_issuance_per_block: msg.staking_issuance_per_block,
+            liquidity_reward_bps: msg.liquidity_reward_bps,
         })
     }
 }
@@ -29,6 +33,7 @@ impl From<DistributionsParameters> for pb::DistributionsParameters {
     fn from(params: DistributionsParameters) -> Self {
         pb::DistributionsParameters {
             staking_issuance_per_block: params.staking_issuance_per_block,
+            liquidity_reward_bps: params.liquidity_reward_bps,
         }
     }
 }
@@ -37,6 +42,7 @@ impl Default for DistributionsParameters {
     fn default() -> Self {
         Self {
             staking_issuance_per_block: 1,
+            liquidity_reward_bps: 0,
         }
     }
 }
@@ -1,3 +1,4 @@
+mod liquidity_rewards;
 pub mod metrics;
 mod state_key;
 pub mod view;
@@ -6,6 +7,7 @@ pub use metrics::register_metrics;
 
 /* Component implementation */
 use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_num::Amount;
 use penumbra_stake::component::validator_handler::ValidatorDataRead;
 pub use view::{StateReadExt, StateWriteExt};
 
@@ -72,6 +74,17 @@ impl Component for Funding {
         let fetching_funding_queue_duration = funding_execution_start.elapsed().as_millis() as f64;
         histogram!(metrics::FETCH_FUNDING_QUEUE_LATENCY,).record(fetching_funding_queue_duration);
 
+        // Liquidity rewards are paid out of their own share of the issuance, and
+        // do not depend on the base rate. They are distributed before anything
+        // else, as the DEX prunes the ending epoch's volumes at the next epoch.
+        let liquidity_rewards = liquidity_rewards::distribute_liquidity_rewards(state)
+            .await
+            .unwrap_or_else(|error| {
+                tracing::error!(?error, "failed to distribute liquidity rewards");
+                Amount::zero()
+            });
+        gauge!(metrics::TOTAL_LIQUIDITY_REWARDS).set(liquidity_rewards.value() as f64);
+
         let Some(base_rate) = state.get_previous_base_rate() else {
             tracing::error!("the ending epoch's base rate has not been found in object storage, computing rewards is not possible");
             return Ok(());
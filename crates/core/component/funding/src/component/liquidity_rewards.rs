@@ -0,0 +1,62 @@
+use anyhow::Result;
+use cnidarium::{StateDelta, StateWrite};
+use penumbra_dex::component::RewardManager as _;
+use penumbra_distributions::component::StateReadExt as _;
+use penumbra_num::Amount;
+use penumbra_sct::component::clock::EpochRead as _;
+
+use super::StateReadExt as _;
+
+/// Pays the liquidity providers of the rewarded pairs the liquidity reward
+/// issuance that the distributions component set aside for the ending epoch.
+///
+/// The rewards are split evenly between the pairs, then between the positions
+/// of each pair according to the volume and liquidity they provided during the
+/// epoch. Each pair is rewarded in its own transaction, so that a pair whose
+/// rewards can't be distributed is skipped, without leaving any of its rewards
+/// behind or preventing the epoch from ending. Returns the total rewards
+/// distributed; whatever is not distributed is never issued.
+pub(crate) async fn distribute_liquidity_rewards<S: StateWrite>(state: &mut S) -> Result<Amount> {
+    let params = state.get_funding_params().await?;
+    let rewards = state
+        .get_liquidity_reward_issuance_for_epoch()
+        .unwrap_or_default();
+    if rewards == Amount::zero() || params.liquidity_reward_pairs.is_empty() {
+        return Ok(Amount::zero());
+    }
+
+    let rewards_per_pair =
+        Amount::from(rewards.value() / params.liquidity_reward_pairs.len() as u128);
+
+    // The DEX has not yet advanced to the next epoch, so the current epoch is
+    // the one whose volume is being rewarded.
+    let epoch = state.get_current_epoch().await?;
+
+    let mut distributed = Amount::zero();
+    for pair in &params.liquidity_reward_pairs {
+        let mut pair_state = StateDelta::new(&mut *state);
+        match pair_state
+            .reward_liquidity_providers(&epoch, pair, rewards_per_pair)
+            .await
+        {
+            Ok(pair_rewards) => {
+                let (state, events) = pair_state.apply();
+                for event in events {
+                    state.record(event);
+                }
+                distributed += pair_rewards;
+            }
+            Err(error) => {
+                tracing::error!(?error, %pair, "failed to distribute liquidity rewards, skipping pair");
+            }
+        }
+    }
+
+    tracing::debug!(
+        epoch_index = epoch.index,
+        ?rewards_per_pair,
+        ?distributed,
+        "distributed liquidity rewards"
+    );
+    Ok(distributed)
+}
@@ -21,6 +21,12 @@ pub fn register_metrics() {
         "The total amount of rewards distributed to validators during the epoch"
     );
 
+    describe_gauge!(
+        TOTAL_LIQUIDITY_REWARDS,
+        Unit::Count,
+        "The total amount of rewards distributed to liquidity providers during the epoch"
+    );
+
     describe_gauge!(
         VALIDATOR_FUNDING_VS_BUDGET_DIFFERENCE,
         Unit::Count,
@@ -41,6 +47,7 @@ pub fn register_metrics() {
 }
 
 pub const TOTAL_VALIDATOR_REWARDS: &str = "penumbra_funding_total_validator_rewards_staking_token";
+pub const TOTAL_LIQUIDITY_REWARDS: &str = "penumbra_funding_total_liquidity_rewards_staking_token";
 pub const VALIDATOR_FUNDING_VS_BUDGET_DIFFERENCE: &str =
     "penumbra_funding_validator_vs_budget_difference_staking_token";
 pub const FETCH_FUNDING_QUEUE_LATENCY: &str =
@@ -1,10 +1,15 @@
+use penumbra_dex::TradingPair;
 use penumbra_proto::core::component::funding::v1 as pb;
 use penumbra_proto::DomainType;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(try_from = "pb::FundingParameters", into = "pb::FundingParameters")]
-pub struct FundingParameters {}
+pub struct FundingParameters {
+    /// The trading pairs whose liquidity providers are rewarded, each of which
+    /// must include the staking token.
+    pub liquidity_reward_pairs: Vec<TradingPair>,
+}
 
 impl DomainType for FundingParameters {
     type Proto = pb::FundingParameters;
@@ -13,19 +18,33 @@ impl DomainType for FundingParameters {
 impl TryFrom<pb::FundingParameters> for FundingParameters {
     type Error = anyhow::Error;
 
-    fn try_from(_params: pb::FundingParameters) -> anyhow::Result<Self> {
-        Ok(FundingParameters {})
+    fn try_from(params: pb::FundingParameters) -> anyhow::Result<Self> {
+        Ok(FundingParameters {
+            liquidity_reward_pairs: params
+                .liquidity_reward_pairs
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
 impl From<FundingParameters> for pb::FundingParameters {
-    fn from(_params: FundingParameters) -> Self {
-        pb::FundingParameters {}
+    fn from(params: FundingParameters) -> Self {
+        pb::FundingParameters {
+            liquidity_reward_pairs: params
+                .liquidity_reward_pairs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
     }
 }
 
 impl Default for FundingParameters {
     fn default() -> Self {
-        Self {}
+        Self {
+            liquidity_reward_pairs: Vec::new(),
+        }
     }
 }
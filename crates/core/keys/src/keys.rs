@@ -5,7 +5,7 @@ mod nullifier;
 pub use nullifier::{NullifierKey, NullifierKeyVar, NK_LEN_BYTES};
 
 mod seed_phrase;
-pub use seed_phrase::SeedPhrase;
+pub use seed_phrase::{SeedPhrase, SeedPhraseShare};
 
 mod spend;
 pub use spend::{SpendKey, SpendKeyBytes, SPENDKEY_LEN_BYTES};
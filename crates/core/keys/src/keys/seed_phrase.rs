@@ -3,7 +3,9 @@ use std::fmt;
 use rand_core::{CryptoRng, RngCore};
 use sha2::Digest;
 
+mod shares;
 mod words;
+pub use shares::{SeedPhraseShare, MAX_SHARES};
 use words::BIP39_WORDS;
 
 pub const NUM_PBKDF2_ROUNDS: u32 = 2048;
@@ -130,6 +132,11 @@ impl SeedPhrase {
 
     /// Verify the checksum of this [`SeedPhrase`].
     fn verify_checksum(&self) -> anyhow::Result<()> {
+        self.randomness().map(|_| ())
+    }
+
+    /// Recover the randomness this [`SeedPhrase`] was generated from, verifying its checksum.
+    fn randomness(&self) -> anyhow::Result<Vec<u8>> {
         let seed_phrase_type = SeedPhraseType::from_length(self.length())?;
         let mut bits = vec![false; seed_phrase_type.num_total_bits()];
         for (i, word) in self.0.iter().enumerate() {
@@ -169,7 +176,7 @@ impl SeedPhrase {
                 return Err(anyhow::anyhow!("seed phrase checksum did not validate"));
             }
         }
-        Ok(())
+        Ok(randomness)
     }
 }
 
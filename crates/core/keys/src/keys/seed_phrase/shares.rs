@@ -0,0 +1,399 @@
+//! Splitting a [`SeedPhrase`] into k-of-n shares, in the style of SLIP-39.
+//!
+//! The entropy of the seed phrase is split byte-wise using Shamir secret sharing over
+//! GF(256), so that any `threshold` of the shares recover it, while fewer reveal nothing
+//! about it. As in SLIP-39, the sharing polynomial also passes through a digest of the
+//! secret, which lets recovery detect shares that don't belong together.
+//!
+//! Each share is written as a mnemonic using the BIP39 word list, encoding:
+//!
+//! - a random 16-bit identifier, shared by all the shares of a secret;
+//! - the threshold, minus one, in 4 bits;
+//! - the index of the share, in 4 bits;
+//! - the share value, as long as the entropy of the seed phrase;
+//! - a checksum filling out the last word, of at least 16 bits.
+//!
+//! This gives 16 word shares for 12 word seed phrases, and 27 word shares for 24 word ones,
+//! so they can't be confused with seed phrases themselves.
+
+use std::fmt;
+
+use hmac::{Hmac, Mac};
+use rand_core::{CryptoRng, RngCore};
+use sha2::Digest;
+
+use super::{
+    convert_bits_to_usize, SeedPhrase, BIP39_WORDS, NUM_BITS_PER_BYTE, NUM_BITS_PER_WORD,
+    NUM_ENTROPY_BITS_LONG, NUM_ENTROPY_BITS_SHORT,
+};
+
+/// The maximum number of shares a seed phrase can be split into.
+pub const MAX_SHARES: u8 = 16;
+
+/// The minimum number of checksum bits in a share mnemonic.
+const MIN_CHECKSUM_BITS: usize = 16;
+/// The number of bytes of the identifier, threshold and index preceding the share value.
+const HEADER_BYTES: usize = 3;
+/// The number of bytes of the digest of the secret included in the digest share.
+const DIGEST_BYTES: usize = 4;
+/// The x-coordinate at which the sharing polynomial holds the digest share.
+const DIGEST_INDEX: u8 = 254;
+/// The x-coordinate at which the sharing polynomial holds the secret.
+const SECRET_INDEX: u8 = 255;
+const CHECKSUM_DOMAIN: &[u8] = b"Penumbra_SeedPhraseShare";
+
+/// One share of a [`SeedPhrase`], produced by [`SeedPhrase::split`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeedPhraseShare {
+    /// A random identifier common to all of the shares of a seed phrase.
+    pub identifier: u16,
+    /// The number of shares needed to recover the seed phrase.
+    pub threshold: u8,
+    /// The index of this share, starting from zero.
+    pub index: u8,
+    value: Vec<u8>,
+}
+
+impl SeedPhrase {
+    /// Split this seed phrase into `count` shares, any `threshold` of which can recover it.
+    pub fn split<R: RngCore + CryptoRng>(
+        &self,
+        threshold: u8,
+        count: u8,
+        mut rng: R,
+    ) -> anyhow::Result<Vec<SeedPhraseShare>> {
+        anyhow::ensure!(
+            0 < threshold && threshold <= count && count <= MAX_SHARES,
+            "the threshold must be at least 1, and at most the number of shares, which must be at most {}",
+            MAX_SHARES
+        );
+        let secret = self.randomness()?;
+        let identifier = (rng.next_u32() & 0xffff) as u16;
+        let share = |index: u8, value: Vec<u8>| SeedPhraseShare {
+            identifier,
+            threshold,
+            index,
+            value,
+        };
+
+        if threshold == 1 {
+            return Ok((0..count)
+                .map(|index| share(index, secret.clone()))
+                .collect());
+        }
+
+        // The first `threshold - 2` shares are random, and along with the digest and the
+        // secret, they determine the polynomial from which we interpolate the others.
+        let mut random_part = vec![0u8; secret.len() - DIGEST_BYTES];
+        rng.fill_bytes(&mut random_part);
+        let mut digest_share = digest(&random_part, &secret).to_vec();
+        digest_share.extend_from_slice(&random_part);
+
+        let mut points = Vec::new();
+        for index in 0..threshold - 2 {
+            let mut value = vec![0u8; secret.len()];
+            rng.fill_bytes(&mut value);
+            points.push((index, value));
+        }
+        points.push((DIGEST_INDEX, digest_share));
+        points.push((SECRET_INDEX, secret));
+
+        Ok((0..count)
+            .map(|index| {
+                let value = if index < threshold - 2 {
+                    points[index as usize].1.clone()
+                } else {
+                    interpolate(&points, index)
+                };
+                share(index, value)
+            })
+            .collect())
+    }
+
+    /// Recover a seed phrase from at least as many of its shares as its threshold.
+    pub fn from_shares(shares: &[SeedPhraseShare]) -> anyhow::Result<Self> {
+        let first = shares
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("no seed phrase shares were provided"))?;
+
+        let mut points = Vec::<(u8, Vec<u8>)>::new();
+        for share in shares {
+            anyhow::ensure!(
+                share.identifier == first.identifier
+                    && share.threshold == first.threshold
+                    && share.value.len() == first.value.len(),
+                "share {} does not belong to the same seed phrase as share {}",
+                share.index,
+                first.index
+            );
+            if !points.iter().any(|(index, _)| *index == share.index) {
+                points.push((share.index, share.value.clone()));
+            }
+        }
+        anyhow::ensure!(
+            points.len() >= first.threshold as usize,
+            "{} distinct shares are needed to recover the seed phrase, but only {} were provided",
+            first.threshold,
+            points.len()
+        );
+        points.truncate(first.threshold as usize);
+
+        let secret = if first.threshold == 1 {
+            points.swap_remove(0).1
+        } else {
+            let secret = interpolate(&points, SECRET_INDEX);
+            let digest_share = interpolate(&points, DIGEST_INDEX);
+            let (expected_digest, random_part) = digest_share.split_at(DIGEST_BYTES);
+            anyhow::ensure!(
+                digest(random_part, &secret)[..] == *expected_digest,
+                "the recovered seed phrase failed its integrity check; some shares are incorrect"
+            );
+            secret
+        };
+
+        Ok(SeedPhrase::from_randomness(&secret))
+    }
+}
+
+impl SeedPhraseShare {
+    /// The number of words in the mnemonic for a share of `value_len` bytes.
+    fn num_words(value_len: usize) -> usize {
+        let data_bits = (HEADER_BYTES + value_len) * NUM_BITS_PER_BYTE;
+        (data_bits + MIN_CHECKSUM_BITS + NUM_BITS_PER_WORD - 1) / NUM_BITS_PER_WORD
+    }
+
+    /// The identifier, threshold, index and value of the share, which the checksum covers.
+    fn data(&self) -> Vec<u8> {
+        let mut data = self.identifier.to_be_bytes().to_vec();
+        data.push(((self.threshold - 1) << 4) | self.index);
+        data.extend_from_slice(&self.value);
+        data
+    }
+}
+
+impl fmt::Display for SeedPhraseShare {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let data = self.data();
+        let num_words = Self::num_words(self.value.len());
+
+        let mut bits = to_bits(&data);
+        let checksum = to_bits(&checksum(&data));
+        bits.extend_from_slice(&checksum[..num_words * NUM_BITS_PER_WORD - bits.len()]);
+
+        for (i, word_bits) in bits.chunks(NUM_BITS_PER_WORD).enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(BIP39_WORDS[convert_bits_to_usize(word_bits)])?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for SeedPhraseShare {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words = s
+            .split_whitespace()
+            .map(|w| w.to_lowercase())
+            .collect::<Vec<String>>();
+
+        let value_len = [NUM_ENTROPY_BITS_SHORT, NUM_ENTROPY_BITS_LONG]
+            .into_iter()
+            .map(|bits| bits / NUM_BITS_PER_BYTE)
+            .find(|len| Self::num_words(*len) == words.len())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "seed phrase shares should have {} or {} words",
+                    Self::num_words(NUM_ENTROPY_BITS_SHORT / NUM_BITS_PER_BYTE),
+                    Self::num_words(NUM_ENTROPY_BITS_LONG / NUM_BITS_PER_BYTE)
+                )
+            })?;
+
+        let mut bits = Vec::with_capacity(words.len() * NUM_BITS_PER_WORD);
+        for word in &words {
+            let word_index = BIP39_WORDS
+                .iter()
+                .position(|&x| x == word)
+                .ok_or_else(|| anyhow::anyhow!("invalid word {word} in seed phrase share"))?;
+            bits.extend(
+                (0..NUM_BITS_PER_WORD)
+                    .map(|j| (word_index >> (NUM_BITS_PER_WORD - 1 - j)) & 1 == 1),
+            );
+        }
+
+        let (data_bits, checksum_bits) =
+            bits.split_at((HEADER_BYTES + value_len) * NUM_BITS_PER_BYTE);
+        let data = data_bits
+            .chunks(NUM_BITS_PER_BYTE)
+            .map(|byte_bits| convert_bits_to_usize(byte_bits) as u8)
+            .collect::<Vec<_>>();
+        anyhow::ensure!(
+            to_bits(&checksum(&data))[..checksum_bits.len()] == *checksum_bits,
+            "seed phrase share checksum did not validate"
+        );
+
+        Ok(SeedPhraseShare {
+            identifier: u16::from_be_bytes([data[0], data[1]]),
+            threshold: (data[2] >> 4) + 1,
+            index: data[2] & 0x0f,
+            value: data[HEADER_BYTES..].to_vec(),
+        })
+    }
+}
+
+/// The digest of the secret included in the digest share, keyed by the rest of that share.
+fn digest(random_part: &[u8], secret: &[u8]) -> [u8; DIGEST_BYTES] {
+    let mut mac =
+        Hmac::<sha2::Sha256>::new_from_slice(random_part).expect("HMAC accepts keys of any length");
+    mac.update(secret);
+    mac.finalize().into_bytes()[..DIGEST_BYTES]
+        .try_into()
+        .expect("digest is 4 bytes")
+}
+
+fn checksum(data: &[u8]) -> [u8; 32] {
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(CHECKSUM_DOMAIN);
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+fn to_bits(bytes: &[u8]) -> Vec<bool> {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..NUM_BITS_PER_BYTE).map(move |i| byte & (1 << (7 - i)) > 0))
+        .collect()
+}
+
+/// Evaluates, at `x`, the polynomial over GF(256) passing through each of the `points`,
+/// byte by byte.
+fn interpolate(points: &[(u8, Vec<u8>)], x: u8) -> Vec<u8> {
+    let mut result = vec![0u8; points[0].1.len()];
+    for (i, (x_i, y_i)) in points.iter().enumerate() {
+        // Subtraction in GF(256) is XOR.
+        let basis = points
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .fold(1, |basis, (_, (x_j, _))| {
+                gf256_mul(basis, gf256_mul(x ^ x_j, gf256_inv(x_i ^ x_j)))
+            });
+        for (r, y) in result.iter_mut().zip(y_i) {
+            *r ^= gf256_mul(basis, *y);
+        }
+    }
+    result
+}
+
+/// Multiplication in GF(256), modulo the Rijndael polynomial, without secret-dependent branches.
+fn gf256_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    for _ in 0..8 {
+        product ^= a & 0u8.wrapping_sub(b & 1);
+        let carry = a >> 7;
+        a = (a << 1) ^ (0x1b & 0u8.wrapping_sub(carry));
+        b >>= 1;
+    }
+    product
+}
+
+/// The multiplicative inverse in GF(256), as `a^254`.
+fn gf256_inv(a: u8) -> u8 {
+    let mut result = 1;
+    let mut base = a;
+    let mut exponent = 254u8;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = gf256_mul(result, base);
+        }
+        base = gf256_mul(base, base);
+        exponent >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::keys::{Bip44Path, SpendKey};
+
+    #[test]
+    fn gf256_inverses() {
+        for a in 1..=255u8 {
+            assert_eq!(gf256_mul(a, gf256_inv(a)), 1);
+        }
+    }
+
+    #[test]
+    fn split_and_recover() {
+        for seed_phrase in [
+            SeedPhrase::generate(OsRng),
+            SeedPhrase::short_generate(OsRng),
+        ] {
+            let shares = seed_phrase.split(3, 5, OsRng).unwrap();
+
+            // Shares survive being written down.
+            let shares = shares
+                .iter()
+                .map(|share| SeedPhraseShare::from_str(&share.to_string()).unwrap())
+                .collect::<Vec<_>>();
+
+            for subset in [
+                &shares[0..3],
+                &shares[2..5],
+                &[shares[4].clone(), shares[0].clone(), shares[3].clone()],
+            ] {
+                let recovered = SeedPhrase::from_shares(subset).unwrap();
+                assert_eq!(recovered.to_string(), seed_phrase.to_string());
+                assert_eq!(
+                    SpendKey::from_seed_phrase_bip39(recovered.clone(), 0),
+                    SpendKey::from_seed_phrase_bip39(seed_phrase.clone(), 0),
+                );
+                assert_eq!(
+                    SpendKey::from_seed_phrase_bip44(recovered, &Bip44Path::new(0)),
+                    SpendKey::from_seed_phrase_bip44(seed_phrase.clone(), &Bip44Path::new(0)),
+                );
+            }
+
+            // Too few shares, even if one is repeated.
+            assert!(SeedPhrase::from_shares(&shares[0..2]).is_err());
+            assert!(SeedPhrase::from_shares(&[
+                shares[0].clone(),
+                shares[1].clone(),
+                shares[1].clone()
+            ])
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn detects_bad_shares() {
+        let seed_phrase = SeedPhrase::generate(OsRng);
+        let shares = seed_phrase.split(2, 3, OsRng).unwrap();
+
+        // A mistyped word fails the checksum.
+        let mut words = shares[0]
+            .to_string()
+            .split(' ')
+            .map(str::to_owned)
+            .collect::<Vec<_>>();
+        words[5] = if words[5] == "zoo" { "abandon" } else { "zoo" }.to_owned();
+        assert!(SeedPhraseShare::from_str(&words.join(" ")).is_err());
+
+        // Shares of different seed phrases can't be combined...
+        let other = SeedPhrase::generate(OsRng).split(2, 3, OsRng).unwrap();
+        assert!(SeedPhrase::from_shares(&[shares[0].clone(), other[1].clone()]).is_err());
+        // ...even if their identifiers happen to match.
+        let forged = SeedPhraseShare {
+            identifier: shares[0].identifier,
+            ..other[1].clone()
+        };
+        assert!(SeedPhrase::from_shares(&[shares[0].clone(), forged]).is_err());
+    }
+}
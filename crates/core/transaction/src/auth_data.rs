@@ -2,10 +2,12 @@ use decaf377_rdsa::{Signature, SpendAuth};
 
 use penumbra_proto::{core::transaction::v1 as pb, DomainType};
 use penumbra_txhash::EffectHash;
+use serde::{Deserialize, Serialize};
 
 /// Authorization data returned in response to a
 /// [`TransactionDescription`](crate::TransactionDescription).
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(try_from = "pb::AuthorizationData", into = "pb::AuthorizationData")]
 pub struct AuthorizationData {
     /// The computed authorization hash for the approved transaction.
     pub effect_hash: Option<EffectHash>,
@@ -2,7 +2,9 @@ use penumbra_auction::auction::dutch::actions::{
     ActionDutchAuctionEnd, ActionDutchAuctionSchedule, ActionDutchAuctionWithdraw,
 };
 use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
-use penumbra_dex::{PositionClose, PositionOpen, PositionWithdraw, Swap, SwapClaim};
+use penumbra_dex::{
+    PositionClose, PositionOpen, PositionRewardClaim, PositionWithdraw, Swap, SwapClaim,
+};
 use penumbra_fee::Gas;
 use penumbra_ibc::IbcRelay;
 use penumbra_shielded_pool::{Ics20Withdrawal, Output, Spend};
@@ -246,6 +248,25 @@ fn position_withdraw_gas_cost() -> Gas {
     }
 }
 
+fn position_reward_claim_gas_cost() -> Gas {
+    Gas {
+        // PositionId `position_id`                                        = 32 bytes
+        // penumbra.core.asset.v1.BalanceCommitment `rewards_commitment`   = 32 bytes
+        // uint64 `sequence`                                               = 8 bytes
+
+        // The block space measured as the byte length of the encoded action.
+        block_space: 72, // 72 bytes
+        // The compact block space cost is based on the byte size of the data the [`Action`] adds
+        // to the compact block.
+        // For a PositionRewardClaim the compact block is not modified.
+        compact_block_space: 0,
+        // Does not include a zk-SNARK proof, so there's no verification cost.
+        verification: 0,
+        // Execution cost is currently hardcoded at 10 for all `Action`` variants.
+        execution: 10,
+    }
+}
+
 fn dutch_auction_schedule_gas_cost(dutch_action_schedule: &ActionDutchAuctionSchedule) -> Gas {
     Gas {
         // penumbra.core.asset.v1.Value `input` = 48 bytes
@@ -321,6 +342,7 @@ impl GasCost for ActionPlan {
             ActionPlan::SwapClaim(_) => swap_claim_gas_cost(),
             ActionPlan::DelegatorVote(_) => delegator_vote_gas_cost(),
             ActionPlan::PositionWithdraw(_) => position_withdraw_gas_cost(),
+            ActionPlan::PositionRewardClaim(_) => position_reward_claim_gas_cost(),
             ActionPlan::ActionDutchAuctionSchedule(das) => das.gas_cost(),
             ActionPlan::ActionDutchAuctionEnd(_) => dutch_auction_end_gas_cost(),
             ActionPlan::ActionDutchAuctionWithdraw(_) => dutch_auction_withdraw_gas_cost(),
@@ -361,6 +383,7 @@ impl GasCost for Action {
             Action::PositionOpen(p) => p.gas_cost(),
             Action::PositionClose(p) => p.gas_cost(),
             Action::PositionWithdraw(p) => p.gas_cost(),
+            Action::PositionRewardClaim(p) => p.gas_cost(),
             Action::Ics20Withdrawal(withdrawal) => withdrawal.gas_cost(),
             Action::CommunityPoolDeposit(deposit) => deposit.gas_cost(),
             Action::CommunityPoolSpend(spend) => spend.gas_cost(),
@@ -541,6 +564,12 @@ impl GasCost for PositionWithdraw {
     }
 }
 
+impl GasCost for PositionRewardClaim {
+    fn gas_cost(&self) -> Gas {
+        position_reward_claim_gas_cost()
+    }
+}
+
 impl GasCost for Ics20Withdrawal {
     fn gas_cost(&self) -> Gas {
         Gas {
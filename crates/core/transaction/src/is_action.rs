@@ -8,7 +8,7 @@ use penumbra_auction::auction::dutch::actions::{
 use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
 use penumbra_dex::{
     lp::{
-        action::{PositionClose, PositionOpen, PositionWithdraw},
+        action::{PositionClose, PositionOpen, PositionRewardClaim, PositionWithdraw},
         position, LpNft,
     },
     swap::{Swap, SwapCiphertext, SwapView},
@@ -312,6 +312,43 @@ impl IsAction for PositionWithdraw {
     }
 }
 
+impl IsAction for PositionRewardClaim {
+    fn balance_commitment(&self) -> balance::Commitment {
+        // A claim is only valid on a withdrawn position, so the sequence number
+        // is checked to be nonzero by the stateless checks.
+        let prev_state_nft = Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(
+                self.position_id,
+                position::State::Withdrawn {
+                    sequence: self.sequence.saturating_sub(1),
+                },
+            )
+            .asset_id(),
+        }
+        .commit(Fr::zero());
+
+        let next_state_nft = Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(
+                self.position_id,
+                position::State::Withdrawn {
+                    sequence: self.sequence,
+                },
+            )
+            .asset_id(),
+        }
+        .commit(Fr::zero());
+
+        // The action consumes a withdrawn position NFT and produces the position's rewards and a withdrawn position NFT.
+        self.rewards_commitment - prev_state_nft + next_state_nft
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::PositionRewardClaim(self.to_owned())
+    }
+}
+
 impl IsAction for Swap {
     /// Compute a commitment to the value contributed to a transaction by this swap.
     /// Will subtract (v1,t1), (v2,t2), and (f,fee_token)
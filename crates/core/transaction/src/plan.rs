@@ -6,7 +6,7 @@ use decaf377_fmd::Precision;
 use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
 use penumbra_dex::{
     lp::action::{PositionClose, PositionOpen},
-    lp::plan::PositionWithdrawPlan,
+    lp::plan::{PositionRewardClaimPlan, PositionWithdrawPlan},
     swap::SwapPlan,
     swap_claim::SwapClaimPlan,
 };
@@ -313,6 +313,16 @@ impl TransactionPlan {
         })
     }
 
+    pub fn position_reward_claims(&self) -> impl Iterator<Item = &PositionRewardClaimPlan> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::PositionRewardClaim(v) = action {
+                Some(v)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn ics20_withdrawals(&self) -> impl Iterator<Item = &Ics20Withdrawal> {
         self.actions.iter().filter_map(|action| {
             if let ActionPlan::Ics20Withdrawal(v) = action {
@@ -13,7 +13,7 @@ use penumbra_txhash::{EffectHash, EffectingData};
 use penumbra_dex::{
     lp::{
         action::{PositionClose, PositionOpen},
-        plan::PositionWithdrawPlan,
+        plan::{PositionRewardClaimPlan, PositionWithdrawPlan},
     },
     swap::SwapPlan,
     swap_claim::SwapClaimPlan,
@@ -25,6 +25,7 @@ use penumbra_governance::{
 
 use penumbra_ibc::IbcRelay;
 use penumbra_keys::{symmetric::PayloadKey, FullViewingKey};
+use penumbra_proof_params::GROTH16_PROOF_LENGTH_BYTES;
 use penumbra_proto::{core::transaction::v1 as pb_t, DomainType};
 use penumbra_shielded_pool::{Ics20Withdrawal, OutputPlan, SpendPlan};
 use penumbra_stake::{Delegate, Undelegate, UndelegateClaimPlan};
@@ -72,6 +73,9 @@ pub enum ActionPlan {
     // PositionWithdrawPlan requires the balance of the funds to be withdrawn, so
     // a plan must be used.
     PositionWithdraw(PositionWithdrawPlan),
+    // PositionRewardClaimPlan requires the rewards accrued by the position, so
+    // a plan must be used.
+    PositionRewardClaim(PositionRewardClaimPlan),
 
     CommunityPoolSpend(CommunityPoolSpend),
     CommunityPoolOutput(CommunityPoolOutput),
@@ -156,6 +160,7 @@ impl ActionPlan {
             PositionOpen(plan) => Action::PositionOpen(plan.clone()),
             PositionClose(plan) => Action::PositionClose(plan.clone()),
             PositionWithdraw(plan) => Action::PositionWithdraw(plan.position_withdraw()),
+            PositionRewardClaim(plan) => Action::PositionRewardClaim(plan.position_reward_claim()),
             CommunityPoolSpend(plan) => Action::CommunityPoolSpend(plan.clone()),
             CommunityPoolOutput(plan) => Action::CommunityPoolOutput(plan.clone()),
             CommunityPoolDeposit(plan) => Action::CommunityPoolDeposit(plan.clone()),
@@ -168,6 +173,84 @@ impl ActionPlan {
         })
     }
 
+    /// Builds a stand-in for the [`Action`] specified by this [`ActionPlan`], with the same
+    /// effects, but with zeroed proofs and authorization data.
+    ///
+    /// Unlike [`ActionPlan::build_unauth`], this doesn't need witness data and doesn't
+    /// create any proofs, so it's cheap, and can be done offline, e.g. to view the
+    /// action before authorizing it. The resulting action is not valid.
+    pub fn build_stub(
+        action_plan: ActionPlan,
+        fvk: &FullViewingKey,
+        memo_key: &PayloadKey,
+    ) -> Result<Action> {
+        use penumbra_proto::core::component::{
+            dex::v1 as pb_dex, governance::v1 as pb_governance, shielded_pool::v1 as pb_sp,
+            stake::v1 as pb_stake,
+        };
+        use ActionPlan::*;
+
+        let zero_proof = || vec![0u8; GROTH16_PROOF_LENGTH_BYTES];
+
+        Ok(match action_plan {
+            Spend(plan) => Action::Spend(penumbra_shielded_pool::Spend {
+                body: plan.spend_body(fvk),
+                auth_sig: [0; 64].into(),
+                proof: pb_sp::ZkSpendProof {
+                    inner: zero_proof(),
+                }
+                .try_into()?,
+            }),
+            Output(plan) => Action::Output(penumbra_shielded_pool::Output {
+                body: plan.output_body(fvk.outgoing(), memo_key),
+                proof: pb_sp::ZkOutputProof {
+                    inner: zero_proof(),
+                }
+                .try_into()?,
+            }),
+            Swap(plan) => Action::Swap(penumbra_dex::Swap {
+                body: plan.swap_body(fvk),
+                proof: pb_dex::ZkSwapProof {
+                    inner: zero_proof(),
+                }
+                .try_into()?,
+            }),
+            SwapClaim(plan) => Action::SwapClaim(penumbra_dex::SwapClaim {
+                body: plan.swap_claim_body(fvk),
+                proof: pb_dex::ZkSwapClaimProof {
+                    inner: zero_proof(),
+                }
+                .try_into()?,
+                epoch_duration: plan.epoch_duration,
+            }),
+            DelegatorVote(plan) => Action::DelegatorVote(penumbra_governance::DelegatorVote {
+                body: plan.delegator_vote_body(fvk),
+                auth_sig: [0; 64].into(),
+                proof: pb_governance::ZkDelegatorVoteProof {
+                    inner: zero_proof(),
+                }
+                .try_into()?,
+            }),
+            UndelegateClaim(plan) => Action::UndelegateClaim(penumbra_stake::UndelegateClaim {
+                body: plan.undelegate_claim_body(),
+                proof: pb_stake::ZkUndelegateClaimProof {
+                    inner: zero_proof(),
+                }
+                .try_into()?,
+            }),
+            // None of the other actions have proofs, so they don't need any witness data.
+            other => ActionPlan::build_unauth(
+                other,
+                fvk,
+                &WitnessData {
+                    anchor: penumbra_tct::Tree::new().root(),
+                    state_commitment_proofs: Default::default(),
+                },
+                Some(memo_key.clone()),
+            )?,
+        })
+    }
+
     /// Canonical action plan ordering according to protobuf definitions
     pub fn variant_index(&self) -> usize {
         match self {
@@ -185,6 +268,7 @@ impl ActionPlan {
             ActionPlan::PositionOpen(_) => 30,
             ActionPlan::PositionClose(_) => 31,
             ActionPlan::PositionWithdraw(_) => 32,
+            ActionPlan::PositionRewardClaim(_) => 34,
             ActionPlan::Delegate(_) => 40,
             ActionPlan::Undelegate(_) => 41,
             ActionPlan::UndelegateClaim(_) => 42,
@@ -219,6 +303,7 @@ impl ActionPlan {
             PositionOpen(position_open) => position_open.balance(),
             PositionClose(position_close) => position_close.balance(),
             PositionWithdraw(position_withdraw) => position_withdraw.balance(),
+            PositionRewardClaim(position_reward_claim) => position_reward_claim.balance(),
             Ics20Withdrawal(withdrawal) => withdrawal.balance(),
             ActionDutchAuctionSchedule(action) => action.balance(),
             ActionDutchAuctionEnd(action) => action.balance(),
@@ -250,6 +335,7 @@ impl ActionPlan {
             PositionOpen(_) => Fr::zero(),
             PositionClose(_) => Fr::zero(),
             PositionWithdraw(_) => Fr::zero(),
+            PositionRewardClaim(_) => Fr::zero(),
             CommunityPoolSpend(_) => Fr::zero(),
             CommunityPoolOutput(_) => Fr::zero(),
             CommunityPoolDeposit(_) => Fr::zero(),
@@ -282,6 +368,7 @@ impl ActionPlan {
             PositionOpen(plan) => plan.effect_hash(),
             PositionClose(plan) => plan.effect_hash(),
             PositionWithdraw(plan) => plan.position_withdraw().effect_hash(),
+            PositionRewardClaim(plan) => plan.position_reward_claim().effect_hash(),
             CommunityPoolSpend(plan) => plan.effect_hash(),
             CommunityPoolOutput(plan) => plan.effect_hash(),
             CommunityPoolDeposit(plan) => plan.effect_hash(),
@@ -385,6 +472,12 @@ impl From<PositionWithdrawPlan> for ActionPlan {
     }
 }
 
+impl From<PositionRewardClaimPlan> for ActionPlan {
+    fn from(inner: PositionRewardClaimPlan) -> ActionPlan {
+        ActionPlan::PositionRewardClaim(inner)
+    }
+}
+
 impl From<CommunityPoolSpend> for ActionPlan {
     fn from(inner: CommunityPoolSpend) -> ActionPlan {
         ActionPlan::CommunityPoolSpend(inner)
@@ -503,6 +596,13 @@ impl From<ActionPlan> for pb_t::ActionPlan {
                     inner
                 ))),
             },
+            ActionPlan::PositionRewardClaim(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::PositionRewardClaim(Into::<
+                    penumbra_proto::core::component::dex::v1::PositionRewardClaimPlan,
+                >::into(
+                    inner
+                ))),
+            },
             ActionPlan::CommunityPoolDeposit(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::CommunityPoolDeposit(
                     inner.into(),
@@ -593,8 +693,8 @@ impl TryFrom<pb_t::ActionPlan> for ActionPlan {
             pb_t::action_plan::Action::PositionWithdraw(inner) => {
                 Ok(ActionPlan::PositionWithdraw(inner.try_into()?))
             }
-            pb_t::action_plan::Action::PositionRewardClaim(_) => {
-                Err(anyhow!("PositionRewardClaim is deprecated and unsupported"))
+            pb_t::action_plan::Action::PositionRewardClaim(inner) => {
+                Ok(ActionPlan::PositionRewardClaim(inner.try_into()?))
             }
             pb_t::action_plan::Action::CommunityPoolSpend(inner) => {
                 Ok(ActionPlan::CommunityPoolSpend(inner.try_into()?))
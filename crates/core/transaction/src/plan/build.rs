@@ -112,6 +112,31 @@ impl TransactionPlan {
         Ok(transaction)
     }
 
+    /// Build a stand-in for the transaction this plan describes, with the same effects, but
+    /// with zeroed proofs and signatures.
+    ///
+    /// This is not a valid transaction, but it can be viewed like one, e.g. to show what a
+    /// plan does before authorizing it, without the witness data needed to build it.
+    pub fn build_stub(&self, full_viewing_key: &FullViewingKey) -> Result<Transaction> {
+        // As for the effect hash, with no memo there are no outputs to use the memo key.
+        let memo_key = self.memo_key().unwrap_or([0u8; 32].into());
+        let actions = self
+            .actions
+            .iter()
+            .map(|action_plan| {
+                ActionPlan::build_stub(action_plan.clone(), full_viewing_key, &memo_key)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.clone().build_unauth_with_actions(
+            actions,
+            &WitnessData {
+                anchor: penumbra_tct::Tree::new().root(),
+                state_commitment_proofs: Default::default(),
+            },
+        )
+    }
+
     /// Build the serial transaction this plan describes.
     pub fn build(
         self,
@@ -254,6 +254,7 @@ impl Transaction {
                 | Action::PositionOpen(_)
                 | Action::PositionClose(_)
                 | Action::PositionWithdraw(_)
+                | Action::PositionRewardClaim(_)
                 | Action::Ics20Withdrawal(_)
                 | Action::CommunityPoolSpend(_)
                 | Action::CommunityPoolOutput(_)
@@ -0,0 +1,383 @@
+//! A tamper-evident log of the authorization requests a custody service has decided on.
+//!
+//! The log is a file of newline-delimited JSON records, each holding an [`Entry`] and its
+//! hash. Every entry includes the hash of the one before it, so that modifying, removing or
+//! reordering any entry breaks the chain from that point on, which [`AuditLog::verify`]
+//! detects. Removing entries from the end of the log can only be detected by comparing
+//! against a previously recorded [`AuditLog::head`], which operators may want to copy
+//! elsewhere periodically.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::Write as _,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context as _;
+use penumbra_asset::asset;
+use penumbra_keys::FullViewingKey;
+use penumbra_transaction::TransactionPlan;
+use serde::{Deserialize, Serialize};
+
+use crate::{policy::AuthPolicy, PreAuthorization};
+
+/// The `previous_hash` of the first entry in a log.
+const GENESIS_HASH: [u8; 32] = [0; 32];
+
+/// A single decision recorded in the audit log.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    /// The position of this entry in the log, starting from zero.
+    pub sequence: u64,
+    /// The time of the decision, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The hex-encoded hash of the previous entry.
+    pub previous_hash: String,
+    /// What was requested.
+    pub request: RequestSummary,
+    /// Whether the request was approved.
+    pub decision: Decision,
+}
+
+/// An [`Entry`] together with its hash, as stored in the log file.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct Record {
+    #[serde(flatten)]
+    pub entry: Entry,
+    /// The hex-encoded hash of the entry.
+    pub hash: String,
+}
+
+/// The kind of authorization that was requested.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RequestKind {
+    Transaction,
+    ValidatorDefinition,
+    ValidatorVote,
+}
+
+/// A summary of an authorization request.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct RequestSummary {
+    pub kind: RequestKind,
+    /// The hex-encoded effect hash of a transaction plan.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effect_hash: Option<String>,
+    /// The total value provided by the plan's actions, per asset.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub provided: Vec<AssetAmount>,
+    /// The total value required by the plan's actions and fee, per asset.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required: Vec<AssetAmount>,
+    /// The signers of the pre-authorizations presented with the request, as
+    /// `<scheme>:<base64 key>`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pre_authorizations: Vec<String>,
+}
+
+/// An amount of some asset.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct AssetAmount {
+    pub asset_id: String,
+    pub amount: String,
+}
+
+/// The outcome of an authorization request.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(tag = "outcome")]
+pub enum Decision {
+    Approved {
+        /// The policies that allowed the request.
+        policies: Vec<String>,
+    },
+    Rejected {
+        /// The policy that rejected the request, if it was rejected by a policy.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        policy: Option<String>,
+        reason: String,
+    },
+}
+
+impl RequestSummary {
+    /// Summarize a transaction plan, along with the pre-authorizations presented for it.
+    pub fn transaction(
+        plan: &TransactionPlan,
+        pre_authorizations: &[PreAuthorization],
+        fvk: &FullViewingKey,
+    ) -> Self {
+        let mut provided = BTreeMap::<asset::Id, u128>::new();
+        let mut required = BTreeMap::<asset::Id, u128>::new();
+        for action in &plan.actions {
+            let balance = action.balance();
+            for value in balance.provided() {
+                let total = provided.entry(value.asset_id).or_default();
+                *total = total.saturating_add(value.amount.value());
+            }
+            for value in balance.required() {
+                let total = required.entry(value.asset_id).or_default();
+                *total = total.saturating_add(value.amount.value());
+            }
+        }
+        let fee = plan.transaction_parameters.fee.value();
+        let total = required.entry(fee.asset_id).or_default();
+        *total = total.saturating_add(fee.amount.value());
+
+        let amounts = |totals: BTreeMap<asset::Id, u128>| {
+            totals
+                .into_iter()
+                .filter(|(_, amount)| *amount != 0)
+                .map(|(asset_id, amount)| AssetAmount {
+                    asset_id: asset_id.to_string(),
+                    amount: amount.to_string(),
+                })
+                .collect()
+        };
+
+        Self {
+            kind: RequestKind::Transaction,
+            // An unhashable plan won't be signed, but its rejection should still be logged.
+            effect_hash: plan
+                .effect_hash(fvk)
+                .ok()
+                .map(|effect_hash| hex::encode(effect_hash.as_bytes())),
+            provided: amounts(provided),
+            required: amounts(required),
+            pre_authorizations: signers(pre_authorizations),
+        }
+    }
+
+    /// Summarize a request of a kind without a transaction plan.
+    pub fn other(kind: RequestKind, pre_authorizations: &[PreAuthorization]) -> Self {
+        Self {
+            kind,
+            effect_hash: None,
+            provided: Vec::new(),
+            required: Vec::new(),
+            pre_authorizations: signers(pre_authorizations),
+        }
+    }
+}
+
+fn signers(pre_authorizations: &[PreAuthorization]) -> Vec<String> {
+    use base64::prelude::*;
+
+    pre_authorizations
+        .iter()
+        .map(|pre_authorization| {
+            let signer = pre_authorization.signer();
+            format!(
+                "{}:{}",
+                signer.scheme(),
+                BASE64_STANDARD.encode(signer.to_bytes())
+            )
+        })
+        .collect()
+}
+
+/// A short name for a policy, to record which one made a decision.
+pub fn policy_name(policy: &AuthPolicy) -> String {
+    let value = serde_json::to_value(policy).unwrap_or_default();
+    let field = |name| value.get(name).and_then(serde_json::Value::as_str);
+    match (field("type"), field("method")) {
+        (Some(kind), Some(method)) => format!("{kind}({method})"),
+        (Some(kind), None) => kind.to_owned(),
+        _ => format!("{policy:?}"),
+    }
+}
+
+impl Entry {
+    /// The hash of this entry, which the next entry will include.
+    pub fn hash(&self) -> anyhow::Result<[u8; 32]> {
+        let bytes = serde_json::to_vec(self)?;
+        let hash = blake2b_simd::Params::new()
+            .personal(b"penumbra_auditlg")
+            .hash_length(32)
+            .hash(&bytes);
+        Ok(hash.as_bytes().try_into().expect("hash length is 32 bytes"))
+    }
+}
+
+/// An append-only, hash-chained log of authorization decisions.
+pub struct AuditLog {
+    path: PathBuf,
+    /// The next sequence number and the hash of the last entry, once we've read them.
+    head: Mutex<Option<(u64, [u8; 32])>>,
+}
+
+impl AuditLog {
+    /// Use the log at `path`, which will be created if it doesn't exist yet.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            head: Mutex::new(None),
+        }
+    }
+
+    /// Record a decision about a request, returning the new record.
+    ///
+    /// Callers should not act on an approval if this fails, so that every approval is logged.
+    pub fn append(&self, request: RequestSummary, decision: Decision) -> anyhow::Result<Record> {
+        let mut head = self.head.lock().expect("audit log lock is not poisoned");
+        let (sequence, previous_hash) = match *head {
+            Some(head) => head,
+            None => Self::head(&self.path)?,
+        };
+
+        let entry = Entry {
+            sequence,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .context("system clock is before the Unix epoch")?
+                .as_secs(),
+            previous_hash: hex::encode(previous_hash),
+            request,
+            decision,
+        };
+        let hash = entry.hash()?;
+        let record = Record {
+            entry,
+            hash: hex::encode(hash),
+        };
+
+        let mut line = serde_json::to_vec(&record)?;
+        line.push(b'\n');
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("failed to open audit log {}", self.path.display()))?;
+        file.write_all(&line)?;
+        file.sync_data()?;
+
+        *head = Some((sequence + 1, hash));
+        Ok(record)
+    }
+
+    /// Read the records in the log at `path`, without checking them.
+    pub fn read(path: &Path) -> anyhow::Result<Vec<Record>> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => {
+                return Err(e)
+                    .with_context(|| format!("failed to read audit log {}", path.display()))
+            }
+        };
+        contents
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                serde_json::from_str(line)
+                    .with_context(|| format!("failed to parse line {} of the audit log", i + 1))
+            })
+            .collect()
+    }
+
+    /// Read the records in the log at `path`, checking that they form an unbroken hash chain.
+    pub fn verify(path: &Path) -> anyhow::Result<Vec<Record>> {
+        let records = Self::read(path)?;
+        let mut previous_hash = hex::encode(GENESIS_HASH);
+        for (i, record) in records.iter().enumerate() {
+            let entry = &record.entry;
+            anyhow::ensure!(
+                entry.sequence == i as u64,
+                "entry {} has sequence number {}",
+                i,
+                entry.sequence
+            );
+            anyhow::ensure!(
+                entry.previous_hash == previous_hash,
+                "entry {} does not follow the previous entry",
+                i
+            );
+            let hash = hex::encode(entry.hash()?);
+            anyhow::ensure!(
+                record.hash == hash,
+                "entry {} has been modified: its hash is {}, but {} is recorded",
+                i,
+                hash,
+                record.hash
+            );
+            previous_hash = hash;
+        }
+        Ok(records)
+    }
+
+    /// The next sequence number and the hash of the last entry of the log at `path`.
+    ///
+    /// This only reads the last record, so it does not verify the log.
+    pub fn head(path: &Path) -> anyhow::Result<(u64, [u8; 32])> {
+        let Some(last) = Self::read(path)?.pop() else {
+            return Ok((0, GENESIS_HASH));
+        };
+        let hash = hex::decode(&last.hash)?
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("audit log hash has the wrong length"))?;
+        Ok((last.entry.sequence + 1, hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{OsRng, RngCore};
+
+    use super::*;
+
+    fn append(log: &AuditLog, approved: bool) {
+        let decision = if approved {
+            Decision::Approved {
+                policies: vec!["OnlyIbcRelay".to_owned()],
+            }
+        } else {
+            Decision::Rejected {
+                policy: None,
+                reason: "declined".to_owned(),
+            }
+        };
+        log.append(
+            RequestSummary::other(RequestKind::ValidatorVote, &[]),
+            decision,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn detects_tampering() {
+        let path = std::env::temp_dir().join(format!("audit-log-{}.jsonl", OsRng.next_u64()));
+
+        append(&AuditLog::new(&path), true);
+        // A new handle picks up where the last one left off.
+        let log = AuditLog::new(&path);
+        append(&log, false);
+        append(&log, true);
+
+        let records = AuditLog::verify(&path).unwrap();
+        assert_eq!(records.len(), 3);
+        assert_eq!(
+            AuditLog::head(&path).unwrap().1.to_vec(),
+            hex::decode(&records[2].hash).unwrap()
+        );
+
+        // Changing a decision after the fact is detected...
+        let mut lines = fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(str::to_owned)
+            .collect::<Vec<_>>();
+        let original = lines.clone();
+        lines[1] = lines[1].replace("declined", "approved");
+        fs::write(&path, lines.join("\n")).unwrap();
+        assert!(AuditLog::verify(&path).is_err());
+
+        // ...as is removing an entry from the middle of the log.
+        let mut lines = original;
+        lines.remove(1);
+        fs::write(&path, lines.join("\n")).unwrap();
+        assert!(AuditLog::verify(&path).is_err());
+
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,47 @@
+//! A reference implementation of an external signer, backed by the software KMS.
+//!
+//! Usage:
+//!
+//! ```text
+//! penumbra-reference-signer <CONFIG> [--socket <PATH>]
+//! ```
+//!
+//! `CONFIG` is a JSON file containing a software KMS config. With `--socket`, the signer
+//! listens on a Unix socket at `PATH`; otherwise, it answers requests on its standard
+//! input and output, so that it can be used with the `Command` transport.
+//!
+//! Integrations with hardware wallets and HSMs can follow the same structure, replacing
+//! the software KMS with their own implementation of the custody service.
+
+use std::{path::PathBuf, sync::Arc};
+
+use anyhow::{Context, Result};
+use penumbra_custody::{external, soft_kms};
+
+const USAGE: &str = "usage: penumbra-reference-signer <CONFIG> [--socket <PATH>]";
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let config_path = args.next().context(USAGE)?;
+    let socket = match (args.next().as_deref(), args.next()) {
+        (None, _) => None,
+        (Some("--socket"), Some(path)) => Some(PathBuf::from(path)),
+        _ => anyhow::bail!(USAGE),
+    };
+
+    let config: soft_kms::Config = serde_json::from_str(
+        &std::fs::read_to_string(&config_path)
+            .with_context(|| format!("failed to read config from {config_path}"))?,
+    )?;
+    let kms = soft_kms::SoftKms::new(config);
+
+    match socket {
+        Some(path) => {
+            // Nothing but protocol messages can go to stdout, so we log to stderr.
+            eprintln!("listening for signing requests on {}", path.display());
+            external::serve_socket(Arc::new(kms), path).await
+        }
+        None => external::serve(&kms, tokio::io::stdin(), tokio::io::stdout()).await,
+    }
+}
@@ -0,0 +1,334 @@
+//! A custody backend that forwards requests to a separate signer process.
+//!
+//! Requests are sent as length-delimited [`pb::ExternalSignerRequest`] messages, either over
+//! a Unix socket, or over the standard input of a command run for each request, and the
+//! signer replies with a single [`pb::ExternalSignerResponse`]. This allows hardware
+//! wallets, HSMs and other signers to be integrated without living in this repository.
+//!
+//! The [`serve`] and [`serve_socket`] functions implement the signer side of the protocol
+//! on top of any other custody service.
+
+use std::{path::PathBuf, sync::Arc};
+
+use anyhow::Result;
+use penumbra_proto::{
+    custody::v1::{
+        self as pb, custody_service_server::CustodyService,
+        external_signer_request::Request as SignerRequest,
+        external_signer_response::Response as SignerResponse,
+    },
+    Message,
+};
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tonic::{async_trait, Request, Response, Status};
+
+/// The largest message we'll accept from the other side of the connection.
+const MAX_MESSAGE_SIZE: u32 = 16 * 1024 * 1024;
+
+/// How to reach an external signer.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(tag = "transport")]
+pub enum Config {
+    /// A signer listening on a Unix socket at this path.
+    Socket { path: PathBuf },
+    /// A command to run for each request, exchanging messages over its standard input and output.
+    Command {
+        program: PathBuf,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        args: Vec<String>,
+    },
+}
+
+/// Write a single length-delimited message.
+pub async fn write_message<M: Message>(
+    writer: &mut (impl AsyncWrite + Unpin),
+    message: &M,
+) -> Result<()> {
+    let bytes = message.encode_to_vec();
+    writer.write_u32(bytes.len().try_into()?).await?;
+    writer.write_all(&bytes).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+/// Read a single length-delimited message, or `None` if the stream ended cleanly.
+pub async fn read_message<M: Message + Default>(
+    reader: &mut (impl AsyncRead + Unpin),
+) -> Result<Option<M>> {
+    let len = match reader.read_u32().await {
+        Ok(len) => len,
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    anyhow::ensure!(
+        len <= MAX_MESSAGE_SIZE,
+        "message of {len} bytes is too large"
+    );
+    let mut bytes = vec![0u8; len as usize];
+    reader.read_exact(&mut bytes).await?;
+    Ok(Some(M::decode(bytes.as_slice())?))
+}
+
+/// A custody backend forwarding requests to an external signer.
+pub struct External {
+    config: Config,
+}
+
+impl External {
+    /// Initialize with the given [`Config`].
+    pub fn new(config: Config) -> Self {
+        Self { config }
+    }
+
+    async fn exchange(
+        &self,
+        request: pb::ExternalSignerRequest,
+    ) -> Result<pb::ExternalSignerResponse> {
+        let response = match &self.config {
+            Config::Socket { path } => {
+                let mut stream = tokio::net::UnixStream::connect(path).await?;
+                write_message(&mut stream, &request).await?;
+                read_message(&mut stream).await?
+            }
+            Config::Command { program, args } => {
+                let mut child = tokio::process::Command::new(program)
+                    .args(args)
+                    .stdin(std::process::Stdio::piped())
+                    .stdout(std::process::Stdio::piped())
+                    .kill_on_drop(true)
+                    .spawn()?;
+                {
+                    // Dropping stdin closes it, which tells the signer there are no more requests.
+                    let mut stdin = child.stdin.take().expect("stdin is piped");
+                    write_message(&mut stdin, &request).await?;
+                }
+                let mut stdout = child.stdout.take().expect("stdout is piped");
+                let response = read_message(&mut stdout).await?;
+                child.wait().await?;
+                response
+            }
+        };
+        response.ok_or_else(|| anyhow::anyhow!("external signer closed the connection"))
+    }
+
+    /// Send a request to the signer, turning any errors it reports into a [`Status`].
+    async fn request(&self, request: SignerRequest) -> Result<SignerResponse, Status> {
+        let response = self
+            .exchange(pb::ExternalSignerRequest {
+                request: Some(request),
+            })
+            .await
+            .map_err(|e| Status::unavailable(format!("error contacting external signer: {e:#}")))?;
+        match response.response {
+            Some(SignerResponse::Error(error)) => Err(Status::unauthenticated(error)),
+            Some(response) => Ok(response),
+            None => Err(Status::internal("external signer sent an empty response")),
+        }
+    }
+}
+
+fn wrong_response() -> Status {
+    Status::internal("external signer sent a response of the wrong kind")
+}
+
+#[async_trait]
+impl CustodyService for External {
+    async fn authorize(
+        &self,
+        request: Request<pb::AuthorizeRequest>,
+    ) -> Result<Response<pb::AuthorizeResponse>, Status> {
+        match self
+            .request(SignerRequest::Authorize(request.into_inner()))
+            .await?
+        {
+            SignerResponse::Authorize(response) => Ok(Response::new(response)),
+            _ => Err(wrong_response()),
+        }
+    }
+
+    async fn authorize_validator_definition(
+        &self,
+        request: Request<pb::AuthorizeValidatorDefinitionRequest>,
+    ) -> Result<Response<pb::AuthorizeValidatorDefinitionResponse>, Status> {
+        match self
+            .request(SignerRequest::AuthorizeValidatorDefinition(
+                request.into_inner(),
+            ))
+            .await?
+        {
+            SignerResponse::AuthorizeValidatorDefinition(response) => Ok(Response::new(response)),
+            _ => Err(wrong_response()),
+        }
+    }
+
+    async fn authorize_validator_vote(
+        &self,
+        request: Request<pb::AuthorizeValidatorVoteRequest>,
+    ) -> Result<Response<pb::AuthorizeValidatorVoteResponse>, Status> {
+        match self
+            .request(SignerRequest::AuthorizeValidatorVote(request.into_inner()))
+            .await?
+        {
+            SignerResponse::AuthorizeValidatorVote(response) => Ok(Response::new(response)),
+            _ => Err(wrong_response()),
+        }
+    }
+
+    async fn export_full_viewing_key(
+        &self,
+        request: Request<pb::ExportFullViewingKeyRequest>,
+    ) -> Result<Response<pb::ExportFullViewingKeyResponse>, Status> {
+        match self
+            .request(SignerRequest::ExportFullViewingKey(request.into_inner()))
+            .await?
+        {
+            SignerResponse::ExportFullViewingKey(response) => Ok(Response::new(response)),
+            _ => Err(wrong_response()),
+        }
+    }
+
+    async fn confirm_address(
+        &self,
+        request: Request<pb::ConfirmAddressRequest>,
+    ) -> Result<Response<pb::ConfirmAddressResponse>, Status> {
+        match self
+            .request(SignerRequest::ConfirmAddress(request.into_inner()))
+            .await?
+        {
+            SignerResponse::ConfirmAddress(response) => Ok(Response::new(response)),
+            _ => Err(wrong_response()),
+        }
+    }
+}
+
+/// Handle a single request with `service`, reporting any error in the response.
+async fn handle<S: CustodyService>(
+    service: &S,
+    request: pb::ExternalSignerRequest,
+) -> pb::ExternalSignerResponse {
+    let result = match request.request {
+        Some(SignerRequest::Authorize(request)) => service
+            .authorize(Request::new(request))
+            .await
+            .map(|rsp| SignerResponse::Authorize(rsp.into_inner())),
+        Some(SignerRequest::AuthorizeValidatorDefinition(request)) => service
+            .authorize_validator_definition(Request::new(request))
+            .await
+            .map(|rsp| SignerResponse::AuthorizeValidatorDefinition(rsp.into_inner())),
+        Some(SignerRequest::AuthorizeValidatorVote(request)) => service
+            .authorize_validator_vote(Request::new(request))
+            .await
+            .map(|rsp| SignerResponse::AuthorizeValidatorVote(rsp.into_inner())),
+        Some(SignerRequest::ExportFullViewingKey(request)) => service
+            .export_full_viewing_key(Request::new(request))
+            .await
+            .map(|rsp| SignerResponse::ExportFullViewingKey(rsp.into_inner())),
+        Some(SignerRequest::ConfirmAddress(request)) => service
+            .confirm_address(Request::new(request))
+            .await
+            .map(|rsp| SignerResponse::ConfirmAddress(rsp.into_inner())),
+        None => Err(Status::invalid_argument("empty external signer request")),
+    };
+    pb::ExternalSignerResponse {
+        response: Some(
+            result.unwrap_or_else(|status| SignerResponse::Error(status.message().to_owned())),
+        ),
+    }
+}
+
+/// Answer requests read from `reader` with `service`, until the stream ends.
+///
+/// This is the signer side of the protocol, e.g. over standard input and output.
+pub async fn serve<S: CustodyService>(
+    service: &S,
+    mut reader: impl AsyncRead + Unpin,
+    mut writer: impl AsyncWrite + Unpin,
+) -> Result<()> {
+    while let Some(request) = read_message(&mut reader).await? {
+        let response = handle(service, request).await;
+        write_message(&mut writer, &response).await?;
+    }
+    Ok(())
+}
+
+/// Answer requests from every connection to a Unix socket at `path` with `service`.
+pub async fn serve_socket<S: CustodyService>(service: Arc<S>, path: PathBuf) -> Result<()> {
+    let listener = tokio::net::UnixListener::bind(&path)?;
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let service = service.clone();
+        tokio::spawn(async move {
+            let (reader, writer) = stream.into_split();
+            if let Err(e) = serve(service.as_ref(), reader, writer).await {
+                tracing::warn!(?e, "error serving external signer connection");
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_keys::keys::{SeedPhrase, SpendKey};
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::soft_kms::SoftKms;
+
+    #[tokio::test]
+    async fn test_socket_signer() -> Result<()> {
+        let spend_key = SpendKey::from_seed_phrase_bip44(
+            SeedPhrase::generate(OsRng),
+            &penumbra_keys::keys::Bip44Path::new(0),
+        );
+        let path = std::env::temp_dir().join(format!(
+            "penumbra-external-signer-{}.sock",
+            hex::encode(rand_core::RngCore::next_u64(&mut OsRng).to_le_bytes())
+        ));
+        tokio::spawn(serve_socket(
+            Arc::new(SoftKms::new(spend_key.clone().into())),
+            path.clone(),
+        ));
+        // Wait for the signer to start listening.
+        while !path.exists() {
+            tokio::task::yield_now().await;
+        }
+
+        let external = External::new(Config::Socket { path: path.clone() });
+        let fvk = external
+            .export_full_viewing_key(Request::new(Default::default()))
+            .await?
+            .into_inner()
+            .full_viewing_key;
+        assert_eq!(fvk, Some(spend_key.full_viewing_key().clone().into()));
+
+        // Addresses are derived by the signer.
+        let address = external
+            .confirm_address(Request::new(pb::ConfirmAddressRequest {
+                address_index: Some(penumbra_keys::keys::AddressIndex::new(1).into()),
+            }))
+            .await?
+            .into_inner()
+            .address;
+        assert_eq!(
+            address,
+            Some(
+                spend_key
+                    .full_viewing_key()
+                    .payment_address(1.into())
+                    .0
+                    .into()
+            )
+        );
+
+        // Errors from the signer are passed along.
+        let status = external
+            .authorize(Request::new(Default::default()))
+            .await
+            .expect_err("an empty plan should be rejected");
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+}
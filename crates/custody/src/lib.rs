@@ -15,14 +15,16 @@ mod pre_auth;
 mod request;
 mod terminal;
 
+pub mod audit;
 pub mod encrypted;
+pub mod external;
 pub mod null_kms;
 pub mod policy;
 pub mod soft_kms;
 pub mod threshold;
 
 pub use client::CustodyClient;
-pub use pre_auth::PreAuthorization;
+pub use pre_auth::{PreAuthorization, PreAuthorizationKey};
 pub use request::{
     AuthorizeRequest, AuthorizeValidatorDefinitionRequest, AuthorizeValidatorVoteRequest,
 };
@@ -2,6 +2,8 @@
 
 use std::collections::HashSet;
 
+use decaf377_rdsa::SpendAuth;
+use penumbra_asset::{asset, Value};
 use penumbra_keys::Address;
 use penumbra_proto::{
     core::{
@@ -13,14 +15,19 @@ use penumbra_proto::{
     },
     Message as _,
 };
-use penumbra_transaction::plan::ActionPlan;
+use penumbra_transaction::{plan::ActionPlan, TransactionPlan};
 use serde::{Deserialize, Serialize};
+use serde_with::DisplayFromStr;
 
 use crate::{
     AuthorizeRequest, AuthorizeValidatorDefinitionRequest, AuthorizeValidatorVoteRequest,
-    PreAuthorization,
+    PreAuthorization, PreAuthorizationKey,
 };
 
+mod spend_limit;
+
+pub use spend_limit::{PendingOutflows, SpendLimit, SpendLimitPolicy};
+
 /// A trait for checking whether a transaction plan is allowed by a policy.
 pub trait Policy {
     /// Checks whether the proposed transaction plan is allowed by this policy.
@@ -42,8 +49,12 @@ pub trait Policy {
 /// file.  More complex policy logic than than should be implemented by a custom implementation of
 /// the [`Policy`] trait.
 ///
+/// Policies can be combined with the `All`, `Any` and `Not` combinators to express rules like
+/// "`OnlyIbcRelay` or (`DestinationAllowList` and `PreAuthorization`)".
+///
 /// These policies do not permit validator votes or validator definition updates, so a custom policy
 /// must be used to approve these actions.
+#[serde_as]
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 #[serde(tag = "type")]
 pub enum AuthPolicy {
@@ -61,6 +72,309 @@ pub enum AuthPolicy {
     OnlyIbcRelay,
     /// Require specific pre-authorizations for submitted [`TransactionPlan`](penumbra_transaction::TransactionPlan)s.
     PreAuthorization(PreAuthorizationPolicy),
+    /// Limit the value of each asset spent per transaction and within a rolling time window.
+    ///
+    /// Unlike the other policies, this one is stateful: every transaction that is authorized is
+    /// recorded in a ledger on disk, once all the other policies have allowed it too.
+    SpendLimit(SpendLimitPolicy),
+    /// Only allow transactions that every one of the inner policies allows.
+    All { policies: Vec<AuthPolicy> },
+    /// Only allow transactions that at least one of the inner policies allows.
+    Any { policies: Vec<AuthPolicy> },
+    /// Only allow transactions that the inner policy rejects.
+    ///
+    /// The inner policy may not be stateful.
+    Not { policy: Box<AuthPolicy> },
+    /// Only allow transactions whose actions are all of the listed kinds.
+    AllowedActions { actions: Vec<ActionKind> },
+    /// Only allow transactions whose actions and fee only involve the listed assets.
+    AllowedAssets {
+        #[serde_as(as = "Vec<DisplayFromStr>")]
+        asset_ids: Vec<asset::Id>,
+    },
+    /// Only allow transactions paying at most `max_fee` (in base units of the fee asset).
+    MaxFee {
+        #[serde_as(as = "DisplayFromStr")]
+        max_fee: u128,
+    },
+    /// Only allow transactions sending at most `max_amount` of the asset to other addresses.
+    ///
+    /// Outputs sent back to the address of a note spent by the transaction, such as change, are
+    /// not counted.
+    MaxOutputValue {
+        #[serde_as(as = "DisplayFromStr")]
+        asset_id: asset::Id,
+        #[serde_as(as = "DisplayFromStr")]
+        max_amount: u128,
+    },
+}
+
+/// The kinds of action a transaction plan can contain, for use in an
+/// [`AuthPolicy::AllowedActions`] policy.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ActionKind {
+    Spend,
+    Output,
+    Swap,
+    SwapClaim,
+    ValidatorDefinition,
+    IbcAction,
+    ProposalSubmit,
+    ProposalWithdraw,
+    ValidatorVote,
+    DelegatorVote,
+    ProposalDepositClaim,
+    PositionOpen,
+    PositionClose,
+    PositionWithdraw,
+    PositionRewardClaim,
+    Delegate,
+    Undelegate,
+    UndelegateClaim,
+    CommunityPoolSpend,
+    CommunityPoolOutput,
+    CommunityPoolDeposit,
+    Ics20Withdrawal,
+    ActionDutchAuctionSchedule,
+    ActionDutchAuctionEnd,
+    ActionDutchAuctionWithdraw,
+}
+
+impl ActionKind {
+    /// The kind of the given action plan.
+    pub fn of(action: &ActionPlan) -> Self {
+        match action {
+            ActionPlan::Spend(_) => ActionKind::Spend,
+            ActionPlan::Output(_) => ActionKind::Output,
+            ActionPlan::Swap(_) => ActionKind::Swap,
+            ActionPlan::SwapClaim(_) => ActionKind::SwapClaim,
+            ActionPlan::ValidatorDefinition(_) => ActionKind::ValidatorDefinition,
+            ActionPlan::IbcAction(_) => ActionKind::IbcAction,
+            ActionPlan::ProposalSubmit(_) => ActionKind::ProposalSubmit,
+            ActionPlan::ProposalWithdraw(_) => ActionKind::ProposalWithdraw,
+            ActionPlan::ValidatorVote(_) => ActionKind::ValidatorVote,
+            ActionPlan::DelegatorVote(_) => ActionKind::DelegatorVote,
+            ActionPlan::ProposalDepositClaim(_) => ActionKind::ProposalDepositClaim,
+            ActionPlan::PositionOpen(_) => ActionKind::PositionOpen,
+            ActionPlan::PositionClose(_) => ActionKind::PositionClose,
+            ActionPlan::PositionWithdraw(_) => ActionKind::PositionWithdraw,
+            ActionPlan::PositionRewardClaim(_) => ActionKind::PositionRewardClaim,
+            ActionPlan::Delegate(_) => ActionKind::Delegate,
+            ActionPlan::Undelegate(_) => ActionKind::Undelegate,
+            ActionPlan::UndelegateClaim(_) => ActionKind::UndelegateClaim,
+            ActionPlan::CommunityPoolSpend(_) => ActionKind::CommunityPoolSpend,
+            ActionPlan::CommunityPoolOutput(_) => ActionKind::CommunityPoolOutput,
+            ActionPlan::CommunityPoolDeposit(_) => ActionKind::CommunityPoolDeposit,
+            ActionPlan::Ics20Withdrawal(_) => ActionKind::Ics20Withdrawal,
+            ActionPlan::ActionDutchAuctionSchedule(_) => ActionKind::ActionDutchAuctionSchedule,
+            ActionPlan::ActionDutchAuctionEnd(_) => ActionKind::ActionDutchAuctionEnd,
+            ActionPlan::ActionDutchAuctionWithdraw(_) => ActionKind::ActionDutchAuctionWithdraw,
+        }
+    }
+}
+
+/// The state changes that stateful policies make when they allow a transaction.
+///
+/// These are collected while the transaction is checked against every policy, and only committed
+/// once all of them have allowed it, so that a policy rejecting the transaction can't leave
+/// another policy's state behind.
+#[derive(Clone, Debug, Default)]
+pub struct PolicyEffects {
+    outflows: Vec<PendingOutflows>,
+}
+
+impl PolicyEffects {
+    /// Commits the effects: either all of them, or none if a spend limit has been used up since
+    /// the transaction was checked.
+    pub fn commit(self) -> anyhow::Result<()> {
+        spend_limit::record_outflows(&self.outflows)
+    }
+}
+
+impl AuthPolicy {
+    /// Whether this policy has effects to commit when it allows a transaction.
+    pub fn is_stateful(&self) -> bool {
+        match self {
+            AuthPolicy::SpendLimit(_) => true,
+            AuthPolicy::All { policies } | AuthPolicy::Any { policies } => {
+                policies.iter().any(AuthPolicy::is_stateful)
+            }
+            AuthPolicy::Not { policy } => policy.is_stateful(),
+            _ => false,
+        }
+    }
+
+    /// Checks whether the proposed transaction plan is allowed by this policy, without recording
+    /// anything in the state of stateful policies.
+    pub fn dry_run(&self, request: &AuthorizeRequest) -> anyhow::Result<()> {
+        self.evaluate(request, &mut PolicyEffects::default())
+    }
+
+    /// Checks whether the proposed transaction plan is allowed by this policy, adding the state
+    /// changes of any stateful policies that allow it to `effects`.
+    ///
+    /// Nothing is recorded until the `effects` are committed.
+    pub fn evaluate(
+        &self,
+        request: &AuthorizeRequest,
+        effects: &mut PolicyEffects,
+    ) -> anyhow::Result<()> {
+        let plan = &request.plan;
+        match self {
+            AuthPolicy::DestinationAllowList {
+                allowed_destination_addresses,
+            } => {
+                for output in plan.output_plans() {
+                    if !allowed_destination_addresses.contains(&output.dest_address) {
+                        anyhow::bail!("output {:?} has dest_address not in allow list", output);
+                    }
+                }
+                for swap in plan.swap_plans() {
+                    if !allowed_destination_addresses.contains(&swap.swap_plaintext.claim_address) {
+                        anyhow::bail!("swap {:?} has claim_address not in allow list", swap);
+                    }
+                }
+                Ok(())
+            }
+            AuthPolicy::OnlyIbcRelay => {
+                for action in &plan.actions {
+                    match action {
+                        ActionPlan::Spend { .. }
+                        | ActionPlan::Output { .. }
+                        | ActionPlan::IbcAction { .. } => {}
+                        _ => {
+                            anyhow::bail!("action {:?} not allowed by OnlyRelay policy", action);
+                        }
+                    }
+                }
+                Ok(())
+            }
+            AuthPolicy::PreAuthorization(policy) => policy.check_transaction(request),
+            AuthPolicy::SpendLimit(policy) => {
+                effects.outflows.push(policy.check_plan(plan)?);
+                Ok(())
+            }
+            AuthPolicy::All { policies } => {
+                for policy in stateful_last(policies) {
+                    policy.evaluate(request, effects)?;
+                }
+                Ok(())
+            }
+            AuthPolicy::Any { policies } => {
+                let mut errors = Vec::new();
+                for policy in stateful_last(policies) {
+                    // Only the alternative that allows the transaction gets to commit its effects.
+                    let mut alternative_effects = PolicyEffects::default();
+                    match policy.evaluate(request, &mut alternative_effects) {
+                        Ok(()) => {
+                            effects.outflows.extend(alternative_effects.outflows);
+                            return Ok(());
+                        }
+                        Err(e) => errors.push(format!("{e:#}")),
+                    }
+                }
+                anyhow::bail!(
+                    "transaction not allowed by any alternative policy: {}",
+                    errors.join("; ")
+                );
+            }
+            AuthPolicy::Not { policy } => {
+                if policy.is_stateful() {
+                    anyhow::bail!("stateful policies cannot be negated");
+                }
+                match policy.dry_run(request) {
+                    Ok(()) => anyhow::bail!("transaction allowed by negated policy {:?}", policy),
+                    Err(_) => Ok(()),
+                }
+            }
+            AuthPolicy::AllowedActions { actions } => {
+                for action in &plan.actions {
+                    let kind = ActionKind::of(action);
+                    if !actions.contains(&kind) {
+                        anyhow::bail!(
+                            "action kind {:?} not allowed by AllowedActions policy",
+                            kind
+                        );
+                    }
+                }
+                Ok(())
+            }
+            AuthPolicy::AllowedAssets { asset_ids } => {
+                let fee_asset_id = plan.transaction_parameters.fee.asset_id();
+                if !asset_ids.contains(&fee_asset_id) {
+                    anyhow::bail!(
+                        "fee asset {} not allowed by AllowedAssets policy",
+                        fee_asset_id
+                    );
+                }
+                for action in &plan.actions {
+                    let balance = action.balance();
+                    for value in balance.provided().chain(balance.required()) {
+                        if !asset_ids.contains(&value.asset_id) {
+                            anyhow::bail!(
+                                "action {:?} involves asset {} not allowed by AllowedAssets policy",
+                                ActionKind::of(action),
+                                value.asset_id
+                            );
+                        }
+                    }
+                }
+                Ok(())
+            }
+            AuthPolicy::MaxFee { max_fee } => {
+                let fee = plan.transaction_parameters.fee.amount().value();
+                if fee > *max_fee {
+                    anyhow::bail!("fee {} exceeds the maximum fee of {}", fee, max_fee);
+                }
+                Ok(())
+            }
+            AuthPolicy::MaxOutputValue {
+                asset_id,
+                max_amount,
+            } => {
+                let total = external_output_values(plan)
+                    .into_iter()
+                    .filter(|value| value.asset_id == *asset_id)
+                    .map(|value| value.amount.value())
+                    .fold(0u128, u128::saturating_add);
+                if total > *max_amount {
+                    anyhow::bail!(
+                        "outputs send {} of asset {}, exceeding the maximum of {}",
+                        total,
+                        asset_id,
+                        max_amount
+                    );
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Orders the policies so that the cheap stateless ones are checked before the stateful ones,
+/// which have to read their state from disk.
+pub fn stateful_last(policies: &[AuthPolicy]) -> impl Iterator<Item = &AuthPolicy> {
+    policies
+        .iter()
+        .filter(|policy| !policy.is_stateful())
+        .chain(policies.iter().filter(|policy| policy.is_stateful()))
+}
+
+/// The addresses of the notes the plan spends: outputs sent back to them, such as change, don't
+/// leave the wallet.
+fn own_addresses(plan: &TransactionPlan) -> Vec<Address> {
+    plan.spend_plans()
+        .map(|spend| spend.note.address())
+        .collect()
+}
+
+/// The values of the plan's outputs that aren't sent back to the address of a note it spends.
+fn external_output_values(plan: &TransactionPlan) -> Vec<Value> {
+    let own_addresses = own_addresses(plan);
+    plan.output_plans()
+        .filter(|output| !own_addresses.contains(&output.dest_address))
+        .map(|output| output.value)
+        .collect()
 }
 
 /// A set of pre-authorization policies.
@@ -78,54 +392,113 @@ pub enum PreAuthorizationPolicy {
         /// Each `allowed_signer`'s contributions count only once towards this total.
         required_signatures: u32,
         /// A list of pre-authorization keys that can be used to authorize a transaction plan.
-        #[serde(with = "ed25519_vec_base64")]
+        #[serde(with = "key_vec_base64")]
         allowed_signers: Vec<ed25519_consensus::VerificationKey>,
     },
+    /// ECDSA signatures over the NIST P-256 curve, as produced by cloud KMS and WebAuthn devices.
+    EcdsaP256 {
+        /// The number of distinct pre-authorizations required to authorize a transaction plan.
+        ///
+        /// Each `allowed_signer`'s contributions count only once towards this total.
+        required_signatures: u32,
+        /// A list of SEC1-encoded pre-authorization keys that can be used to authorize a
+        /// transaction plan.
+        #[serde(with = "key_vec_base64")]
+        allowed_signers: Vec<p256::ecdsa::VerifyingKey>,
+    },
+    /// decaf377-rdsa spend authorization signatures.
+    DecafRdsa {
+        /// The number of distinct pre-authorizations required to authorize a transaction plan.
+        ///
+        /// Each `allowed_signer`'s contributions count only once towards this total.
+        required_signatures: u32,
+        /// A list of pre-authorization keys that can be used to authorize a transaction plan.
+        #[serde(with = "key_vec_base64")]
+        allowed_signers: Vec<decaf377_rdsa::VerificationKey<SpendAuth>>,
+    },
+    /// Signers of any of the supported schemes, which all count towards the same total.
+    ///
+    /// In toml, each signer is written as `{ scheme = 'EcdsaP256', key = '<base64>' }`.
+    Mixed {
+        /// The number of distinct pre-authorizations required to authorize a transaction plan.
+        ///
+        /// Each `allowed_signer`'s contributions count only once towards this total.
+        required_signatures: u32,
+        /// A list of pre-authorization keys that can be used to authorize a transaction plan.
+        allowed_signers: Vec<PreAuthorizationKey>,
+    },
 }
 
 impl PreAuthorizationPolicy {
+    /// The number of signatures required, and the keys allowed to provide them.
+    fn signers(&self) -> (u32, HashSet<PreAuthorizationKey>) {
+        match self {
+            PreAuthorizationPolicy::Ed25519 {
+                required_signatures,
+                allowed_signers,
+            } => (
+                *required_signatures,
+                allowed_signers
+                    .iter()
+                    .map(|vk| PreAuthorizationKey::Ed25519(*vk))
+                    .collect(),
+            ),
+            PreAuthorizationPolicy::EcdsaP256 {
+                required_signatures,
+                allowed_signers,
+            } => (
+                *required_signatures,
+                allowed_signers
+                    .iter()
+                    .map(|vk| PreAuthorizationKey::EcdsaP256(*vk))
+                    .collect(),
+            ),
+            PreAuthorizationPolicy::DecafRdsa {
+                required_signatures,
+                allowed_signers,
+            } => (
+                *required_signatures,
+                allowed_signers
+                    .iter()
+                    .map(|vk| PreAuthorizationKey::DecafRdsa(*vk))
+                    .collect(),
+            ),
+            PreAuthorizationPolicy::Mixed {
+                required_signatures,
+                allowed_signers,
+            } => (
+                *required_signatures,
+                allowed_signers.iter().cloned().collect(),
+            ),
+        }
+    }
+
     fn check_pre_authorizations(
         &self,
         pre_authorizations: &[PreAuthorization],
         signed_data: impl AsRef<[u8]>,
     ) -> anyhow::Result<()> {
         let signed_data = signed_data.as_ref();
-        match self {
-            PreAuthorizationPolicy::Ed25519 {
-                required_signatures,
-                allowed_signers,
-            } => {
-                #[allow(clippy::unnecessary_filter_map)]
-                let ed25519_pre_auths =
-                    pre_authorizations
-                        .iter()
-                        .filter_map(|pre_auth| match pre_auth {
-                            PreAuthorization::Ed25519(pre_auth) => Some(pre_auth),
-                            // _ => None,
-                        });
-
-                let mut allowed_signers = allowed_signers.iter().cloned().collect::<HashSet<_>>();
-                let mut seen_signers = HashSet::new();
-
-                for pre_auth in ed25519_pre_auths {
-                    // Remove the signer from the allowed signers set, so that
-                    // each signer can only submit one pre-authorization.
-                    if let Some(signer) = allowed_signers.take(&pre_auth.vk) {
-                        pre_auth.verify(signed_data)?;
-                        seen_signers.insert(signer);
-                    }
-                }
+        let (required_signatures, mut allowed_signers) = self.signers();
+        let mut seen_signers = HashSet::new();
 
-                if seen_signers.len() < *required_signatures as usize {
-                    anyhow::bail!(
-                        "required {} pre-authorization signatures but only saw {}",
-                        required_signatures,
-                        seen_signers.len(),
-                    );
-                }
-                Ok(())
+        for pre_auth in pre_authorizations {
+            // Remove the signer from the allowed signers set, so that
+            // each signer can only submit one pre-authorization.
+            if let Some(signer) = allowed_signers.take(&pre_auth.signer()) {
+                pre_auth.verify(signed_data)?;
+                seen_signers.insert(signer);
             }
         }
+
+        if seen_signers.len() < required_signatures as usize {
+            anyhow::bail!(
+                "required {} pre-authorization signatures but only saw {}",
+                required_signatures,
+                seen_signers.len(),
+            );
+        }
+        Ok(())
     }
 }
 
@@ -165,23 +538,69 @@ mod address_as_string {
 /// and Go's Ed25519 keys are byte[] values, this hopefully makes it easier to
 /// copy-paste pre-authorization keys from Go programs into the Rust config.
 // TODO: remove this after <https://github.com/penumbra-zone/ed25519-consensus/issues/7>
-mod ed25519_vec_base64 {
+mod key_vec_base64 {
     use base64::prelude::*;
 
-    pub fn serialize<S: serde::Serializer>(
-        keys: &[ed25519_consensus::VerificationKey],
+    use crate::PreAuthorizationKey;
+
+    /// A key type that can be used in a pre-authorization policy.
+    pub trait Key: Sized {
+        fn into_key(self) -> PreAuthorizationKey;
+        fn from_key(key: PreAuthorizationKey) -> Option<Self>;
+        const SCHEME: &'static str;
+    }
+
+    impl Key for ed25519_consensus::VerificationKey {
+        fn into_key(self) -> PreAuthorizationKey {
+            PreAuthorizationKey::Ed25519(self)
+        }
+        fn from_key(key: PreAuthorizationKey) -> Option<Self> {
+            match key {
+                PreAuthorizationKey::Ed25519(vk) => Some(vk),
+                _ => None,
+            }
+        }
+        const SCHEME: &'static str = "Ed25519";
+    }
+
+    impl Key for p256::ecdsa::VerifyingKey {
+        fn into_key(self) -> PreAuthorizationKey {
+            PreAuthorizationKey::EcdsaP256(self)
+        }
+        fn from_key(key: PreAuthorizationKey) -> Option<Self> {
+            match key {
+                PreAuthorizationKey::EcdsaP256(vk) => Some(vk),
+                _ => None,
+            }
+        }
+        const SCHEME: &'static str = "EcdsaP256";
+    }
+
+    impl Key for decaf377_rdsa::VerificationKey<decaf377_rdsa::SpendAuth> {
+        fn into_key(self) -> PreAuthorizationKey {
+            PreAuthorizationKey::DecafRdsa(self)
+        }
+        fn from_key(key: PreAuthorizationKey) -> Option<Self> {
+            match key {
+                PreAuthorizationKey::DecafRdsa(vk) => Some(vk),
+                _ => None,
+            }
+        }
+        const SCHEME: &'static str = "DecafRdsa";
+    }
+
+    pub fn serialize<K: Key + Copy, S: serde::Serializer>(
+        keys: &[K],
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
         use serde::Serialize;
         let mut base64_keys = Vec::with_capacity(keys.len());
         for key in keys {
-            base64_keys.push(BASE64_STANDARD.encode(key.as_bytes()));
+            base64_keys.push(BASE64_STANDARD.encode(key.into_key().to_bytes()));
         }
         base64_keys.serialize(serializer)
     }
-    pub fn deserialize<'de, D>(
-        deserializer: D,
-    ) -> Result<Vec<ed25519_consensus::VerificationKey>, D::Error>
+    pub fn deserialize<'de, K: Key, D>(deserializer: D) -> Result<Vec<K>, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
@@ -192,9 +611,9 @@ mod ed25519_vec_base64 {
             let bytes = BASE64_STANDARD
                 .decode(base64_key)
                 .map_err(serde::de::Error::custom)?;
-            let vk = ed25519_consensus::VerificationKey::try_from(bytes.as_slice())
+            let key = PreAuthorizationKey::from_bytes(K::SCHEME, &bytes)
                 .map_err(serde::de::Error::custom)?;
-            vks.push(vk);
+            vks.push(K::from_key(key).expect("parsed a key of the right scheme"));
         }
         Ok(vks)
     }
@@ -202,38 +621,9 @@ mod ed25519_vec_base64 {
 
 impl Policy for AuthPolicy {
     fn check_transaction(&self, request: &AuthorizeRequest) -> anyhow::Result<()> {
-        let plan = &request.plan;
-        match self {
-            AuthPolicy::DestinationAllowList {
-                allowed_destination_addresses,
-            } => {
-                for output in plan.output_plans() {
-                    if !allowed_destination_addresses.contains(&output.dest_address) {
-                        anyhow::bail!("output {:?} has dest_address not in allow list", output);
-                    }
-                }
-                for swap in plan.swap_plans() {
-                    if !allowed_destination_addresses.contains(&swap.swap_plaintext.claim_address) {
-                        anyhow::bail!("swap {:?} has claim_address not in allow list", swap);
-                    }
-                }
-                Ok(())
-            }
-            AuthPolicy::OnlyIbcRelay => {
-                for action in &plan.actions {
-                    match action {
-                        ActionPlan::Spend { .. }
-                        | ActionPlan::Output { .. }
-                        | ActionPlan::IbcAction { .. } => {}
-                        _ => {
-                            anyhow::bail!("action {:?} not allowed by OnlyRelay policy", action);
-                        }
-                    }
-                }
-                Ok(())
-            }
-            AuthPolicy::PreAuthorization(policy) => policy.check_transaction(request),
-        }
+        let mut effects = PolicyEffects::default();
+        self.evaluate(request, &mut effects)?;
+        effects.commit()
     }
 
     fn check_validator_definition(
@@ -273,3 +663,267 @@ impl Policy for PreAuthorizationPolicy {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> AuthorizeRequest {
+        AuthorizeRequest {
+            plan: TransactionPlan::default(),
+            pre_authorizations: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn combinators() {
+        let allow = AuthPolicy::All { policies: vec![] };
+        let deny = AuthPolicy::Any { policies: vec![] };
+
+        assert!(allow.check_transaction(&request()).is_ok());
+        assert!(deny.check_transaction(&request()).is_err());
+
+        let not_allow = AuthPolicy::Not {
+            policy: Box::new(allow.clone()),
+        };
+        let not_deny = AuthPolicy::Not {
+            policy: Box::new(deny.clone()),
+        };
+        assert!(not_allow.check_transaction(&request()).is_err());
+        assert!(not_deny.check_transaction(&request()).is_ok());
+
+        let any = AuthPolicy::Any {
+            policies: vec![deny.clone(), allow.clone()],
+        };
+        let all = AuthPolicy::All {
+            policies: vec![allow, deny],
+        };
+        assert!(any.check_transaction(&request()).is_ok());
+        assert!(all.check_transaction(&request()).is_err());
+
+        // Negating a stateful policy is never allowed, since it would record
+        // the transactions it rejects.
+        let not_stateful = AuthPolicy::Not {
+            policy: Box::new(AuthPolicy::SpendLimit(SpendLimitPolicy {
+                ledger_path: "unused.json".into(),
+                limits: vec![],
+            })),
+        };
+        assert!(not_stateful.is_stateful());
+        assert!(not_stateful.check_transaction(&request()).is_err());
+    }
+
+    #[test]
+    fn leaf_predicates() {
+        // An empty plan has no actions and pays no fee.
+        let policies = [
+            AuthPolicy::AllowedActions { actions: vec![] },
+            AuthPolicy::MaxFee { max_fee: 0 },
+            AuthPolicy::MaxOutputValue {
+                asset_id: *penumbra_asset::STAKING_TOKEN_ASSET_ID,
+                max_amount: 0,
+            },
+        ];
+        for policy in policies {
+            assert!(policy.check_transaction(&request()).is_ok());
+        }
+
+        // The fee asset must be allowed, even if no fee is paid.
+        let no_assets = AuthPolicy::AllowedAssets { asset_ids: vec![] };
+        assert!(no_assets.check_transaction(&request()).is_err());
+    }
+
+    /// A plan spending 100 of the staking token from `ADDRESS_0`, sending 30 to `ADDRESS_1` and
+    /// the other 70 back to `ADDRESS_0` as change.
+    fn payment_request() -> AuthorizeRequest {
+        use penumbra_keys::test_keys::{ADDRESS_0, ADDRESS_1};
+        use penumbra_shielded_pool::{Note, OutputPlan, SpendPlan};
+        use rand_core::OsRng;
+
+        let um = |amount: u128| Value {
+            asset_id: *penumbra_asset::STAKING_TOKEN_ASSET_ID,
+            amount: amount.into(),
+        };
+        let note = Note::generate(&mut OsRng, &ADDRESS_0, um(100));
+
+        AuthorizeRequest {
+            plan: TransactionPlan {
+                actions: vec![
+                    SpendPlan::new(&mut OsRng, note, 0u64.into()).into(),
+                    OutputPlan::new(&mut OsRng, um(30), ADDRESS_1.clone()).into(),
+                    OutputPlan::new(&mut OsRng, um(70), ADDRESS_0.clone()).into(),
+                ],
+                ..Default::default()
+            },
+            pre_authorizations: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn leaf_predicates_on_payment() {
+        use penumbra_keys::test_keys::{ADDRESS_0, ADDRESS_1};
+
+        let um = *penumbra_asset::STAKING_TOKEN_ASSET_ID;
+        let gm = asset::REGISTRY.parse_unit("gm").id();
+        let request = payment_request();
+        let check = |policy: AuthPolicy| policy.check_transaction(&request);
+
+        assert!(check(AuthPolicy::DestinationAllowList {
+            allowed_destination_addresses: vec![ADDRESS_0.clone()],
+        })
+        .is_err());
+        assert!(check(AuthPolicy::DestinationAllowList {
+            allowed_destination_addresses: vec![ADDRESS_0.clone(), ADDRESS_1.clone()],
+        })
+        .is_ok());
+
+        assert!(check(AuthPolicy::OnlyIbcRelay).is_ok());
+        assert!(check(AuthPolicy::AllowedActions {
+            actions: vec![ActionKind::Spend],
+        })
+        .is_err());
+        assert!(check(AuthPolicy::AllowedActions {
+            actions: vec![ActionKind::Spend, ActionKind::Output],
+        })
+        .is_ok());
+
+        assert!(check(AuthPolicy::AllowedAssets {
+            asset_ids: vec![um],
+        })
+        .is_ok());
+        assert!(check(AuthPolicy::AllowedAssets {
+            asset_ids: vec![gm],
+        })
+        .is_err());
+
+        // Only the 30 sent to another address counts, not the change.
+        assert!(check(AuthPolicy::MaxOutputValue {
+            asset_id: um,
+            max_amount: 29,
+        })
+        .is_err());
+        assert!(check(AuthPolicy::MaxOutputValue {
+            asset_id: um,
+            max_amount: 30,
+        })
+        .is_ok());
+        assert!(check(AuthPolicy::MaxOutputValue {
+            asset_id: gm,
+            max_amount: 0,
+        })
+        .is_ok());
+
+        assert_eq!(
+            SpendLimitPolicy::outflows(&request.plan),
+            vec![Value {
+                asset_id: um,
+                amount: 30u128.into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn stateful_policies_commit_together() {
+        use rand_core::{OsRng, RngCore};
+
+        let spend_limit = |max_per_window: u128| {
+            AuthPolicy::SpendLimit(SpendLimitPolicy {
+                ledger_path: std::env::temp_dir()
+                    .join(format!("spend-ledger-{}.json", OsRng.next_u64())),
+                limits: vec![SpendLimit {
+                    asset_id: *penumbra_asset::STAKING_TOKEN_ASSET_ID,
+                    max_per_transaction: None,
+                    max_per_window: Some(max_per_window),
+                    window_seconds: 60 * 60,
+                }],
+            })
+        };
+        let ledger_path = |policy: &AuthPolicy| match policy {
+            AuthPolicy::SpendLimit(policy) => policy.ledger_path.clone(),
+            _ => unreachable!(),
+        };
+        let loose = spend_limit(100);
+        let strict = spend_limit(10);
+        let request = payment_request();
+
+        // The strict limit rejects the payment, so the loose one mustn't record it.
+        let all = AuthPolicy::All {
+            policies: vec![loose.clone(), strict.clone()],
+        };
+        assert!(all.check_transaction(&request).is_err());
+        assert!(!ledger_path(&loose).exists());
+        assert!(!ledger_path(&strict).exists());
+
+        // Only the alternative that allows the payment records it.
+        let any = AuthPolicy::Any {
+            policies: vec![strict.clone(), loose.clone()],
+        };
+        for _ in 0..3 {
+            any.check_transaction(&request).unwrap();
+        }
+        assert!(!ledger_path(&strict).exists());
+        // Dry runs don't record anything, but see what has been recorded.
+        assert!(any.dry_run(&request).is_err());
+        assert!(loose.dry_run(&request).is_err());
+
+        std::fs::remove_file(ledger_path(&loose)).unwrap();
+    }
+
+    #[test]
+    fn mixed_pre_authorizations() {
+        use p256::ecdsa::signature::Signer as _;
+        use rand_core::OsRng;
+
+        use crate::pre_auth::{DecafRdsa, EcdsaP256, Ed25519};
+
+        let signed_data = ProtoTransactionPlan::from(TransactionPlan::default()).encode_to_vec();
+
+        let ed25519_sk = ed25519_consensus::SigningKey::new(OsRng);
+        let ed25519 = PreAuthorization::Ed25519(Ed25519 {
+            vk: ed25519_sk.verification_key(),
+            sig: ed25519_sk.sign(&signed_data),
+        });
+        let p256_sk = p256::ecdsa::SigningKey::random(&mut OsRng);
+        let p256 = PreAuthorization::EcdsaP256(EcdsaP256 {
+            vk: *p256_sk.verifying_key(),
+            sig: p256_sk.sign(&signed_data),
+        });
+        let rdsa_sk = decaf377_rdsa::SigningKey::<SpendAuth>::new(OsRng);
+        let rdsa = PreAuthorization::DecafRdsa(DecafRdsa {
+            vk: (&rdsa_sk).into(),
+            sig: rdsa_sk.sign(OsRng, &signed_data),
+        });
+
+        let mixed = PreAuthorizationPolicy::Mixed {
+            required_signatures: 2,
+            allowed_signers: vec![ed25519.signer(), p256.signer(), rdsa.signer()],
+        };
+        let check = |policy: &PreAuthorizationPolicy, pre_authorizations: &[PreAuthorization]| {
+            policy.check_transaction(&AuthorizeRequest {
+                plan: TransactionPlan::default(),
+                pre_authorizations: pre_authorizations.to_vec(),
+            })
+        };
+
+        // Signers of different schemes count towards the same total...
+        assert!(check(&mixed, &[p256.clone(), rdsa.clone()]).is_ok());
+        assert!(check(&mixed, &[ed25519.clone(), p256.clone()]).is_ok());
+        // ...but each of them only counts once.
+        assert!(check(&mixed, &[p256.clone(), p256.clone()]).is_err());
+
+        // Single-scheme policies ignore pre-authorizations of other schemes.
+        let only_p256 = PreAuthorizationPolicy::EcdsaP256 {
+            required_signatures: 1,
+            allowed_signers: vec![*p256_sk.verifying_key()],
+        };
+        assert!(check(&only_p256, &[p256.clone()]).is_ok());
+        assert!(check(&only_p256, &[ed25519.clone(), rdsa.clone()]).is_err());
+
+        // Signatures over other data are rejected.
+        let forged = PreAuthorization::EcdsaP256(EcdsaP256 {
+            vk: *p256_sk.verifying_key(),
+            sig: p256_sk.sign(b"something else"),
+        });
+        assert!(check(&only_p256, &[forged]).is_err());
+    }
+}
@@ -0,0 +1,386 @@
+//! A stateful policy limiting how much value can leave the wallet over time.
+
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    fs,
+    path::PathBuf,
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context as _;
+use penumbra_asset::{asset, Balance, Value};
+use penumbra_transaction::TransactionPlan;
+use serde::{Deserialize, Serialize};
+use serde_with::DisplayFromStr;
+
+use super::{own_addresses, Policy};
+use crate::{AuthorizeRequest, AuthorizeValidatorDefinitionRequest, AuthorizeValidatorVoteRequest};
+
+/// Serializes reads and writes of spend ledgers within this process, so that concurrent
+/// authorization requests can't both fit under a limit that only one of them should.
+static LEDGER_LOCK: Mutex<()> = Mutex::new(());
+
+/// Limit the value of each asset that can be authorized, both per transaction and over a rolling
+/// time window.
+///
+/// Each authorized outflow is recorded in a ledger file at `ledger_path`, so that the window
+/// limits hold across restarts. The outflow of a transaction plan is the value it spends from the
+/// wallet's notes, less any outputs sent back to the addresses of those notes; in particular,
+/// change sent to a different address of the same wallet is counted as an outflow.
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SpendLimitPolicy {
+    /// The file in which authorized outflows are recorded.
+    pub ledger_path: PathBuf,
+    /// The limits to enforce, one per asset.
+    pub limits: Vec<SpendLimit>,
+}
+
+/// The limits placed on outflows of a single asset.
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SpendLimit {
+    /// The asset this limit applies to.
+    #[serde_as(as = "DisplayFromStr")]
+    pub asset_id: asset::Id,
+    /// The maximum amount of the asset that a single transaction may spend.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_per_transaction: Option<u128>,
+    /// The maximum amount of the asset that may be spent within any `window_seconds`.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_per_window: Option<u128>,
+    /// The length of the rolling window, in seconds.
+    #[serde(default = "default_window_seconds")]
+    pub window_seconds: u64,
+}
+
+fn default_window_seconds() -> u64 {
+    // One day.
+    24 * 60 * 60
+}
+
+/// An outflow recorded in the spend ledger.
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct LedgerEntry {
+    #[serde_as(as = "DisplayFromStr")]
+    asset_id: asset::Id,
+    #[serde_as(as = "DisplayFromStr")]
+    amount: u128,
+    /// The time the outflow was authorized, in seconds since the Unix epoch.
+    timestamp: u64,
+}
+
+/// Outflows that a [`SpendLimitPolicy`] allows, to be recorded in its ledger once every other
+/// policy has allowed the transaction too.
+#[derive(Clone, Debug)]
+pub struct PendingOutflows {
+    policy: SpendLimitPolicy,
+    outflows: Vec<Value>,
+    /// The time the outflows were checked, in seconds since the Unix epoch.
+    now: u64,
+}
+
+impl SpendLimitPolicy {
+    /// Computes the value that the transaction plan moves out of the wallet, per asset.
+    pub fn outflows(plan: &TransactionPlan) -> Vec<Value> {
+        let own_addresses = own_addresses(plan);
+
+        let mut balance = Balance::zero();
+        for spend in plan.spend_plans() {
+            balance += spend.balance();
+        }
+        for output in plan.output_plans() {
+            if own_addresses.contains(&output.dest_address) {
+                balance += output.balance();
+            }
+        }
+
+        balance.provided().collect()
+    }
+
+    /// Checks the transaction plan against the limits as of the current time, returning the
+    /// outflows to record if the transaction goes ahead.
+    ///
+    /// Nothing is recorded until the returned outflows are passed to [`record_outflows`].
+    pub(super) fn check_plan(&self, plan: &TransactionPlan) -> anyhow::Result<PendingOutflows> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .context("system clock is before the Unix epoch")?
+            .as_secs();
+        let outflows = Self::outflows(plan);
+
+        let ledger = {
+            let _guard = LEDGER_LOCK.lock().expect("ledger lock is not poisoned");
+            self.load_ledger()?
+        };
+        self.check_outflows(&ledger, &outflows, now)?;
+
+        Ok(PendingOutflows {
+            policy: self.clone(),
+            outflows,
+            now,
+        })
+    }
+
+    /// Checks the outflows against the limits, given the outflows already in the `ledger`.
+    fn check_outflows(
+        &self,
+        ledger: &[LedgerEntry],
+        outflows: &[Value],
+        now: u64,
+    ) -> anyhow::Result<()> {
+        for limit in &self.limits {
+            let amount = outflows
+                .iter()
+                .filter(|value| value.asset_id == limit.asset_id)
+                .map(|value| value.amount.value())
+                .fold(0u128, u128::saturating_add);
+            if amount == 0 {
+                continue;
+            }
+
+            if let Some(max) = limit.max_per_transaction {
+                if amount > max {
+                    anyhow::bail!(
+                        "transaction spends {} of asset {}, exceeding the per-transaction limit of {}",
+                        amount,
+                        limit.asset_id,
+                        max,
+                    );
+                }
+            }
+
+            if let Some(max) = limit.max_per_window {
+                let window_start = now.saturating_sub(limit.window_seconds);
+                let spent = ledger
+                    .iter()
+                    .filter(|entry| entry.asset_id == limit.asset_id)
+                    .filter(|entry| entry.timestamp > window_start)
+                    .map(|entry| entry.amount)
+                    .fold(0u128, u128::saturating_add);
+                if spent.saturating_add(amount) > max {
+                    anyhow::bail!(
+                        "transaction spends {} of asset {}, but {} has already been spent in the last {} seconds, and the limit is {}",
+                        amount,
+                        limit.asset_id,
+                        spent,
+                        limit.window_seconds,
+                        max,
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds the outflows to the `ledger` as of `now`.
+    fn record(&self, ledger: &mut Vec<LedgerEntry>, outflows: &[Value], now: u64) {
+        // Forget entries that have fallen out of every window, so the ledger doesn't grow forever.
+        let windows = self
+            .limits
+            .iter()
+            .map(|limit| (limit.asset_id, limit.window_seconds))
+            .collect::<BTreeMap<_, _>>();
+        ledger.retain(|entry| {
+            windows
+                .get(&entry.asset_id)
+                .map(|window| entry.timestamp > now.saturating_sub(*window))
+                .unwrap_or(false)
+        });
+        ledger.extend(
+            outflows
+                .iter()
+                .filter(|value| windows.contains_key(&value.asset_id))
+                .map(|value| LedgerEntry {
+                    asset_id: value.asset_id,
+                    amount: value.amount.value(),
+                    timestamp: now,
+                }),
+        );
+    }
+
+    fn load_ledger(&self) -> anyhow::Result<Vec<LedgerEntry>> {
+        if !self.ledger_path.exists() {
+            return Ok(Vec::new());
+        }
+        let contents = fs::read_to_string(&self.ledger_path).with_context(|| {
+            format!("could not read spend ledger {}", self.ledger_path.display())
+        })?;
+        serde_json::from_str(&contents).with_context(|| {
+            format!(
+                "could not parse spend ledger {}",
+                self.ledger_path.display()
+            )
+        })
+    }
+
+    fn save_ledger(&self, ledger: &[LedgerEntry]) -> anyhow::Result<()> {
+        // Write to a temporary file and rename it into place, so that a crash can't leave a
+        // truncated ledger behind.
+        let tmp_path = self.ledger_path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_vec_pretty(ledger)?)
+            .with_context(|| format!("could not write spend ledger {}", tmp_path.display()))?;
+        fs::rename(&tmp_path, &self.ledger_path).with_context(|| {
+            format!(
+                "could not write spend ledger {}",
+                self.ledger_path.display()
+            )
+        })
+    }
+}
+
+/// Records the pending outflows in their ledgers: either all of them, or none if any of them no
+/// longer fits under its limits.
+pub(super) fn record_outflows(pending: &[PendingOutflows]) -> anyhow::Result<()> {
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    let _guard = LEDGER_LOCK.lock().expect("ledger lock is not poisoned");
+
+    // Check each outflow again under the lock, since another request may have used up the room
+    // since it was first checked. Policies can share a ledger, so each is checked against the
+    // ledger as it will be once the outflows before it are recorded.
+    let mut ledgers = BTreeMap::<PathBuf, (&SpendLimitPolicy, Vec<LedgerEntry>)>::new();
+    for PendingOutflows {
+        policy,
+        outflows,
+        now,
+    } in pending
+    {
+        let (_, ledger) = match ledgers.entry(policy.ledger_path.clone()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert((policy, policy.load_ledger()?)),
+        };
+        policy.check_outflows(ledger, outflows, *now)?;
+        policy.record(ledger, outflows, *now);
+    }
+
+    for (policy, ledger) in ledgers.values() {
+        policy.save_ledger(ledger)?;
+    }
+    Ok(())
+}
+
+impl Policy for SpendLimitPolicy {
+    fn check_transaction(&self, request: &AuthorizeRequest) -> anyhow::Result<()> {
+        record_outflows(&[self.check_plan(&request.plan)?])
+    }
+
+    fn check_validator_definition(
+        &self,
+        _request: &AuthorizeValidatorDefinitionRequest,
+    ) -> anyhow::Result<()> {
+        // Validator definitions don't move any funds.
+        Ok(())
+    }
+
+    fn check_validator_vote(&self, _request: &AuthorizeValidatorVoteRequest) -> anyhow::Result<()> {
+        // Validator votes don't move any funds.
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{OsRng, RngCore};
+
+    use super::*;
+
+    fn value(asset_id: asset::Id, amount: u128) -> Value {
+        Value {
+            asset_id,
+            amount: amount.into(),
+        }
+    }
+
+    fn policy(limits: Vec<SpendLimit>) -> SpendLimitPolicy {
+        SpendLimitPolicy {
+            ledger_path: std::env::temp_dir()
+                .join(format!("spend-ledger-{}.json", OsRng.next_u64())),
+            limits,
+        }
+    }
+
+    fn pending(policy: &SpendLimitPolicy, outflows: &[Value], now: u64) -> PendingOutflows {
+        PendingOutflows {
+            policy: policy.clone(),
+            outflows: outflows.to_vec(),
+            now,
+        }
+    }
+
+    /// Checks the outflows and, if they are allowed, records them.
+    fn spend(policy: &SpendLimitPolicy, outflows: &[Value], now: u64) -> anyhow::Result<()> {
+        policy.check_outflows(&policy.load_ledger()?, outflows, now)?;
+        record_outflows(&[pending(policy, outflows, now)])
+    }
+
+    #[test]
+    fn enforces_transaction_and_window_limits() {
+        let um = *penumbra_asset::STAKING_TOKEN_ASSET_ID;
+        let policy = policy(vec![SpendLimit {
+            asset_id: um,
+            max_per_transaction: Some(100),
+            max_per_window: Some(150),
+            window_seconds: 60,
+        }]);
+
+        // Too much for a single transaction.
+        assert!(spend(&policy, &[value(um, 101)], 0).is_err());
+        // Checks don't record anything...
+        policy.check_outflows(&[], &[value(um, 100)], 0).unwrap();
+        assert!(policy.load_ledger().unwrap().is_empty());
+        // ...but recording does.
+        spend(&policy, &[value(um, 100)], 0).unwrap();
+        // Fits per transaction, but not in the window.
+        assert!(spend(&policy, &[value(um, 51)], 30).is_err());
+        spend(&policy, &[value(um, 50)], 30).unwrap();
+        // Once the first outflow leaves the window, there's room again.
+        spend(&policy, &[value(um, 100)], 61).unwrap();
+        assert!(spend(&policy, &[value(um, 1)], 62).is_err());
+
+        // Other assets are unrestricted.
+        let other = asset::REGISTRY.parse_unit("gm").id();
+        spend(&policy, &[value(other, u128::MAX)], 62).unwrap();
+
+        fs::remove_file(&policy.ledger_path).unwrap();
+    }
+
+    #[test]
+    fn records_all_outflows_or_none() {
+        let um = *penumbra_asset::STAKING_TOKEN_ASSET_ID;
+        let limit = |max| SpendLimit {
+            asset_id: um,
+            max_per_transaction: None,
+            max_per_window: Some(max),
+            window_seconds: 60,
+        };
+        let loose = policy(vec![limit(100)]);
+        let strict = policy(vec![limit(10)]);
+
+        // The strict policy rejects, so the loose one mustn't record anything either.
+        let outflows = [value(um, 50)];
+        assert!(record_outflows(&[
+            pending(&loose, &outflows, 0),
+            pending(&strict, &outflows, 0)
+        ])
+        .is_err());
+        assert!(loose.load_ledger().unwrap().is_empty());
+        assert!(strict.load_ledger().unwrap().is_empty());
+
+        // Outflows checked separately can't together exceed a ledger they share.
+        let pair = [pending(&loose, &outflows, 0), pending(&loose, &outflows, 0)];
+        record_outflows(&pair).unwrap();
+        assert!(record_outflows(&[pending(&loose, &[value(um, 1)], 1)]).is_err());
+        let triple = [pair[0].clone(), pair[0].clone(), pair[0].clone()];
+        fs::remove_file(&loose.ledger_path).unwrap();
+        assert!(record_outflows(&triple).is_err());
+        assert!(loose.load_ledger().unwrap().is_empty());
+    }
+}
@@ -1,3 +1,6 @@
+use base64::prelude::*;
+use decaf377_rdsa::SpendAuth;
+use p256::ecdsa::signature::Verifier as _;
 use penumbra_proto::{custody::v1 as pb, DomainType};
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +12,110 @@ use serde::{Deserialize, Serialize};
 #[serde(try_from = "pb::PreAuthorization", into = "pb::PreAuthorization")]
 pub enum PreAuthorization {
     Ed25519(Ed25519),
+    EcdsaP256(EcdsaP256),
+    DecafRdsa(DecafRdsa),
+}
+
+impl PreAuthorization {
+    /// The key that produced this pre-authorization.
+    pub fn signer(&self) -> PreAuthorizationKey {
+        match self {
+            PreAuthorization::Ed25519(pre_auth) => PreAuthorizationKey::Ed25519(pre_auth.vk),
+            PreAuthorization::EcdsaP256(pre_auth) => PreAuthorizationKey::EcdsaP256(pre_auth.vk),
+            PreAuthorization::DecafRdsa(pre_auth) => PreAuthorizationKey::DecafRdsa(pre_auth.vk),
+        }
+    }
+
+    /// Verifies the pre-authorization's signature over the provided `TransactionPlan`.
+    pub fn verify(&self, message: impl AsRef<[u8]>) -> anyhow::Result<()> {
+        match self {
+            PreAuthorization::Ed25519(pre_auth) => pre_auth.verify(message),
+            PreAuthorization::EcdsaP256(pre_auth) => pre_auth.verify(message),
+            PreAuthorization::DecafRdsa(pre_auth) => pre_auth.verify(message),
+        }
+    }
+}
+
+/// A key that can produce pre-authorizations, of any of the supported schemes.
+///
+/// Keys of different schemes are never equal, even if their encodings are.
+///
+/// In config files, keys are written as their scheme and base64-encoded bytes.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "KeyConfig", into = "KeyConfig")]
+pub enum PreAuthorizationKey {
+    Ed25519(ed25519_consensus::VerificationKey),
+    EcdsaP256(p256::ecdsa::VerifyingKey),
+    DecafRdsa(decaf377_rdsa::VerificationKey<SpendAuth>),
+}
+
+impl PreAuthorizationKey {
+    /// The name of the key's signature scheme.
+    pub fn scheme(&self) -> &'static str {
+        match self {
+            PreAuthorizationKey::Ed25519(_) => "Ed25519",
+            PreAuthorizationKey::EcdsaP256(_) => "EcdsaP256",
+            PreAuthorizationKey::DecafRdsa(_) => "DecafRdsa",
+        }
+    }
+
+    /// The canonical encoding of the key, which is SEC1 compressed for P-256 keys.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            PreAuthorizationKey::Ed25519(vk) => vk.to_bytes().to_vec(),
+            PreAuthorizationKey::EcdsaP256(vk) => vk.to_encoded_point(true).as_bytes().to_vec(),
+            PreAuthorizationKey::DecafRdsa(vk) => vk.to_bytes().to_vec(),
+        }
+    }
+
+    /// Parse a key of the named scheme from its encoding.
+    pub fn from_bytes(scheme: &str, bytes: &[u8]) -> anyhow::Result<Self> {
+        Ok(match scheme {
+            "Ed25519" => PreAuthorizationKey::Ed25519(bytes.try_into()?),
+            "EcdsaP256" => {
+                PreAuthorizationKey::EcdsaP256(p256::ecdsa::VerifyingKey::from_sec1_bytes(bytes)?)
+            }
+            "DecafRdsa" => PreAuthorizationKey::DecafRdsa(bytes.try_into()?),
+            other => anyhow::bail!("unknown pre-authorization scheme {other}"),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeyConfig {
+    scheme: String,
+    key: String,
+}
+
+impl TryFrom<KeyConfig> for PreAuthorizationKey {
+    type Error = anyhow::Error;
+    fn try_from(value: KeyConfig) -> Result<Self, Self::Error> {
+        Self::from_bytes(&value.scheme, &BASE64_STANDARD.decode(value.key)?)
+    }
+}
+
+impl From<PreAuthorizationKey> for KeyConfig {
+    fn from(value: PreAuthorizationKey) -> Self {
+        Self {
+            scheme: value.scheme().to_owned(),
+            key: BASE64_STANDARD.encode(value.to_bytes()),
+        }
+    }
+}
+
+impl PartialEq for PreAuthorizationKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.scheme() == other.scheme() && self.to_bytes() == other.to_bytes()
+    }
+}
+
+impl Eq for PreAuthorizationKey {}
+
+impl std::hash::Hash for PreAuthorizationKey {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.scheme().hash(state);
+        self.to_bytes().hash(state);
+    }
 }
 
 /// An Ed25519-based preauthorization, containing an Ed25519 signature over the
@@ -33,6 +140,54 @@ impl Ed25519 {
     }
 }
 
+/// An ECDSA-based preauthorization over the NIST P-256 curve, containing a
+/// signature over the SHA-256 hash of the `TransactionPlan`.
+///
+/// This is the scheme used by most cloud KMS offerings and WebAuthn devices.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::pre_authorization::EcdsaP256",
+    into = "pb::pre_authorization::EcdsaP256"
+)]
+pub struct EcdsaP256 {
+    /// The verification key used to pre-authorize the `TransactionPlan`.
+    pub vk: p256::ecdsa::VerifyingKey,
+    /// An ECDSA signature over the `TransactionPlan`.
+    pub sig: p256::ecdsa::Signature,
+}
+
+impl EcdsaP256 {
+    /// Verifies the provided `TransactionPlan`.
+    pub fn verify(&self, message: impl AsRef<[u8]>) -> anyhow::Result<()> {
+        self.vk
+            .verify(message.as_ref(), &self.sig)
+            .map_err(Into::into)
+    }
+}
+
+/// A decaf377-rdsa-based preauthorization, containing a spend authorization
+/// signature over the `TransactionPlan`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::pre_authorization::DecafRdsa",
+    into = "pb::pre_authorization::DecafRdsa"
+)]
+pub struct DecafRdsa {
+    /// The verification key used to pre-authorize the `TransactionPlan`.
+    pub vk: decaf377_rdsa::VerificationKey<SpendAuth>,
+    /// A decaf377-rdsa signature over the `TransactionPlan`.
+    pub sig: decaf377_rdsa::Signature<SpendAuth>,
+}
+
+impl DecafRdsa {
+    /// Verifies the provided `TransactionPlan`.
+    pub fn verify(&self, message: impl AsRef<[u8]>) -> anyhow::Result<()> {
+        self.vk
+            .verify(message.as_ref(), &self.sig)
+            .map_err(Into::into)
+    }
+}
+
 impl DomainType for PreAuthorization {
     type Proto = pb::PreAuthorization;
 }
@@ -44,6 +199,12 @@ impl TryFrom<pb::PreAuthorization> for PreAuthorization {
             Some(pb::pre_authorization::PreAuthorization::Ed25519(ed)) => {
                 Self::Ed25519(ed.try_into()?)
             }
+            Some(pb::pre_authorization::PreAuthorization::EcdsaP256(ecdsa)) => {
+                Self::EcdsaP256(ecdsa.try_into()?)
+            }
+            Some(pb::pre_authorization::PreAuthorization::DecafRdsa(rdsa)) => {
+                Self::DecafRdsa(rdsa.try_into()?)
+            }
             None => {
                 anyhow::bail!("missing pre-authorization");
             }
@@ -58,6 +219,12 @@ impl From<PreAuthorization> for pb::PreAuthorization {
                 PreAuthorization::Ed25519(ed) => {
                     pb::pre_authorization::PreAuthorization::Ed25519(ed.into())
                 }
+                PreAuthorization::EcdsaP256(ecdsa) => {
+                    pb::pre_authorization::PreAuthorization::EcdsaP256(ecdsa.into())
+                }
+                PreAuthorization::DecafRdsa(rdsa) => {
+                    pb::pre_authorization::PreAuthorization::DecafRdsa(rdsa.into())
+                }
             }),
         }
     }
@@ -85,3 +252,49 @@ impl From<Ed25519> for pb::pre_authorization::Ed25519 {
         }
     }
 }
+
+impl DomainType for EcdsaP256 {
+    type Proto = pb::pre_authorization::EcdsaP256;
+}
+
+impl TryFrom<pb::pre_authorization::EcdsaP256> for EcdsaP256 {
+    type Error = anyhow::Error;
+    fn try_from(value: pb::pre_authorization::EcdsaP256) -> Result<Self, Self::Error> {
+        Ok(Self {
+            vk: p256::ecdsa::VerifyingKey::from_sec1_bytes(&value.vk)?,
+            sig: p256::ecdsa::Signature::from_der(&value.sig)?,
+        })
+    }
+}
+
+impl From<EcdsaP256> for pb::pre_authorization::EcdsaP256 {
+    fn from(value: EcdsaP256) -> pb::pre_authorization::EcdsaP256 {
+        Self {
+            vk: value.vk.to_encoded_point(true).as_bytes().to_vec(),
+            sig: value.sig.to_der().as_bytes().to_vec(),
+        }
+    }
+}
+
+impl DomainType for DecafRdsa {
+    type Proto = pb::pre_authorization::DecafRdsa;
+}
+
+impl TryFrom<pb::pre_authorization::DecafRdsa> for DecafRdsa {
+    type Error = anyhow::Error;
+    fn try_from(value: pb::pre_authorization::DecafRdsa) -> Result<Self, Self::Error> {
+        Ok(Self {
+            vk: value.vk.as_slice().try_into()?,
+            sig: value.sig.as_slice().try_into()?,
+        })
+    }
+}
+
+impl From<DecafRdsa> for pb::pre_authorization::DecafRdsa {
+    fn from(value: DecafRdsa) -> pb::pre_authorization::DecafRdsa {
+        Self {
+            vk: value.vk.to_bytes().into(),
+            sig: value.sig.to_bytes().into(),
+        }
+    }
+}
@@ -1,6 +1,9 @@
 //! A basic software key management system that stores keys in memory but
 //! presents as an asynchronous signer.
 
+use std::cell::RefCell;
+
+use anyhow::Context as _;
 use decaf377_rdsa::{Signature, SpendAuth};
 use penumbra_proto::{
     core::component::{
@@ -15,8 +18,9 @@ use rand_core::OsRng;
 use tonic::{async_trait, Request, Response, Status};
 
 use crate::{
-    policy::Policy, AuthorizeRequest, AuthorizeValidatorDefinitionRequest,
-    AuthorizeValidatorVoteRequest,
+    audit::{self, AuditLog, Decision, RequestKind, RequestSummary},
+    policy::{stateful_last, AuthPolicy, Policy, PolicyEffects},
+    AuthorizeRequest, AuthorizeValidatorDefinitionRequest, AuthorizeValidatorVoteRequest,
 };
 
 mod config;
@@ -27,12 +31,14 @@ pub use config::Config;
 /// presents as an asynchronous signer.
 pub struct SoftKms {
     config: Config,
+    audit_log: Option<AuditLog>,
 }
 
 impl SoftKms {
     /// Initialize with the given [`Config`].
     pub fn new(config: Config) -> Self {
-        Self { config }
+        let audit_log = config.audit_log.clone().map(AuditLog::new);
+        Self { config, audit_log }
     }
 
     /// Attempt to authorize the requested [`TransactionPlan`](penumbra_transaction::TransactionPlan).
@@ -40,11 +46,25 @@ impl SoftKms {
     pub fn sign(&self, request: &AuthorizeRequest) -> anyhow::Result<AuthorizationData> {
         tracing::debug!(?request.plan);
 
-        for policy in &self.config.auth_policy {
-            policy.check_transaction(request)?;
-        }
-
-        Ok(request.plan.authorize(OsRng, &self.config.spend_key)?)
+        // The effects of stateful policies are only committed once every policy has allowed the
+        // transaction, and the signature is only handed out if they could be.
+        let effects = RefCell::new(PolicyEffects::default());
+        self.decide(
+            || {
+                RequestSummary::transaction(
+                    &request.plan,
+                    &request.pre_authorizations,
+                    self.config.spend_key.full_viewing_key(),
+                )
+            },
+            stateful_last(&self.config.auth_policy),
+            |policy| policy.evaluate(request, &mut effects.borrow_mut()),
+            || {
+                let authorization_data = request.plan.authorize(OsRng, &self.config.spend_key)?;
+                effects.take().commit()?;
+                Ok(authorization_data)
+            },
+        )
     }
 
     /// Attempt to authorize the requested validator definition.
@@ -55,18 +75,27 @@ impl SoftKms {
     ) -> anyhow::Result<Signature<SpendAuth>> {
         tracing::debug!(?request.validator_definition);
 
-        for policy in &self.config.auth_policy {
-            policy.check_validator_definition(request)?;
-        }
-
-        let protobuf_serialized: ProtoValidator = request.validator_definition.clone().into();
-        let validator_definition_bytes = protobuf_serialized.encode_to_vec();
-
-        Ok(self
-            .config
-            .spend_key
-            .spend_auth_key()
-            .sign(OsRng, &validator_definition_bytes))
+        self.decide(
+            || {
+                RequestSummary::other(
+                    RequestKind::ValidatorDefinition,
+                    &request.pre_authorizations,
+                )
+            },
+            self.config.auth_policy.iter(),
+            |policy| policy.check_validator_definition(request),
+            || {
+                let protobuf_serialized: ProtoValidator =
+                    request.validator_definition.clone().into();
+                let validator_definition_bytes = protobuf_serialized.encode_to_vec();
+
+                Ok(self
+                    .config
+                    .spend_key
+                    .spend_auth_key()
+                    .sign(OsRng, &validator_definition_bytes))
+            },
+        )
     }
 
     /// Attempt to authorize the requested validator vote.
@@ -77,18 +106,83 @@ impl SoftKms {
     ) -> anyhow::Result<Signature<SpendAuth>> {
         tracing::debug!(?request.validator_vote);
 
-        for policy in &self.config.auth_policy {
-            policy.check_validator_vote(request)?;
+        self.decide(
+            || RequestSummary::other(RequestKind::ValidatorVote, &request.pre_authorizations),
+            self.config.auth_policy.iter(),
+            |policy| policy.check_validator_vote(request),
+            || {
+                let protobuf_serialized: ProtoValidatorVoteBody =
+                    request.validator_vote.clone().into();
+                let validator_vote_bytes = protobuf_serialized.encode_to_vec();
+
+                Ok(self
+                    .config
+                    .spend_key
+                    .spend_auth_key()
+                    .sign(OsRng, &validator_vote_bytes))
+            },
+        )
+    }
+
+    /// Check a request against each of the `policies` in turn and, if they all allow it, sign
+    /// it, recording the decision in the audit log if there is one.
+    fn decide<'a, T>(
+        &self,
+        summary: impl Fn() -> RequestSummary,
+        policies: impl Iterator<Item = &'a AuthPolicy>,
+        check: impl Fn(&AuthPolicy) -> anyhow::Result<()>,
+        sign: impl FnOnce() -> anyhow::Result<T>,
+    ) -> anyhow::Result<T> {
+        let mut allowed_by = Vec::new();
+        for policy in policies {
+            if let Err(e) = check(policy) {
+                self.record(
+                    summary,
+                    Decision::Rejected {
+                        policy: Some(audit::policy_name(policy)),
+                        reason: format!("{e:#}"),
+                    },
+                )?;
+                return Err(e);
+            }
+            allowed_by.push(audit::policy_name(policy));
         }
 
-        let protobuf_serialized: ProtoValidatorVoteBody = request.validator_vote.clone().into();
-        let validator_vote_bytes = protobuf_serialized.encode_to_vec();
+        match sign() {
+            Ok(signed) => {
+                // If the approval can't be recorded, we don't hand out the signature.
+                self.record(
+                    summary,
+                    Decision::Approved {
+                        policies: allowed_by,
+                    },
+                )?;
+                Ok(signed)
+            }
+            Err(e) => {
+                self.record(
+                    summary,
+                    Decision::Rejected {
+                        policy: None,
+                        reason: format!("{e:#}"),
+                    },
+                )?;
+                Err(e)
+            }
+        }
+    }
 
-        Ok(self
-            .config
-            .spend_key
-            .spend_auth_key()
-            .sign(OsRng, &validator_vote_bytes))
+    fn record(
+        &self,
+        summary: impl Fn() -> RequestSummary,
+        decision: Decision,
+    ) -> anyhow::Result<()> {
+        if let Some(audit_log) = &self.audit_log {
+            audit_log
+                .append(summary(), decision)
+                .context("failed to record the decision in the audit log")?;
+        }
+        Ok(())
     }
 }
 
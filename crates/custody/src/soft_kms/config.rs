@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use crate::policy::AuthPolicy;
 use penumbra_keys::keys::SpendKey;
 use serde::{Deserialize, Serialize};
@@ -14,6 +16,9 @@ pub struct Config {
     pub spend_key: SpendKey,
     #[serde(default, skip_serializing_if = "is_default")]
     pub auth_policy: Vec<AuthPolicy>,
+    /// If set, every authorization decision is recorded in an audit log at this path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audit_log: Option<PathBuf>,
 }
 
 impl From<SpendKey> for Config {
@@ -21,6 +26,7 @@ impl From<SpendKey> for Config {
         Self {
             spend_key,
             auth_policy: Default::default(),
+            audit_log: None,
         }
     }
 }
@@ -40,7 +46,10 @@ fn is_default<T: Default + Eq>(value: &T) -> bool {
 mod tests {
     use penumbra_keys::keys::{Bip44Path, SeedPhrase};
 
-    use crate::policy::PreAuthorizationPolicy;
+    use crate::{
+        policy::{ActionKind, PreAuthorizationPolicy, SpendLimit, SpendLimitPolicy},
+        PreAuthorizationKey,
+    };
 
     use super::*;
 
@@ -51,6 +60,9 @@ mod tests {
 
         let pak = ed25519_consensus::SigningKey::new(rand_core::OsRng);
         let pvk = pak.verification_key();
+        let p256_vk = *p256::ecdsa::SigningKey::random(&mut rand_core::OsRng).verifying_key();
+        let rdsa_sk = decaf377_rdsa::SigningKey::<decaf377_rdsa::SpendAuth>::new(rand_core::OsRng);
+        let rdsa_vk = decaf377_rdsa::VerificationKey::from(&rdsa_sk);
 
         let auth_policy = vec![
             AuthPolicy::OnlyIbcRelay,
@@ -66,11 +78,55 @@ mod tests {
                 required_signatures: 1,
                 allowed_signers: vec![pvk],
             }),
+            AuthPolicy::PreAuthorization(PreAuthorizationPolicy::EcdsaP256 {
+                required_signatures: 1,
+                allowed_signers: vec![p256_vk],
+            }),
+            AuthPolicy::PreAuthorization(PreAuthorizationPolicy::Mixed {
+                required_signatures: 2,
+                allowed_signers: vec![
+                    PreAuthorizationKey::Ed25519(pvk),
+                    PreAuthorizationKey::EcdsaP256(p256_vk),
+                    PreAuthorizationKey::DecafRdsa(rdsa_vk),
+                ],
+            }),
+            AuthPolicy::SpendLimit(SpendLimitPolicy {
+                ledger_path: "/tmp/spend_ledger.json".into(),
+                limits: vec![SpendLimit {
+                    asset_id: *penumbra_asset::STAKING_TOKEN_ASSET_ID,
+                    max_per_transaction: Some(1_000_000),
+                    max_per_window: Some(5_000_000),
+                    window_seconds: 24 * 60 * 60,
+                }],
+            }),
+            AuthPolicy::Any {
+                policies: vec![
+                    AuthPolicy::OnlyIbcRelay,
+                    AuthPolicy::All {
+                        policies: vec![
+                            AuthPolicy::AllowedActions {
+                                actions: vec![ActionKind::Spend, ActionKind::Output],
+                            },
+                            AuthPolicy::Not {
+                                policy: Box::new(AuthPolicy::MaxFee { max_fee: 1_000 }),
+                            },
+                        ],
+                    },
+                ],
+            },
+            AuthPolicy::AllowedAssets {
+                asset_ids: vec![*penumbra_asset::STAKING_TOKEN_ASSET_ID],
+            },
+            AuthPolicy::MaxOutputValue {
+                asset_id: *penumbra_asset::STAKING_TOKEN_ASSET_ID,
+                max_amount: 1_000_000,
+            },
         ];
 
         let example = Config {
             spend_key: spend_key.clone(),
             auth_policy,
+            audit_log: Some("/var/lib/pclientd/audit.jsonl".into()),
         };
 
         let encoded = toml::to_string_pretty(&example).unwrap();
@@ -33,6 +33,14 @@ pub trait Terminal: Sync {
     /// Backends can replace this with a no-op.
     fn explain(&self, msg: &str) -> Result<()>;
 
+    /// Signal that a new run of the signing protocol is starting.
+    ///
+    /// Transports which relay messages automatically can use this to keep the messages
+    /// of separate runs apart. Backends can leave this as a no-op.
+    async fn begin_session(&self) -> Result<()> {
+        Ok(())
+    }
+
     /// Broadcast a message to other users.
     async fn broadcast(&self, data: &str) -> Result<()>;
 
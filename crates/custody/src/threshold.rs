@@ -7,14 +7,21 @@ use tonic::{async_trait, Request, Response, Status};
 use penumbra_keys::{keys::AddressIndex, Address, FullViewingKey};
 use penumbra_proto::{custody::v1 as pb, DomainType};
 
-use crate::{AuthorizeRequest, AuthorizeValidatorDefinitionRequest, AuthorizeValidatorVoteRequest};
+use crate::{
+    audit::{AuditLog, Decision, RequestKind, RequestSummary},
+    AuthorizeRequest, AuthorizeValidatorDefinitionRequest, AuthorizeValidatorVoteRequest,
+    PreAuthorization,
+};
 
 pub use self::config::Config;
+pub use self::network::{Coordinator, NetworkTerminal};
 use self::sign::no_signature_response;
 pub use crate::terminal::{SigningRequest, Terminal};
 
 mod config;
 mod dkg;
+mod network;
+mod reshare;
 mod sign;
 
 /// Authorization data returned in response to some signing request, which may be a request to
@@ -68,6 +75,8 @@ pub async fn follow(
             "cannot threshold sign validator vote using a non-threshold validator governance custody backend"
         ))?,
     };
+    // Refuse to take part before asking the user, if our shares can't sign together.
+    sign::check_key_epoch(config, round1_message.key_epoch())?;
     if !terminal
         .confirm_request(round1_message.signing_request())
         .await?
@@ -170,6 +179,104 @@ pub async fn dkg(t: u16, n: u16, terminal: &impl Terminal) -> Result<Config> {
     dkg::round3(&mut OsRng, state, round2_replies)
 }
 
+/// Redistribute the shares of an existing threshold key, keeping the same key and FVK.
+///
+/// `old` is our current config, if we're one of the `dealers` holders of an old share taking
+/// part, and `member` says whether we should receive one of the `n` new shares, with a new
+/// threshold of `t`. This returns our new config, if we're a member.
+///
+/// At least as many dealers as the old threshold need to take part. The new shares can't be
+/// combined with the old ones, so the old configs should be deleted afterwards.
+///
+/// This takes in a terminal, because it requires interacting with the other participants.
+pub async fn reshare(
+    old: Option<&Config>,
+    member: bool,
+    t: u16,
+    n: u16,
+    dealers: u16,
+    terminal: &impl Terminal,
+) -> Result<Option<Config>> {
+    // Round 1 top
+    let (round1_message, state) = reshare::round1(&mut OsRng, old, member)?;
+    terminal.explain("Round 1/2: Send this message to all other participants:")?;
+    terminal.broadcast(&to_json(&round1_message)?).await?;
+    // Round 1 bottom
+    //
+    // We don't know how many participants both deal and receive a share, so we gather
+    // messages until we've seen every dealer and every member, counting ourselves.
+    terminal.explain(&format!(
+        "Round 1/2: Gather messages from the other participants, until {dealers} dealers and {n} members are present:"
+    ))?;
+    let round1_replies = {
+        let (mut dealer_count, mut member_count) = (u16::from(old.is_some()), u16::from(member));
+        let mut acc: Vec<reshare::Round1> = Vec::new();
+        while dealer_count < dealers || member_count < n {
+            let rsp = terminal.next_response::<reshare::Round1>().await?;
+            // Before we accept, check that the user hasn't double-pasted the same message.
+            if acc
+                .iter()
+                // Inefficient but good enough.
+                .any(|existing| existing.encode_to_vec() == rsp.encode_to_vec())
+            {
+                terminal.explain("Received a duplicate message, ignoring")?;
+                continue;
+            }
+            // Before we accept, check that the user hasn't pasted their own message.
+            if round1_message.encode_to_vec() == rsp.encode_to_vec() {
+                terminal.explain("Received our own outbound message by mistake, ignoring")?;
+                continue;
+            }
+            let pb = rsp.to_proto();
+            dealer_count += u16::from(!pb.dealer_vk.is_empty());
+            member_count += u16::from(!pb.member_vk.is_empty());
+            acc.push(rsp);
+            terminal.explain(&format!(
+                "Received {dealer_count}/{dealers} dealers and {member_count}/{n} members..."
+            ))?;
+        }
+        acc
+    };
+
+    // Round 2 top
+    let expected_responses = round1_replies.len();
+    let (round2_message, state) = reshare::round2(&mut OsRng, state, t, n, round1_replies)?;
+    terminal.explain("Round 2/2: Send this message to all other participants:")?;
+    terminal.broadcast(&to_json(&round2_message)?).await?;
+    // Round 2 bottom
+    terminal.explain(&format!(
+        "Round 2/2: Gather {expected_responses} messages from the other participants:"
+    ))?;
+    let round2_replies = {
+        let mut acc: Vec<reshare::Round2> = Vec::new();
+        while acc.len() < expected_responses {
+            let rsp = terminal.next_response::<reshare::Round2>().await?;
+            // Before we accept, check that the user hasn't double-pasted the same message.
+            if acc
+                .iter()
+                // Inefficient but good enough.
+                .any(|existing| existing.encode_to_vec() == rsp.encode_to_vec())
+            {
+                terminal.explain("Received a duplicate message, ignoring")?;
+                continue;
+            }
+            // Before we accept, check that the user hasn't pasted their own message.
+            if round2_message.encode_to_vec() == rsp.encode_to_vec() {
+                terminal.explain("Received our own outbound message by mistake, ignoring")?;
+                continue;
+            }
+            acc.push(rsp);
+            terminal.explain(&format!(
+                "Received {}/{} responses...",
+                acc.len(),
+                expected_responses
+            ))?;
+        }
+        acc
+    };
+    reshare::round3(&mut OsRng, state, round2_replies)
+}
+
 /// A custody backend using threshold signing.
 ///
 /// This backend is initialized with a full viewing key, but only a share
@@ -179,15 +286,65 @@ pub async fn dkg(t: u16, n: u16, terminal: &impl Terminal) -> Result<Config> {
 pub struct Threshold<T> {
     config: Config,
     terminal: T,
+    audit_log: Option<AuditLog>,
 }
 
 impl<T> Threshold<T> {
     pub fn new(config: Config, terminal: T) -> Self {
-        Threshold { config, terminal }
+        Threshold {
+            config,
+            terminal,
+            audit_log: None,
+        }
+    }
+
+    /// Record the outcome of every authorization request in an audit log.
+    pub fn with_audit_log(self, audit_log: AuditLog) -> Self {
+        Threshold {
+            audit_log: Some(audit_log),
+            ..self
+        }
     }
 }
 
 impl<T: Terminal> Threshold<T> {
+    /// Like [`Self::authorize`], but recording the outcome in the audit log, if there is one.
+    ///
+    /// Requests are only approved once enough of the other signers have agreed to sign them,
+    /// so failures include other signers declining, as well as errors in the protocol.
+    async fn authorize_and_record(
+        &self,
+        request: SigningRequest,
+        pre_authorizations: &[PreAuthorization],
+    ) -> Result<SigningResponse> {
+        let Some(audit_log) = &self.audit_log else {
+            return self.authorize(request).await;
+        };
+        let summary = match &request {
+            SigningRequest::TransactionPlan(plan) => {
+                RequestSummary::transaction(plan, pre_authorizations, self.config.fvk())
+            }
+            SigningRequest::ValidatorDefinition(_) => {
+                RequestSummary::other(RequestKind::ValidatorDefinition, pre_authorizations)
+            }
+            SigningRequest::ValidatorVote(_) => {
+                RequestSummary::other(RequestKind::ValidatorVote, pre_authorizations)
+            }
+        };
+        let result = self.authorize(request).await;
+        let decision = match &result {
+            Ok(_) => Decision::Approved {
+                policies: vec![format!("Threshold({})", self.config.threshold())],
+            },
+            Err(e) => Decision::Rejected {
+                policy: None,
+                reason: format!("{e:#}"),
+            },
+        };
+        audit_log.append(summary, decision)?;
+        result
+    }
+
     /// Try and create the necessary signatures to authorize the transaction plan.
     async fn authorize(&self, request: SigningRequest) -> Result<SigningResponse> {
         // Some requests will have no signatures to gather, so there's no need
@@ -195,6 +352,7 @@ impl<T: Terminal> Threshold<T> {
         if let Some(out) = no_signature_response(self.config.fvk(), &request)? {
             return Ok(out);
         }
+        self.terminal.begin_session().await?;
         // Round 1
         let (round1_message, state1) = sign::coordinator_round1(&mut OsRng, &self.config, request)?;
         self.terminal
@@ -259,7 +417,10 @@ impl<T: Terminal + Sync + Send + 'static> pb::custody_service_server::CustodySer
             .try_into()
             .map_err(|e| Status::invalid_argument(format!("{e}")))?;
         let data = self
-            .authorize(SigningRequest::TransactionPlan(request.plan))
+            .authorize_and_record(
+                SigningRequest::TransactionPlan(request.plan),
+                &request.pre_authorizations,
+            )
             .await
             .map_err(|e| {
                 Status::internal(format!(
@@ -286,9 +447,10 @@ impl<T: Terminal + Sync + Send + 'static> pb::custody_service_server::CustodySer
             .try_into()
             .map_err(|e| Status::invalid_argument(format!("{e}")))?;
         let data = self
-            .authorize(SigningRequest::ValidatorDefinition(
-                request.validator_definition,
-            ))
+            .authorize_and_record(
+                SigningRequest::ValidatorDefinition(request.validator_definition),
+                &request.pre_authorizations,
+            )
             .await
             .map_err(|e| {
                 Status::internal(format!(
@@ -314,7 +476,10 @@ impl<T: Terminal + Sync + Send + 'static> pb::custody_service_server::CustodySer
             .try_into()
             .map_err(|e| Status::invalid_argument(format!("{e}")))?;
         let data = self
-            .authorize(SigningRequest::ValidatorVote(request.validator_vote))
+            .authorize_and_record(
+                SigningRequest::ValidatorVote(request.validator_vote),
+                &request.pre_authorizations,
+            )
             .await
             .map_err(|e| {
                 Status::internal(format!(
@@ -362,12 +527,115 @@ impl<T: Terminal + Sync + Send + 'static> pb::custody_service_server::CustodySer
 mod test {
     use std::collections::HashMap;
 
+    use penumbra_proto::custody::threshold::v1::{
+        coordinator_service_client::CoordinatorServiceClient,
+        coordinator_service_server::CoordinatorServiceServer,
+    };
     use penumbra_transaction::TransactionPlan;
+    use tonic::transport::Channel;
 
     use tokio::sync;
 
     use super::*;
 
+    const TEST_PLAN: &'static str = r#"
+{
+    "actions": [
+        {
+            "output": {
+                "value": {
+                    "amount": {
+                        "lo": "1000000000"
+                    },
+                    "assetId": {
+                        "inner": "KeqcLzNx9qSH5+lcJHBB9KNW+YPrBk5dKzvPMiypahA="
+                    }
+                },
+                "destAddress": {
+                    "inner": "UuFEV0VoZNxNTttsJVJzRqEzW4bm0z2RCxhUneve0KTvDjQipeg/1zx0ftbDjgr6uPiSA70yJIdlpFyxeLyXfAAtmSy6BCpR3YjEkf1bI5Q="
+                },
+                "rseed": "4m4bxumA0sHuonPjr12UnI4CWKj1wuq4y6rrMRb0nw0=",
+                "valueBlinding": "HHS7tY19JuWMwdKJvtKs8AmhMVa7osSpZ+CCBszu/AE=",
+                "proofBlindingR": "FmbXZoh5Pd2mEtiAEkkAZpllWo9pdwTPlXeODBXHUxA=",
+                "proofBlindingS": "0x96kUchW8jFfnxglAoMtvzPT5/RLg2RvfkRKjlU8BA="
+            }
+        },
+        {
+            "spend": {
+                "note": {
+                    "value": {
+                        "amount": {
+                            "lo": "1000000000000"
+                        },
+                        "assetId": {
+                            "inner": "KeqcLzNx9qSH5+lcJHBB9KNW+YPrBk5dKzvPMiypahA="
+                        }
+                    },
+                    "rseed": "3svSxWREwvvVzb2upQuu3Cyr56O2kRbo0nuX4+OWcdc=",
+                    "address": {
+                        "inner": "6146pY5upA9bQa4tag+6hXpMXa2kO5fcicSJGVEUP4HhZt7m4FpwAJ3+qwr5gpbHUON7DigyEJRpeV31FATGdfJhHBzGDWC+CIvi8dyIzGo="
+                    }
+                },
+                "position": "90",
+                "randomizer": "dJvg8FGvw5rJAvtSQvlQ4imLXahVXn419+xroVMLSwA=",
+                "valueBlinding": "Ce1/hBKLEMB/bjEA06b4zUJVEstNUjkDBWM3WrVu+QM=",
+                "proofBlindingR": "gXA7M4VR48IoxKrf4w4jGae2O7OGlTecU/RBXd4g6QI=",
+                "proofBlindingS": "7+Rhrve7mdgsKbkfFq41yfq9+Mx2qRAZDtwP3VUDAAs="
+            }
+        },
+        {
+            "output": {
+                "value": {
+                    "amount": {
+                        "lo": "999000000000"
+                    },
+                    "assetId": {
+                        "inner": "KeqcLzNx9qSH5+lcJHBB9KNW+YPrBk5dKzvPMiypahA="
+                    }
+                },
+                "destAddress": {
+                    "inner": "6146pY5upA9bQa4tag+6hXpMXa2kO5fcicSJGVEUP4HhZt7m4FpwAJ3+qwr5gpbHUON7DigyEJRpeV31FATGdfJhHBzGDWC+CIvi8dyIzGo="
+                },
+                "rseed": "rCTbPc6xWyEcDV73Pl+W6XXbACShVOM+8/vdc7RSLlo=",
+                "valueBlinding": "DP0FN5CV4g9xZN6u2W6/4o6I/Zwr38n81q4YnJ6COAA=",
+                "proofBlindingR": "KV3u8Dc+cZo0HFUIn7n95UkQVXWeYp+3vAVuIpCIZRI=",
+                "proofBlindingS": "i00KyJVklWXUhVRy37N3p9szFIvo7383to/qxBexnBE="
+            }
+        }
+    ],
+    "transactionParameters": {
+        "chainId": "penumbra-testnet-rhea-8b2dfc5c",
+        "fee": {
+            "amount": {}
+        }
+    },
+    "detectionData": {
+        "cluePlans": [
+            {
+                "address": {
+                    "inner": "UuFEV0VoZNxNTttsJVJzRqEzW4bm0z2RCxhUneve0KTvDjQipeg/1zx0ftbDjgr6uPiSA70yJIdlpFyxeLyXfAAtmSy6BCpR3YjEkf1bI5Q="
+                },
+                "rseed": "1Li0Qx05txsyOrx2pfO9kD5rDSUMy9e+j/hHmucqARI="
+            },
+            {
+                "address": {
+                    "inner": "6146pY5upA9bQa4tag+6hXpMXa2kO5fcicSJGVEUP4HhZt7m4FpwAJ3+qwr5gpbHUON7DigyEJRpeV31FATGdfJhHBzGDWC+CIvi8dyIzGo="
+                },
+                "rseed": "ePtCm9/tFcpLBdlgyu8bYRKV5CHbqd823UGDhG1LsGY="
+            }
+        ]
+    },
+    "memo": {
+        "plaintext": {
+            "returnAddress": {
+                "inner": "OB8AEHEehWo0o0/Dn7JtNmgdDX1VRPaDgn6MLl6n41hVjI3llljrTDCFRRjN5mkNwVwsAyJ/UdfjNIFzbGV62YVXfBJ/IMVTq2CNAHwR8Qo="
+            }
+        },
+        "key": "3plOcPZzKKj8KT3sVdKnblUUFDRzCmMWYtgwB3BqfXQ="
+    }
+}
+"#;
+
     struct FollowerTerminal {
         incoming: sync::Mutex<sync::mpsc::Receiver<String>>,
         outgoing: sync::mpsc::Sender<String>,
@@ -522,103 +790,6 @@ mod test {
 
     #[tokio::test]
     async fn test_transaction_signing() -> Result<()> {
-        const TEST_PLAN: &'static str = r#"
-{
-    "actions": [
-        {
-            "output": {
-                "value": {
-                    "amount": {
-                        "lo": "1000000000"
-                    },
-                    "assetId": {
-                        "inner": "KeqcLzNx9qSH5+lcJHBB9KNW+YPrBk5dKzvPMiypahA="
-                    }
-                },
-                "destAddress": {
-                    "inner": "UuFEV0VoZNxNTttsJVJzRqEzW4bm0z2RCxhUneve0KTvDjQipeg/1zx0ftbDjgr6uPiSA70yJIdlpFyxeLyXfAAtmSy6BCpR3YjEkf1bI5Q="
-                },
-                "rseed": "4m4bxumA0sHuonPjr12UnI4CWKj1wuq4y6rrMRb0nw0=",
-                "valueBlinding": "HHS7tY19JuWMwdKJvtKs8AmhMVa7osSpZ+CCBszu/AE=",
-                "proofBlindingR": "FmbXZoh5Pd2mEtiAEkkAZpllWo9pdwTPlXeODBXHUxA=",
-                "proofBlindingS": "0x96kUchW8jFfnxglAoMtvzPT5/RLg2RvfkRKjlU8BA="
-            }
-        },
-        {
-            "spend": {
-                "note": {
-                    "value": {
-                        "amount": {
-                            "lo": "1000000000000"
-                        },
-                        "assetId": {
-                            "inner": "KeqcLzNx9qSH5+lcJHBB9KNW+YPrBk5dKzvPMiypahA="
-                        }
-                    },
-                    "rseed": "3svSxWREwvvVzb2upQuu3Cyr56O2kRbo0nuX4+OWcdc=",
-                    "address": {
-                        "inner": "6146pY5upA9bQa4tag+6hXpMXa2kO5fcicSJGVEUP4HhZt7m4FpwAJ3+qwr5gpbHUON7DigyEJRpeV31FATGdfJhHBzGDWC+CIvi8dyIzGo="
-                    }
-                },
-                "position": "90",
-                "randomizer": "dJvg8FGvw5rJAvtSQvlQ4imLXahVXn419+xroVMLSwA=",
-                "valueBlinding": "Ce1/hBKLEMB/bjEA06b4zUJVEstNUjkDBWM3WrVu+QM=",
-                "proofBlindingR": "gXA7M4VR48IoxKrf4w4jGae2O7OGlTecU/RBXd4g6QI=",
-                "proofBlindingS": "7+Rhrve7mdgsKbkfFq41yfq9+Mx2qRAZDtwP3VUDAAs="
-            }
-        },
-        {
-            "output": {
-                "value": {
-                    "amount": {
-                        "lo": "999000000000"
-                    },
-                    "assetId": {
-                        "inner": "KeqcLzNx9qSH5+lcJHBB9KNW+YPrBk5dKzvPMiypahA="
-                    }
-                },
-                "destAddress": {
-                    "inner": "6146pY5upA9bQa4tag+6hXpMXa2kO5fcicSJGVEUP4HhZt7m4FpwAJ3+qwr5gpbHUON7DigyEJRpeV31FATGdfJhHBzGDWC+CIvi8dyIzGo="
-                },
-                "rseed": "rCTbPc6xWyEcDV73Pl+W6XXbACShVOM+8/vdc7RSLlo=",
-                "valueBlinding": "DP0FN5CV4g9xZN6u2W6/4o6I/Zwr38n81q4YnJ6COAA=",
-                "proofBlindingR": "KV3u8Dc+cZo0HFUIn7n95UkQVXWeYp+3vAVuIpCIZRI=",
-                "proofBlindingS": "i00KyJVklWXUhVRy37N3p9szFIvo7383to/qxBexnBE="
-            }
-        }
-    ],
-    "transactionParameters": {
-        "chainId": "penumbra-testnet-rhea-8b2dfc5c",
-        "fee": {
-            "amount": {}
-        }
-    },
-    "detectionData": {
-        "cluePlans": [
-            {
-                "address": {
-                    "inner": "UuFEV0VoZNxNTttsJVJzRqEzW4bm0z2RCxhUneve0KTvDjQipeg/1zx0ftbDjgr6uPiSA70yJIdlpFyxeLyXfAAtmSy6BCpR3YjEkf1bI5Q="
-                },
-                "rseed": "1Li0Qx05txsyOrx2pfO9kD5rDSUMy9e+j/hHmucqARI="
-            },
-            {
-                "address": {
-                    "inner": "6146pY5upA9bQa4tag+6hXpMXa2kO5fcicSJGVEUP4HhZt7m4FpwAJ3+qwr5gpbHUON7DigyEJRpeV31FATGdfJhHBzGDWC+CIvi8dyIzGo="
-                },
-                "rseed": "ePtCm9/tFcpLBdlgyu8bYRKV5CHbqd823UGDhG1LsGY="
-            }
-        ]
-    },
-    "memo": {
-        "plaintext": {
-            "returnAddress": {
-                "inner": "OB8AEHEehWo0o0/Dn7JtNmgdDX1VRPaDgn6MLl6n41hVjI3llljrTDCFRRjN5mkNwVwsAyJ/UdfjNIFzbGV62YVXfBJ/IMVTq2CNAHwR8Qo="
-            }
-        },
-        "key": "3plOcPZzKKj8KT3sVdKnblUUFDRzCmMWYtgwB3BqfXQ="
-    }
-}
-        "#;
         const T: u16 = 3;
         const N: u16 = 3;
 
@@ -638,12 +809,124 @@ mod test {
         let authorization_data = Threshold::new(coordinator_config, coordinator_terminal)
             .authorize(SigningRequest::TransactionPlan(plan.clone()))
             .await?;
+        check_transaction_authorization(&plan, &fvk, authorization_data)
+    }
+
+    #[tokio::test]
+    async fn test_signing_is_audited() -> Result<()> {
+        const T: u16 = 2;
+        const N: u16 = 2;
+
+        let (coordinator_config, follower_configs) = {
+            let mut configs = run_dkg(T, N).await?;
+            (configs.pop().unwrap(), configs)
+        };
+        let (coordinator_terminal, follower_terminals) = make_terminals((N - 1) as usize);
+        for (config, terminal) in follower_configs
+            .into_iter()
+            .zip(follower_terminals.into_iter())
+        {
+            tokio::spawn(async move { follow(Some(&config), Some(&config), &terminal).await });
+        }
+        let path = std::env::temp_dir().join(format!(
+            "threshold-audit-log-{}.jsonl",
+            rand_core::RngCore::next_u64(&mut OsRng)
+        ));
+        let plan = serde_json::from_str::<TransactionPlan>(TEST_PLAN)?;
+        let threshold = Threshold::new(coordinator_config, coordinator_terminal)
+            .with_audit_log(AuditLog::new(&path));
+        threshold
+            .authorize_and_record(SigningRequest::TransactionPlan(plan.clone()), &[])
+            .await?;
+
+        let records = AuditLog::verify(&path)?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(records.len(), 1);
+        let entry = &records[0].entry;
+        assert_eq!(entry.request.kind, RequestKind::Transaction);
+        assert_eq!(
+            entry.request.effect_hash,
+            Some(hex::encode(
+                plan.effect_hash(threshold.config.fvk())?.as_bytes()
+            ))
+        );
+        assert_eq!(
+            entry.decision,
+            Decision::Approved {
+                policies: vec!["Threshold(2)".to_owned()],
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reshare_keeps_fvk_and_can_sign() -> Result<()> {
+        const T: u16 = 3;
+        const N: u16 = 4;
+        // Two of the old 2-of-3 participants deal, and one of them stays on.
+        let mut old_configs = Config::deal(&mut OsRng, 2, 3)?;
+        let fvk = old_configs[0].fvk().clone();
+        let old_keys = old_configs[0].verification_keys();
+        let stale = old_configs[2].clone();
+        old_configs.truncate(2);
+        let mut terminals = make_symmetric_terminals(5);
+        let mut handles = Vec::new();
+        for (i, config) in old_configs.into_iter().enumerate() {
+            let terminal = terminals.pop().unwrap();
+            handles.push(tokio::spawn(async move {
+                reshare(Some(&config), i == 0, T, N, 2, &terminal).await
+            }));
+        }
+        for terminal in terminals {
+            handles.push(tokio::spawn(async move {
+                reshare(None, true, T, N, 2, &terminal).await
+            }));
+        }
+        let mut configs = Vec::new();
+        for handle in handles {
+            configs.extend(handle.await??);
+        }
+        assert_eq!(configs.len(), N as usize);
+        for config in &configs {
+            assert_eq!(config.fvk(), &fvk);
+            assert_eq!(config.threshold(), T);
+            assert!(config.verification_keys().is_disjoint(&old_keys));
+            assert_eq!(config.key_epoch(), stale.key_epoch() + 1);
+        }
+
+        // Shares from before the reshare are refused, whether they're held by the coordinator
+        // or by a follower.
+        let request = SigningRequest::TransactionPlan(serde_json::from_str(TEST_PLAN)?);
+        let (stale_round1, _) = sign::coordinator_round1(&mut OsRng, &stale, request.clone())?;
+        assert!(sign::follower_round1(&mut OsRng, &configs[0], stale_round1.clone()).is_err());
+        let (stale_reply, _) = sign::follower_round1(&mut OsRng, &stale, stale_round1)?;
+        let (round1, state) = sign::coordinator_round1(&mut OsRng, &configs[0], request)?;
+        assert!(sign::follower_round1(&mut OsRng, &stale, round1).is_err());
+        assert!(sign::coordinator_round2(&configs[0], state, &[stale_reply]).is_err());
+
+        let coordinator_config = configs.pop().unwrap();
+        let (coordinator_terminal, follower_terminals) = make_terminals((T - 1) as usize);
+        for (config, terminal) in configs.into_iter().zip(follower_terminals.into_iter()) {
+            tokio::spawn(async move { follow(Some(&config), Some(&config), &terminal).await });
+        }
+        let plan = serde_json::from_str::<TransactionPlan>(TEST_PLAN)?;
+        let authorization_data = Threshold::new(coordinator_config, coordinator_terminal)
+            .authorize(SigningRequest::TransactionPlan(plan.clone()))
+            .await?;
+        check_transaction_authorization(&plan, &fvk, authorization_data)
+    }
+
+    fn check_transaction_authorization(
+        plan: &TransactionPlan,
+        fvk: &FullViewingKey,
+        authorization_data: SigningResponse,
+    ) -> Result<()> {
         let tx_authorization_data = match authorization_data {
             SigningResponse::Transaction(tx) => tx,
             _ => panic!("expected transaction authorization data"),
         };
         assert_eq!(
-            plan.effect_hash(&fvk)?,
+            plan.effect_hash(fvk)?,
             tx_authorization_data
                 .effect_hash
                 .expect("effect hash not present")
@@ -664,4 +947,130 @@ mod test {
         }
         Ok(())
     }
+
+    /// A terminal confirming every request, and forwarding its explanations to a channel.
+    struct ConfirmingTerminal(sync::mpsc::UnboundedSender<String>);
+
+    #[async_trait]
+    impl Terminal for ConfirmingTerminal {
+        async fn confirm_request(&self, _request: &SigningRequest) -> Result<bool> {
+            Ok(true)
+        }
+
+        fn explain(&self, msg: &str) -> Result<()> {
+            // Nobody may be listening, which is fine.
+            let _ = self.0.send(msg.to_owned());
+            Ok(())
+        }
+
+        async fn broadcast(&self, _data: &str) -> Result<()> {
+            anyhow::bail!("messages should be sent over the network")
+        }
+
+        async fn read_line_raw(&self) -> Result<String> {
+            anyhow::bail!("messages should be received over the network")
+        }
+
+        async fn get_password(&self) -> Result<String> {
+            Ok(Default::default())
+        }
+    }
+
+    fn confirming_terminal() -> ConfirmingTerminal {
+        ConfirmingTerminal(sync::mpsc::unbounded_channel().0)
+    }
+
+    /// Run a coordinator on a local port, returning a client connected to it.
+    async fn spawn_coordinator() -> Result<CoordinatorServiceClient<Channel>> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let incoming = futures::stream::unfold(listener, |listener| async move {
+            let conn = listener.accept().await.map(|(stream, _)| stream);
+            Some((conn, listener))
+        });
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(CoordinatorServiceServer::new(Coordinator::new(None)))
+                .serve_with_incoming(incoming),
+        );
+        let channel = Channel::from_shared(format!("http://{addr}"))?.connect_lazy();
+        Ok(CoordinatorServiceClient::new(channel))
+    }
+
+    async fn run_network_signing(t: u16, n: u16) -> Result<()> {
+        let client = spawn_coordinator().await?;
+        let (coordinator_config, follower_configs) = {
+            let mut configs = Config::deal(&mut OsRng, t, n)?;
+            (configs.pop().unwrap(), configs)
+        };
+        let plan = serde_json::from_str::<TransactionPlan>(TEST_PLAN)?;
+        let fvk = coordinator_config.fvk().clone();
+
+        let (explanations_send, mut explanations) = sync::mpsc::unbounded_channel();
+        let terminal = NetworkTerminal::coordinator(
+            ConfirmingTerminal(explanations_send),
+            client.clone(),
+            &coordinator_config,
+        );
+        let authorization = {
+            let plan = plan.clone();
+            tokio::spawn(async move {
+                Threshold::new(coordinator_config, terminal)
+                    .authorize(SigningRequest::TransactionPlan(plan))
+                    .await
+            })
+        };
+
+        // The followers find out which session to join from the coordinator's terminal.
+        let session = loop {
+            let explanation = explanations.recv().await.expect("coordinator is running");
+            if let Some(rest) = explanation.strip_prefix("Started threshold signing session ") {
+                break rest.split(';').next().unwrap().to_owned();
+            }
+        };
+        for config in follower_configs {
+            let terminal = NetworkTerminal::follower(
+                confirming_terminal(),
+                client.clone(),
+                session.clone(),
+                *config.signing_key(),
+                config.verification_keys(),
+            );
+            // With more followers than needed, the ones the coordinator doesn't pick will fail.
+            tokio::spawn(async move { follow(Some(&config), Some(&config), &terminal).await });
+        }
+
+        check_transaction_authorization(&plan, &fvk, authorization.await??)
+    }
+
+    #[tokio::test]
+    async fn test_network_signing() -> Result<()> {
+        run_network_signing(3, 3).await
+    }
+
+    #[tokio::test]
+    async fn test_network_signing_with_extra_followers() -> Result<()> {
+        run_network_signing(2, 4).await
+    }
+
+    #[tokio::test]
+    async fn test_network_dkg() -> Result<()> {
+        const T: u16 = 2;
+        const N: u16 = 3;
+        let client = spawn_coordinator().await?;
+        let mut handles = Vec::new();
+        for _ in 0..N {
+            let terminal =
+                NetworkTerminal::peer(confirming_terminal(), client.clone(), "dkg".to_owned());
+            handles.push(tokio::spawn(async move { dkg(T, N, &terminal).await }));
+        }
+        let mut configs = Vec::new();
+        for handle in handles {
+            configs.push(handle.await??);
+        }
+        for config in &configs[1..] {
+            assert_eq!(configs[0].fvk(), config.fvk());
+        }
+        Ok(())
+    }
 }
@@ -99,6 +99,12 @@ pub struct Config {
         as = "HashMap<TryFromInto<VerificationKeyWrapper>, TryFromInto<VerifyingShareWrapper>>"
     )]
     verifying_shares: HashMap<VerificationKey, frost::keys::VerifyingShare>,
+    /// How many times the key has been reshared.
+    ///
+    /// Shares from different epochs can't sign together, and participants refuse to sign with
+    /// anyone whose share is from a different epoch than their own.
+    #[serde(default)]
+    key_epoch: u64,
 }
 
 impl PartialEq for Config {
@@ -109,19 +115,21 @@ impl PartialEq for Config {
             // TIMING LEAK
             && self.signing_key.as_bytes() == other.signing_key.as_bytes()
             && self.verifying_shares == other.verifying_shares
+            && self.key_epoch == other.key_epoch
     }
 }
 
 impl Eq for Config {}
 
 impl Config {
-    /// Create a config from the parts that get spit out by the DKG protocol.
+    /// Create a config from the parts that get spit out by the DKG and resharing protocols.
     pub(crate) fn from_parts(
         key_package: frost::keys::KeyPackage,
         public_key_package: frost::keys::PublicKeyPackage,
         signing_key: SigningKey,
         verification_keys: Vec<VerificationKey>,
         nullifier_key: Fq,
+        key_epoch: u64,
     ) -> Self {
         let fvk = FullViewingKey::from_components(
             public_key_package
@@ -147,6 +155,7 @@ impl Config {
             spend_key_share,
             signing_key,
             verifying_shares,
+            key_epoch,
         }
     }
 
@@ -203,6 +212,7 @@ impl Config {
                     fvk: fvk.clone(),
                     spend_key_share: signing_share,
                     verifying_shares: verifying_shares.clone(),
+                    key_epoch: 0,
                 }
             })
             .collect())
@@ -259,6 +269,10 @@ impl Config {
     pub fn verification_keys(&self) -> HashSet<VerificationKey> {
         self.verifying_shares.keys().cloned().collect()
     }
+
+    pub fn key_epoch(&self) -> u64 {
+        self.key_epoch
+    }
 }
 
 #[cfg(test)]
@@ -278,6 +292,7 @@ mod test {
         assert_eq!(config.fvk, config2.fvk);
         assert_eq!(config.spend_key_share, config2.spend_key_share);
         assert_eq!(config.verifying_shares, config2.verifying_shares);
+        assert_eq!(config.key_epoch, config2.key_epoch);
         Ok(())
     }
 }
@@ -0,0 +1,484 @@
+//! Relaying the messages of the threshold protocols over the network.
+//!
+//! Instead of having participants copy and paste messages between their terminals,
+//! a [`Coordinator`] service relays them, and each participant uses a [`NetworkTerminal`]
+//! to publish and receive messages automatically.
+//!
+//! Every message is signed by its sender, and bound to a session and a round, so neither the
+//! coordinator nor anyone else on the network can forge, redirect, or replay messages.
+use std::{
+    collections::{HashMap, HashSet},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+use ed25519_consensus::{Signature, SigningKey, VerificationKey};
+use futures::Stream;
+use penumbra_proto::custody::threshold::v1::{
+    self as pb, coordinator_service_client::CoordinatorServiceClient,
+    coordinator_service_server::CoordinatorService,
+};
+use prost::Message as _;
+use rand_core::{OsRng, RngCore as _};
+use tokio::sync::Notify;
+use tonic::{async_trait, transport::Channel, Request, Response, Status, Streaming};
+
+use super::Config;
+use crate::terminal::{SigningRequest, Terminal};
+
+/// How long a session can go without any new messages before the coordinator forgets it.
+const SESSION_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+fn vk_to_pb(vk: &VerificationKey) -> pb::VerificationKey {
+    pb::VerificationKey {
+        inner: vk.to_bytes().to_vec(),
+    }
+}
+
+/// Sign a session message, replacing any existing signature.
+fn sign_message(signing_key: &SigningKey, mut message: pb::SessionMessage) -> pb::SessionMessage {
+    message.sig = None;
+    let sig = signing_key.sign(&message.encode_to_vec());
+    message.sig = Some(pb::Signature {
+        inner: sig.to_bytes().to_vec(),
+    });
+    message
+}
+
+/// Check the signature on a session message, returning the key of its sender.
+fn verify_message(message: &pb::SessionMessage) -> Result<VerificationKey> {
+    let sender: VerificationKey = message
+        .sender
+        .as_ref()
+        .ok_or(anyhow!("missing sender"))?
+        .inner
+        .as_slice()
+        .try_into()?;
+    let sig: Signature = message
+        .sig
+        .as_ref()
+        .ok_or(anyhow!("missing sig"))?
+        .inner
+        .as_slice()
+        .try_into()?;
+    let mut unsigned = message.clone();
+    unsigned.sig = None;
+    sender.verify(&sig, &unsigned.encode_to_vec())?;
+    Ok(sender)
+}
+
+/// The messages published to a single session.
+struct Session {
+    messages: Vec<pb::SessionMessage>,
+    /// The last round published by each sender.
+    rounds: HashMap<VerificationKey, u64>,
+    last_active: Instant,
+}
+
+impl Session {
+    fn new() -> Self {
+        Self {
+            messages: Vec::new(),
+            rounds: HashMap::new(),
+            last_active: Instant::now(),
+        }
+    }
+}
+
+struct CoordinatorState {
+    sessions: Mutex<HashMap<String, Session>>,
+    /// Notified whenever a message is published to any session.
+    published: Notify,
+}
+
+/// A service relaying the messages of threshold signing and DKG sessions between participants.
+///
+/// The coordinator doesn't need to be trusted, since participants check the signatures on the
+/// messages they receive themselves. It checks them as well, rejecting messages which are replayed
+/// or, if it was given a set of participants, which were sent by anyone else.
+pub struct Coordinator {
+    participants: Option<HashSet<VerificationKey>>,
+    state: Arc<CoordinatorState>,
+}
+
+impl Coordinator {
+    /// Create a new coordinator, only accepting messages from `participants`, if set.
+    pub fn new(participants: Option<HashSet<VerificationKey>>) -> Self {
+        Self {
+            participants,
+            state: Arc::new(CoordinatorState {
+                sessions: Mutex::new(HashMap::new()),
+                published: Notify::new(),
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl CoordinatorService for Coordinator {
+    type SubscribeStream =
+        Pin<Box<dyn Stream<Item = Result<pb::SubscribeResponse, Status>> + Send>>;
+
+    async fn publish(
+        &self,
+        request: Request<pb::PublishRequest>,
+    ) -> Result<Response<pb::PublishResponse>, Status> {
+        let message = request
+            .into_inner()
+            .message
+            .ok_or_else(|| Status::invalid_argument("missing message"))?;
+        let sender = verify_message(&message)
+            .map_err(|e| Status::unauthenticated(format!("invalid message signature: {e:#}")))?;
+        if let Some(participants) = &self.participants {
+            if !participants.contains(&sender) {
+                return Err(Status::permission_denied(
+                    "sender is not a participant of this coordinator",
+                ));
+            }
+        }
+
+        {
+            let mut sessions = self.state.sessions.lock().expect("lock is not poisoned");
+            sessions.retain(|_, session| session.last_active.elapsed() < SESSION_TIMEOUT);
+            let session = sessions
+                .entry(message.session.clone())
+                .or_insert_with(Session::new);
+            let last_round = session.rounds.entry(sender).or_default();
+            if message.round != *last_round + 1 {
+                return Err(Status::failed_precondition(format!(
+                    "expected round {} from this sender, got round {}",
+                    *last_round + 1,
+                    message.round
+                )));
+            }
+            *last_round = message.round;
+            session.messages.push(message);
+            session.last_active = Instant::now();
+        }
+        self.state.published.notify_waiters();
+
+        Ok(Response::new(pb::PublishResponse {}))
+    }
+
+    async fn subscribe(
+        &self,
+        request: Request<pb::SubscribeRequest>,
+    ) -> Result<Response<Self::SubscribeStream>, Status> {
+        let pb::SubscribeRequest {
+            session,
+            participant,
+        } = request.into_inner();
+        let participant =
+            participant.ok_or_else(|| Status::invalid_argument("missing participant"))?;
+
+        let state = self.state.clone();
+        let stream = futures::stream::unfold(0usize, move |mut next| {
+            let state = state.clone();
+            let session = session.clone();
+            let participant = participant.clone();
+            async move {
+                loop {
+                    // Register for notifications before checking, so that we can't miss a message
+                    // published in between.
+                    let published = state.published.notified();
+                    let message = state
+                        .sessions
+                        .lock()
+                        .expect("lock is not poisoned")
+                        .get(&session)
+                        .and_then(|session| session.messages.get(next).cloned());
+                    let Some(message) = message else {
+                        published.await;
+                        continue;
+                    };
+                    next += 1;
+                    // Skip our own messages, and those meant for someone else.
+                    let ours = message.sender.as_ref() == Some(&participant);
+                    let for_us = message
+                        .recipient
+                        .as_ref()
+                        .map_or(true, |recipient| recipient == &participant);
+                    if !ours && for_us {
+                        let response = pb::SubscribeResponse {
+                            message: Some(message),
+                        };
+                        return Some((Ok(response), next));
+                    }
+                }
+            }
+        });
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+/// The part a participant plays in a session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Role {
+    /// Starts signing sessions, and sends messages to every other participant.
+    Coordinator,
+    /// Replies to the messages of a coordinator, and only to it.
+    Follower,
+    /// Sends messages to, and receives messages from, every other participant.
+    Peer,
+}
+
+/// A message received from another participant.
+struct Received {
+    sender: VerificationKey,
+    round: u64,
+    payload: String,
+}
+
+#[derive(Default)]
+struct SessionState {
+    session: Option<String>,
+    incoming: Option<Streaming<pb::SubscribeResponse>>,
+    /// The number of messages we've published to the session.
+    sent: u64,
+    /// The coordinator we're following, once we've heard from it.
+    leader: Option<VerificationKey>,
+    /// Messages we've received, but which aren't part of the current round yet.
+    pending: Vec<Received>,
+    /// The sender and round of each message we've returned so far.
+    delivered: HashSet<(VerificationKey, u64)>,
+}
+
+impl SessionState {
+    fn new(session: String) -> Self {
+        Self {
+            session: Some(session),
+            ..Default::default()
+        }
+    }
+
+    /// Take the next message belonging to the current round, if we've received one.
+    fn take_next(&mut self, role: Role) -> Option<Received> {
+        // Followers speak after the coordinator, everyone else speaks first.
+        let expected = match role {
+            Role::Follower => self.sent + 1,
+            Role::Coordinator | Role::Peer => self.sent,
+        };
+        // Messages from rounds we've moved past can't be used anymore.
+        self.pending.retain(|received| received.round >= expected);
+        loop {
+            let leader = self.leader;
+            let i = self.pending.iter().position(|received| {
+                received.round == expected
+                    && leader.map_or(true, |leader| leader == received.sender)
+            })?;
+            let received = self.pending.remove(i);
+            if !self.delivered.insert((received.sender, received.round)) {
+                continue;
+            }
+            if role == Role::Follower {
+                self.leader = Some(received.sender);
+            }
+            return Some(received);
+        }
+    }
+}
+
+/// A terminal which exchanges protocol messages with the other participants through a
+/// [`Coordinator`], rather than having the user copy and paste them.
+///
+/// Signing requests are still confirmed through the inner terminal.
+pub struct NetworkTerminal<T> {
+    inner: T,
+    client: CoordinatorServiceClient<Channel>,
+    role: Role,
+    signing_key: SigningKey,
+    /// The participants we accept messages from, if known in advance.
+    participants: Option<HashSet<VerificationKey>>,
+    state: tokio::sync::Mutex<SessionState>,
+}
+
+impl<T> NetworkTerminal<T> {
+    /// Create a terminal for coordinating signing sessions with the signers of `config`.
+    ///
+    /// A fresh session is started for each signing request, and its name is shown through the
+    /// inner terminal, so that the other signers can join it.
+    pub fn coordinator(
+        inner: T,
+        client: CoordinatorServiceClient<Channel>,
+        config: &Config,
+    ) -> Self {
+        Self {
+            inner,
+            client,
+            role: Role::Coordinator,
+            signing_key: *config.signing_key(),
+            participants: Some(config.verification_keys()),
+            state: Default::default(),
+        }
+    }
+
+    /// Create a terminal for following the coordinator of the signing session named `session`.
+    ///
+    /// Only messages sent by one of `participants` are accepted.
+    pub fn follower(
+        inner: T,
+        client: CoordinatorServiceClient<Channel>,
+        session: String,
+        signing_key: SigningKey,
+        participants: HashSet<VerificationKey>,
+    ) -> Self {
+        Self {
+            inner,
+            client,
+            role: Role::Follower,
+            signing_key,
+            participants: Some(participants),
+            state: tokio::sync::Mutex::new(SessionState::new(session)),
+        }
+    }
+
+    /// Create a terminal for taking part in the DKG session named `session`.
+    ///
+    /// Since participants don't have an identity before the DKG, messages are signed with a
+    /// key generated for this session alone, and messages from anyone in the session are
+    /// accepted. The session name should therefore be hard to guess.
+    pub fn peer(inner: T, client: CoordinatorServiceClient<Channel>, session: String) -> Self {
+        Self {
+            inner,
+            client,
+            role: Role::Peer,
+            signing_key: SigningKey::new(OsRng),
+            participants: None,
+            state: tokio::sync::Mutex::new(SessionState::new(session)),
+        }
+    }
+
+    /// Check that a message was validly sent to us in this session.
+    fn check(&self, session: &str, message: &pb::SessionMessage) -> Result<VerificationKey> {
+        let sender = verify_message(message)?;
+        if message.session != session {
+            anyhow::bail!("message belongs to session {}", message.session);
+        }
+        if let Some(participants) = &self.participants {
+            if !participants.contains(&sender) {
+                anyhow::bail!("unknown sender: {}", hex::encode(sender.as_bytes()));
+            }
+        }
+        if let Some(recipient) = &message.recipient {
+            if recipient != &vk_to_pb(&self.signing_key.verification_key()) {
+                anyhow::bail!("message is addressed to another participant");
+            }
+        }
+        Ok(sender)
+    }
+}
+
+#[async_trait]
+impl<T: Terminal + Send> Terminal for NetworkTerminal<T> {
+    async fn confirm_request(&self, request: &SigningRequest) -> Result<bool> {
+        self.inner.confirm_request(request).await
+    }
+
+    fn explain(&self, msg: &str) -> Result<()> {
+        // The protocol's explanations are about relaying messages by hand, which we do for the user.
+        tracing::debug!("{}", msg);
+        Ok(())
+    }
+
+    async fn begin_session(&self) -> Result<()> {
+        if self.role != Role::Coordinator {
+            return Ok(());
+        }
+        let mut bytes = [0u8; 16];
+        OsRng.fill_bytes(&mut bytes);
+        let session = hex::encode(bytes);
+        self.inner.explain(&format!(
+            "Started threshold signing session {session}; the other signers need to join it to continue."
+        ))?;
+        *self.state.lock().await = SessionState::new(session);
+        Ok(())
+    }
+
+    async fn broadcast(&self, data: &str) -> Result<()> {
+        let mut state = self.state.lock().await;
+        let session = state
+            .session
+            .clone()
+            .ok_or(anyhow!("no session has been started"))?;
+        let recipient = match self.role {
+            Role::Follower => Some(
+                state
+                    .leader
+                    .ok_or(anyhow!("cannot reply before hearing from the coordinator"))?,
+            ),
+            Role::Coordinator | Role::Peer => None,
+        };
+        let round = state.sent + 1;
+        let message = sign_message(
+            &self.signing_key,
+            pb::SessionMessage {
+                session,
+                sender: Some(vk_to_pb(&self.signing_key.verification_key())),
+                recipient: recipient.as_ref().map(vk_to_pb),
+                round,
+                payload: data.to_owned(),
+                sig: None,
+            },
+        );
+        self.client
+            .clone()
+            .publish(pb::PublishRequest {
+                message: Some(message),
+            })
+            .await?;
+        state.sent = round;
+        Ok(())
+    }
+
+    async fn read_line_raw(&self) -> Result<String> {
+        let mut state = self.state.lock().await;
+        let session = state
+            .session
+            .clone()
+            .ok_or(anyhow!("no session has been started"))?;
+        if state.incoming.is_none() {
+            let incoming = self
+                .client
+                .clone()
+                .subscribe(pb::SubscribeRequest {
+                    session: session.clone(),
+                    participant: Some(vk_to_pb(&self.signing_key.verification_key())),
+                })
+                .await?
+                .into_inner();
+            state.incoming = Some(incoming);
+        }
+        loop {
+            if let Some(received) = state.take_next(self.role) {
+                self.inner.explain(&format!(
+                    "Received a message from {}",
+                    hex::encode(received.sender.as_bytes())
+                ))?;
+                return Ok(received.payload);
+            }
+            let message = state
+                .incoming
+                .as_mut()
+                .expect("subscribed to the session")
+                .message()
+                .await?
+                .and_then(|response| response.message)
+                .ok_or(anyhow!("the coordinator closed the session"))?;
+            match self.check(&session, &message) {
+                Ok(sender) => state.pending.push(Received {
+                    sender,
+                    round: message.round,
+                    payload: message.payload,
+                }),
+                Err(e) => tracing::warn!(?e, "ignoring invalid message from the coordinator"),
+            }
+        }
+    }
+
+    async fn get_password(&self) -> Result<String> {
+        self.inner.get_password().await
+    }
+}
@@ -0,0 +1,628 @@
+//! A protocol to redistribute the shares of an existing threshold key.
+//!
+//! Each participating holder of an old share deals a fresh sharing of their own share,
+//! using a polynomial with the new threshold. The new participants combine these sharings
+//! with the Lagrange coefficients of the dealers, which produces shares of the same group
+//! key under the new threshold. Because the old and new polynomials are unrelated, old
+//! shares can't be combined with new ones, and become useless once the old participants
+//! delete them.
+//!
+//! The new shares belong to the next key epoch, and participants refuse to sign with anyone
+//! whose share is from another epoch, so that an old share that wasn't deleted isn't accepted
+//! alongside the new ones.
+use anyhow::{anyhow, Result};
+use ark_ff::{Field as _, One, UniformRand, Zero};
+use decaf377::{Element, FieldExt, Fr};
+use decaf377_frost as frost;
+use ed25519_consensus::{Signature, SigningKey, VerificationKey};
+use penumbra_keys::FullViewingKey;
+use penumbra_proto::{custody::threshold::v1 as pb, DomainType, Message};
+use rand_core::CryptoRngCore;
+use std::collections::{HashMap, HashSet};
+
+use super::dkg::encryption::{DecryptionKey, EncryptionKey};
+use super::Config;
+
+/// The scalar a verification key gets used as when evaluating polynomials.
+///
+/// This needs to match the identifiers FROST uses when signing.
+fn identifier_scalar(vk: &VerificationKey) -> Result<Fr> {
+    decode_scalar(&frost::Identifier::derive(vk.as_bytes())?.serialize())
+}
+
+/// Compute the Lagrange coefficient of `x` at 0, relative to the set `xs`.
+fn lagrange_coefficient(x: Fr, xs: impl Iterator<Item = Fr>) -> Result<Fr> {
+    let mut num = Fr::one();
+    let mut den = Fr::one();
+    for other in xs.filter(|other| *other != x) {
+        num *= other;
+        den *= other - x;
+    }
+    Ok(num * den.inverse().ok_or(anyhow!("duplicate identifiers"))?)
+}
+
+/// Evaluate the polynomial committed to by `commitments` at `x`, in the exponent.
+fn evaluate_commitments(commitments: &[Element], x: Fr) -> Element {
+    commitments
+        .iter()
+        .rev()
+        .fold(Element::default(), |acc, c| acc * x + c)
+}
+
+fn decode_scalar(bytes: &[u8]) -> Result<Fr> {
+    Fr::from_bytes(bytes.try_into()?).map_err(|_| anyhow!("invalid scalar encoding"))
+}
+
+fn decode_element(bytes: &[u8]) -> Result<Element> {
+    decaf377::Encoding(bytes.try_into()?)
+        .vartime_decompress()
+        .map_err(|_| anyhow!("invalid group element encoding"))
+}
+
+/// Return None for empty bytes, and parse them otherwise.
+fn optional<T>(bytes: &[u8], parse: impl FnOnce(&[u8]) -> Result<T>) -> Result<Option<T>> {
+    if bytes.is_empty() {
+        Ok(None)
+    } else {
+        parse(bytes).map(Some)
+    }
+}
+
+/// The message we send in round 1 of the resharing protocol.
+///
+/// This announces which roles we're playing in the protocol: dealing one of the old
+/// shares, receiving one of the new shares, or both.
+#[derive(Clone)]
+pub struct Round1 {
+    /// The verification key from our old config, if we're dealing.
+    dealer: Option<(VerificationKey, Signature)>,
+    /// Our new verification key, and a key to encrypt our share to, if we're a new member.
+    member: Option<(VerificationKey, EncryptionKey, Signature)>,
+}
+
+impl Round1 {
+    /// The encoding of this message that both signatures cover.
+    fn signed_data(
+        dealer: Option<VerificationKey>,
+        member: Option<(VerificationKey, EncryptionKey)>,
+    ) -> Vec<u8> {
+        pb::ReshareRound1 {
+            dealer_vk: dealer.map(|vk| vk.as_bytes().to_vec()).unwrap_or_default(),
+            member_vk: member
+                .map(|(vk, _)| vk.as_bytes().to_vec())
+                .unwrap_or_default(),
+            epk: member
+                .map(|(_, epk)| epk.as_bytes().to_vec())
+                .unwrap_or_default(),
+            dealer_sig: Vec::new(),
+            member_sig: Vec::new(),
+        }
+        .encode_to_vec()
+    }
+
+    fn make(dealer: Option<&SigningKey>, member: Option<(&SigningKey, EncryptionKey)>) -> Self {
+        let data = Self::signed_data(
+            dealer.map(|sk| sk.verification_key()),
+            member.map(|(sk, epk)| (sk.verification_key(), epk)),
+        );
+        Self {
+            dealer: dealer.map(|sk| (sk.verification_key(), sk.sign(&data))),
+            member: member.map(|(sk, epk)| (sk.verification_key(), epk, sk.sign(&data))),
+        }
+    }
+
+    fn verify(&self) -> Result<()> {
+        if self.dealer.is_none() && self.member.is_none() {
+            anyhow::bail!("round 1 message is neither from a dealer nor from a member");
+        }
+        let data = Self::signed_data(
+            self.dealer.map(|(vk, _)| vk),
+            self.member.map(|(vk, epk, _)| (vk, epk)),
+        );
+        if let Some((vk, sig)) = &self.dealer {
+            vk.verify(sig, &data)?;
+        }
+        if let Some((vk, _, sig)) = &self.member {
+            vk.verify(sig, &data)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Round1> for pb::ReshareRound1 {
+    fn from(value: Round1) -> Self {
+        Self {
+            dealer_vk: value
+                .dealer
+                .map(|(vk, _)| vk.as_bytes().to_vec())
+                .unwrap_or_default(),
+            member_vk: value
+                .member
+                .map(|(vk, _, _)| vk.as_bytes().to_vec())
+                .unwrap_or_default(),
+            epk: value
+                .member
+                .map(|(_, epk, _)| epk.as_bytes().to_vec())
+                .unwrap_or_default(),
+            dealer_sig: value
+                .dealer
+                .map(|(_, sig)| sig.to_bytes().to_vec())
+                .unwrap_or_default(),
+            member_sig: value
+                .member
+                .map(|(_, _, sig)| sig.to_bytes().to_vec())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl TryFrom<pb::ReshareRound1> for Round1 {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::ReshareRound1) -> std::result::Result<Self, Self::Error> {
+        let dealer = optional(&value.dealer_vk, |bytes| {
+            Ok((bytes.try_into()?, value.dealer_sig.as_slice().try_into()?))
+        })?;
+        let member = optional(&value.member_vk, |bytes| {
+            Ok((
+                bytes.try_into()?,
+                value.epk.as_slice().try_into()?,
+                value.member_sig.as_slice().try_into()?,
+            ))
+        })?;
+        Ok(Self { dealer, member })
+    }
+}
+
+impl DomainType for Round1 {
+    type Proto = pb::ReshareRound1;
+}
+
+fn round2_to_pb(
+    vk: VerificationKey,
+    commitments: &[Element],
+    encrypted_shares: &HashMap<VerificationKey, Vec<u8>>,
+    fvk: Option<&FullViewingKey>,
+    key_epoch: u64,
+    sig: Option<Signature>,
+) -> pb::ReshareRound2 {
+    // Need to sort to guarantee a deterministic encoding for signing.
+    let encrypted_shares = {
+        let mut acc: Vec<_> = encrypted_shares
+            .iter()
+            .map(|(k, v)| pb::ReshareTargetedShare {
+                vk: k.as_bytes().to_vec(),
+                encrypted_share: v.clone(),
+            })
+            .collect();
+        acc.sort_by_key(|x| x.vk.clone());
+        acc
+    };
+    pb::ReshareRound2 {
+        vk: vk.as_bytes().to_vec(),
+        commitments: commitments
+            .iter()
+            .map(|c| c.vartime_compress().0.to_vec())
+            .collect(),
+        encrypted_shares,
+        fvk: fvk.map(|fvk| fvk.to_string()).unwrap_or_default(),
+        sig: sig.map(|sig| sig.to_bytes().to_vec()).unwrap_or_default(),
+        key_epoch,
+    }
+}
+
+/// The message we send in round 2 of the resharing protocol.
+///
+/// Dealers send commitments to their polynomial, along with an encrypted share for
+/// each new member. Other participants just acknowledge the first round, so that
+/// every participant sends the same number of messages.
+#[derive(Clone, Debug)]
+pub struct Round2 {
+    /// Our old verification key if we're dealing, and our new one otherwise.
+    vk: VerificationKey,
+    /// Commitments to the coefficients of our polynomial, starting with the constant term.
+    commitments: Vec<Element>,
+    /// For each new member, a ciphertext containing their share of our polynomial.
+    encrypted_shares: HashMap<VerificationKey, Vec<u8>>,
+    /// The full viewing key we're resharing, if we're dealing.
+    fvk: Option<FullViewingKey>,
+    /// The key epoch of the share we're dealing, if we're dealing.
+    key_epoch: u64,
+    /// A signature over the rest of the message.
+    sig: Signature,
+}
+
+impl From<Round2> for pb::ReshareRound2 {
+    fn from(value: Round2) -> Self {
+        round2_to_pb(
+            value.vk,
+            &value.commitments,
+            &value.encrypted_shares,
+            value.fvk.as_ref(),
+            value.key_epoch,
+            Some(value.sig),
+        )
+    }
+}
+
+impl TryFrom<pb::ReshareRound2> for Round2 {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::ReshareRound2) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            vk: value.vk.as_slice().try_into()?,
+            commitments: value
+                .commitments
+                .iter()
+                .map(|c| decode_element(c))
+                .collect::<Result<_>>()?,
+            encrypted_shares: value
+                .encrypted_shares
+                .into_iter()
+                .map(|x| Ok((x.vk.as_slice().try_into()?, x.encrypted_share)))
+                .collect::<Result<HashMap<_, _>, Self::Error>>()?,
+            fvk: if value.fvk.is_empty() {
+                None
+            } else {
+                Some(value.fvk.parse()?)
+            },
+            key_epoch: value.key_epoch,
+            sig: value.sig.as_slice().try_into()?,
+        })
+    }
+}
+
+impl DomainType for Round2 {
+    type Proto = pb::ReshareRound2;
+}
+
+impl Round2 {
+    fn make(
+        sk: &SigningKey,
+        commitments: Vec<Element>,
+        encrypted_shares: HashMap<VerificationKey, Vec<u8>>,
+        fvk: Option<FullViewingKey>,
+        key_epoch: u64,
+    ) -> Self {
+        let vk = sk.verification_key();
+        let data = round2_to_pb(
+            vk,
+            &commitments,
+            &encrypted_shares,
+            fvk.as_ref(),
+            key_epoch,
+            None,
+        )
+        .encode_to_vec();
+        Self {
+            vk,
+            commitments,
+            encrypted_shares,
+            fvk,
+            key_epoch,
+            sig: sk.sign(&data),
+        }
+    }
+
+    fn verify(&self) -> Result<()> {
+        let data = round2_to_pb(
+            self.vk,
+            &self.commitments,
+            &self.encrypted_shares,
+            self.fvk.as_ref(),
+            self.key_epoch,
+            None,
+        )
+        .encode_to_vec();
+        self.vk.verify(&self.sig, &data)?;
+        Ok(())
+    }
+}
+
+/// The state we need to remember after round 1.
+pub struct Round1State {
+    /// Our old config, if we're dealing.
+    old: Option<Config>,
+    /// Our new signing key, and the key to decrypt our new share, if we're a new member.
+    member: Option<(SigningKey, DecryptionKey)>,
+    /// The message we sent, which we need to count ourselves in the next round.
+    round1: Round1,
+}
+
+/// The state we need to remember after round 2.
+pub struct Round2State {
+    /// The new threshold.
+    t: u16,
+    /// The old verification keys of the dealers, along with their identifiers.
+    dealers: HashMap<VerificationKey, Fr>,
+    /// The new verification keys of the members, along with their identifiers.
+    members: HashMap<VerificationKey, Fr>,
+    /// The new verification keys of participants who only acknowledge round 1.
+    acks: HashSet<VerificationKey>,
+    /// Our old config, if we're dealing.
+    old: Option<Config>,
+    /// Our new signing key, and the key to decrypt our new share, if we're a new member.
+    member: Option<(SigningKey, DecryptionKey)>,
+    /// The message we sent, which contains our own contribution.
+    round2: Round2,
+}
+
+pub fn round1(
+    mut rng: impl CryptoRngCore,
+    old: Option<&Config>,
+    member: bool,
+) -> Result<(Round1, Round1State)> {
+    if old.is_none() && !member {
+        anyhow::bail!("a participant in resharing must either deal a share or receive one");
+    }
+    let member = if member {
+        Some((SigningKey::new(&mut rng), DecryptionKey::new(&mut rng)))
+    } else {
+        None
+    };
+    let round1 = Round1::make(
+        old.map(|config| config.signing_key()),
+        member.as_ref().map(|(sk, edk)| (sk, edk.public())),
+    );
+    let state = Round1State {
+        old: old.cloned(),
+        member,
+        round1: round1.clone(),
+    };
+    Ok((round1, state))
+}
+
+pub fn round2(
+    mut rng: impl CryptoRngCore,
+    state: Round1State,
+    t: u16,
+    n: u16,
+    messages: Vec<Round1>,
+) -> Result<(Round2, Round2State)> {
+    let mut dealers = HashMap::new();
+    let mut members = HashMap::new();
+    let mut acks = HashSet::new();
+    for message in messages.iter().chain(std::iter::once(&state.round1)) {
+        message.verify()?;
+        if let Some((vk, _)) = &message.dealer {
+            if dealers.insert(*vk, identifier_scalar(vk)?).is_some() {
+                anyhow::bail!("duplicate dealer verification key in messages");
+            }
+        }
+        if let Some((vk, epk, _)) = &message.member {
+            if members
+                .insert(*vk, (identifier_scalar(vk)?, *epk))
+                .is_some()
+            {
+                anyhow::bail!("duplicate member verification key in messages");
+            }
+            if message.dealer.is_none() {
+                acks.insert(*vk);
+            }
+        }
+    }
+    if members.len() != usize::from(n) {
+        anyhow::bail!("expected {} new members, found {}", n, members.len());
+    }
+    if t < 2 || t > n {
+        anyhow::bail!("invalid threshold {} for {} participants", t, n);
+    }
+    if let Some(old) = &state.old {
+        let old_keys = old.verification_keys();
+        if let Some(unknown) = dealers.keys().find(|vk| !old_keys.contains(vk)) {
+            anyhow::bail!(
+                "dealer {} is not part of the current configuration",
+                hex::encode(unknown.as_bytes())
+            );
+        }
+        if dealers.len() < usize::from(old.threshold()) {
+            anyhow::bail!(
+                "resharing needs at least {} dealers, found {}",
+                old.threshold(),
+                dealers.len()
+            );
+        }
+    }
+
+    let round2 = match (&state.old, &state.member) {
+        (Some(old), _) => {
+            let secret = decode_scalar(&old.key_package().secret_share().serialize())?;
+            let coefficients = std::iter::once(secret)
+                .chain((1..t).map(|_| Fr::rand(&mut rng)))
+                .collect::<Vec<_>>();
+            let commitments = coefficients
+                .iter()
+                .map(|a| decaf377::basepoint() * a)
+                .collect();
+            let encrypted_shares = members
+                .iter()
+                .map(|(vk, (x, epk))| {
+                    let share = coefficients
+                        .iter()
+                        .rev()
+                        .fold(Fr::zero(), |acc, a| acc * x + a);
+                    (*vk, epk.encrypt(&mut rng, &share.to_bytes()))
+                })
+                .collect();
+            Round2::make(
+                old.signing_key(),
+                commitments,
+                encrypted_shares,
+                Some(old.fvk().clone()),
+                old.key_epoch(),
+            )
+        }
+        (None, Some((sk, _))) => Round2::make(sk, Vec::new(), HashMap::new(), None, 0),
+        (None, None) => unreachable!("checked in round 1"),
+    };
+    let state = Round2State {
+        t,
+        dealers,
+        members: members.into_iter().map(|(vk, (x, _))| (vk, x)).collect(),
+        acks,
+        old: state.old,
+        member: state.member,
+        round2: round2.clone(),
+    };
+    Ok((round2, state))
+}
+
+/// Finish the protocol, producing our new config if we're a new member.
+pub fn round3(
+    mut rng: impl CryptoRngCore,
+    state: Round2State,
+    messages: Vec<Round2>,
+) -> Result<Option<Config>> {
+    // Sort the messages into dealings and acknowledgements, checking that everyone replied once.
+    let mut dealings = HashMap::new();
+    let mut acked = HashSet::new();
+    for message in messages.into_iter().chain(std::iter::once(state.round2)) {
+        message.verify()?;
+        if state.dealers.contains_key(&message.vk) {
+            if message.commitments.len() != usize::from(state.t) {
+                anyhow::bail!("dealer committed to a polynomial of the wrong degree");
+            }
+            let recipients = message.encrypted_shares.keys().collect::<HashSet<_>>();
+            if recipients != state.members.keys().collect::<HashSet<_>>() {
+                anyhow::bail!("dealer did not send shares to exactly the new members");
+            }
+            if dealings.insert(message.vk, message).is_some() {
+                anyhow::bail!("duplicate round 2 message from dealer");
+            }
+        } else if state.acks.contains(&message.vk) {
+            if !acked.insert(message.vk) {
+                anyhow::bail!("duplicate round 2 message from member");
+            }
+        } else {
+            anyhow::bail!("unknown verification key in round 2 message");
+        }
+    }
+    if dealings.len() != state.dealers.len() || acked.len() != state.acks.len() {
+        anyhow::bail!("missing round 2 messages");
+    }
+
+    // Every dealer needs to be resharing the same key, which we can check against our own.
+    let fvk = {
+        let mut fvks = dealings.values().map(|message| message.fvk.as_ref());
+        let fvk = fvks
+            .next()
+            .flatten()
+            .ok_or(anyhow!("dealer did not send a full viewing key"))?
+            .clone();
+        if fvks.any(|other| other != Some(&fvk)) {
+            anyhow::bail!("dealers are resharing different full viewing keys");
+        }
+        if let Some(old) = &state.old {
+            if old.fvk() != &fvk {
+                anyhow::bail!("dealers are resharing a different full viewing key than ours");
+            }
+        }
+        fvk
+    };
+
+    // Likewise, every dealer needs to be dealing a share from the same epoch, and the new
+    // shares belong to the next one.
+    let key_epoch = {
+        let mut epochs = dealings.values().map(|message| message.key_epoch);
+        let key_epoch = epochs.next().ok_or(anyhow!("no dealer sent a share"))?;
+        if epochs.any(|other| other != key_epoch) {
+            anyhow::bail!("dealers are resharing shares from different key epochs");
+        }
+        if let Some(old) = &state.old {
+            if old.key_epoch() != key_epoch {
+                anyhow::bail!("dealers are resharing shares from a different key epoch than ours");
+            }
+        }
+        key_epoch
+            .checked_add(1)
+            .ok_or(anyhow!("key epoch overflow"))?
+    };
+
+    let lagrange = state
+        .dealers
+        .iter()
+        .map(|(vk, x)| {
+            Ok((
+                *vk,
+                lagrange_coefficient(*x, state.dealers.values().copied())?,
+            ))
+        })
+        .collect::<Result<HashMap<_, _>>>()?;
+
+    // The dealers' shares must interpolate to the group key, otherwise the new shares would
+    // be shares of a different key. If we held an old share, we can also check each dealer's
+    // share against their old verifying share.
+    let group_key = decode_element(&fvk.spend_verification_key().to_bytes())?;
+    let interpolated = dealings
+        .iter()
+        .fold(Element::default(), |acc, (vk, message)| {
+            acc + message.commitments[0] * lagrange[vk]
+        });
+    if interpolated != group_key {
+        anyhow::bail!("dealt shares do not match the group key");
+    }
+    if let Some(old) = &state.old {
+        let public_key_package = old.public_key_package();
+        for (vk, message) in &dealings {
+            let id = frost::Identifier::derive(vk.as_bytes())?;
+            let verifying_share = public_key_package
+                .signer_pubkeys()
+                .get(&id)
+                .ok_or(anyhow!("unknown dealer identifier"))?;
+            if decode_element(&verifying_share.serialize())? != message.commitments[0] {
+                anyhow::bail!("dealer's commitment does not match their old share");
+            }
+        }
+    }
+
+    let (sk, edk) = match state.member {
+        Some(member) => member,
+        None => return Ok(None),
+    };
+    let our_vk = sk.verification_key();
+    let our_x = state.members[&our_vk];
+    let mut signing_share = Fr::zero();
+    for (vk, message) in &dealings {
+        let plaintext = edk.decrypt(&mut rng, &message.encrypted_shares[&our_vk])?;
+        let share = decode_scalar(&plaintext)?;
+        if decaf377::basepoint() * share != evaluate_commitments(&message.commitments, our_x) {
+            anyhow::bail!("dealt share does not match the dealer's commitments");
+        }
+        signing_share += lagrange[vk] * share;
+    }
+    let verifying_shares = state
+        .members
+        .iter()
+        .map(|(vk, x)| {
+            let element = dealings
+                .iter()
+                .fold(Element::default(), |acc, (dealer, message)| {
+                    acc + evaluate_commitments(&message.commitments, *x) * lagrange[dealer]
+                });
+            Ok((
+                frost::Identifier::derive(vk.as_bytes())?,
+                frost::keys::VerifyingShare::deserialize(element.vartime_compress().0.to_vec())?,
+            ))
+        })
+        .collect::<Result<HashMap<_, _>>>()?;
+
+    let identifier = frost::Identifier::derive(our_vk.as_bytes())?;
+    let group_public =
+        frost::keys::VerifyingKey::deserialize(fvk.spend_verification_key().to_bytes().to_vec())?;
+    let key_package = frost::keys::KeyPackage::new(
+        identifier,
+        frost::keys::SigningShare::deserialize(signing_share.to_bytes().to_vec())?,
+        verifying_shares[&identifier],
+        group_public,
+        state.t,
+    );
+    let public_key_package = frost::keys::PublicKeyPackage::new(verifying_shares, group_public);
+    Ok(Some(Config::from_parts(
+        key_package,
+        public_key_package,
+        sk,
+        state.members.into_keys().collect(),
+        fvk.nullifier_key().0,
+        key_epoch,
+    )))
+}
@@ -4,7 +4,7 @@ use std::{
 };
 
 use anyhow::{anyhow, Result};
-use ed25519_consensus::{Signature, SigningKey, VerificationKey};
+use ed25519_consensus::{Signature, VerificationKey};
 use penumbra_keys::FullViewingKey;
 use rand_core::CryptoRngCore;
 
@@ -28,6 +28,8 @@ use super::{config::Config, SigningResponse};
 #[derive(Debug, Clone)]
 pub struct CoordinatorRound1 {
     request: SigningRequest,
+    /// The key epoch of the coordinator's share.
+    key_epoch: u64,
 }
 
 impl CoordinatorRound1 {
@@ -37,24 +39,31 @@ impl CoordinatorRound1 {
     pub fn signing_request(&self) -> &SigningRequest {
         &self.request
     }
+
+    /// The key epoch of the share the coordinator signs with.
+    pub fn key_epoch(&self) -> u64 {
+        self.key_epoch
+    }
 }
 
 impl From<CoordinatorRound1> for pb::CoordinatorRound1 {
     fn from(value: CoordinatorRound1) -> Self {
-        match value.request {
-            SigningRequest::TransactionPlan(plan) => Self {
-                request: Some(pb::coordinator_round1::Request::Plan(plan.into())),
-            },
-            SigningRequest::ValidatorDefinition(validator) => Self {
-                request: Some(pb::coordinator_round1::Request::ValidatorDefinition(
+        let request = match value.request {
+            SigningRequest::TransactionPlan(plan) => {
+                pb::coordinator_round1::Request::Plan(plan.into())
+            }
+            SigningRequest::ValidatorDefinition(validator) => {
+                pb::coordinator_round1::Request::ValidatorDefinition(
                     ProtoValidator::from(validator).into(),
-                )),
-            },
-            SigningRequest::ValidatorVote(vote) => Self {
-                request: Some(pb::coordinator_round1::Request::ValidatorVote(
-                    ProtoValidatorVoteBody::from(vote).into(),
-                )),
-            },
+                )
+            }
+            SigningRequest::ValidatorVote(vote) => pb::coordinator_round1::Request::ValidatorVote(
+                ProtoValidatorVoteBody::from(vote).into(),
+            ),
+        };
+        Self {
+            request: Some(request),
+            key_epoch: value.key_epoch,
         }
     }
 }
@@ -63,20 +72,24 @@ impl TryFrom<pb::CoordinatorRound1> for CoordinatorRound1 {
     type Error = anyhow::Error;
 
     fn try_from(value: pb::CoordinatorRound1) -> Result<Self, Self::Error> {
-        match value
+        let request = match value
             .request
             .ok_or_else(|| anyhow::anyhow!("missing request"))?
         {
-            pb::coordinator_round1::Request::Plan(plan) => Ok(Self {
-                request: SigningRequest::TransactionPlan(plan.try_into()?),
-            }),
-            pb::coordinator_round1::Request::ValidatorDefinition(def) => Ok(Self {
-                request: SigningRequest::ValidatorDefinition(def.try_into()?),
-            }),
-            pb::coordinator_round1::Request::ValidatorVote(vote) => Ok(Self {
-                request: SigningRequest::ValidatorVote(vote.try_into()?),
-            }),
-        }
+            pb::coordinator_round1::Request::Plan(plan) => {
+                SigningRequest::TransactionPlan(plan.try_into()?)
+            }
+            pb::coordinator_round1::Request::ValidatorDefinition(def) => {
+                SigningRequest::ValidatorDefinition(def.try_into()?)
+            }
+            pb::coordinator_round1::Request::ValidatorVote(vote) => {
+                SigningRequest::ValidatorVote(vote.try_into()?)
+            }
+        };
+        Ok(Self {
+            request,
+            key_epoch: value.key_epoch,
+        })
     }
 }
 
@@ -92,9 +105,11 @@ pub struct CoordinatorRound2 {
 
 fn commitments_to_pb(
     commitments: impl IntoIterator<Item = frost::round1::SigningCommitments>,
+    key_epoch: u64,
 ) -> pb::follower_round1::Inner {
     pb::follower_round1::Inner {
         commitments: commitments.into_iter().map(|x| x.into()).collect(),
+        key_epoch,
     }
 }
 
@@ -160,6 +175,8 @@ impl DomainType for CoordinatorRound2 {
 pub struct FollowerRound1 {
     /// A commitment for each spend we need to authorize.
     pub(self) commitments: Vec<frost::round1::SigningCommitments>,
+    /// The key epoch of the sender's share.
+    pub(self) key_epoch: u64,
     /// A verification key identifying who the sender is.
     pub(self) pk: VerificationKey,
     /// The signature over the protobuf encoding of the commitments.
@@ -169,7 +186,7 @@ pub struct FollowerRound1 {
 impl From<FollowerRound1> for pb::FollowerRound1 {
     fn from(value: FollowerRound1) -> Self {
         Self {
-            inner: Some(commitments_to_pb(value.commitments)),
+            inner: Some(commitments_to_pb(value.commitments, value.key_epoch)),
             pk: Some(pb::VerificationKey {
                 inner: value.pk.to_bytes().to_vec(),
             }),
@@ -184,14 +201,14 @@ impl TryFrom<pb::FollowerRound1> for FollowerRound1 {
     type Error = anyhow::Error;
 
     fn try_from(value: pb::FollowerRound1) -> Result<Self, Self::Error> {
+        let inner = value.inner.ok_or(anyhow!("missing inner"))?;
         Ok(Self {
-            commitments: value
-                .inner
-                .ok_or(anyhow!("missing inner"))?
+            commitments: inner
                 .commitments
                 .into_iter()
                 .map(|x| x.try_into())
                 .collect::<Result<Vec<_>, _>>()?,
+            key_epoch: inner.key_epoch,
             pk: value
                 .pk
                 .ok_or(anyhow!("missing pk"))?
@@ -210,20 +227,27 @@ impl TryFrom<pb::FollowerRound1> for FollowerRound1 {
 
 impl FollowerRound1 {
     // Make a round1 message, automatically signing the right bytes
-    fn make(signing_key: &SigningKey, commitments: Vec<SigningCommitments>) -> Self {
+    fn make(config: &Config, commitments: Vec<SigningCommitments>) -> Self {
+        let signing_key = config.signing_key();
         Self {
             commitments: commitments.clone(),
+            key_epoch: config.key_epoch(),
             pk: signing_key.verification_key(),
-            sig: signing_key.sign(&commitments_to_pb(commitments).encode_to_vec()),
+            sig: signing_key
+                .sign(&commitments_to_pb(commitments, config.key_epoch()).encode_to_vec()),
         }
     }
 
-    // Extract the commitments from this struct, checking the signature
-    fn checked_commitments(self) -> Result<(VerificationKey, Vec<SigningCommitments>)> {
+    // Extract the commitments from this struct, checking the signature and key epoch
+    fn checked_commitments(
+        self,
+        config: &Config,
+    ) -> Result<(VerificationKey, Vec<SigningCommitments>)> {
         self.pk.verify(
             &self.sig,
-            &commitments_to_pb(self.commitments.clone()).encode_to_vec(),
+            &commitments_to_pb(self.commitments.clone(), self.key_epoch).encode_to_vec(),
         )?;
+        check_key_epoch(config, self.key_epoch)?;
         Ok((self.pk, self.commitments))
     }
 }
@@ -232,9 +256,13 @@ impl DomainType for FollowerRound1 {
     type Proto = pb::FollowerRound1;
 }
 
-fn shares_to_pb(shares: Vec<frost::round2::SignatureShare>) -> pb::follower_round2::Inner {
+fn shares_to_pb(
+    shares: Vec<frost::round2::SignatureShare>,
+    key_epoch: u64,
+) -> pb::follower_round2::Inner {
     pb::follower_round2::Inner {
         shares: shares.into_iter().map(|x| x.into()).collect(),
+        key_epoch,
     }
 }
 
@@ -243,6 +271,8 @@ fn shares_to_pb(shares: Vec<frost::round2::SignatureShare>) -> pb::follower_roun
 pub struct FollowerRound2 {
     /// A share of each signature we need to produce.
     pub(self) shares: Vec<frost::round2::SignatureShare>,
+    /// The key epoch of the sender's share.
+    pub(self) key_epoch: u64,
     /// A verification key identifying who the sender is.
     pub(self) pk: VerificationKey,
     /// The signature over the protobuf encoding of the sahres.
@@ -252,7 +282,7 @@ pub struct FollowerRound2 {
 impl From<FollowerRound2> for pb::FollowerRound2 {
     fn from(value: FollowerRound2) -> Self {
         Self {
-            inner: Some(shares_to_pb(value.shares)),
+            inner: Some(shares_to_pb(value.shares, value.key_epoch)),
             pk: Some(pb::VerificationKey {
                 inner: value.pk.to_bytes().to_vec(),
             }),
@@ -267,14 +297,14 @@ impl TryFrom<pb::FollowerRound2> for FollowerRound2 {
     type Error = anyhow::Error;
 
     fn try_from(value: pb::FollowerRound2) -> Result<Self, Self::Error> {
+        let inner = value.inner.ok_or(anyhow!("missing inner"))?;
         Ok(Self {
-            shares: value
-                .inner
-                .ok_or(anyhow!("missing inner"))?
+            shares: inner
                 .shares
                 .into_iter()
                 .map(|x| x.try_into())
                 .collect::<Result<Vec<_>, _>>()?,
+            key_epoch: inner.key_epoch,
             pk: value
                 .pk
                 .ok_or(anyhow!("missing pk"))?
@@ -293,20 +323,26 @@ impl TryFrom<pb::FollowerRound2> for FollowerRound2 {
 
 impl FollowerRound2 {
     // Make a round1 message, automatically signing the right bytes
-    fn make(signing_key: &SigningKey, shares: Vec<frost::round2::SignatureShare>) -> Self {
+    fn make(config: &Config, shares: Vec<frost::round2::SignatureShare>) -> Self {
+        let signing_key = config.signing_key();
         Self {
             shares: shares.clone(),
+            key_epoch: config.key_epoch(),
             pk: signing_key.verification_key(),
-            sig: signing_key.sign(&shares_to_pb(shares).encode_to_vec()),
+            sig: signing_key.sign(&shares_to_pb(shares, config.key_epoch()).encode_to_vec()),
         }
     }
 
-    // Extract the commitments from this struct, checking the signature
-    fn checked_shares(self) -> Result<(VerificationKey, Vec<frost::round2::SignatureShare>)> {
+    // Extract the commitments from this struct, checking the signature and key epoch
+    fn checked_shares(
+        self,
+        config: &Config,
+    ) -> Result<(VerificationKey, Vec<frost::round2::SignatureShare>)> {
         self.pk.verify(
             &self.sig,
-            &shares_to_pb(self.shares.clone()).encode_to_vec(),
+            &shares_to_pb(self.shares.clone(), self.key_epoch).encode_to_vec(),
         )?;
+        check_key_epoch(config, self.key_epoch)?;
         Ok((self.pk, self.shares))
     }
 }
@@ -315,6 +351,21 @@ impl DomainType for FollowerRound2 {
     type Proto = pb::FollowerRound2;
 }
 
+/// Check that another participant's share is from the same key epoch as ours.
+///
+/// Resharing moves everyone to a new epoch, so this stops shares from before a reshare from
+/// being used alongside current ones, whichever side kept the old share.
+pub fn check_key_epoch(config: &Config, key_epoch: u64) -> Result<()> {
+    if key_epoch != config.key_epoch() {
+        anyhow::bail!(
+            "the other participant's share is from key epoch {}, but ours is from key epoch {}; shares from before a reshare can no longer be used",
+            key_epoch,
+            config.key_epoch(),
+        );
+    }
+    Ok(())
+}
+
 /// Calculate the number of required signatures for a plan.
 ///
 /// A plan can require more than one signature, hence the need for this method.
@@ -403,6 +454,7 @@ pub fn coordinator_round1(
 ) -> Result<(CoordinatorRound1, CoordinatorState1)> {
     let message = CoordinatorRound1 {
         request: request.clone(),
+        key_epoch: config.key_epoch(),
     };
     let (my_round1_reply, my_round1_state) = follower_round1(rng, config, message.clone())?;
     let state = CoordinatorState1 {
@@ -424,7 +476,7 @@ pub fn coordinator_round2(
         .cloned()
         .chain(iter::once(state.my_round1_reply))
     {
-        let (pk, commitments) = message.checked_commitments()?;
+        let (pk, commitments) = message.checked_commitments(config)?;
         if !config.verification_keys().contains(&pk) {
             anyhow::bail!("unknown verification key: {:?}", pk);
         }
@@ -468,7 +520,7 @@ pub fn coordinator_round3(
         .cloned()
         .chain(iter::once(state.my_round2_reply))
     {
-        let (pk, shares) = message.checked_shares()?;
+        let (pk, shares) = message.checked_shares(config)?;
         if !config.verification_keys().contains(&pk) {
             anyhow::bail!("unknown verification key: {:?}", pk);
         }
@@ -541,11 +593,12 @@ pub fn follower_round1(
     config: &Config,
     coordinator: CoordinatorRound1,
 ) -> Result<(FollowerRound1, FollowerState)> {
+    check_key_epoch(config, coordinator.key_epoch)?;
     let required = required_signatures(&coordinator.request);
     let (nonces, commitments) = (0..required)
         .map(|_| frost::round1::commit(&config.key_package().secret_share(), rng))
         .unzip();
-    let reply = FollowerRound1::make(config.signing_key(), commitments);
+    let reply = FollowerRound1::make(config, commitments);
     let state = FollowerState {
         request: coordinator.request,
         nonces,
@@ -581,7 +634,7 @@ pub fn follower_round2(
                     )
                 })
                 .collect::<Result<_, _>>()?;
-            Ok(FollowerRound2::make(config.signing_key(), shares))
+            Ok(FollowerRound2::make(config, shares))
         }
         SigningRequest::ValidatorDefinition(_) | SigningRequest::ValidatorVote(_) => {
             let shares = signing_packages
@@ -590,7 +643,7 @@ pub fn follower_round2(
                     frost::round2::sign(&signing_package, &signer_nonces, &config.key_package())
                 })
                 .collect::<Result<_, _>>()?;
-            Ok(FollowerRound2::make(config.signing_key(), shares))
+            Ok(FollowerRound2::make(config, shares))
         }
     }
 }
@@ -0,0 +1,71 @@
+//! Runs the reference signer as a separate process, over the `Command` transport.
+
+use penumbra_custody::{external, soft_kms};
+use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+use penumbra_proto::custody::v1::{self as pb, custody_service_server::CustodyService};
+use rand_core::{OsRng, RngCore};
+use tonic::Request;
+
+#[tokio::test]
+async fn command_signer_answers_each_request() -> anyhow::Result<()> {
+    let spend_key =
+        SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(OsRng), &Bip44Path::new(0));
+    let config_path =
+        std::env::temp_dir().join(format!("penumbra-stub-signer-{}.json", OsRng.next_u64()));
+    std::fs::write(
+        &config_path,
+        serde_json::to_vec(&soft_kms::Config::from(spend_key.clone()))?,
+    )?;
+
+    // The signer process is started afresh for every request.
+    let signer = external::External::new(external::Config::Command {
+        program: env!("CARGO_BIN_EXE_penumbra-reference-signer").into(),
+        args: vec![config_path.display().to_string()],
+    });
+
+    let fvk = signer
+        .export_full_viewing_key(Request::new(Default::default()))
+        .await?
+        .into_inner()
+        .full_viewing_key;
+    assert_eq!(fvk, Some(spend_key.full_viewing_key().clone().into()));
+
+    let address = signer
+        .confirm_address(Request::new(pb::ConfirmAddressRequest {
+            address_index: Some(penumbra_keys::keys::AddressIndex::new(7).into()),
+        }))
+        .await?
+        .into_inner()
+        .address;
+    assert_eq!(
+        address,
+        Some(
+            spend_key
+                .full_viewing_key()
+                .payment_address(7.into())
+                .0
+                .into()
+        )
+    );
+
+    // Errors reported by the signer process are passed along.
+    let status = signer
+        .authorize(Request::new(Default::default()))
+        .await
+        .expect_err("an empty request should be rejected");
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+    // A signer that can't be started is reported as unavailable.
+    let missing = external::External::new(external::Config::Command {
+        program: config_path.with_extension("missing"),
+        args: Vec::new(),
+    });
+    let status = missing
+        .export_full_viewing_key(Request::new(Default::default()))
+        .await
+        .expect_err("a missing signer should fail");
+    assert_eq!(status.code(), tonic::Code::Unavailable);
+
+    std::fs::remove_file(config_path)?;
+    Ok(())
+}
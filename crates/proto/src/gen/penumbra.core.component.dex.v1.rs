@@ -123,6 +123,15 @@ pub struct SwapBody {
     /// The swap commitment and encryption of the swap data.
     #[prost(message, optional, tag = "5")]
     pub payload: ::core::option::Option<SwapPayload>,
+    /// The minimum acceptable output of the swap, if any.
+    ///
+    /// Only one-directional swaps, with exactly one of `delta_1_i` and `delta_2_i`
+    /// nonzero, may set a minimum output. If the swap's pro rata share of the batch
+    /// output would be less than its minimum output, none of the swaps in its
+    /// direction of the batch are executed, and their inputs are returned when they
+    /// are claimed.
+    #[prost(message, optional, tag = "6")]
+    pub min_output: ::core::option::Option<super::super::super::num::v1::Amount>,
 }
 impl ::prost::Name for SwapBody {
     const NAME: &'static str = "SwapBody";
@@ -192,6 +201,9 @@ pub struct SwapPlan {
     /// The second blinding factor to use for the ZK swap proof.
     #[prost(bytes = "vec", tag = "4")]
     pub proof_blinding_s: ::prost::alloc::vec::Vec<u8>,
+    /// The minimum acceptable output of the swap, if any.
+    #[prost(message, optional, tag = "5")]
+    pub min_output: ::core::option::Option<super::super::super::num::v1::Amount>,
 }
 impl ::prost::Name for SwapPlan {
     const NAME: &'static str = "SwapPlan";
@@ -819,16 +831,26 @@ impl ::prost::Name for PositionWithdraw {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
-/// Deprecated.
+/// A transaction action that claims the liquidity rewards accrued by a withdrawn position.
+///
+/// This action's contribution to the transaction's value balance is to consume a
+/// withdrawn position NFT and contribute a withdrawn position NFT with the next
+/// sequence number, as well as the rewards accrued by the position.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PositionRewardClaim {
     #[prost(message, optional, tag = "1")]
     pub position_id: ::core::option::Option<PositionId>,
+    /// A transparent (zero blinding factor) commitment to the rewards accrued by the position.
+    ///
+    /// The chain will check this commitment by recomputing it with the on-chain state.
     #[prost(message, optional, tag = "2")]
     pub rewards_commitment: ::core::option::Option<
         super::super::super::asset::v1::BalanceCommitment,
     >,
+    /// The sequence number of the withdrawn position NFT produced by the claim.
+    #[prost(uint64, tag = "3")]
+    pub sequence: u64,
 }
 impl ::prost::Name for PositionRewardClaim {
     const NAME: &'static str = "PositionRewardClaim";
@@ -903,12 +925,18 @@ impl ::prost::Name for PositionWithdrawPlan {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
-/// Deprecated.
+/// Contains private and public data for claiming the rewards of a withdrawn position.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PositionRewardClaimPlan {
-    #[prost(message, optional, tag = "1")]
-    pub reserves: ::core::option::Option<Reserves>,
+    #[prost(message, optional, tag = "2")]
+    pub position_id: ::core::option::Option<PositionId>,
+    /// The sequence number of the withdrawn position NFT produced by the claim.
+    #[prost(uint64, tag = "3")]
+    pub sequence: u64,
+    /// The rewards accrued by the position, in the staking token.
+    #[prost(message, optional, tag = "4")]
+    pub rewards: ::core::option::Option<super::super::super::num::v1::Amount>,
 }
 impl ::prost::Name for PositionRewardClaimPlan {
     const NAME: &'static str = "PositionRewardClaimPlan";
@@ -979,6 +1007,11 @@ impl ::prost::Name for SwapExecutionResponse {
 pub struct ArbExecutionRequest {
     #[prost(uint64, tag = "2")]
     pub height: u64,
+    /// The arb token to request the execution of.
+    ///
+    /// If this is unset, the staking token is used.
+    #[prost(message, optional, tag = "3")]
+    pub arb_token: ::core::option::Option<super::super::super::asset::v1::AssetId>,
 }
 impl ::prost::Name for ArbExecutionRequest {
     const NAME: &'static str = "ArbExecutionRequest";
@@ -1048,6 +1081,9 @@ pub struct ArbExecutionsRequest {
     /// If present, only return arb executions occurring before the given height.
     #[prost(uint64, tag = "3")]
     pub end_height: u64,
+    /// If present, only return arb executions against the given arb token.
+    #[prost(message, optional, tag = "4")]
+    pub arb_token: ::core::option::Option<super::super::super::asset::v1::AssetId>,
 }
 impl ::prost::Name for ArbExecutionsRequest {
     const NAME: &'static str = "ArbExecutionsRequest";
@@ -1227,6 +1263,9 @@ pub struct SimulateTradeRequest {
     pub output: ::core::option::Option<super::super::super::asset::v1::AssetId>,
     #[prost(message, optional, tag = "3")]
     pub routing: ::core::option::Option<simulate_trade_request::Routing>,
+    /// If set, the response explains how the trade was routed and filled.
+    #[prost(bool, tag = "4")]
+    pub explain: bool,
 }
 /// Nested message and enum types in `SimulateTradeRequest`.
 pub mod simulate_trade_request {
@@ -1298,6 +1337,9 @@ pub struct SimulateTradeResponse {
     /// Estimated input amount that will not be swapped due to liquidity
     #[prost(message, optional, tag = "2")]
     pub unfilled: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// How the trade was routed and filled, if `explain` was set in the request.
+    #[prost(message, optional, tag = "3")]
+    pub explanation: ::core::option::Option<RouteExplanation>,
 }
 impl ::prost::Name for SimulateTradeResponse {
     const NAME: &'static str = "SimulateTradeResponse";
@@ -1569,6 +1611,29 @@ pub struct DexParameters {
     /// inventory get evicted from the DEX.
     #[prost(uint32, tag = "4")]
     pub max_positions_per_pair: u32,
+    /// The tokens to arbitrage against at the end of each block, in order.
+    ///
+    /// If this is empty, the staking token is used, with its surplus burned.
+    #[prost(message, repeated, tag = "5")]
+    pub arb_tokens: ::prost::alloc::vec::Vec<ArbToken>,
+    /// The maximum number of path searches performed while routing a single trade.
+    #[prost(uint32, tag = "6")]
+    pub max_path_searches: u32,
+    /// The maximum number of executions against positions while routing a single trade.
+    #[prost(uint32, tag = "7")]
+    pub max_executions: u32,
+    /// The maximum number of path searches performed while routing all of a block's
+    /// batch swaps.
+    #[prost(uint32, tag = "8")]
+    pub max_block_path_searches: u32,
+    /// The maximum number of executions against positions while routing all of a
+    /// block's batch swaps.
+    #[prost(uint32, tag = "9")]
+    pub max_block_executions: u32,
+    /// The number of hops arbitrage may traverse beyond `max_hops`, to route out of
+    /// an arb token and back.
+    #[prost(uint32, tag = "10")]
+    pub arb_extra_hops: u32,
 }
 impl ::prost::Name for DexParameters {
     const NAME: &'static str = "DexParameters";
@@ -1577,6 +1642,24 @@ impl ::prost::Name for DexParameters {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// A token the DEX arbitrages against at the end of each block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ArbToken {
+    /// The asset to arbitrage against.
+    #[prost(message, optional, tag = "1")]
+    pub asset_id: ::core::option::Option<super::super::super::asset::v1::AssetId>,
+    /// Where the surplus of arbitrage against this asset goes.
+    #[prost(enumeration = "ArbSurplusDestination", tag = "2")]
+    pub surplus_destination: i32,
+}
+impl ::prost::Name for ArbToken {
+    const NAME: &'static str = "ArbToken";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisContent {
@@ -1595,6 +1678,8 @@ impl ::prost::Name for GenesisContent {
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CandlestickData {
     /// The height of the candlestick data.
+    ///
+    /// For rolled-up candlesticks, this is the last height included in the candlestick.
     #[prost(uint64, tag = "1")]
     pub height: u64,
     /// The first observed price during the block execution.
@@ -1615,6 +1700,17 @@ pub struct CandlestickData {
     /// The volume that traded as part of swaps, which could have traversed multiple routes.
     #[prost(double, tag = "7")]
     pub swap_volume: f64,
+    /// The first height included in the candlestick.
+    ///
+    /// Only set for rolled-up candlesticks.
+    #[prost(uint64, tag = "8")]
+    pub start_height: u64,
+    /// The bucket the candlestick covers.
+    ///
+    /// For time-based resolutions, this is the UNIX timestamp, in seconds, of the start of the bucket.
+    /// For the epoch resolution, this is the epoch index. Only set for rolled-up candlesticks.
+    #[prost(uint64, tag = "9")]
+    pub bucket: u64,
 }
 impl ::prost::Name for CandlestickData {
     const NAME: &'static str = "CandlestickData";
@@ -1641,8 +1737,26 @@ pub struct CandlestickDataRequest {
     /// The height to start the query from.
     ///
     /// If this is unset (= 0), the server will return the most recent data points.
+    /// Only used for per-block candlesticks.
     #[prost(uint64, tag = "3")]
     pub start_height: u64,
+    /// The resolution of the candlesticks to return.
+    ///
+    /// If this is unset, the server returns one candlestick per block.
+    #[prost(enumeration = "CandlestickResolution", tag = "4")]
+    pub resolution: i32,
+    /// The bucket to start the query from, for rolled-up resolutions.
+    ///
+    /// This is a UNIX timestamp in seconds for time-based resolutions, rounded down to
+    /// the start of its bucket, or an epoch index. If this is unset (= 0), the server
+    /// will return the most recent data points.
+    #[prost(uint64, tag = "5")]
+    pub start_bucket: u64,
+    /// The bucket to end the query at, exclusive, for rolled-up resolutions.
+    ///
+    /// If this is unset (= 0), the range is only bounded by `limit`.
+    #[prost(uint64, tag = "6")]
+    pub end_bucket: u64,
 }
 impl ::prost::Name for CandlestickDataRequest {
     const NAME: &'static str = "CandlestickDataRequest";
@@ -1693,6 +1807,451 @@ impl ::prost::Name for CandlestickDataStreamResponse {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// The resolution of rolled-up candlestick data.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration
+)]
+#[repr(i32)]
+pub enum CandlestickResolution {
+    /// One candlestick per block.
+    Unspecified = 0,
+    /// One candlestick per minute, based on block timestamps.
+    Minute = 1,
+    /// One candlestick per hour, based on block timestamps.
+    Hour = 2,
+    /// One candlestick per day (UTC), based on block timestamps.
+    Day = 3,
+    /// One candlestick per epoch.
+    Epoch = 4,
+}
+impl CandlestickResolution {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CandlestickResolution::Unspecified => "CANDLESTICK_RESOLUTION_UNSPECIFIED",
+            CandlestickResolution::Minute => "CANDLESTICK_RESOLUTION_MINUTE",
+            CandlestickResolution::Hour => "CANDLESTICK_RESOLUTION_HOUR",
+            CandlestickResolution::Day => "CANDLESTICK_RESOLUTION_DAY",
+            CandlestickResolution::Epoch => "CANDLESTICK_RESOLUTION_EPOCH",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CANDLESTICK_RESOLUTION_UNSPECIFIED" => Some(Self::Unspecified),
+            "CANDLESTICK_RESOLUTION_MINUTE" => Some(Self::Minute),
+            "CANDLESTICK_RESOLUTION_HOUR" => Some(Self::Hour),
+            "CANDLESTICK_RESOLUTION_DAY" => Some(Self::Day),
+            "CANDLESTICK_RESOLUTION_EPOCH" => Some(Self::Epoch),
+            _ => None,
+        }
+    }
+}
+/// A cumulative price accumulator for a directed trading pair.
+///
+/// The accumulator is updated at the end of each block in which the pair traded,
+/// with the price of the pair's last execution in that block, and is used to
+/// compute time-weighted average prices.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceAccumulator {
+    /// The height at which the accumulator was last updated.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The price recorded at `height`, which stays in effect until the next update,
+    /// encoded as a `U128x128`.
+    #[prost(bytes = "vec", tag = "2")]
+    pub last_price: ::prost::alloc::vec::Vec<u8>,
+    /// The sum of the prices in effect at every height before `height`, encoded as
+    /// a `U128x128`.
+    #[prost(bytes = "vec", tag = "3")]
+    pub cumulative_price: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for PriceAccumulator {
+    const NAME: &'static str = "PriceAccumulator";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeWeightedAveragePriceRequest {
+    /// The directed trading pair to compute the average price for.
+    #[prost(message, optional, tag = "1")]
+    pub pair: ::core::option::Option<DirectedTradingPair>,
+    /// The first height of the window, inclusive.
+    ///
+    /// Only the last 17,280 blocks of each pair's price history are kept, so a
+    /// window can't start before them.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+    /// The last height of the window, inclusive.
+    ///
+    /// If this is unset (= 0), the window ends at the current height.
+    #[prost(uint64, tag = "3")]
+    pub end_height: u64,
+}
+impl ::prost::Name for TimeWeightedAveragePriceRequest {
+    const NAME: &'static str = "TimeWeightedAveragePriceRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeWeightedAveragePriceResponse {
+    /// The average of the prices in effect at every height of the window.
+    ///
+    /// Prices are quoted in the same way as in `CandlestickData`.
+    #[prost(double, tag = "1")]
+    pub price: f64,
+    /// The first height of the window.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+    /// The last height of the window.
+    #[prost(uint64, tag = "3")]
+    pub end_height: u64,
+}
+impl ::prost::Name for TimeWeightedAveragePriceResponse {
+    const NAME: &'static str = "TimeWeightedAveragePriceResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketDepthRequest {
+    /// The directed trading pair to get the depth of.
+    ///
+    /// The depth covers trades from the start asset into the end asset: liquidity is
+    /// denominated in the end asset, and prices are amounts of the start asset per
+    /// unit of the end asset, in base units.
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<DirectedTradingPair>,
+    /// The width of the price levels.
+    ///
+    /// If this is unset (= 0), each distinct position price is its own level.
+    #[prost(double, tag = "2")]
+    pub tick_size: f64,
+    /// The maximum number of price levels to return.
+    ///
+    /// If this is unset (= 0), or above the server's maximum, the server's maximum is used.
+    #[prost(uint32, tag = "3")]
+    pub depth_limit: u32,
+}
+impl ::prost::Name for MarketDepthRequest {
+    const NAME: &'static str = "MarketDepthRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketDepthResponse {
+    /// The price levels, from the best price to the worst.
+    #[prost(message, repeated, tag = "1")]
+    pub levels: ::prost::alloc::vec::Vec<MarketDepthLevel>,
+}
+impl ::prost::Name for MarketDepthResponse {
+    const NAME: &'static str = "MarketDepthResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// The liquidity available at a price level.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketDepthLevel {
+    /// The worst price of the level: every position in it trades at this price or better.
+    #[prost(double, tag = "1")]
+    pub price: f64,
+    /// The liquidity of the positions in the level, in the end asset.
+    #[prost(message, optional, tag = "2")]
+    pub liquidity: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The liquidity of the positions in this level and all better levels, in the end asset.
+    #[prost(message, optional, tag = "3")]
+    pub cumulative_liquidity: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+    /// The number of positions in the level.
+    #[prost(uint32, tag = "4")]
+    pub position_count: u32,
+}
+impl ::prost::Name for MarketDepthLevel {
+    const NAME: &'static str = "MarketDepthLevel";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// Where the surplus of an arbitrage execution goes.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration
+)]
+#[repr(i32)]
+pub enum ArbSurplusDestination {
+    /// The surplus is burned, as with `BURN`.
+    Unspecified = 0,
+    /// The surplus is burned.
+    Burn = 1,
+    /// The surplus is deposited into the community pool.
+    CommunityPool = 2,
+}
+impl ArbSurplusDestination {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ArbSurplusDestination::Unspecified => "ARB_SURPLUS_DESTINATION_UNSPECIFIED",
+            ArbSurplusDestination::Burn => "ARB_SURPLUS_DESTINATION_BURN",
+            ArbSurplusDestination::CommunityPool => "ARB_SURPLUS_DESTINATION_COMMUNITY_POOL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ARB_SURPLUS_DESTINATION_UNSPECIFIED" => Some(Self::Unspecified),
+            "ARB_SURPLUS_DESTINATION_BURN" => Some(Self::Burn),
+            "ARB_SURPLUS_DESTINATION_COMMUNITY_POOL" => Some(Self::CommunityPool),
+            _ => None,
+        }
+    }
+}
+/// A record of a single execution against a liquidity position.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionExecution {
+    /// The height at which the execution occurred.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The reserves of the position before the execution.
+    #[prost(message, optional, tag = "2")]
+    pub prev_reserves: ::core::option::Option<Reserves>,
+    /// The reserves of the position after the execution.
+    #[prost(message, optional, tag = "3")]
+    pub reserves: ::core::option::Option<Reserves>,
+    /// The direction of the trade: the position received the start asset, and
+    /// gave out the end asset.
+    #[prost(message, optional, tag = "4")]
+    pub direction: ::core::option::Option<DirectedTradingPair>,
+    /// The fee earned by the position, in the start asset.
+    #[prost(message, optional, tag = "5")]
+    pub fee: ::core::option::Option<super::super::super::num::v1::Amount>,
+}
+impl ::prost::Name for PositionExecution {
+    const NAME: &'static str = "PositionExecution";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionExecutionsRequest {
+    /// The position to get the executions of.
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<PositionId>,
+    /// If present, only return executions occurring at or after the given height.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+    /// If present, only return executions occurring at or before the given height.
+    #[prost(uint64, tag = "3")]
+    pub end_height: u64,
+}
+impl ::prost::Name for PositionExecutionsRequest {
+    const NAME: &'static str = "PositionExecutionsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionExecutionsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub execution: ::core::option::Option<PositionExecution>,
+}
+impl ::prost::Name for PositionExecutionsResponse {
+    const NAME: &'static str = "PositionExecutionsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionRewardsRequest {
+    /// The position to get the rewards of.
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<PositionId>,
+}
+impl ::prost::Name for PositionRewardsRequest {
+    const NAME: &'static str = "PositionRewardsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionRewardsResponse {
+    /// The unclaimed rewards accrued by the position, in the staking token.
+    #[prost(message, optional, tag = "1")]
+    pub rewards: ::core::option::Option<super::super::super::num::v1::Amount>,
+}
+impl ::prost::Name for PositionRewardsResponse {
+    const NAME: &'static str = "PositionRewardsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// Indicates that the rewards of a withdrawn position were claimed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventPositionRewardClaim {
+    /// The ID of the position whose rewards were claimed.
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<PositionId>,
+    /// The sequence number of the claim.
+    #[prost(uint64, tag = "2")]
+    pub sequence: u64,
+    /// The rewards claimed, in the staking token.
+    #[prost(message, optional, tag = "3")]
+    pub rewards: ::core::option::Option<super::super::super::num::v1::Amount>,
+}
+impl ::prost::Name for EventPositionRewardClaim {
+    const NAME: &'static str = "EventPositionRewardClaim";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// An explanation of how a trade was routed and filled, for debugging.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouteExplanation {
+    /// The successive splits of the trade, in the order they were routed.
+    #[prost(message, repeated, tag = "1")]
+    pub splits: ::prost::alloc::vec::Vec<RouteSplit>,
+    /// The number of path searches performed.
+    #[prost(uint32, tag = "2")]
+    pub path_searches: u32,
+    /// The maximum number of path searches allowed by the circuit breaker.
+    #[prost(uint32, tag = "3")]
+    pub max_path_searches: u32,
+    /// The number of executions performed.
+    #[prost(uint32, tag = "4")]
+    pub executions: u32,
+    /// The maximum number of executions allowed by the circuit breaker.
+    #[prost(uint32, tag = "5")]
+    pub max_executions: u32,
+    /// Why routing stopped.
+    #[prost(string, tag = "6")]
+    pub stop_reason: ::prost::alloc::string::String,
+}
+impl ::prost::Name for RouteExplanation {
+    const NAME: &'static str = "RouteExplanation";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// A split of a trade: a path search, followed by filling along the route it selected.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouteSplit {
+    /// The paths explored by the path search, to the output asset and to intermediate assets.
+    #[prost(message, repeated, tag = "1")]
+    pub candidates: ::prost::alloc::vec::Vec<RouteCandidate>,
+    /// The route selected by the path search, including the input asset.
+    ///
+    /// If this is empty, no route was found.
+    #[prost(message, repeated, tag = "2")]
+    pub route: ::prost::alloc::vec::Vec<super::super::super::asset::v1::AssetId>,
+    /// The spill price the route was filled up to, in units of the input asset per
+    /// unit of the output asset.
+    ///
+    /// If this is unset (= 0), the route was filled without a limit.
+    #[prost(double, tag = "3")]
+    pub spill_price: f64,
+    /// The fills along the route, in the order they were executed.
+    #[prost(message, repeated, tag = "4")]
+    pub fills: ::prost::alloc::vec::Vec<RouteFill>,
+}
+impl ::prost::Name for RouteSplit {
+    const NAME: &'static str = "RouteSplit";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// A path explored by the path search.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouteCandidate {
+    /// The assets along the path, including the input asset.
+    #[prost(message, repeated, tag = "1")]
+    pub route: ::prost::alloc::vec::Vec<super::super::super::asset::v1::AssetId>,
+    /// The estimated price of the path, in units of the input asset per unit of the
+    /// asset it ends at.
+    #[prost(double, tag = "2")]
+    pub estimated_price: f64,
+    /// Whether this is the second-best path to the asset it ends at, rather than the best.
+    #[prost(bool, tag = "3")]
+    pub spill: bool,
+}
+impl ::prost::Name for RouteCandidate {
+    const NAME: &'static str = "RouteCandidate";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// A fill along a route, executing against one position per hop.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouteFill {
+    /// The positions executed against, one per hop of the route.
+    #[prost(message, repeated, tag = "1")]
+    pub position_ids: ::prost::alloc::vec::Vec<PositionId>,
+    /// The amounts traded at each hop of the route.
+    #[prost(message, optional, tag = "2")]
+    pub trace: ::core::option::Option<swap_execution::Trace>,
+}
+impl ::prost::Name for RouteFill {
+    const NAME: &'static str = "RouteFill";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -2162,15 +2721,139 @@ pub mod query_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
-    }
-}
-/// Generated client implementations.
-#[cfg(feature = "rpc")]
-pub mod simulation_service_client {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    use tonic::codegen::http::Uri;
-    /// Simulation for the DEX component.
+        /// Computes the time-weighted average price of a trading pair over a window of heights.
+        pub async fn time_weighted_average_price(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TimeWeightedAveragePriceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimeWeightedAveragePriceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/TimeWeightedAveragePrice",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "TimeWeightedAveragePrice",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the liquidity available on a trading pair, aggregated by price level.
+        pub async fn market_depth(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MarketDepthRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MarketDepthResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/MarketDepth",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "MarketDepth",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the executions against a liquidity position, from the oldest to the newest.
+        pub async fn position_executions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PositionExecutionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::PositionExecutionsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/PositionExecutions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "PositionExecutions",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Get the unclaimed liquidity rewards accrued by a position.
+        pub async fn position_rewards(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PositionRewardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PositionRewardsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/PositionRewards",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "PositionRewards",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "rpc")]
+pub mod simulation_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Simulation for the DEX component.
     ///
     /// This is a separate service from the QueryService because it's not just a
     /// simple read query from the state. Thus it poses greater DoS risks, and node
@@ -2444,6 +3127,44 @@ pub mod query_service_server {
             tonic::Response<Self::CandlestickDataStreamStream>,
             tonic::Status,
         >;
+        /// Computes the time-weighted average price of a trading pair over a window of heights.
+        async fn time_weighted_average_price(
+            &self,
+            request: tonic::Request<super::TimeWeightedAveragePriceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimeWeightedAveragePriceResponse>,
+            tonic::Status,
+        >;
+        /// Get the liquidity available on a trading pair, aggregated by price level.
+        async fn market_depth(
+            &self,
+            request: tonic::Request<super::MarketDepthRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MarketDepthResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the PositionExecutions method.
+        type PositionExecutionsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::PositionExecutionsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Get the executions against a liquidity position, from the oldest to the newest.
+        async fn position_executions(
+            &self,
+            request: tonic::Request<super::PositionExecutionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::PositionExecutionsStream>,
+            tonic::Status,
+        >;
+        /// Get the unclaimed liquidity rewards accrued by a position.
+        async fn position_rewards(
+            &self,
+            request: tonic::Request<super::PositionRewardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PositionRewardsResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the DEX component.
     #[derive(Debug)]
@@ -3107,6 +3828,191 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.dex.v1.QueryService/TimeWeightedAveragePrice" => {
+                    #[allow(non_camel_case_types)]
+                    struct TimeWeightedAveragePriceSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::TimeWeightedAveragePriceRequest>
+                    for TimeWeightedAveragePriceSvc<T> {
+                        type Response = super::TimeWeightedAveragePriceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TimeWeightedAveragePriceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::time_weighted_average_price(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = TimeWeightedAveragePriceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/MarketDepth" => {
+                    #[allow(non_camel_case_types)]
+                    struct MarketDepthSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::MarketDepthRequest>
+                    for MarketDepthSvc<T> {
+                        type Response = super::MarketDepthResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MarketDepthRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::market_depth(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = MarketDepthSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/PositionExecutions" => {
+                    #[allow(non_camel_case_types)]
+                    struct PositionExecutionsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<super::PositionExecutionsRequest>
+                    for PositionExecutionsSvc<T> {
+                        type Response = super::PositionExecutionsResponse;
+                        type ResponseStream = T::PositionExecutionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PositionExecutionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::position_executions(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PositionExecutionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/PositionRewards" => {
+                    #[allow(non_camel_case_types)]
+                    struct PositionRewardsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::PositionRewardsRequest>
+                    for PositionRewardsSvc<T> {
+                        type Response = super::PositionRewardsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PositionRewardsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::position_rewards(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PositionRewardsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -9,11 +9,17 @@ impl serde::Serialize for ArbExecutionRequest {
         if self.height != 0 {
             len += 1;
         }
+        if self.arb_token.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ArbExecutionRequest", len)?;
         if self.height != 0 {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
+        if let Some(v) = self.arb_token.as_ref() {
+            struct_ser.serialize_field("arbToken", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -25,11 +31,14 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionRequest {
     {
         const FIELDS: &[&str] = &[
             "height",
+            "arb_token",
+            "arbToken",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Height,
+            ArbToken,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -53,6 +62,7 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionRequest {
                     {
                         match value {
                             "height" => Ok(GeneratedField::Height),
+                            "arbToken" | "arb_token" => Ok(GeneratedField::ArbToken),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -73,6 +83,7 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut height__ = None;
+                let mut arb_token__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Height => {
@@ -83,6 +94,12 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionRequest {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::ArbToken => {
+                            if arb_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("arbToken"));
+                            }
+                            arb_token__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -90,6 +107,7 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionRequest {
                 }
                 Ok(ArbExecutionRequest {
                     height: height__.unwrap_or_default(),
+                    arb_token: arb_token__,
                 })
             }
         }
@@ -226,6 +244,9 @@ impl serde::Serialize for ArbExecutionsRequest {
         if self.end_height != 0 {
             len += 1;
         }
+        if self.arb_token.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ArbExecutionsRequest", len)?;
         if self.start_height != 0 {
             #[allow(clippy::needless_borrow)]
@@ -235,6 +256,9 @@ impl serde::Serialize for ArbExecutionsRequest {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
         }
+        if let Some(v) = self.arb_token.as_ref() {
+            struct_ser.serialize_field("arbToken", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -249,12 +273,15 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionsRequest {
             "startHeight",
             "end_height",
             "endHeight",
+            "arb_token",
+            "arbToken",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             StartHeight,
             EndHeight,
+            ArbToken,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -279,6 +306,7 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionsRequest {
                         match value {
                             "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
                             "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "arbToken" | "arb_token" => Ok(GeneratedField::ArbToken),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -300,6 +328,7 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionsRequest {
             {
                 let mut start_height__ = None;
                 let mut end_height__ = None;
+                let mut arb_token__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::StartHeight => {
@@ -318,6 +347,12 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionsRequest {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::ArbToken => {
+                            if arb_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("arbToken"));
+                            }
+                            arb_token__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -326,6 +361,7 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionsRequest {
                 Ok(ArbExecutionsRequest {
                     start_height: start_height__.unwrap_or_default(),
                     end_height: end_height__.unwrap_or_default(),
+                    arb_token: arb_token__,
                 })
             }
         }
@@ -448,6 +484,196 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionsResponse {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.ArbExecutionsResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ArbSurplusDestination {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "ARB_SURPLUS_DESTINATION_UNSPECIFIED",
+            Self::Burn => "ARB_SURPLUS_DESTINATION_BURN",
+            Self::CommunityPool => "ARB_SURPLUS_DESTINATION_COMMUNITY_POOL",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ArbSurplusDestination {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ARB_SURPLUS_DESTINATION_UNSPECIFIED",
+            "ARB_SURPLUS_DESTINATION_BURN",
+            "ARB_SURPLUS_DESTINATION_COMMUNITY_POOL",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ArbSurplusDestination;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "ARB_SURPLUS_DESTINATION_UNSPECIFIED" => Ok(ArbSurplusDestination::Unspecified),
+                    "ARB_SURPLUS_DESTINATION_BURN" => Ok(ArbSurplusDestination::Burn),
+                    "ARB_SURPLUS_DESTINATION_COMMUNITY_POOL" => Ok(ArbSurplusDestination::CommunityPool),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ArbToken {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.surplus_destination != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ArbToken", len)?;
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if self.surplus_destination != 0 {
+            let v = ArbSurplusDestination::try_from(self.surplus_destination)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.surplus_destination)))?;
+            struct_ser.serialize_field("surplusDestination", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ArbToken {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset_id",
+            "assetId",
+            "surplus_destination",
+            "surplusDestination",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AssetId,
+            SurplusDestination,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "surplusDestination" | "surplus_destination" => Ok(GeneratedField::SurplusDestination),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ArbToken;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.ArbToken")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ArbToken, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset_id__ = None;
+                let mut surplus_destination__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::SurplusDestination => {
+                            if surplus_destination__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("surplusDestination"));
+                            }
+                            surplus_destination__ = Some(map_.next_value::<ArbSurplusDestination>()? as i32);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ArbToken {
+                    asset_id: asset_id__,
+                    surplus_destination: surplus_destination__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ArbToken", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for BareTradingFunction {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1085,6 +1311,12 @@ impl serde::Serialize for CandlestickData {
         if self.swap_volume != 0. {
             len += 1;
         }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.bucket != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.CandlestickData", len)?;
         if self.height != 0 {
             #[allow(clippy::needless_borrow)]
@@ -1108,6 +1340,14 @@ impl serde::Serialize for CandlestickData {
         if self.swap_volume != 0. {
             struct_ser.serialize_field("swapVolume", &self.swap_volume)?;
         }
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.bucket != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("bucket", ToString::to_string(&self.bucket).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -1127,6 +1367,9 @@ impl<'de> serde::Deserialize<'de> for CandlestickData {
             "directVolume",
             "swap_volume",
             "swapVolume",
+            "start_height",
+            "startHeight",
+            "bucket",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1138,6 +1381,8 @@ impl<'de> serde::Deserialize<'de> for CandlestickData {
             Low,
             DirectVolume,
             SwapVolume,
+            StartHeight,
+            Bucket,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1167,6 +1412,8 @@ impl<'de> serde::Deserialize<'de> for CandlestickData {
                             "low" => Ok(GeneratedField::Low),
                             "directVolume" | "direct_volume" => Ok(GeneratedField::DirectVolume),
                             "swapVolume" | "swap_volume" => Ok(GeneratedField::SwapVolume),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "bucket" => Ok(GeneratedField::Bucket),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1193,6 +1440,8 @@ impl<'de> serde::Deserialize<'de> for CandlestickData {
                 let mut low__ = None;
                 let mut direct_volume__ = None;
                 let mut swap_volume__ = None;
+                let mut start_height__ = None;
+                let mut bucket__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Height => {
@@ -1251,6 +1500,22 @@ impl<'de> serde::Deserialize<'de> for CandlestickData {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Bucket => {
+                            if bucket__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bucket"));
+                            }
+                            bucket__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1264,6 +1529,8 @@ impl<'de> serde::Deserialize<'de> for CandlestickData {
                     low: low__.unwrap_or_default(),
                     direct_volume: direct_volume__.unwrap_or_default(),
                     swap_volume: swap_volume__.unwrap_or_default(),
+                    start_height: start_height__.unwrap_or_default(),
+                    bucket: bucket__.unwrap_or_default(),
                 })
             }
         }
@@ -1287,6 +1554,15 @@ impl serde::Serialize for CandlestickDataRequest {
         if self.start_height != 0 {
             len += 1;
         }
+        if self.resolution != 0 {
+            len += 1;
+        }
+        if self.start_bucket != 0 {
+            len += 1;
+        }
+        if self.end_bucket != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.CandlestickDataRequest", len)?;
         if let Some(v) = self.pair.as_ref() {
             struct_ser.serialize_field("pair", v)?;
@@ -1299,6 +1575,19 @@ impl serde::Serialize for CandlestickDataRequest {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
         }
+        if self.resolution != 0 {
+            let v = CandlestickResolution::try_from(self.resolution)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.resolution)))?;
+            struct_ser.serialize_field("resolution", &v)?;
+        }
+        if self.start_bucket != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startBucket", ToString::to_string(&self.start_bucket).as_str())?;
+        }
+        if self.end_bucket != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endBucket", ToString::to_string(&self.end_bucket).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -1313,6 +1602,11 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
             "limit",
             "start_height",
             "startHeight",
+            "resolution",
+            "start_bucket",
+            "startBucket",
+            "end_bucket",
+            "endBucket",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1320,6 +1614,9 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
             Pair,
             Limit,
             StartHeight,
+            Resolution,
+            StartBucket,
+            EndBucket,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1345,6 +1642,9 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
                             "pair" => Ok(GeneratedField::Pair),
                             "limit" => Ok(GeneratedField::Limit),
                             "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "resolution" => Ok(GeneratedField::Resolution),
+                            "startBucket" | "start_bucket" => Ok(GeneratedField::StartBucket),
+                            "endBucket" | "end_bucket" => Ok(GeneratedField::EndBucket),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1367,6 +1667,9 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
                 let mut pair__ = None;
                 let mut limit__ = None;
                 let mut start_height__ = None;
+                let mut resolution__ = None;
+                let mut start_bucket__ = None;
+                let mut end_bucket__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Pair => {
@@ -1391,6 +1694,28 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::Resolution => {
+                            if resolution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("resolution"));
+                            }
+                            resolution__ = Some(map_.next_value::<CandlestickResolution>()? as i32);
+                        }
+                        GeneratedField::StartBucket => {
+                            if start_bucket__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startBucket"));
+                            }
+                            start_bucket__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndBucket => {
+                            if end_bucket__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endBucket"));
+                            }
+                            end_bucket__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1400,6 +1725,9 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
                     pair: pair__,
                     limit: limit__.unwrap_or_default(),
                     start_height: start_height__.unwrap_or_default(),
+                    resolution: resolution__.unwrap_or_default(),
+                    start_bucket: start_bucket__.unwrap_or_default(),
+                    end_bucket: end_bucket__.unwrap_or_default(),
                 })
             }
         }
@@ -1691,57 +2019,185 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataStreamResponse {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.CandlestickDataStreamResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for DexParameters {
+impl serde::Serialize for CandlestickResolution {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.is_enabled {
-            len += 1;
-        }
-        if !self.fixed_candidates.is_empty() {
-            len += 1;
-        }
-        if self.max_hops != 0 {
-            len += 1;
-        }
-        if self.max_positions_per_pair != 0 {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DexParameters", len)?;
-        if self.is_enabled {
-            struct_ser.serialize_field("isEnabled", &self.is_enabled)?;
-        }
-        if !self.fixed_candidates.is_empty() {
-            struct_ser.serialize_field("fixedCandidates", &self.fixed_candidates)?;
-        }
-        if self.max_hops != 0 {
-            struct_ser.serialize_field("maxHops", &self.max_hops)?;
-        }
-        if self.max_positions_per_pair != 0 {
-            struct_ser.serialize_field("maxPositionsPerPair", &self.max_positions_per_pair)?;
-        }
-        struct_ser.end()
+        let variant = match self {
+            Self::Unspecified => "CANDLESTICK_RESOLUTION_UNSPECIFIED",
+            Self::Minute => "CANDLESTICK_RESOLUTION_MINUTE",
+            Self::Hour => "CANDLESTICK_RESOLUTION_HOUR",
+            Self::Day => "CANDLESTICK_RESOLUTION_DAY",
+            Self::Epoch => "CANDLESTICK_RESOLUTION_EPOCH",
+        };
+        serializer.serialize_str(variant)
     }
 }
-impl<'de> serde::Deserialize<'de> for DexParameters {
+impl<'de> serde::Deserialize<'de> for CandlestickResolution {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "is_enabled",
-            "isEnabled",
-            "fixed_candidates",
-            "fixedCandidates",
-            "max_hops",
-            "maxHops",
-            "max_positions_per_pair",
-            "maxPositionsPerPair",
+            "CANDLESTICK_RESOLUTION_UNSPECIFIED",
+            "CANDLESTICK_RESOLUTION_MINUTE",
+            "CANDLESTICK_RESOLUTION_HOUR",
+            "CANDLESTICK_RESOLUTION_DAY",
+            "CANDLESTICK_RESOLUTION_EPOCH",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CandlestickResolution;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "CANDLESTICK_RESOLUTION_UNSPECIFIED" => Ok(CandlestickResolution::Unspecified),
+                    "CANDLESTICK_RESOLUTION_MINUTE" => Ok(CandlestickResolution::Minute),
+                    "CANDLESTICK_RESOLUTION_HOUR" => Ok(CandlestickResolution::Hour),
+                    "CANDLESTICK_RESOLUTION_DAY" => Ok(CandlestickResolution::Day),
+                    "CANDLESTICK_RESOLUTION_EPOCH" => Ok(CandlestickResolution::Epoch),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DexParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.is_enabled {
+            len += 1;
+        }
+        if !self.fixed_candidates.is_empty() {
+            len += 1;
+        }
+        if self.max_hops != 0 {
+            len += 1;
+        }
+        if self.max_positions_per_pair != 0 {
+            len += 1;
+        }
+        if !self.arb_tokens.is_empty() {
+            len += 1;
+        }
+        if self.max_path_searches != 0 {
+            len += 1;
+        }
+        if self.max_executions != 0 {
+            len += 1;
+        }
+        if self.max_block_path_searches != 0 {
+            len += 1;
+        }
+        if self.max_block_executions != 0 {
+            len += 1;
+        }
+        if self.arb_extra_hops != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DexParameters", len)?;
+        if self.is_enabled {
+            struct_ser.serialize_field("isEnabled", &self.is_enabled)?;
+        }
+        if !self.fixed_candidates.is_empty() {
+            struct_ser.serialize_field("fixedCandidates", &self.fixed_candidates)?;
+        }
+        if self.max_hops != 0 {
+            struct_ser.serialize_field("maxHops", &self.max_hops)?;
+        }
+        if self.max_positions_per_pair != 0 {
+            struct_ser.serialize_field("maxPositionsPerPair", &self.max_positions_per_pair)?;
+        }
+        if !self.arb_tokens.is_empty() {
+            struct_ser.serialize_field("arbTokens", &self.arb_tokens)?;
+        }
+        if self.max_path_searches != 0 {
+            struct_ser.serialize_field("maxPathSearches", &self.max_path_searches)?;
+        }
+        if self.max_executions != 0 {
+            struct_ser.serialize_field("maxExecutions", &self.max_executions)?;
+        }
+        if self.max_block_path_searches != 0 {
+            struct_ser.serialize_field("maxBlockPathSearches", &self.max_block_path_searches)?;
+        }
+        if self.max_block_executions != 0 {
+            struct_ser.serialize_field("maxBlockExecutions", &self.max_block_executions)?;
+        }
+        if self.arb_extra_hops != 0 {
+            struct_ser.serialize_field("arbExtraHops", &self.arb_extra_hops)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DexParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "is_enabled",
+            "isEnabled",
+            "fixed_candidates",
+            "fixedCandidates",
+            "max_hops",
+            "maxHops",
+            "max_positions_per_pair",
+            "maxPositionsPerPair",
+            "arb_tokens",
+            "arbTokens",
+            "max_path_searches",
+            "maxPathSearches",
+            "max_executions",
+            "maxExecutions",
+            "max_block_path_searches",
+            "maxBlockPathSearches",
+            "max_block_executions",
+            "maxBlockExecutions",
+            "arb_extra_hops",
+            "arbExtraHops",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1750,6 +2206,12 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
             FixedCandidates,
             MaxHops,
             MaxPositionsPerPair,
+            ArbTokens,
+            MaxPathSearches,
+            MaxExecutions,
+            MaxBlockPathSearches,
+            MaxBlockExecutions,
+            ArbExtraHops,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1776,6 +2238,12 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                             "fixedCandidates" | "fixed_candidates" => Ok(GeneratedField::FixedCandidates),
                             "maxHops" | "max_hops" => Ok(GeneratedField::MaxHops),
                             "maxPositionsPerPair" | "max_positions_per_pair" => Ok(GeneratedField::MaxPositionsPerPair),
+                            "arbTokens" | "arb_tokens" => Ok(GeneratedField::ArbTokens),
+                            "maxPathSearches" | "max_path_searches" => Ok(GeneratedField::MaxPathSearches),
+                            "maxExecutions" | "max_executions" => Ok(GeneratedField::MaxExecutions),
+                            "maxBlockPathSearches" | "max_block_path_searches" => Ok(GeneratedField::MaxBlockPathSearches),
+                            "maxBlockExecutions" | "max_block_executions" => Ok(GeneratedField::MaxBlockExecutions),
+                            "arbExtraHops" | "arb_extra_hops" => Ok(GeneratedField::ArbExtraHops),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1799,6 +2267,12 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                 let mut fixed_candidates__ = None;
                 let mut max_hops__ = None;
                 let mut max_positions_per_pair__ = None;
+                let mut arb_tokens__ = None;
+                let mut max_path_searches__ = None;
+                let mut max_executions__ = None;
+                let mut max_block_path_searches__ = None;
+                let mut max_block_executions__ = None;
+                let mut arb_extra_hops__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::IsEnabled => {
@@ -1811,7 +2285,7 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                             if fixed_candidates__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("fixedCandidates"));
                             }
-                            fixed_candidates__ = Some(map_.next_value()?);
+                            fixed_candidates__ = map_.next_value()?;
                         }
                         GeneratedField::MaxHops => {
                             if max_hops__.is_some() {
@@ -1829,6 +2303,52 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::ArbTokens => {
+                            if arb_tokens__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("arbTokens"));
+                            }
+                            arb_tokens__ = map_.next_value()?;
+                        }
+                        GeneratedField::MaxPathSearches => {
+                            if max_path_searches__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxPathSearches"));
+                            }
+                            max_path_searches__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxExecutions => {
+                            if max_executions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxExecutions"));
+                            }
+                            max_executions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxBlockPathSearches => {
+                            if max_block_path_searches__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxBlockPathSearches"));
+                            }
+                            max_block_path_searches__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxBlockExecutions => {
+                            if max_block_executions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxBlockExecutions"));
+                            }
+                            max_block_executions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ArbExtraHops => {
+                            if arb_extra_hops__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("arbExtraHops"));
+                            }
+                            arb_extra_hops__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1839,6 +2359,12 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                     fixed_candidates: fixed_candidates__.unwrap_or_default(),
                     max_hops: max_hops__.unwrap_or_default(),
                     max_positions_per_pair: max_positions_per_pair__.unwrap_or_default(),
+                    arb_tokens: arb_tokens__.unwrap_or_default(),
+                    max_path_searches: max_path_searches__.unwrap_or_default(),
+                    max_executions: max_executions__.unwrap_or_default(),
+                    max_block_path_searches: max_block_path_searches__.unwrap_or_default(),
+                    max_block_executions: max_block_executions__.unwrap_or_default(),
+                    arb_extra_hops: arb_extra_hops__.unwrap_or_default(),
                 })
             }
         }
@@ -2674,7 +3200,7 @@ impl<'de> serde::Deserialize<'de> for EventPositionOpen {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionOpen", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventPositionWithdraw {
+impl serde::Serialize for EventPositionRewardClaim {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2685,39 +3211,27 @@ impl serde::Serialize for EventPositionWithdraw {
         if self.position_id.is_some() {
             len += 1;
         }
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.reserves_1.is_some() {
-            len += 1;
-        }
-        if self.reserves_2.is_some() {
+        if self.sequence != 0 {
             len += 1;
         }
-        if self.sequence != 0 {
+        if self.rewards.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionRewardClaim", len)?;
         if let Some(v) = self.position_id.as_ref() {
             struct_ser.serialize_field("positionId", v)?;
         }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if let Some(v) = self.reserves_1.as_ref() {
-            struct_ser.serialize_field("reserves1", v)?;
-        }
-        if let Some(v) = self.reserves_2.as_ref() {
-            struct_ser.serialize_field("reserves2", v)?;
-        }
         if self.sequence != 0 {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
         }
+        if let Some(v) = self.rewards.as_ref() {
+            struct_ser.serialize_field("rewards", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
+impl<'de> serde::Deserialize<'de> for EventPositionRewardClaim {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -2726,22 +3240,15 @@ impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
         const FIELDS: &[&str] = &[
             "position_id",
             "positionId",
-            "trading_pair",
-            "tradingPair",
-            "reserves_1",
-            "reserves1",
-            "reserves_2",
-            "reserves2",
             "sequence",
+            "rewards",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             PositionId,
-            TradingPair,
-            Reserves1,
-            Reserves2,
             Sequence,
+            Rewards,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2765,10 +3272,8 @@ impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
                     {
                         match value {
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
-                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
                             "sequence" => Ok(GeneratedField::Sequence),
+                            "rewards" => Ok(GeneratedField::Rewards),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2778,21 +3283,19 @@ impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventPositionWithdraw;
+            type Value = EventPositionRewardClaim;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionWithdraw")
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionRewardClaim")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionWithdraw, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionRewardClaim, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut position_id__ = None;
-                let mut trading_pair__ = None;
-                let mut reserves_1__ = None;
-                let mut reserves_2__ = None;
                 let mut sequence__ = None;
+                let mut rewards__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::PositionId => {
@@ -2801,24 +3304,6 @@ impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
                             }
                             position_id__ = map_.next_value()?;
                         }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves1 => {
-                            if reserves_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves1"));
-                            }
-                            reserves_1__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves2 => {
-                            if reserves_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves2"));
-                            }
-                            reserves_2__ = map_.next_value()?;
-                        }
                         GeneratedField::Sequence => {
                             if sequence__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("sequence"));
@@ -2827,24 +3312,28 @@ impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventPositionWithdraw {
+                Ok(EventPositionRewardClaim {
                     position_id: position_id__,
-                    trading_pair: trading_pair__,
-                    reserves_1: reserves_1__,
-                    reserves_2: reserves_2__,
                     sequence: sequence__.unwrap_or_default(),
+                    rewards: rewards__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionRewardClaim", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventQueuePositionClose {
+impl serde::Serialize for EventPositionWithdraw {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2855,14 +3344,39 @@ impl serde::Serialize for EventQueuePositionClose {
         if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventQueuePositionClose", len)?;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.reserves_1.is_some() {
+            len += 1;
+        }
+        if self.reserves_2.is_some() {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", len)?;
         if let Some(v) = self.position_id.as_ref() {
             struct_ser.serialize_field("positionId", v)?;
         }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.reserves_1.as_ref() {
+            struct_ser.serialize_field("reserves1", v)?;
+        }
+        if let Some(v) = self.reserves_2.as_ref() {
+            struct_ser.serialize_field("reserves2", v)?;
+        }
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventQueuePositionClose {
+impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -2871,11 +3385,22 @@ impl<'de> serde::Deserialize<'de> for EventQueuePositionClose {
         const FIELDS: &[&str] = &[
             "position_id",
             "positionId",
+            "trading_pair",
+            "tradingPair",
+            "reserves_1",
+            "reserves1",
+            "reserves_2",
+            "reserves2",
+            "sequence",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             PositionId,
+            TradingPair,
+            Reserves1,
+            Reserves2,
+            Sequence,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2899,6 +3424,10 @@ impl<'de> serde::Deserialize<'de> for EventQueuePositionClose {
                     {
                         match value {
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
+                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
+                            "sequence" => Ok(GeneratedField::Sequence),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2908,7 +3437,137 @@ impl<'de> serde::Deserialize<'de> for EventQueuePositionClose {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventQueuePositionClose;
+            type Value = EventPositionWithdraw;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionWithdraw")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionWithdraw, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut trading_pair__ = None;
+                let mut reserves_1__ = None;
+                let mut reserves_2__ = None;
+                let mut sequence__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves1 => {
+                            if reserves_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves1"));
+                            }
+                            reserves_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves2 => {
+                            if reserves_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves2"));
+                            }
+                            reserves_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventPositionWithdraw {
+                    position_id: position_id__,
+                    trading_pair: trading_pair__,
+                    reserves_1: reserves_1__,
+                    reserves_2: reserves_2__,
+                    sequence: sequence__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventQueuePositionClose {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventQueuePositionClose", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventQueuePositionClose {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventQueuePositionClose;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 formatter.write_str("struct penumbra.core.component.dex.v1.EventQueuePositionClose")
@@ -4513,7 +5172,7 @@ impl<'de> serde::Deserialize<'de> for LpNft {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.LpNft", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Position {
+impl serde::Serialize for MarketDepthLevel {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4521,63 +5180,55 @@ impl serde::Serialize for Position {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.phi.is_some() {
-            len += 1;
-        }
-        if !self.nonce.is_empty() {
+        if self.price != 0. {
             len += 1;
         }
-        if self.state.is_some() {
+        if self.liquidity.is_some() {
             len += 1;
         }
-        if self.reserves.is_some() {
+        if self.cumulative_liquidity.is_some() {
             len += 1;
         }
-        if self.close_on_fill {
+        if self.position_count != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Position", len)?;
-        if let Some(v) = self.phi.as_ref() {
-            struct_ser.serialize_field("phi", v)?;
-        }
-        if !self.nonce.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.MarketDepthLevel", len)?;
+        if self.price != 0. {
+            struct_ser.serialize_field("price", &self.price)?;
         }
-        if let Some(v) = self.state.as_ref() {
-            struct_ser.serialize_field("state", v)?;
+        if let Some(v) = self.liquidity.as_ref() {
+            struct_ser.serialize_field("liquidity", v)?;
         }
-        if let Some(v) = self.reserves.as_ref() {
-            struct_ser.serialize_field("reserves", v)?;
+        if let Some(v) = self.cumulative_liquidity.as_ref() {
+            struct_ser.serialize_field("cumulativeLiquidity", v)?;
         }
-        if self.close_on_fill {
-            struct_ser.serialize_field("closeOnFill", &self.close_on_fill)?;
+        if self.position_count != 0 {
+            struct_ser.serialize_field("positionCount", &self.position_count)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Position {
+impl<'de> serde::Deserialize<'de> for MarketDepthLevel {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "phi",
-            "nonce",
-            "state",
-            "reserves",
-            "close_on_fill",
-            "closeOnFill",
+            "price",
+            "liquidity",
+            "cumulative_liquidity",
+            "cumulativeLiquidity",
+            "position_count",
+            "positionCount",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Phi,
-            Nonce,
-            State,
-            Reserves,
-            CloseOnFill,
+            Price,
+            Liquidity,
+            CumulativeLiquidity,
+            PositionCount,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4600,11 +5251,10 @@ impl<'de> serde::Deserialize<'de> for Position {
                         E: serde::de::Error,
                     {
                         match value {
-                            "phi" => Ok(GeneratedField::Phi),
-                            "nonce" => Ok(GeneratedField::Nonce),
-                            "state" => Ok(GeneratedField::State),
-                            "reserves" => Ok(GeneratedField::Reserves),
-                            "closeOnFill" | "close_on_fill" => Ok(GeneratedField::CloseOnFill),
+                            "price" => Ok(GeneratedField::Price),
+                            "liquidity" => Ok(GeneratedField::Liquidity),
+                            "cumulativeLiquidity" | "cumulative_liquidity" => Ok(GeneratedField::CumulativeLiquidity),
+                            "positionCount" | "position_count" => Ok(GeneratedField::PositionCount),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4614,73 +5264,67 @@ impl<'de> serde::Deserialize<'de> for Position {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Position;
+            type Value = MarketDepthLevel;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.Position")
+                formatter.write_str("struct penumbra.core.component.dex.v1.MarketDepthLevel")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Position, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketDepthLevel, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut phi__ = None;
-                let mut nonce__ = None;
-                let mut state__ = None;
-                let mut reserves__ = None;
-                let mut close_on_fill__ = None;
+                let mut price__ = None;
+                let mut liquidity__ = None;
+                let mut cumulative_liquidity__ = None;
+                let mut position_count__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Phi => {
-                            if phi__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("phi"));
-                            }
-                            phi__ = map_.next_value()?;
-                        }
-                        GeneratedField::Nonce => {
-                            if nonce__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nonce"));
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
                             }
-                            nonce__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            price__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
+                        GeneratedField::Liquidity => {
+                            if liquidity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("liquidity"));
                             }
-                            state__ = map_.next_value()?;
+                            liquidity__ = map_.next_value()?;
                         }
-                        GeneratedField::Reserves => {
-                            if reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves"));
+                        GeneratedField::CumulativeLiquidity => {
+                            if cumulative_liquidity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cumulativeLiquidity"));
                             }
-                            reserves__ = map_.next_value()?;
+                            cumulative_liquidity__ = map_.next_value()?;
                         }
-                        GeneratedField::CloseOnFill => {
-                            if close_on_fill__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("closeOnFill"));
+                        GeneratedField::PositionCount => {
+                            if position_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionCount"));
                             }
-                            close_on_fill__ = Some(map_.next_value()?);
+                            position_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Position {
-                    phi: phi__,
-                    nonce: nonce__.unwrap_or_default(),
-                    state: state__,
-                    reserves: reserves__,
-                    close_on_fill: close_on_fill__.unwrap_or_default(),
+                Ok(MarketDepthLevel {
+                    price: price__.unwrap_or_default(),
+                    liquidity: liquidity__,
+                    cumulative_liquidity: cumulative_liquidity__,
+                    position_count: position_count__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Position", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.MarketDepthLevel", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionClose {
+impl serde::Serialize for MarketDepthRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4688,30 +5332,48 @@ impl serde::Serialize for PositionClose {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionClose", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        if self.tick_size != 0. {
+            len += 1;
+        }
+        if self.depth_limit != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.MarketDepthRequest", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if self.tick_size != 0. {
+            struct_ser.serialize_field("tickSize", &self.tick_size)?;
+        }
+        if self.depth_limit != 0 {
+            struct_ser.serialize_field("depthLimit", &self.depth_limit)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionClose {
+impl<'de> serde::Deserialize<'de> for MarketDepthRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
+            "trading_pair",
+            "tradingPair",
+            "tick_size",
+            "tickSize",
+            "depth_limit",
+            "depthLimit",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
+            TradingPair,
+            TickSize,
+            DepthLimit,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4734,7 +5396,9 @@ impl<'de> serde::Deserialize<'de> for PositionClose {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "tickSize" | "tick_size" => Ok(GeneratedField::TickSize),
+                            "depthLimit" | "depth_limit" => Ok(GeneratedField::DepthLimit),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4744,39 +5408,59 @@ impl<'de> serde::Deserialize<'de> for PositionClose {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionClose;
+            type Value = MarketDepthRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionClose")
+                formatter.write_str("struct penumbra.core.component.dex.v1.MarketDepthRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionClose, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketDepthRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
+                let mut trading_pair__ = None;
+                let mut tick_size__ = None;
+                let mut depth_limit__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            position_id__ = map_.next_value()?;
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::TickSize => {
+                            if tick_size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tickSize"));
+                            }
+                            tick_size__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DepthLimit => {
+                            if depth_limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("depthLimit"));
+                            }
+                            depth_limit__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionClose {
-                    position_id: position_id__,
+                Ok(MarketDepthRequest {
+                    trading_pair: trading_pair__,
+                    tick_size: tick_size__.unwrap_or_default(),
+                    depth_limit: depth_limit__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionClose", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.MarketDepthRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionId {
+impl serde::Serialize for MarketDepthResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4784,39 +5468,29 @@ impl serde::Serialize for PositionId {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.inner.is_empty() {
+        if !self.levels.is_empty() {
             len += 1;
         }
-        if !self.alt_bech32m.is_empty() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionId", len)?;
-        if !self.inner.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
-        }
-        if !self.alt_bech32m.is_empty() {
-            struct_ser.serialize_field("altBech32m", &self.alt_bech32m)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.MarketDepthResponse", len)?;
+        if !self.levels.is_empty() {
+            struct_ser.serialize_field("levels", &self.levels)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionId {
+impl<'de> serde::Deserialize<'de> for MarketDepthResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "inner",
-            "alt_bech32m",
-            "altBech32m",
+            "levels",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Inner,
-            AltBech32m,
+            Levels,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4839,8 +5513,7 @@ impl<'de> serde::Deserialize<'de> for PositionId {
                         E: serde::de::Error,
                     {
                         match value {
-                            "inner" => Ok(GeneratedField::Inner),
-                            "altBech32m" | "alt_bech32m" => Ok(GeneratedField::AltBech32m),
+                            "levels" => Ok(GeneratedField::Levels),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4850,49 +5523,39 @@ impl<'de> serde::Deserialize<'de> for PositionId {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionId;
+            type Value = MarketDepthResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionId")
+                formatter.write_str("struct penumbra.core.component.dex.v1.MarketDepthResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionId, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketDepthResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut inner__ = None;
-                let mut alt_bech32m__ = None;
+                let mut levels__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Inner => {
-                            if inner__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("inner"));
-                            }
-                            inner__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::AltBech32m => {
-                            if alt_bech32m__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("altBech32m"));
+                        GeneratedField::Levels => {
+                            if levels__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("levels"));
                             }
-                            alt_bech32m__ = Some(map_.next_value()?);
+                            levels__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionId {
-                    inner: inner__.unwrap_or_default(),
-                    alt_bech32m: alt_bech32m__.unwrap_or_default(),
+                Ok(MarketDepthResponse {
+                    levels: levels__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionId", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.MarketDepthResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionOpen {
+impl serde::Serialize for Position {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4900,29 +5563,63 @@ impl serde::Serialize for PositionOpen {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position.is_some() {
+        if self.phi.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionOpen", len)?;
-        if let Some(v) = self.position.as_ref() {
-            struct_ser.serialize_field("position", v)?;
+        if !self.nonce.is_empty() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        if self.reserves.is_some() {
+            len += 1;
+        }
+        if self.close_on_fill {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Position", len)?;
+        if let Some(v) = self.phi.as_ref() {
+            struct_ser.serialize_field("phi", v)?;
+        }
+        if !self.nonce.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if self.close_on_fill {
+            struct_ser.serialize_field("closeOnFill", &self.close_on_fill)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionOpen {
+impl<'de> serde::Deserialize<'de> for Position {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position",
+            "phi",
+            "nonce",
+            "state",
+            "reserves",
+            "close_on_fill",
+            "closeOnFill",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Position,
+            Phi,
+            Nonce,
+            State,
+            Reserves,
+            CloseOnFill,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4945,7 +5642,11 @@ impl<'de> serde::Deserialize<'de> for PositionOpen {
                         E: serde::de::Error,
                     {
                         match value {
-                            "position" => Ok(GeneratedField::Position),
+                            "phi" => Ok(GeneratedField::Phi),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            "state" => Ok(GeneratedField::State),
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "closeOnFill" | "close_on_fill" => Ok(GeneratedField::CloseOnFill),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4955,39 +5656,73 @@ impl<'de> serde::Deserialize<'de> for PositionOpen {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionOpen;
+            type Value = Position;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionOpen")
+                formatter.write_str("struct penumbra.core.component.dex.v1.Position")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionOpen, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Position, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position__ = None;
+                let mut phi__ = None;
+                let mut nonce__ = None;
+                let mut state__ = None;
+                let mut reserves__ = None;
+                let mut close_on_fill__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
+                        GeneratedField::Phi => {
+                            if phi__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("phi"));
                             }
-                            position__ = map_.next_value()?;
+                            phi__ = map_.next_value()?;
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
+                            }
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::CloseOnFill => {
+                            if close_on_fill__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closeOnFill"));
+                            }
+                            close_on_fill__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionOpen {
-                    position: position__,
+                Ok(Position {
+                    phi: phi__,
+                    nonce: nonce__.unwrap_or_default(),
+                    state: state__,
+                    reserves: reserves__,
+                    close_on_fill: close_on_fill__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionOpen", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Position", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionRewardClaim {
+impl serde::Serialize for PositionClose {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4998,20 +5733,14 @@ impl serde::Serialize for PositionRewardClaim {
         if self.position_id.is_some() {
             len += 1;
         }
-        if self.rewards_commitment.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewardClaim", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionClose", len)?;
         if let Some(v) = self.position_id.as_ref() {
             struct_ser.serialize_field("positionId", v)?;
         }
-        if let Some(v) = self.rewards_commitment.as_ref() {
-            struct_ser.serialize_field("rewardsCommitment", v)?;
-        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionRewardClaim {
+impl<'de> serde::Deserialize<'de> for PositionClose {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -5020,14 +5749,11 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaim {
         const FIELDS: &[&str] = &[
             "position_id",
             "positionId",
-            "rewards_commitment",
-            "rewardsCommitment",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             PositionId,
-            RewardsCommitment,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5051,7 +5777,6 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaim {
                     {
                         match value {
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "rewardsCommitment" | "rewards_commitment" => Ok(GeneratedField::RewardsCommitment),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5061,18 +5786,17 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaim {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionRewardClaim;
+            type Value = PositionClose;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewardClaim")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionClose")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardClaim, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionClose, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut position_id__ = None;
-                let mut rewards_commitment__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::PositionId => {
@@ -5081,27 +5805,20 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaim {
                             }
                             position_id__ = map_.next_value()?;
                         }
-                        GeneratedField::RewardsCommitment => {
-                            if rewards_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rewardsCommitment"));
-                            }
-                            rewards_commitment__ = map_.next_value()?;
-                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionRewardClaim {
+                Ok(PositionClose {
                     position_id: position_id__,
-                    rewards_commitment: rewards_commitment__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionClose", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionRewardClaimPlan {
+impl serde::Serialize for PositionExecution {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5109,29 +5826,63 @@ impl serde::Serialize for PositionRewardClaimPlan {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.prev_reserves.is_some() {
+            len += 1;
+        }
         if self.reserves.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewardClaimPlan", len)?;
+        if self.direction.is_some() {
+            len += 1;
+        }
+        if self.fee.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionExecution", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.prev_reserves.as_ref() {
+            struct_ser.serialize_field("prevReserves", v)?;
+        }
         if let Some(v) = self.reserves.as_ref() {
             struct_ser.serialize_field("reserves", v)?;
         }
+        if let Some(v) = self.direction.as_ref() {
+            struct_ser.serialize_field("direction", v)?;
+        }
+        if let Some(v) = self.fee.as_ref() {
+            struct_ser.serialize_field("fee", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
+impl<'de> serde::Deserialize<'de> for PositionExecution {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "height",
+            "prev_reserves",
+            "prevReserves",
             "reserves",
+            "direction",
+            "fee",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Height,
+            PrevReserves,
             Reserves,
+            Direction,
+            Fee,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5154,7 +5905,11 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
                         E: serde::de::Error,
                     {
                         match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "prevReserves" | "prev_reserves" => Ok(GeneratedField::PrevReserves),
                             "reserves" => Ok(GeneratedField::Reserves),
+                            "direction" => Ok(GeneratedField::Direction),
+                            "fee" => Ok(GeneratedField::Fee),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5164,39 +5919,73 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionRewardClaimPlan;
+            type Value = PositionExecution;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewardClaimPlan")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionExecution")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardClaimPlan, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionExecution, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
+                let mut height__ = None;
+                let mut prev_reserves__ = None;
                 let mut reserves__ = None;
+                let mut direction__ = None;
+                let mut fee__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PrevReserves => {
+                            if prev_reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prevReserves"));
+                            }
+                            prev_reserves__ = map_.next_value()?;
+                        }
                         GeneratedField::Reserves => {
                             if reserves__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("reserves"));
                             }
                             reserves__ = map_.next_value()?;
                         }
+                        GeneratedField::Direction => {
+                            if direction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("direction"));
+                            }
+                            direction__ = map_.next_value()?;
+                        }
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
+                            }
+                            fee__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionRewardClaimPlan {
+                Ok(PositionExecution {
+                    height: height__.unwrap_or_default(),
+                    prev_reserves: prev_reserves__,
                     reserves: reserves__,
+                    direction: direction__,
+                    fee: fee__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardClaimPlan", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionExecution", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionState {
+impl serde::Serialize for PositionExecutionsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5204,40 +5993,50 @@ impl serde::Serialize for PositionState {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.state != 0 {
+        if self.position_id.is_some() {
             len += 1;
         }
-        if self.sequence != 0 {
+        if self.start_height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionState", len)?;
-        if self.state != 0 {
-            let v = position_state::PositionStateEnum::try_from(self.state)
-                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
-            struct_ser.serialize_field("state", &v)?;
+        if self.end_height != 0 {
+            len += 1;
         }
-        if self.sequence != 0 {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionExecutionsRequest", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if self.start_height != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionState {
+impl<'de> serde::Deserialize<'de> for PositionExecutionsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "state",
-            "sequence",
+            "position_id",
+            "positionId",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            State,
-            Sequence,
+            PositionId,
+            StartHeight,
+            EndHeight,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5260,8 +6059,9 @@ impl<'de> serde::Deserialize<'de> for PositionState {
                         E: serde::de::Error,
                     {
                         match value {
-                            "state" => Ok(GeneratedField::State),
-                            "sequence" => Ok(GeneratedField::Sequence),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5271,31 +6071,40 @@ impl<'de> serde::Deserialize<'de> for PositionState {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionState;
+            type Value = PositionExecutionsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionState")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionExecutionsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionState, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionExecutionsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut state__ = None;
-                let mut sequence__ = None;
+                let mut position_id__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            state__ = Some(map_.next_value::<position_state::PositionStateEnum>()? as i32);
+                            position_id__ = map_.next_value()?;
                         }
-                        GeneratedField::Sequence => {
-                            if sequence__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sequence"));
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
                             }
-                            sequence__ = 
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
@@ -5304,96 +6113,1663 @@ impl<'de> serde::Deserialize<'de> for PositionState {
                         }
                     }
                 }
-                Ok(PositionState {
-                    state: state__.unwrap_or_default(),
-                    sequence: sequence__.unwrap_or_default(),
+                Ok(PositionExecutionsRequest {
+                    position_id: position_id__,
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionState", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionExecutionsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for position_state::PositionStateEnum {
+impl serde::Serialize for PositionExecutionsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let variant = match self {
-            Self::Unspecified => "POSITION_STATE_ENUM_UNSPECIFIED",
-            Self::Opened => "POSITION_STATE_ENUM_OPENED",
-            Self::Closed => "POSITION_STATE_ENUM_CLOSED",
-            Self::Withdrawn => "POSITION_STATE_ENUM_WITHDRAWN",
-            Self::Claimed => "POSITION_STATE_ENUM_CLAIMED",
-        };
-        serializer.serialize_str(variant)
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.execution.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionExecutionsResponse", len)?;
+        if let Some(v) = self.execution.as_ref() {
+            struct_ser.serialize_field("execution", v)?;
+        }
+        struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for position_state::PositionStateEnum {
+impl<'de> serde::Deserialize<'de> for PositionExecutionsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "POSITION_STATE_ENUM_UNSPECIFIED",
-            "POSITION_STATE_ENUM_OPENED",
-            "POSITION_STATE_ENUM_CLOSED",
-            "POSITION_STATE_ENUM_WITHDRAWN",
-            "POSITION_STATE_ENUM_CLAIMED",
+            "execution",
         ];
 
-        struct GeneratedVisitor;
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Execution,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
 
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "execution" => Ok(GeneratedField::Execution),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = position_state::PositionStateEnum;
+            type Value = PositionExecutionsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(formatter, "expected one of: {:?}", &FIELDS)
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionExecutionsResponse")
             }
 
-            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionExecutionsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut execution__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Execution => {
+                            if execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("execution"));
+                            }
+                            execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionExecutionsResponse {
+                    execution: execution__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionExecutionsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionId {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.inner.is_empty() {
+            len += 1;
+        }
+        if !self.alt_bech32m.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionId", len)?;
+        if !self.inner.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
+        }
+        if !self.alt_bech32m.is_empty() {
+            struct_ser.serialize_field("altBech32m", &self.alt_bech32m)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionId {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "inner",
+            "alt_bech32m",
+            "altBech32m",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Inner,
+            AltBech32m,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
             where
-                E: serde::de::Error,
+                D: serde::Deserializer<'de>,
             {
-                i32::try_from(v)
-                    .ok()
-                    .and_then(|x| x.try_into().ok())
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
-                    })
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "inner" => Ok(GeneratedField::Inner),
+                            "altBech32m" | "alt_bech32m" => Ok(GeneratedField::AltBech32m),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionId;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionId")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionId, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut inner__ = None;
+                let mut alt_bech32m__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Inner => {
+                            if inner__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inner"));
+                            }
+                            inner__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AltBech32m => {
+                            if alt_bech32m__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("altBech32m"));
+                            }
+                            alt_bech32m__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionId {
+                    inner: inner__.unwrap_or_default(),
+                    alt_bech32m: alt_bech32m__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionId", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionOpen {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionOpen", len)?;
+        if let Some(v) = self.position.as_ref() {
+            struct_ser.serialize_field("position", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionOpen {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Position,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "position" => Ok(GeneratedField::Position),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionOpen;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionOpen")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionOpen, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionOpen {
+                    position: position__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionOpen", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionRewardClaim {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.rewards_commitment.is_some() {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewardClaim", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.rewards_commitment.as_ref() {
+            struct_ser.serialize_field("rewardsCommitment", v)?;
+        }
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionRewardClaim {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "rewards_commitment",
+            "rewardsCommitment",
+            "sequence",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            RewardsCommitment,
+            Sequence,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "rewardsCommitment" | "rewards_commitment" => Ok(GeneratedField::RewardsCommitment),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionRewardClaim;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewardClaim")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardClaim, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut rewards_commitment__ = None;
+                let mut sequence__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::RewardsCommitment => {
+                            if rewards_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewardsCommitment"));
+                            }
+                            rewards_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionRewardClaim {
+                    position_id: position_id__,
+                    rewards_commitment: rewards_commitment__,
+                    sequence: sequence__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardClaim", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionRewardClaimPlan {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        if self.rewards.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewardClaimPlan", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        if let Some(v) = self.rewards.as_ref() {
+            struct_ser.serialize_field("rewards", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "sequence",
+            "rewards",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            Sequence,
+            Rewards,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            "rewards" => Ok(GeneratedField::Rewards),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionRewardClaimPlan;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewardClaimPlan")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardClaimPlan, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut sequence__ = None;
+                let mut rewards__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionRewardClaimPlan {
+                    position_id: position_id__,
+                    sequence: sequence__.unwrap_or_default(),
+                    rewards: rewards__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardClaimPlan", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionRewardsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewardsRequest", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionRewardsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionRewardsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewardsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionRewardsRequest {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionRewardsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rewards.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewardsResponse", len)?;
+        if let Some(v) = self.rewards.as_ref() {
+            struct_ser.serialize_field("rewards", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionRewardsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rewards",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Rewards,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rewards" => Ok(GeneratedField::Rewards),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionRewardsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewardsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rewards__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionRewardsResponse {
+                    rewards: rewards__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.state != 0 {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionState", len)?;
+        if self.state != 0 {
+            let v = position_state::PositionStateEnum::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
+        }
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "state",
+            "sequence",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            State,
+            Sequence,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "state" => Ok(GeneratedField::State),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut state__ = None;
+                let mut sequence__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = Some(map_.next_value::<position_state::PositionStateEnum>()? as i32);
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionState {
+                    state: state__.unwrap_or_default(),
+                    sequence: sequence__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionState", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for position_state::PositionStateEnum {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "POSITION_STATE_ENUM_UNSPECIFIED",
+            Self::Opened => "POSITION_STATE_ENUM_OPENED",
+            Self::Closed => "POSITION_STATE_ENUM_CLOSED",
+            Self::Withdrawn => "POSITION_STATE_ENUM_WITHDRAWN",
+            Self::Claimed => "POSITION_STATE_ENUM_CLAIMED",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for position_state::PositionStateEnum {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "POSITION_STATE_ENUM_UNSPECIFIED",
+            "POSITION_STATE_ENUM_OPENED",
+            "POSITION_STATE_ENUM_CLOSED",
+            "POSITION_STATE_ENUM_WITHDRAWN",
+            "POSITION_STATE_ENUM_CLAIMED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = position_state::PositionStateEnum;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "POSITION_STATE_ENUM_UNSPECIFIED" => Ok(position_state::PositionStateEnum::Unspecified),
+                    "POSITION_STATE_ENUM_OPENED" => Ok(position_state::PositionStateEnum::Opened),
+                    "POSITION_STATE_ENUM_CLOSED" => Ok(position_state::PositionStateEnum::Closed),
+                    "POSITION_STATE_ENUM_WITHDRAWN" => Ok(position_state::PositionStateEnum::Withdrawn),
+                    "POSITION_STATE_ENUM_CLAIMED" => Ok(position_state::PositionStateEnum::Claimed),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionWithdraw {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.reserves_commitment.is_some() {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionWithdraw", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.reserves_commitment.as_ref() {
+            struct_ser.serialize_field("reservesCommitment", v)?;
+        }
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionWithdraw {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "reserves_commitment",
+            "reservesCommitment",
+            "sequence",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            ReservesCommitment,
+            Sequence,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "reservesCommitment" | "reserves_commitment" => Ok(GeneratedField::ReservesCommitment),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionWithdraw;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionWithdraw")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionWithdraw, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut reserves_commitment__ = None;
+                let mut sequence__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::ReservesCommitment => {
+                            if reserves_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reservesCommitment"));
+                            }
+                            reserves_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionWithdraw {
+                    position_id: position_id__,
+                    reserves_commitment: reserves_commitment__,
+                    sequence: sequence__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionWithdraw", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionWithdrawPlan {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.reserves.is_some() {
+            len += 1;
+        }
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.pair.is_some() {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        if !self.rewards.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionWithdrawPlan", len)?;
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.pair.as_ref() {
+            struct_ser.serialize_field("pair", v)?;
+        }
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        if !self.rewards.is_empty() {
+            struct_ser.serialize_field("rewards", &self.rewards)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reserves",
+            "position_id",
+            "positionId",
+            "pair",
+            "sequence",
+            "rewards",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reserves,
+            PositionId,
+            Pair,
+            Sequence,
+            Rewards,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "pair" => Ok(GeneratedField::Pair),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            "rewards" => Ok(GeneratedField::Rewards),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionWithdrawPlan;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionWithdrawPlan")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionWithdrawPlan, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reserves__ = None;
+                let mut position_id__ = None;
+                let mut pair__ = None;
+                let mut sequence__ = None;
+                let mut rewards__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
+                            }
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Pair => {
+                            if pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pair"));
+                            }
+                            pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionWithdrawPlan {
+                    reserves: reserves__,
+                    position_id: position_id__,
+                    pair: pair__,
+                    sequence: sequence__.unwrap_or_default(),
+                    rewards: rewards__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionWithdrawPlan", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PriceAccumulator {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if !self.last_price.is_empty() {
+            len += 1;
+        }
+        if !self.cumulative_price.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PriceAccumulator", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if !self.last_price.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("lastPrice", pbjson::private::base64::encode(&self.last_price).as_str())?;
+        }
+        if !self.cumulative_price.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("cumulativePrice", pbjson::private::base64::encode(&self.cumulative_price).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PriceAccumulator {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "last_price",
+            "lastPrice",
+            "cumulative_price",
+            "cumulativePrice",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            LastPrice,
+            CumulativePrice,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "lastPrice" | "last_price" => Ok(GeneratedField::LastPrice),
+                            "cumulativePrice" | "cumulative_price" => Ok(GeneratedField::CumulativePrice),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PriceAccumulator;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PriceAccumulator")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PriceAccumulator, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut last_price__ = None;
+                let mut cumulative_price__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LastPrice => {
+                            if last_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastPrice"));
+                            }
+                            last_price__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CumulativePrice => {
+                            if cumulative_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cumulativePrice"));
+                            }
+                            cumulative_price__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PriceAccumulator {
+                    height: height__.unwrap_or_default(),
+                    last_price: last_price__.unwrap_or_default(),
+                    cumulative_price: cumulative_price__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PriceAccumulator", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Reserves {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.r1.is_some() {
+            len += 1;
+        }
+        if self.r2.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Reserves", len)?;
+        if let Some(v) = self.r1.as_ref() {
+            struct_ser.serialize_field("r1", v)?;
+        }
+        if let Some(v) = self.r2.as_ref() {
+            struct_ser.serialize_field("r2", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Reserves {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "r1",
+            "r2",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            R1,
+            R2,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "r1" => Ok(GeneratedField::R1),
+                            "r2" => Ok(GeneratedField::R2),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Reserves;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.Reserves")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Reserves, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut r1__ = None;
+                let mut r2__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::R1 => {
+                            if r1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("r1"));
+                            }
+                            r1__ = map_.next_value()?;
+                        }
+                        GeneratedField::R2 => {
+                            if r2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("r2"));
+                            }
+                            r2__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Reserves {
+                    r1: r1__,
+                    r2: r2__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Reserves", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RouteCandidate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.route.is_empty() {
+            len += 1;
+        }
+        if self.estimated_price != 0. {
+            len += 1;
+        }
+        if self.spill {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.RouteCandidate", len)?;
+        if !self.route.is_empty() {
+            struct_ser.serialize_field("route", &self.route)?;
+        }
+        if self.estimated_price != 0. {
+            struct_ser.serialize_field("estimatedPrice", &self.estimated_price)?;
+        }
+        if self.spill {
+            struct_ser.serialize_field("spill", &self.spill)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RouteCandidate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "route",
+            "estimated_price",
+            "estimatedPrice",
+            "spill",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Route,
+            EstimatedPrice,
+            Spill,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "route" => Ok(GeneratedField::Route),
+                            "estimatedPrice" | "estimated_price" => Ok(GeneratedField::EstimatedPrice),
+                            "spill" => Ok(GeneratedField::Spill),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
             }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RouteCandidate;
 
-            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                i32::try_from(v)
-                    .ok()
-                    .and_then(|x| x.try_into().ok())
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
-                    })
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.RouteCandidate")
             }
 
-            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RouteCandidate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
             {
-                match value {
-                    "POSITION_STATE_ENUM_UNSPECIFIED" => Ok(position_state::PositionStateEnum::Unspecified),
-                    "POSITION_STATE_ENUM_OPENED" => Ok(position_state::PositionStateEnum::Opened),
-                    "POSITION_STATE_ENUM_CLOSED" => Ok(position_state::PositionStateEnum::Closed),
-                    "POSITION_STATE_ENUM_WITHDRAWN" => Ok(position_state::PositionStateEnum::Withdrawn),
-                    "POSITION_STATE_ENUM_CLAIMED" => Ok(position_state::PositionStateEnum::Claimed),
-                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                let mut route__ = None;
+                let mut estimated_price__ = None;
+                let mut spill__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Route => {
+                            if route__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("route"));
+                            }
+                            route__ = map_.next_value()?;
+                        }
+                        GeneratedField::EstimatedPrice => {
+                            if estimated_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("estimatedPrice"));
+                            }
+                            estimated_price__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Spill => {
+                            if spill__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spill"));
+                            }
+                            spill__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
+                Ok(RouteCandidate {
+                    route: route__.unwrap_or_default(),
+                    estimated_price: estimated_price__.unwrap_or_default(),
+                    spill: spill__.unwrap_or_default(),
+                })
             }
         }
-        deserializer.deserialize_any(GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.RouteCandidate", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionWithdraw {
+impl serde::Serialize for RouteExplanation {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5401,48 +7777,73 @@ impl serde::Serialize for PositionWithdraw {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if !self.splits.is_empty() {
             len += 1;
         }
-        if self.reserves_commitment.is_some() {
+        if self.path_searches != 0 {
             len += 1;
         }
-        if self.sequence != 0 {
+        if self.max_path_searches != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionWithdraw", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        if self.executions != 0 {
+            len += 1;
         }
-        if let Some(v) = self.reserves_commitment.as_ref() {
-            struct_ser.serialize_field("reservesCommitment", v)?;
+        if self.max_executions != 0 {
+            len += 1;
         }
-        if self.sequence != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        if !self.stop_reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.RouteExplanation", len)?;
+        if !self.splits.is_empty() {
+            struct_ser.serialize_field("splits", &self.splits)?;
+        }
+        if self.path_searches != 0 {
+            struct_ser.serialize_field("pathSearches", &self.path_searches)?;
+        }
+        if self.max_path_searches != 0 {
+            struct_ser.serialize_field("maxPathSearches", &self.max_path_searches)?;
+        }
+        if self.executions != 0 {
+            struct_ser.serialize_field("executions", &self.executions)?;
+        }
+        if self.max_executions != 0 {
+            struct_ser.serialize_field("maxExecutions", &self.max_executions)?;
+        }
+        if !self.stop_reason.is_empty() {
+            struct_ser.serialize_field("stopReason", &self.stop_reason)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionWithdraw {
+impl<'de> serde::Deserialize<'de> for RouteExplanation {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
-            "reserves_commitment",
-            "reservesCommitment",
-            "sequence",
+            "splits",
+            "path_searches",
+            "pathSearches",
+            "max_path_searches",
+            "maxPathSearches",
+            "executions",
+            "max_executions",
+            "maxExecutions",
+            "stop_reason",
+            "stopReason",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
-            ReservesCommitment,
-            Sequence,
+            Splits,
+            PathSearches,
+            MaxPathSearches,
+            Executions,
+            MaxExecutions,
+            StopReason,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5465,9 +7866,12 @@ impl<'de> serde::Deserialize<'de> for PositionWithdraw {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "reservesCommitment" | "reserves_commitment" => Ok(GeneratedField::ReservesCommitment),
-                            "sequence" => Ok(GeneratedField::Sequence),
+                            "splits" => Ok(GeneratedField::Splits),
+                            "pathSearches" | "path_searches" => Ok(GeneratedField::PathSearches),
+                            "maxPathSearches" | "max_path_searches" => Ok(GeneratedField::MaxPathSearches),
+                            "executions" => Ok(GeneratedField::Executions),
+                            "maxExecutions" | "max_executions" => Ok(GeneratedField::MaxExecutions),
+                            "stopReason" | "stop_reason" => Ok(GeneratedField::StopReason),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5477,57 +7881,87 @@ impl<'de> serde::Deserialize<'de> for PositionWithdraw {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionWithdraw;
+            type Value = RouteExplanation;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionWithdraw")
+                formatter.write_str("struct penumbra.core.component.dex.v1.RouteExplanation")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionWithdraw, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RouteExplanation, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                let mut reserves_commitment__ = None;
-                let mut sequence__ = None;
+                let mut splits__ = None;
+                let mut path_searches__ = None;
+                let mut max_path_searches__ = None;
+                let mut executions__ = None;
+                let mut max_executions__ = None;
+                let mut stop_reason__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::Splits => {
+                            if splits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("splits"));
                             }
-                            position_id__ = map_.next_value()?;
+                            splits__ = map_.next_value()?;
                         }
-                        GeneratedField::ReservesCommitment => {
-                            if reserves_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reservesCommitment"));
+                        GeneratedField::PathSearches => {
+                            if path_searches__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pathSearches"));
                             }
-                            reserves_commitment__ = map_.next_value()?;
+                            path_searches__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Sequence => {
-                            if sequence__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sequence"));
+                        GeneratedField::MaxPathSearches => {
+                            if max_path_searches__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxPathSearches"));
                             }
-                            sequence__ = 
+                            max_path_searches__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Executions => {
+                            if executions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executions"));
+                            }
+                            executions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxExecutions => {
+                            if max_executions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxExecutions"));
+                            }
+                            max_executions__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::StopReason => {
+                            if stop_reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stopReason"));
+                            }
+                            stop_reason__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionWithdraw {
-                    position_id: position_id__,
-                    reserves_commitment: reserves_commitment__,
-                    sequence: sequence__.unwrap_or_default(),
+                Ok(RouteExplanation {
+                    splits: splits__.unwrap_or_default(),
+                    path_searches: path_searches__.unwrap_or_default(),
+                    max_path_searches: max_path_searches__.unwrap_or_default(),
+                    executions: executions__.unwrap_or_default(),
+                    max_executions: max_executions__.unwrap_or_default(),
+                    stop_reason: stop_reason__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionWithdraw", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.RouteExplanation", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionWithdrawPlan {
+impl serde::Serialize for RouteFill {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5535,63 +7969,38 @@ impl serde::Serialize for PositionWithdrawPlan {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.reserves.is_some() {
-            len += 1;
-        }
-        if self.position_id.is_some() {
-            len += 1;
-        }
-        if self.pair.is_some() {
-            len += 1;
-        }
-        if self.sequence != 0 {
+        if !self.position_ids.is_empty() {
             len += 1;
         }
-        if !self.rewards.is_empty() {
+        if self.trace.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionWithdrawPlan", len)?;
-        if let Some(v) = self.reserves.as_ref() {
-            struct_ser.serialize_field("reserves", v)?;
-        }
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
-        if let Some(v) = self.pair.as_ref() {
-            struct_ser.serialize_field("pair", v)?;
-        }
-        if self.sequence != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.RouteFill", len)?;
+        if !self.position_ids.is_empty() {
+            struct_ser.serialize_field("positionIds", &self.position_ids)?;
         }
-        if !self.rewards.is_empty() {
-            struct_ser.serialize_field("rewards", &self.rewards)?;
+        if let Some(v) = self.trace.as_ref() {
+            struct_ser.serialize_field("trace", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
+impl<'de> serde::Deserialize<'de> for RouteFill {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "reserves",
-            "position_id",
-            "positionId",
-            "pair",
-            "sequence",
-            "rewards",
+            "position_ids",
+            "positionIds",
+            "trace",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Reserves,
-            PositionId,
-            Pair,
-            Sequence,
-            Rewards,
+            PositionIds,
+            Trace,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5614,11 +8023,8 @@ impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
                         E: serde::de::Error,
                     {
                         match value {
-                            "reserves" => Ok(GeneratedField::Reserves),
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "pair" => Ok(GeneratedField::Pair),
-                            "sequence" => Ok(GeneratedField::Sequence),
-                            "rewards" => Ok(GeneratedField::Rewards),
+                            "positionIds" | "position_ids" => Ok(GeneratedField::PositionIds),
+                            "trace" => Ok(GeneratedField::Trace),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5628,73 +8034,47 @@ impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionWithdrawPlan;
+            type Value = RouteFill;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionWithdrawPlan")
+                formatter.write_str("struct penumbra.core.component.dex.v1.RouteFill")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionWithdrawPlan, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RouteFill, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut reserves__ = None;
-                let mut position_id__ = None;
-                let mut pair__ = None;
-                let mut sequence__ = None;
-                let mut rewards__ = None;
+                let mut position_ids__ = None;
+                let mut trace__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Reserves => {
-                            if reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves"));
-                            }
-                            reserves__ = map_.next_value()?;
-                        }
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
-                            }
-                            position_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::Pair => {
-                            if pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("pair"));
-                            }
-                            pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Sequence => {
-                            if sequence__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sequence"));
+                        GeneratedField::PositionIds => {
+                            if position_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionIds"));
                             }
-                            sequence__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            position_ids__ = map_.next_value()?;
                         }
-                        GeneratedField::Rewards => {
-                            if rewards__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rewards"));
+                        GeneratedField::Trace => {
+                            if trace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("trace"));
                             }
-                            rewards__ = Some(map_.next_value()?);
+                            trace__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionWithdrawPlan {
-                    reserves: reserves__,
-                    position_id: position_id__,
-                    pair: pair__,
-                    sequence: sequence__.unwrap_or_default(),
-                    rewards: rewards__.unwrap_or_default(),
+                Ok(RouteFill {
+                    position_ids: position_ids__.unwrap_or_default(),
+                    trace: trace__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionWithdrawPlan", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.RouteFill", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Reserves {
+impl serde::Serialize for RouteSplit {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5702,37 +8082,54 @@ impl serde::Serialize for Reserves {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.r1.is_some() {
+        if !self.candidates.is_empty() {
             len += 1;
         }
-        if self.r2.is_some() {
+        if !self.route.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Reserves", len)?;
-        if let Some(v) = self.r1.as_ref() {
-            struct_ser.serialize_field("r1", v)?;
+        if self.spill_price != 0. {
+            len += 1;
         }
-        if let Some(v) = self.r2.as_ref() {
-            struct_ser.serialize_field("r2", v)?;
+        if !self.fills.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.RouteSplit", len)?;
+        if !self.candidates.is_empty() {
+            struct_ser.serialize_field("candidates", &self.candidates)?;
+        }
+        if !self.route.is_empty() {
+            struct_ser.serialize_field("route", &self.route)?;
+        }
+        if self.spill_price != 0. {
+            struct_ser.serialize_field("spillPrice", &self.spill_price)?;
+        }
+        if !self.fills.is_empty() {
+            struct_ser.serialize_field("fills", &self.fills)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Reserves {
+impl<'de> serde::Deserialize<'de> for RouteSplit {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "r1",
-            "r2",
+            "candidates",
+            "route",
+            "spill_price",
+            "spillPrice",
+            "fills",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            R1,
-            R2,
+            Candidates,
+            Route,
+            SpillPrice,
+            Fills,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5755,8 +8152,10 @@ impl<'de> serde::Deserialize<'de> for Reserves {
                         E: serde::de::Error,
                     {
                         match value {
-                            "r1" => Ok(GeneratedField::R1),
-                            "r2" => Ok(GeneratedField::R2),
+                            "candidates" => Ok(GeneratedField::Candidates),
+                            "route" => Ok(GeneratedField::Route),
+                            "spillPrice" | "spill_price" => Ok(GeneratedField::SpillPrice),
+                            "fills" => Ok(GeneratedField::Fills),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5766,44 +8165,62 @@ impl<'de> serde::Deserialize<'de> for Reserves {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Reserves;
+            type Value = RouteSplit;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.Reserves")
+                formatter.write_str("struct penumbra.core.component.dex.v1.RouteSplit")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Reserves, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RouteSplit, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut r1__ = None;
-                let mut r2__ = None;
+                let mut candidates__ = None;
+                let mut route__ = None;
+                let mut spill_price__ = None;
+                let mut fills__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::R1 => {
-                            if r1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("r1"));
+                        GeneratedField::Candidates => {
+                            if candidates__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("candidates"));
                             }
-                            r1__ = map_.next_value()?;
+                            candidates__ = map_.next_value()?;
                         }
-                        GeneratedField::R2 => {
-                            if r2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("r2"));
+                        GeneratedField::Route => {
+                            if route__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("route"));
                             }
-                            r2__ = map_.next_value()?;
+                            route__ = map_.next_value()?;
+                        }
+                        GeneratedField::SpillPrice => {
+                            if spill_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spillPrice"));
+                            }
+                            spill_price__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Fills => {
+                            if fills__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fills"));
+                            }
+                            fills__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Reserves {
-                    r1: r1__,
-                    r2: r2__,
+                Ok(RouteSplit {
+                    candidates: candidates__.unwrap_or_default(),
+                    route: route__.unwrap_or_default(),
+                    spill_price: spill_price__.unwrap_or_default(),
+                    fills: fills__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Reserves", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.RouteSplit", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for SimulateTradeRequest {
@@ -5823,6 +8240,9 @@ impl serde::Serialize for SimulateTradeRequest {
         if self.routing.is_some() {
             len += 1;
         }
+        if self.explain {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest", len)?;
         if let Some(v) = self.input.as_ref() {
             struct_ser.serialize_field("input", v)?;
@@ -5833,6 +8253,9 @@ impl serde::Serialize for SimulateTradeRequest {
         if let Some(v) = self.routing.as_ref() {
             struct_ser.serialize_field("routing", v)?;
         }
+        if self.explain {
+            struct_ser.serialize_field("explain", &self.explain)?;
+        }
         struct_ser.end()
     }
 }
@@ -5846,6 +8269,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
             "input",
             "output",
             "routing",
+            "explain",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -5853,6 +8277,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
             Input,
             Output,
             Routing,
+            Explain,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5878,6 +8303,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                             "input" => Ok(GeneratedField::Input),
                             "output" => Ok(GeneratedField::Output),
                             "routing" => Ok(GeneratedField::Routing),
+                            "explain" => Ok(GeneratedField::Explain),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5900,6 +8326,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                 let mut input__ = None;
                 let mut output__ = None;
                 let mut routing__ = None;
+                let mut explain__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Input => {
@@ -5920,6 +8347,12 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                             }
                             routing__ = map_.next_value()?;
                         }
+                        GeneratedField::Explain => {
+                            if explain__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("explain"));
+                            }
+                            explain__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -5929,6 +8362,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                     input: input__,
                     output: output__,
                     routing: routing__,
+                    explain: explain__.unwrap_or_default(),
                 })
             }
         }
@@ -6207,6 +8641,9 @@ impl serde::Serialize for SimulateTradeResponse {
         if self.unfilled.is_some() {
             len += 1;
         }
+        if self.explanation.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeResponse", len)?;
         if let Some(v) = self.output.as_ref() {
             struct_ser.serialize_field("output", v)?;
@@ -6214,6 +8651,9 @@ impl serde::Serialize for SimulateTradeResponse {
         if let Some(v) = self.unfilled.as_ref() {
             struct_ser.serialize_field("unfilled", v)?;
         }
+        if let Some(v) = self.explanation.as_ref() {
+            struct_ser.serialize_field("explanation", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -6226,12 +8666,14 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
         const FIELDS: &[&str] = &[
             "output",
             "unfilled",
+            "explanation",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Output,
             Unfilled,
+            Explanation,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6256,6 +8698,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
                         match value {
                             "output" => Ok(GeneratedField::Output),
                             "unfilled" => Ok(GeneratedField::Unfilled),
+                            "explanation" => Ok(GeneratedField::Explanation),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6277,6 +8720,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
             {
                 let mut output__ = None;
                 let mut unfilled__ = None;
+                let mut explanation__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Output => {
@@ -6291,6 +8735,12 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
                             }
                             unfilled__ = map_.next_value()?;
                         }
+                        GeneratedField::Explanation => {
+                            if explanation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("explanation"));
+                            }
+                            explanation__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -6299,6 +8749,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
                 Ok(SimulateTradeResponse {
                     output: output__,
                     unfilled: unfilled__,
+                    explanation: explanation__,
                 })
             }
         }
@@ -6690,6 +9141,9 @@ impl serde::Serialize for SwapBody {
         if self.payload.is_some() {
             len += 1;
         }
+        if self.min_output.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapBody", len)?;
         if let Some(v) = self.trading_pair.as_ref() {
             struct_ser.serialize_field("tradingPair", v)?;
@@ -6706,6 +9160,9 @@ impl serde::Serialize for SwapBody {
         if let Some(v) = self.payload.as_ref() {
             struct_ser.serialize_field("payload", v)?;
         }
+        if let Some(v) = self.min_output.as_ref() {
+            struct_ser.serialize_field("minOutput", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -6725,6 +9182,8 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
             "fee_commitment",
             "feeCommitment",
             "payload",
+            "min_output",
+            "minOutput",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -6734,6 +9193,7 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
             Delta2I,
             FeeCommitment,
             Payload,
+            MinOutput,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6761,6 +9221,7 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                             "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
                             "feeCommitment" | "fee_commitment" => Ok(GeneratedField::FeeCommitment),
                             "payload" => Ok(GeneratedField::Payload),
+                            "minOutput" | "min_output" => Ok(GeneratedField::MinOutput),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6785,6 +9246,7 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                 let mut delta_2_i__ = None;
                 let mut fee_commitment__ = None;
                 let mut payload__ = None;
+                let mut min_output__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::TradingPair => {
@@ -6817,6 +9279,12 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                             }
                             payload__ = map_.next_value()?;
                         }
+                        GeneratedField::MinOutput => {
+                            if min_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minOutput"));
+                            }
+                            min_output__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -6828,6 +9296,7 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                     delta_2_i: delta_2_i__,
                     fee_commitment: fee_commitment__,
                     payload: payload__,
+                    min_output: min_output__,
                 })
             }
         }
@@ -8721,6 +11190,9 @@ impl serde::Serialize for SwapPlan {
         if !self.proof_blinding_s.is_empty() {
             len += 1;
         }
+        if self.min_output.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapPlan", len)?;
         if let Some(v) = self.swap_plaintext.as_ref() {
             struct_ser.serialize_field("swapPlaintext", v)?;
@@ -8737,6 +11209,9 @@ impl serde::Serialize for SwapPlan {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
         }
+        if let Some(v) = self.min_output.as_ref() {
+            struct_ser.serialize_field("minOutput", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -8755,6 +11230,8 @@ impl<'de> serde::Deserialize<'de> for SwapPlan {
             "proofBlindingR",
             "proof_blinding_s",
             "proofBlindingS",
+            "min_output",
+            "minOutput",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -8763,6 +11240,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlan {
             FeeBlinding,
             ProofBlindingR,
             ProofBlindingS,
+            MinOutput,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -8789,6 +11267,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlan {
                             "feeBlinding" | "fee_blinding" => Ok(GeneratedField::FeeBlinding),
                             "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
                             "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
+                            "minOutput" | "min_output" => Ok(GeneratedField::MinOutput),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -8812,6 +11291,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlan {
                 let mut fee_blinding__ = None;
                 let mut proof_blinding_r__ = None;
                 let mut proof_blinding_s__ = None;
+                let mut min_output__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::SwapPlaintext => {
@@ -8844,6 +11324,12 @@ impl<'de> serde::Deserialize<'de> for SwapPlan {
                                 Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::MinOutput => {
+                            if min_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minOutput"));
+                            }
+                            min_output__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -8854,6 +11340,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlan {
                     fee_blinding: fee_blinding__.unwrap_or_default(),
                     proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
                     proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
+                    min_output: min_output__,
                 })
             }
         }
@@ -9379,6 +11866,282 @@ impl<'de> serde::Deserialize<'de> for swap_view::Visible {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapView.Visible", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for TimeWeightedAveragePriceRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.pair.is_some() {
+            len += 1;
+        }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.TimeWeightedAveragePriceRequest", len)?;
+        if let Some(v) = self.pair.as_ref() {
+            struct_ser.serialize_field("pair", v)?;
+        }
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimeWeightedAveragePriceRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "pair",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Pair,
+            StartHeight,
+            EndHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "pair" => Ok(GeneratedField::Pair),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimeWeightedAveragePriceRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.TimeWeightedAveragePriceRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimeWeightedAveragePriceRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut pair__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Pair => {
+                            if pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pair"));
+                            }
+                            pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TimeWeightedAveragePriceRequest {
+                    pair: pair__,
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.TimeWeightedAveragePriceRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TimeWeightedAveragePriceResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.price != 0. {
+            len += 1;
+        }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.TimeWeightedAveragePriceResponse", len)?;
+        if self.price != 0. {
+            struct_ser.serialize_field("price", &self.price)?;
+        }
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimeWeightedAveragePriceResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "price",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Price,
+            StartHeight,
+            EndHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "price" => Ok(GeneratedField::Price),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimeWeightedAveragePriceResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.TimeWeightedAveragePriceResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimeWeightedAveragePriceResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut price__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TimeWeightedAveragePriceResponse {
+                    price: price__.unwrap_or_default(),
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.TimeWeightedAveragePriceResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for TradingFunction {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
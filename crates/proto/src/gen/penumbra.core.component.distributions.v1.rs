@@ -5,6 +5,12 @@ pub struct DistributionsParameters {
     /// The amount of staking token issued per block.
     #[prost(uint64, tag = "1")]
     pub staking_issuance_per_block: u64,
+    /// The share of each epoch's staking token issuance set aside for liquidity
+    /// provider rewards, in basis points.
+    ///
+    /// This share is deducted from the issuance used to compute the staking base rate.
+    #[prost(uint64, tag = "2")]
+    pub liquidity_reward_bps: u64,
 }
 impl ::prost::Name for DistributionsParameters {
     const NAME: &'static str = "DistributionsParameters";
@@ -9,11 +9,18 @@ impl serde::Serialize for DistributionsParameters {
         if self.staking_issuance_per_block != 0 {
             len += 1;
         }
+        if self.liquidity_reward_bps != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.distributions.v1.DistributionsParameters", len)?;
         if self.staking_issuance_per_block != 0 {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("stakingIssuancePerBlock", ToString::to_string(&self.staking_issuance_per_block).as_str())?;
         }
+        if self.liquidity_reward_bps != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("liquidityRewardBps", ToString::to_string(&self.liquidity_reward_bps).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -26,11 +33,14 @@ impl<'de> serde::Deserialize<'de> for DistributionsParameters {
         const FIELDS: &[&str] = &[
             "staking_issuance_per_block",
             "stakingIssuancePerBlock",
+            "liquidity_reward_bps",
+            "liquidityRewardBps",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             StakingIssuancePerBlock,
+            LiquidityRewardBps,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -54,6 +64,7 @@ impl<'de> serde::Deserialize<'de> for DistributionsParameters {
                     {
                         match value {
                             "stakingIssuancePerBlock" | "staking_issuance_per_block" => Ok(GeneratedField::StakingIssuancePerBlock),
+                            "liquidityRewardBps" | "liquidity_reward_bps" => Ok(GeneratedField::LiquidityRewardBps),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -74,6 +85,7 @@ impl<'de> serde::Deserialize<'de> for DistributionsParameters {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut staking_issuance_per_block__ = None;
+                let mut liquidity_reward_bps__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::StakingIssuancePerBlock => {
@@ -84,6 +96,14 @@ impl<'de> serde::Deserialize<'de> for DistributionsParameters {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::LiquidityRewardBps => {
+                            if liquidity_reward_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("liquidityRewardBps"));
+                            }
+                            liquidity_reward_bps__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -91,6 +111,7 @@ impl<'de> serde::Deserialize<'de> for DistributionsParameters {
                 }
                 Ok(DistributionsParameters {
                     staking_issuance_per_block: staking_issuance_per_block__.unwrap_or_default(),
+                    liquidity_reward_bps: liquidity_reward_bps__.unwrap_or_default(),
                 })
             }
         }
@@ -1,7 +1,20 @@
 /// Funding component configuration data.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct FundingParameters {}
+pub struct FundingParameters {
+    /// The trading pairs whose liquidity providers are rewarded, each of which must
+    /// include the staking token.
+    ///
+    /// The liquidity reward issuance set aside by the distributions component is
+    /// split evenly between the pairs, and the rewards for a pair are split between
+    /// its positions in proportion to their executed volume, each capped by the
+    /// position's average reserves of the staking token over the epoch, and up to
+    /// the fees each position earned.
+    #[prost(message, repeated, tag = "1")]
+    pub liquidity_reward_pairs: ::prost::alloc::vec::Vec<
+        super::super::dex::v1::TradingPair,
+    >,
+}
 impl ::prost::Name for FundingParameters {
     const NAME: &'static str = "FundingParameters";
     const PACKAGE: &'static str = "penumbra.core.component.funding.v1";
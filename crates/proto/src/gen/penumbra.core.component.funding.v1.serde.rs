@@ -5,8 +5,14 @@ impl serde::Serialize for FundingParameters {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.core.component.funding.v1.FundingParameters", len)?;
+        let mut len = 0;
+        if !self.liquidity_reward_pairs.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.funding.v1.FundingParameters", len)?;
+        if !self.liquidity_reward_pairs.is_empty() {
+            struct_ser.serialize_field("liquidityRewardPairs", &self.liquidity_reward_pairs)?;
+        }
         struct_ser.end()
     }
 }
@@ -17,10 +23,13 @@ impl<'de> serde::Deserialize<'de> for FundingParameters {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "liquidity_reward_pairs",
+            "liquidityRewardPairs",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            LiquidityRewardPairs,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -42,7 +51,10 @@ impl<'de> serde::Deserialize<'de> for FundingParameters {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "liquidityRewardPairs" | "liquidity_reward_pairs" => Ok(GeneratedField::LiquidityRewardPairs),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -60,10 +72,22 @@ impl<'de> serde::Deserialize<'de> for FundingParameters {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut liquidity_reward_pairs__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::LiquidityRewardPairs => {
+                            if liquidity_reward_pairs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("liquidityRewardPairs"));
+                            }
+                            liquidity_reward_pairs__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
                 Ok(FundingParameters {
+                    liquidity_reward_pairs: liquidity_reward_pairs__.unwrap_or_default(),
                 })
             }
         }
@@ -34,6 +34,9 @@ impl ::prost::Name for Signature {
 pub struct CoordinatorRound1 {
     #[prost(oneof = "coordinator_round1::Request", tags = "1, 2, 3")]
     pub request: ::core::option::Option<coordinator_round1::Request>,
+    /// The key epoch of the shares the coordinator wants to sign with.
+    #[prost(uint64, tag = "4")]
+    pub key_epoch: u64,
 }
 /// Nested message and enum types in `CoordinatorRound1`.
 pub mod coordinator_round1 {
@@ -148,6 +151,9 @@ pub mod follower_round1 {
         pub commitments: ::prost::alloc::vec::Vec<
             super::super::super::super::crypto::decaf377_frost::v1::SigningCommitments,
         >,
+        /// The key epoch of the share the follower signs with.
+        #[prost(uint64, tag = "2")]
+        pub key_epoch: u64,
     }
     impl ::prost::Name for Inner {
         const NAME: &'static str = "Inner";
@@ -190,6 +196,9 @@ pub mod follower_round2 {
         pub shares: ::prost::alloc::vec::Vec<
             super::super::super::super::crypto::decaf377_frost::v1::SignatureShare,
         >,
+        /// The key epoch of the share the follower signs with.
+        #[prost(uint64, tag = "2")]
+        pub key_epoch: u64,
     }
     impl ::prost::Name for Inner {
         const NAME: &'static str = "Inner";
@@ -297,3 +306,559 @@ impl ::prost::Name for DkgRound2 {
         ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
     }
 }
+/// The message sent by each participant in round 1 of the resharing protocol.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReshareRound1 {
+    /// The sender's verification key in the old configuration, if they're dealing a share.
+    #[prost(bytes = "vec", tag = "1")]
+    pub dealer_vk: ::prost::alloc::vec::Vec<u8>,
+    /// The sender's verification key in the new configuration, if they'll hold a share.
+    #[prost(bytes = "vec", tag = "2")]
+    pub member_vk: ::prost::alloc::vec::Vec<u8>,
+    /// An encryption key to receive the sender's new share, if they'll hold one.
+    #[prost(bytes = "vec", tag = "3")]
+    pub epk: ::prost::alloc::vec::Vec<u8>,
+    /// A signature by the dealer key, over the proto-encoded message with both signatures empty.
+    #[prost(bytes = "vec", tag = "4")]
+    pub dealer_sig: ::prost::alloc::vec::Vec<u8>,
+    /// A signature by the member key, over the proto-encoded message with both signatures empty.
+    #[prost(bytes = "vec", tag = "5")]
+    pub member_sig: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for ReshareRound1 {
+    const NAME: &'static str = "ReshareRound1";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+/// A share of a dealer's polynomial, encrypted to one of the new participants.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReshareTargetedShare {
+    /// A verification key identifying the recipient.
+    #[prost(bytes = "vec", tag = "1")]
+    pub vk: ::prost::alloc::vec::Vec<u8>,
+    /// The ciphertext of the recipient's share.
+    #[prost(bytes = "vec", tag = "2")]
+    pub encrypted_share: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for ReshareTargetedShare {
+    const NAME: &'static str = "ReshareTargetedShare";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+/// The message sent by each participant in round 2 of the resharing protocol.
+///
+/// Participants who aren't dealing a share send this message with only their key and
+/// signature, to acknowledge the first round.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReshareRound2 {
+    /// The sender's verification key: their old key if they're dealing a share, and their new key otherwise.
+    #[prost(bytes = "vec", tag = "1")]
+    pub vk: ::prost::alloc::vec::Vec<u8>,
+    /// Commitments to the coefficients of the dealer's polynomial.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub commitments: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The dealer's shares for each new participant.
+    #[prost(message, repeated, tag = "3")]
+    pub encrypted_shares: ::prost::alloc::vec::Vec<ReshareTargetedShare>,
+    /// The full viewing key being reshared.
+    #[prost(string, tag = "4")]
+    pub fvk: ::prost::alloc::string::String,
+    /// A signature over the proto-encoded message with this field empty.
+    #[prost(bytes = "vec", tag = "5")]
+    pub sig: ::prost::alloc::vec::Vec<u8>,
+    /// The key epoch of the share being dealt, if the sender is dealing.
+    #[prost(uint64, tag = "6")]
+    pub key_epoch: u64,
+}
+impl ::prost::Name for ReshareRound2 {
+    const NAME: &'static str = "ReshareRound2";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+/// A protocol message relayed between the participants of a session.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SessionMessage {
+    /// The name of the session this message belongs to.
+    #[prost(string, tag = "1")]
+    pub session: ::prost::alloc::string::String,
+    /// The verification key identifying the sender.
+    #[prost(message, optional, tag = "2")]
+    pub sender: ::core::option::Option<VerificationKey>,
+    /// The verification key identifying the recipient, or absent if the message is for everyone.
+    #[prost(message, optional, tag = "3")]
+    pub recipient: ::core::option::Option<VerificationKey>,
+    /// The number of messages the sender has published to this session, including this one.
+    #[prost(uint64, tag = "4")]
+    pub round: u64,
+    /// The protocol message itself, encoded as JSON.
+    #[prost(string, tag = "5")]
+    pub payload: ::prost::alloc::string::String,
+    /// A signature by the sender over the proto-encoded message, with this field absent.
+    #[prost(message, optional, tag = "6")]
+    pub sig: ::core::option::Option<Signature>,
+}
+impl ::prost::Name for SessionMessage {
+    const NAME: &'static str = "SessionMessage";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PublishRequest {
+    #[prost(message, optional, tag = "1")]
+    pub message: ::core::option::Option<SessionMessage>,
+}
+impl ::prost::Name for PublishRequest {
+    const NAME: &'static str = "PublishRequest";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PublishResponse {}
+impl ::prost::Name for PublishResponse {
+    const NAME: &'static str = "PublishResponse";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeRequest {
+    /// The name of the session to subscribe to.
+    #[prost(string, tag = "1")]
+    pub session: ::prost::alloc::string::String,
+    /// The verification key of the subscribing participant.
+    ///
+    /// Messages sent by this participant, or addressed to other participants, are not streamed.
+    #[prost(message, optional, tag = "2")]
+    pub participant: ::core::option::Option<VerificationKey>,
+}
+impl ::prost::Name for SubscribeRequest {
+    const NAME: &'static str = "SubscribeRequest";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeResponse {
+    #[prost(message, optional, tag = "1")]
+    pub message: ::core::option::Option<SessionMessage>,
+}
+impl ::prost::Name for SubscribeResponse {
+    const NAME: &'static str = "SubscribeResponse";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "rpc")]
+pub mod coordinator_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Relays the messages of the threshold signing and DKG protocols between participants.
+    ///
+    /// Each run of a protocol takes place in a named session. Participants publish signed
+    /// messages to the session, and subscribe to the messages other participants publish.
+    #[derive(Debug, Clone)]
+    pub struct CoordinatorServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl CoordinatorServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> CoordinatorServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> CoordinatorServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            CoordinatorServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Publishes a message to the participants of a session.
+        pub async fn publish(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PublishRequest>,
+        ) -> std::result::Result<tonic::Response<super::PublishResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.threshold.v1.CoordinatorService/Publish",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.custody.threshold.v1.CoordinatorService",
+                        "Publish",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Streams the messages published to a session, starting from the first one.
+        pub async fn subscribe(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::SubscribeResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.threshold.v1.CoordinatorService/Subscribe",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.custody.threshold.v1.CoordinatorService",
+                        "Subscribe",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "rpc")]
+pub mod coordinator_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with CoordinatorServiceServer.
+    #[async_trait]
+    pub trait CoordinatorService: Send + Sync + 'static {
+        /// Publishes a message to the participants of a session.
+        async fn publish(
+            &self,
+            request: tonic::Request<super::PublishRequest>,
+        ) -> std::result::Result<tonic::Response<super::PublishResponse>, tonic::Status>;
+        /// Server streaming response type for the Subscribe method.
+        type SubscribeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::SubscribeResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Streams the messages published to a session, starting from the first one.
+        async fn subscribe(
+            &self,
+            request: tonic::Request<super::SubscribeRequest>,
+        ) -> std::result::Result<tonic::Response<Self::SubscribeStream>, tonic::Status>;
+    }
+    /// Relays the messages of the threshold signing and DKG protocols between participants.
+    ///
+    /// Each run of a protocol takes place in a named session. Participants publish signed
+    /// messages to the session, and subscribe to the messages other participants publish.
+    #[derive(Debug)]
+    pub struct CoordinatorServiceServer<T: CoordinatorService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: CoordinatorService> CoordinatorServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for CoordinatorServiceServer<T>
+    where
+        T: CoordinatorService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/penumbra.custody.threshold.v1.CoordinatorService/Publish" => {
+                    #[allow(non_camel_case_types)]
+                    struct PublishSvc<T: CoordinatorService>(pub Arc<T>);
+                    impl<
+                        T: CoordinatorService,
+                    > tonic::server::UnaryService<super::PublishRequest>
+                    for PublishSvc<T> {
+                        type Response = super::PublishResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PublishRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoordinatorService>::publish(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PublishSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.custody.threshold.v1.CoordinatorService/Subscribe" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeSvc<T: CoordinatorService>(pub Arc<T>);
+                    impl<
+                        T: CoordinatorService,
+                    > tonic::server::ServerStreamingService<super::SubscribeRequest>
+                    for SubscribeSvc<T> {
+                        type Response = super::SubscribeResponse;
+                        type ResponseStream = T::SubscribeStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubscribeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoordinatorService>::subscribe(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SubscribeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: CoordinatorService> Clone for CoordinatorServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: CoordinatorService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: CoordinatorService> tonic::server::NamedService for CoordinatorServiceServer<T> {
+        const NAME: &'static str = "penumbra.custody.threshold.v1.CoordinatorService";
+    }
+}
@@ -9,6 +9,9 @@ impl serde::Serialize for CoordinatorRound1 {
         if self.request.is_some() {
             len += 1;
         }
+        if self.key_epoch != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.custody.threshold.v1.CoordinatorRound1", len)?;
         if let Some(v) = self.request.as_ref() {
             match v {
@@ -23,6 +26,10 @@ impl serde::Serialize for CoordinatorRound1 {
                 }
             }
         }
+        if self.key_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("keyEpoch", ToString::to_string(&self.key_epoch).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -38,6 +45,8 @@ impl<'de> serde::Deserialize<'de> for CoordinatorRound1 {
             "validatorDefinition",
             "validator_vote",
             "validatorVote",
+            "key_epoch",
+            "keyEpoch",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -45,6 +54,7 @@ impl<'de> serde::Deserialize<'de> for CoordinatorRound1 {
             Plan,
             ValidatorDefinition,
             ValidatorVote,
+            KeyEpoch,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -70,6 +80,7 @@ impl<'de> serde::Deserialize<'de> for CoordinatorRound1 {
                             "plan" => Ok(GeneratedField::Plan),
                             "validatorDefinition" | "validator_definition" => Ok(GeneratedField::ValidatorDefinition),
                             "validatorVote" | "validator_vote" => Ok(GeneratedField::ValidatorVote),
+                            "keyEpoch" | "key_epoch" => Ok(GeneratedField::KeyEpoch),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -90,6 +101,7 @@ impl<'de> serde::Deserialize<'de> for CoordinatorRound1 {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut request__ = None;
+                let mut key_epoch__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Plan => {
@@ -113,6 +125,14 @@ impl<'de> serde::Deserialize<'de> for CoordinatorRound1 {
                             request__ = map_.next_value::<::std::option::Option<_>>()?.map(coordinator_round1::Request::ValidatorVote)
 ;
                         }
+                        GeneratedField::KeyEpoch => {
+                            if key_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("keyEpoch"));
+                            }
+                            key_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -120,6 +140,7 @@ impl<'de> serde::Deserialize<'de> for CoordinatorRound1 {
                 }
                 Ok(CoordinatorRound1 {
                     request: request__,
+                    key_epoch: key_epoch__.unwrap_or_default(),
                 })
             }
         }
@@ -1099,10 +1120,17 @@ impl serde::Serialize for follower_round1::Inner {
         if !self.commitments.is_empty() {
             len += 1;
         }
+        if self.key_epoch != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.custody.threshold.v1.FollowerRound1.Inner", len)?;
         if !self.commitments.is_empty() {
             struct_ser.serialize_field("commitments", &self.commitments)?;
         }
+        if self.key_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("keyEpoch", ToString::to_string(&self.key_epoch).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -1114,11 +1142,14 @@ impl<'de> serde::Deserialize<'de> for follower_round1::Inner {
     {
         const FIELDS: &[&str] = &[
             "commitments",
+            "key_epoch",
+            "keyEpoch",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Commitments,
+            KeyEpoch,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1142,6 +1173,7 @@ impl<'de> serde::Deserialize<'de> for follower_round1::Inner {
                     {
                         match value {
                             "commitments" => Ok(GeneratedField::Commitments),
+                            "keyEpoch" | "key_epoch" => Ok(GeneratedField::KeyEpoch),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1162,6 +1194,7 @@ impl<'de> serde::Deserialize<'de> for follower_round1::Inner {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut commitments__ = None;
+                let mut key_epoch__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Commitments => {
@@ -1170,6 +1203,14 @@ impl<'de> serde::Deserialize<'de> for follower_round1::Inner {
                             }
                             commitments__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::KeyEpoch => {
+                            if key_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("keyEpoch"));
+                            }
+                            key_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1177,6 +1218,7 @@ impl<'de> serde::Deserialize<'de> for follower_round1::Inner {
                 }
                 Ok(follower_round1::Inner {
                     commitments: commitments__.unwrap_or_default(),
+                    key_epoch: key_epoch__.unwrap_or_default(),
                 })
             }
         }
@@ -1323,10 +1365,17 @@ impl serde::Serialize for follower_round2::Inner {
         if !self.shares.is_empty() {
             len += 1;
         }
+        if self.key_epoch != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.custody.threshold.v1.FollowerRound2.Inner", len)?;
         if !self.shares.is_empty() {
             struct_ser.serialize_field("shares", &self.shares)?;
         }
+        if self.key_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("keyEpoch", ToString::to_string(&self.key_epoch).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -1338,11 +1387,14 @@ impl<'de> serde::Deserialize<'de> for follower_round2::Inner {
     {
         const FIELDS: &[&str] = &[
             "shares",
+            "key_epoch",
+            "keyEpoch",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Shares,
+            KeyEpoch,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1366,6 +1418,7 @@ impl<'de> serde::Deserialize<'de> for follower_round2::Inner {
                     {
                         match value {
                             "shares" => Ok(GeneratedField::Shares),
+                            "keyEpoch" | "key_epoch" => Ok(GeneratedField::KeyEpoch),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1386,6 +1439,7 @@ impl<'de> serde::Deserialize<'de> for follower_round2::Inner {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut shares__ = None;
+                let mut key_epoch__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Shares => {
@@ -1394,6 +1448,14 @@ impl<'de> serde::Deserialize<'de> for follower_round2::Inner {
                             }
                             shares__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::KeyEpoch => {
+                            if key_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("keyEpoch"));
+                            }
+                            key_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1401,13 +1463,14 @@ impl<'de> serde::Deserialize<'de> for follower_round2::Inner {
                 }
                 Ok(follower_round2::Inner {
                     shares: shares__.unwrap_or_default(),
+                    key_epoch: key_epoch__.unwrap_or_default(),
                 })
             }
         }
         deserializer.deserialize_struct("penumbra.custody.threshold.v1.FollowerRound2.Inner", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Signature {
+impl serde::Serialize for PublishRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1415,30 +1478,237 @@ impl serde::Serialize for Signature {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.inner.is_empty() {
+        if self.message.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.custody.threshold.v1.Signature", len)?;
-        if !self.inner.is_empty() {
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.threshold.v1.PublishRequest", len)?;
+        if let Some(v) = self.message.as_ref() {
+            struct_ser.serialize_field("message", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PublishRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "message",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Message,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "message" => Ok(GeneratedField::Message),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PublishRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.threshold.v1.PublishRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PublishRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut message__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Message => {
+                            if message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("message"));
+                            }
+                            message__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PublishRequest {
+                    message: message__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.threshold.v1.PublishRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PublishResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.custody.threshold.v1.PublishResponse", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PublishResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PublishResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.threshold.v1.PublishResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PublishResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(PublishResponse {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.threshold.v1.PublishResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReshareRound1 {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.dealer_vk.is_empty() {
+            len += 1;
+        }
+        if !self.member_vk.is_empty() {
+            len += 1;
+        }
+        if !self.epk.is_empty() {
+            len += 1;
+        }
+        if !self.dealer_sig.is_empty() {
+            len += 1;
+        }
+        if !self.member_sig.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.threshold.v1.ReshareRound1", len)?;
+        if !self.dealer_vk.is_empty() {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
+            struct_ser.serialize_field("dealerVk", pbjson::private::base64::encode(&self.dealer_vk).as_str())?;
+        }
+        if !self.member_vk.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("memberVk", pbjson::private::base64::encode(&self.member_vk).as_str())?;
+        }
+        if !self.epk.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epk", pbjson::private::base64::encode(&self.epk).as_str())?;
+        }
+        if !self.dealer_sig.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("dealerSig", pbjson::private::base64::encode(&self.dealer_sig).as_str())?;
+        }
+        if !self.member_sig.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("memberSig", pbjson::private::base64::encode(&self.member_sig).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Signature {
+impl<'de> serde::Deserialize<'de> for ReshareRound1 {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "inner",
+            "dealer_vk",
+            "dealerVk",
+            "member_vk",
+            "memberVk",
+            "epk",
+            "dealer_sig",
+            "dealerSig",
+            "member_sig",
+            "memberSig",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Inner,
+            DealerVk,
+            MemberVk,
+            Epk,
+            DealerSig,
+            MemberSig,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1461,7 +1731,11 @@ impl<'de> serde::Deserialize<'de> for Signature {
                         E: serde::de::Error,
                     {
                         match value {
-                            "inner" => Ok(GeneratedField::Inner),
+                            "dealerVk" | "dealer_vk" => Ok(GeneratedField::DealerVk),
+                            "memberVk" | "member_vk" => Ok(GeneratedField::MemberVk),
+                            "epk" => Ok(GeneratedField::Epk),
+                            "dealerSig" | "dealer_sig" => Ok(GeneratedField::DealerSig),
+                            "memberSig" | "member_sig" => Ok(GeneratedField::MemberSig),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1471,24 +1745,60 @@ impl<'de> serde::Deserialize<'de> for Signature {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Signature;
+            type Value = ReshareRound1;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.custody.threshold.v1.Signature")
+                formatter.write_str("struct penumbra.custody.threshold.v1.ReshareRound1")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Signature, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ReshareRound1, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut inner__ = None;
+                let mut dealer_vk__ = None;
+                let mut member_vk__ = None;
+                let mut epk__ = None;
+                let mut dealer_sig__ = None;
+                let mut member_sig__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Inner => {
-                            if inner__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("inner"));
+                        GeneratedField::DealerVk => {
+                            if dealer_vk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dealerVk"));
                             }
-                            inner__ = 
+                            dealer_vk__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MemberVk => {
+                            if member_vk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memberVk"));
+                            }
+                            member_vk__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Epk => {
+                            if epk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epk"));
+                            }
+                            epk__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DealerSig => {
+                            if dealer_sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dealerSig"));
+                            }
+                            dealer_sig__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MemberSig => {
+                            if member_sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memberSig"));
+                            }
+                            member_sig__ = 
                                 Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
@@ -1497,12 +1807,817 @@ impl<'de> serde::Deserialize<'de> for Signature {
                         }
                     }
                 }
-                Ok(Signature {
-                    inner: inner__.unwrap_or_default(),
+                Ok(ReshareRound1 {
+                    dealer_vk: dealer_vk__.unwrap_or_default(),
+                    member_vk: member_vk__.unwrap_or_default(),
+                    epk: epk__.unwrap_or_default(),
+                    dealer_sig: dealer_sig__.unwrap_or_default(),
+                    member_sig: member_sig__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.custody.threshold.v1.Signature", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.custody.threshold.v1.ReshareRound1", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReshareRound2 {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.vk.is_empty() {
+            len += 1;
+        }
+        if !self.commitments.is_empty() {
+            len += 1;
+        }
+        if !self.encrypted_shares.is_empty() {
+            len += 1;
+        }
+        if !self.fvk.is_empty() {
+            len += 1;
+        }
+        if !self.sig.is_empty() {
+            len += 1;
+        }
+        if self.key_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.threshold.v1.ReshareRound2", len)?;
+        if !self.vk.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("vk", pbjson::private::base64::encode(&self.vk).as_str())?;
+        }
+        if !self.commitments.is_empty() {
+            struct_ser.serialize_field("commitments", &self.commitments.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+        }
+        if !self.encrypted_shares.is_empty() {
+            struct_ser.serialize_field("encryptedShares", &self.encrypted_shares)?;
+        }
+        if !self.fvk.is_empty() {
+            struct_ser.serialize_field("fvk", &self.fvk)?;
+        }
+        if !self.sig.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sig", pbjson::private::base64::encode(&self.sig).as_str())?;
+        }
+        if self.key_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("keyEpoch", ToString::to_string(&self.key_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReshareRound2 {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vk",
+            "commitments",
+            "encrypted_shares",
+            "encryptedShares",
+            "fvk",
+            "sig",
+            "key_epoch",
+            "keyEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Vk,
+            Commitments,
+            EncryptedShares,
+            Fvk,
+            Sig,
+            KeyEpoch,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vk" => Ok(GeneratedField::Vk),
+                            "commitments" => Ok(GeneratedField::Commitments),
+                            "encryptedShares" | "encrypted_shares" => Ok(GeneratedField::EncryptedShares),
+                            "fvk" => Ok(GeneratedField::Fvk),
+                            "sig" => Ok(GeneratedField::Sig),
+                            "keyEpoch" | "key_epoch" => Ok(GeneratedField::KeyEpoch),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReshareRound2;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.threshold.v1.ReshareRound2")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ReshareRound2, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vk__ = None;
+                let mut commitments__ = None;
+                let mut encrypted_shares__ = None;
+                let mut fvk__ = None;
+                let mut sig__ = None;
+                let mut key_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Vk => {
+                            if vk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vk"));
+                            }
+                            vk__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Commitments => {
+                            if commitments__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commitments"));
+                            }
+                            commitments__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::EncryptedShares => {
+                            if encrypted_shares__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("encryptedShares"));
+                            }
+                            encrypted_shares__ = map_.next_value()?;
+                        }
+                        GeneratedField::Fvk => {
+                            if fvk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fvk"));
+                            }
+                            fvk__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Sig => {
+                            if sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sig"));
+                            }
+                            sig__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::KeyEpoch => {
+                            if key_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("keyEpoch"));
+                            }
+                            key_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ReshareRound2 {
+                    vk: vk__.unwrap_or_default(),
+                    commitments: commitments__.unwrap_or_default(),
+                    encrypted_shares: encrypted_shares__.unwrap_or_default(),
+                    fvk: fvk__.unwrap_or_default(),
+                    sig: sig__.unwrap_or_default(),
+                    key_epoch: key_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.threshold.v1.ReshareRound2", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReshareTargetedShare {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.vk.is_empty() {
+            len += 1;
+        }
+        if !self.encrypted_share.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.threshold.v1.ReshareTargetedShare", len)?;
+        if !self.vk.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("vk", pbjson::private::base64::encode(&self.vk).as_str())?;
+        }
+        if !self.encrypted_share.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("encryptedShare", pbjson::private::base64::encode(&self.encrypted_share).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReshareTargetedShare {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vk",
+            "encrypted_share",
+            "encryptedShare",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Vk,
+            EncryptedShare,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vk" => Ok(GeneratedField::Vk),
+                            "encryptedShare" | "encrypted_share" => Ok(GeneratedField::EncryptedShare),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReshareTargetedShare;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.threshold.v1.ReshareTargetedShare")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ReshareTargetedShare, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vk__ = None;
+                let mut encrypted_share__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Vk => {
+                            if vk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vk"));
+                            }
+                            vk__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EncryptedShare => {
+                            if encrypted_share__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("encryptedShare"));
+                            }
+                            encrypted_share__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ReshareTargetedShare {
+                    vk: vk__.unwrap_or_default(),
+                    encrypted_share: encrypted_share__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.threshold.v1.ReshareTargetedShare", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SessionMessage {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.session.is_empty() {
+            len += 1;
+        }
+        if self.sender.is_some() {
+            len += 1;
+        }
+        if self.recipient.is_some() {
+            len += 1;
+        }
+        if self.round != 0 {
+            len += 1;
+        }
+        if !self.payload.is_empty() {
+            len += 1;
+        }
+        if self.sig.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.threshold.v1.SessionMessage", len)?;
+        if !self.session.is_empty() {
+            struct_ser.serialize_field("session", &self.session)?;
+        }
+        if let Some(v) = self.sender.as_ref() {
+            struct_ser.serialize_field("sender", v)?;
+        }
+        if let Some(v) = self.recipient.as_ref() {
+            struct_ser.serialize_field("recipient", v)?;
+        }
+        if self.round != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("round", ToString::to_string(&self.round).as_str())?;
+        }
+        if !self.payload.is_empty() {
+            struct_ser.serialize_field("payload", &self.payload)?;
+        }
+        if let Some(v) = self.sig.as_ref() {
+            struct_ser.serialize_field("sig", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SessionMessage {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "session",
+            "sender",
+            "recipient",
+            "round",
+            "payload",
+            "sig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Session,
+            Sender,
+            Recipient,
+            Round,
+            Payload,
+            Sig,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "session" => Ok(GeneratedField::Session),
+                            "sender" => Ok(GeneratedField::Sender),
+                            "recipient" => Ok(GeneratedField::Recipient),
+                            "round" => Ok(GeneratedField::Round),
+                            "payload" => Ok(GeneratedField::Payload),
+                            "sig" => Ok(GeneratedField::Sig),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SessionMessage;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.threshold.v1.SessionMessage")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SessionMessage, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut session__ = None;
+                let mut sender__ = None;
+                let mut recipient__ = None;
+                let mut round__ = None;
+                let mut payload__ = None;
+                let mut sig__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Session => {
+                            if session__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("session"));
+                            }
+                            session__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Sender => {
+                            if sender__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sender"));
+                            }
+                            sender__ = map_.next_value()?;
+                        }
+                        GeneratedField::Recipient => {
+                            if recipient__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recipient"));
+                            }
+                            recipient__ = map_.next_value()?;
+                        }
+                        GeneratedField::Round => {
+                            if round__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("round"));
+                            }
+                            round__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Payload => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("payload"));
+                            }
+                            payload__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Sig => {
+                            if sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sig"));
+                            }
+                            sig__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SessionMessage {
+                    session: session__.unwrap_or_default(),
+                    sender: sender__,
+                    recipient: recipient__,
+                    round: round__.unwrap_or_default(),
+                    payload: payload__.unwrap_or_default(),
+                    sig: sig__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.threshold.v1.SessionMessage", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Signature {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.inner.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.threshold.v1.Signature", len)?;
+        if !self.inner.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Signature {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "inner",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Inner,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "inner" => Ok(GeneratedField::Inner),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Signature;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.threshold.v1.Signature")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Signature, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut inner__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Inner => {
+                            if inner__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inner"));
+                            }
+                            inner__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Signature {
+                    inner: inner__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.threshold.v1.Signature", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SubscribeRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.session.is_empty() {
+            len += 1;
+        }
+        if self.participant.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.threshold.v1.SubscribeRequest", len)?;
+        if !self.session.is_empty() {
+            struct_ser.serialize_field("session", &self.session)?;
+        }
+        if let Some(v) = self.participant.as_ref() {
+            struct_ser.serialize_field("participant", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubscribeRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "session",
+            "participant",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Session,
+            Participant,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "session" => Ok(GeneratedField::Session),
+                            "participant" => Ok(GeneratedField::Participant),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubscribeRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.threshold.v1.SubscribeRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubscribeRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut session__ = None;
+                let mut participant__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Session => {
+                            if session__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("session"));
+                            }
+                            session__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Participant => {
+                            if participant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("participant"));
+                            }
+                            participant__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SubscribeRequest {
+                    session: session__.unwrap_or_default(),
+                    participant: participant__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.threshold.v1.SubscribeRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SubscribeResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.message.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.threshold.v1.SubscribeResponse", len)?;
+        if let Some(v) = self.message.as_ref() {
+            struct_ser.serialize_field("message", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubscribeResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "message",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Message,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "message" => Ok(GeneratedField::Message),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubscribeResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.threshold.v1.SubscribeResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubscribeResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut message__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Message => {
+                            if message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("message"));
+                            }
+                            message__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SubscribeResponse {
+                    message: message__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.threshold.v1.SubscribeResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for VerificationKey {
@@ -124,7 +124,7 @@ impl ::prost::Name for AuthorizeValidatorVoteResponse {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PreAuthorization {
-    #[prost(oneof = "pre_authorization::PreAuthorization", tags = "1")]
+    #[prost(oneof = "pre_authorization::PreAuthorization", tags = "1, 2, 3")]
     pub pre_authorization: ::core::option::Option<pre_authorization::PreAuthorization>,
 }
 /// Nested message and enum types in `PreAuthorization`.
@@ -150,11 +150,57 @@ pub mod pre_authorization {
             )
         }
     }
+    /// An ECDSA-based preauthorization over the NIST P-256 curve, using SHA-256,
+    /// containing a signature over the `TransactionPlan`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct EcdsaP256 {
+        /// The SEC1-encoded P-256 verification key used to verify the signature.
+        #[prost(bytes = "vec", tag = "1")]
+        pub vk: ::prost::alloc::vec::Vec<u8>,
+        /// The ASN.1 DER-encoded ECDSA signature over the `TransactionPlan`.
+        #[prost(bytes = "vec", tag = "2")]
+        pub sig: ::prost::alloc::vec::Vec<u8>,
+    }
+    impl ::prost::Name for EcdsaP256 {
+        const NAME: &'static str = "EcdsaP256";
+        const PACKAGE: &'static str = "penumbra.custody.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.v1.PreAuthorization.{}", Self::NAME
+            )
+        }
+    }
+    /// A decaf377-rdsa-based preauthorization, using the spend authorization domain,
+    /// containing a signature over the `TransactionPlan`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DecafRdsa {
+        /// The decaf377-rdsa verification key used to verify the signature.
+        #[prost(bytes = "vec", tag = "1")]
+        pub vk: ::prost::alloc::vec::Vec<u8>,
+        /// The decaf377-rdsa signature over the `TransactionPlan`.
+        #[prost(bytes = "vec", tag = "2")]
+        pub sig: ::prost::alloc::vec::Vec<u8>,
+    }
+    impl ::prost::Name for DecafRdsa {
+        const NAME: &'static str = "DecafRdsa";
+        const PACKAGE: &'static str = "penumbra.custody.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.v1.PreAuthorization.{}", Self::NAME
+            )
+        }
+    }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum PreAuthorization {
         #[prost(message, tag = "1")]
         Ed25519(Ed25519),
+        #[prost(message, tag = "2")]
+        EcdsaP256(EcdsaP256),
+        #[prost(message, tag = "3")]
+        DecafRdsa(DecafRdsa),
     }
 }
 impl ::prost::Name for PreAuthorization {
@@ -218,6 +264,76 @@ impl ::prost::Name for ConfirmAddressResponse {
         ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
     }
 }
+/// A request forwarded to an external signer process.
+///
+/// External signers receive these length-delimited over a Unix socket or their standard input,
+/// and reply with an `ExternalSignerResponse`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExternalSignerRequest {
+    #[prost(oneof = "external_signer_request::Request", tags = "1, 2, 3, 4, 5")]
+    pub request: ::core::option::Option<external_signer_request::Request>,
+}
+/// Nested message and enum types in `ExternalSignerRequest`.
+pub mod external_signer_request {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Request {
+        #[prost(message, tag = "1")]
+        Authorize(super::AuthorizeRequest),
+        #[prost(message, tag = "2")]
+        AuthorizeValidatorDefinition(super::AuthorizeValidatorDefinitionRequest),
+        #[prost(message, tag = "3")]
+        AuthorizeValidatorVote(super::AuthorizeValidatorVoteRequest),
+        #[prost(message, tag = "4")]
+        ExportFullViewingKey(super::ExportFullViewingKeyRequest),
+        #[prost(message, tag = "5")]
+        ConfirmAddress(super::ConfirmAddressRequest),
+    }
+}
+impl ::prost::Name for ExternalSignerRequest {
+    const NAME: &'static str = "ExternalSignerRequest";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+/// An external signer's reply to an `ExternalSignerRequest`.
+///
+/// Either the response matching the request is set, or `error` is.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExternalSignerResponse {
+    #[prost(oneof = "external_signer_response::Response", tags = "1, 2, 3, 4, 5, 6")]
+    pub response: ::core::option::Option<external_signer_response::Response>,
+}
+/// Nested message and enum types in `ExternalSignerResponse`.
+pub mod external_signer_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Authorize(super::AuthorizeResponse),
+        #[prost(message, tag = "2")]
+        AuthorizeValidatorDefinition(super::AuthorizeValidatorDefinitionResponse),
+        #[prost(message, tag = "3")]
+        AuthorizeValidatorVote(super::AuthorizeValidatorVoteResponse),
+        #[prost(message, tag = "4")]
+        ExportFullViewingKey(super::ExportFullViewingKeyResponse),
+        /// A description of why the request failed.
+        #[prost(string, tag = "5")]
+        Error(::prost::alloc::string::String),
+        #[prost(message, tag = "6")]
+        ConfirmAddress(super::ConfirmAddressResponse),
+    }
+}
+impl ::prost::Name for ExternalSignerResponse {
+    const NAME: &'static str = "ExternalSignerResponse";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod custody_service_client {
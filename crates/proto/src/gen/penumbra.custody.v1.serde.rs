@@ -985,6 +985,331 @@ impl<'de> serde::Deserialize<'de> for ExportFullViewingKeyResponse {
         deserializer.deserialize_struct("penumbra.custody.v1.ExportFullViewingKeyResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ExternalSignerRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.request.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.ExternalSignerRequest", len)?;
+        if let Some(v) = self.request.as_ref() {
+            match v {
+                external_signer_request::Request::Authorize(v) => {
+                    struct_ser.serialize_field("authorize", v)?;
+                }
+                external_signer_request::Request::AuthorizeValidatorDefinition(v) => {
+                    struct_ser.serialize_field("authorizeValidatorDefinition", v)?;
+                }
+                external_signer_request::Request::AuthorizeValidatorVote(v) => {
+                    struct_ser.serialize_field("authorizeValidatorVote", v)?;
+                }
+                external_signer_request::Request::ExportFullViewingKey(v) => {
+                    struct_ser.serialize_field("exportFullViewingKey", v)?;
+                }
+                external_signer_request::Request::ConfirmAddress(v) => {
+                    struct_ser.serialize_field("confirmAddress", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExternalSignerRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "authorize",
+            "authorize_validator_definition",
+            "authorizeValidatorDefinition",
+            "authorize_validator_vote",
+            "authorizeValidatorVote",
+            "export_full_viewing_key",
+            "exportFullViewingKey",
+            "confirm_address",
+            "confirmAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Authorize,
+            AuthorizeValidatorDefinition,
+            AuthorizeValidatorVote,
+            ExportFullViewingKey,
+            ConfirmAddress,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "authorize" => Ok(GeneratedField::Authorize),
+                            "authorizeValidatorDefinition" | "authorize_validator_definition" => Ok(GeneratedField::AuthorizeValidatorDefinition),
+                            "authorizeValidatorVote" | "authorize_validator_vote" => Ok(GeneratedField::AuthorizeValidatorVote),
+                            "exportFullViewingKey" | "export_full_viewing_key" => Ok(GeneratedField::ExportFullViewingKey),
+                            "confirmAddress" | "confirm_address" => Ok(GeneratedField::ConfirmAddress),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExternalSignerRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.ExternalSignerRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExternalSignerRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut request__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Authorize => {
+                            if request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authorize"));
+                            }
+                            request__ = map_.next_value::<::std::option::Option<_>>()?.map(external_signer_request::Request::Authorize)
+;
+                        }
+                        GeneratedField::AuthorizeValidatorDefinition => {
+                            if request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authorizeValidatorDefinition"));
+                            }
+                            request__ = map_.next_value::<::std::option::Option<_>>()?.map(external_signer_request::Request::AuthorizeValidatorDefinition)
+;
+                        }
+                        GeneratedField::AuthorizeValidatorVote => {
+                            if request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authorizeValidatorVote"));
+                            }
+                            request__ = map_.next_value::<::std::option::Option<_>>()?.map(external_signer_request::Request::AuthorizeValidatorVote)
+;
+                        }
+                        GeneratedField::ExportFullViewingKey => {
+                            if request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("exportFullViewingKey"));
+                            }
+                            request__ = map_.next_value::<::std::option::Option<_>>()?.map(external_signer_request::Request::ExportFullViewingKey)
+;
+                        }
+                        GeneratedField::ConfirmAddress => {
+                            if request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("confirmAddress"));
+                            }
+                            request__ = map_.next_value::<::std::option::Option<_>>()?.map(external_signer_request::Request::ConfirmAddress)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExternalSignerRequest {
+                    request: request__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.ExternalSignerRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ExternalSignerResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.response.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.ExternalSignerResponse", len)?;
+        if let Some(v) = self.response.as_ref() {
+            match v {
+                external_signer_response::Response::Authorize(v) => {
+                    struct_ser.serialize_field("authorize", v)?;
+                }
+                external_signer_response::Response::AuthorizeValidatorDefinition(v) => {
+                    struct_ser.serialize_field("authorizeValidatorDefinition", v)?;
+                }
+                external_signer_response::Response::AuthorizeValidatorVote(v) => {
+                    struct_ser.serialize_field("authorizeValidatorVote", v)?;
+                }
+                external_signer_response::Response::ExportFullViewingKey(v) => {
+                    struct_ser.serialize_field("exportFullViewingKey", v)?;
+                }
+                external_signer_response::Response::Error(v) => {
+                    struct_ser.serialize_field("error", v)?;
+                }
+                external_signer_response::Response::ConfirmAddress(v) => {
+                    struct_ser.serialize_field("confirmAddress", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExternalSignerResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "authorize",
+            "authorize_validator_definition",
+            "authorizeValidatorDefinition",
+            "authorize_validator_vote",
+            "authorizeValidatorVote",
+            "export_full_viewing_key",
+            "exportFullViewingKey",
+            "error",
+            "confirm_address",
+            "confirmAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Authorize,
+            AuthorizeValidatorDefinition,
+            AuthorizeValidatorVote,
+            ExportFullViewingKey,
+            Error,
+            ConfirmAddress,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "authorize" => Ok(GeneratedField::Authorize),
+                            "authorizeValidatorDefinition" | "authorize_validator_definition" => Ok(GeneratedField::AuthorizeValidatorDefinition),
+                            "authorizeValidatorVote" | "authorize_validator_vote" => Ok(GeneratedField::AuthorizeValidatorVote),
+                            "exportFullViewingKey" | "export_full_viewing_key" => Ok(GeneratedField::ExportFullViewingKey),
+                            "error" => Ok(GeneratedField::Error),
+                            "confirmAddress" | "confirm_address" => Ok(GeneratedField::ConfirmAddress),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExternalSignerResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.ExternalSignerResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExternalSignerResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut response__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Authorize => {
+                            if response__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authorize"));
+                            }
+                            response__ = map_.next_value::<::std::option::Option<_>>()?.map(external_signer_response::Response::Authorize)
+;
+                        }
+                        GeneratedField::AuthorizeValidatorDefinition => {
+                            if response__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authorizeValidatorDefinition"));
+                            }
+                            response__ = map_.next_value::<::std::option::Option<_>>()?.map(external_signer_response::Response::AuthorizeValidatorDefinition)
+;
+                        }
+                        GeneratedField::AuthorizeValidatorVote => {
+                            if response__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authorizeValidatorVote"));
+                            }
+                            response__ = map_.next_value::<::std::option::Option<_>>()?.map(external_signer_response::Response::AuthorizeValidatorVote)
+;
+                        }
+                        GeneratedField::ExportFullViewingKey => {
+                            if response__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("exportFullViewingKey"));
+                            }
+                            response__ = map_.next_value::<::std::option::Option<_>>()?.map(external_signer_response::Response::ExportFullViewingKey)
+;
+                        }
+                        GeneratedField::Error => {
+                            if response__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("error"));
+                            }
+                            response__ = map_.next_value::<::std::option::Option<_>>()?.map(external_signer_response::Response::Error)
+;
+                        }
+                        GeneratedField::ConfirmAddress => {
+                            if response__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("confirmAddress"));
+                            }
+                            response__ = map_.next_value::<::std::option::Option<_>>()?.map(external_signer_response::Response::ConfirmAddress)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExternalSignerResponse {
+                    response: response__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.ExternalSignerResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for PreAuthorization {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1002,6 +1327,12 @@ impl serde::Serialize for PreAuthorization {
                 pre_authorization::PreAuthorization::Ed25519(v) => {
                     struct_ser.serialize_field("ed25519", v)?;
                 }
+                pre_authorization::PreAuthorization::EcdsaP256(v) => {
+                    struct_ser.serialize_field("ecdsaP256", v)?;
+                }
+                pre_authorization::PreAuthorization::DecafRdsa(v) => {
+                    struct_ser.serialize_field("decafRdsa", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -1015,11 +1346,17 @@ impl<'de> serde::Deserialize<'de> for PreAuthorization {
     {
         const FIELDS: &[&str] = &[
             "ed25519",
+            "ecdsa_p256",
+            "ecdsaP256",
+            "decaf_rdsa",
+            "decafRdsa",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Ed25519,
+            EcdsaP256,
+            DecafRdsa,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1043,6 +1380,8 @@ impl<'de> serde::Deserialize<'de> for PreAuthorization {
                     {
                         match value {
                             "ed25519" => Ok(GeneratedField::Ed25519),
+                            "ecdsaP256" | "ecdsa_p256" => Ok(GeneratedField::EcdsaP256),
+                            "decafRdsa" | "decaf_rdsa" => Ok(GeneratedField::DecafRdsa),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1070,6 +1409,20 @@ impl<'de> serde::Deserialize<'de> for PreAuthorization {
                                 return Err(serde::de::Error::duplicate_field("ed25519"));
                             }
                             pre_authorization__ = map_.next_value::<::std::option::Option<_>>()?.map(pre_authorization::PreAuthorization::Ed25519)
+;
+                        }
+                        GeneratedField::EcdsaP256 => {
+                            if pre_authorization__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ecdsaP256"));
+                            }
+                            pre_authorization__ = map_.next_value::<::std::option::Option<_>>()?.map(pre_authorization::PreAuthorization::EcdsaP256)
+;
+                        }
+                        GeneratedField::DecafRdsa => {
+                            if pre_authorization__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("decafRdsa"));
+                            }
+                            pre_authorization__ = map_.next_value::<::std::option::Option<_>>()?.map(pre_authorization::PreAuthorization::DecafRdsa)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -1085,6 +1438,242 @@ impl<'de> serde::Deserialize<'de> for PreAuthorization {
         deserializer.deserialize_struct("penumbra.custody.v1.PreAuthorization", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for pre_authorization::DecafRdsa {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.vk.is_empty() {
+            len += 1;
+        }
+        if !self.sig.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.PreAuthorization.DecafRdsa", len)?;
+        if !self.vk.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("vk", pbjson::private::base64::encode(&self.vk).as_str())?;
+        }
+        if !self.sig.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sig", pbjson::private::base64::encode(&self.sig).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for pre_authorization::DecafRdsa {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vk",
+            "sig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Vk,
+            Sig,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vk" => Ok(GeneratedField::Vk),
+                            "sig" => Ok(GeneratedField::Sig),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = pre_authorization::DecafRdsa;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.PreAuthorization.DecafRdsa")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<pre_authorization::DecafRdsa, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vk__ = None;
+                let mut sig__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Vk => {
+                            if vk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vk"));
+                            }
+                            vk__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Sig => {
+                            if sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sig"));
+                            }
+                            sig__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(pre_authorization::DecafRdsa {
+                    vk: vk__.unwrap_or_default(),
+                    sig: sig__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.PreAuthorization.DecafRdsa", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for pre_authorization::EcdsaP256 {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.vk.is_empty() {
+            len += 1;
+        }
+        if !self.sig.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.PreAuthorization.EcdsaP256", len)?;
+        if !self.vk.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("vk", pbjson::private::base64::encode(&self.vk).as_str())?;
+        }
+        if !self.sig.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sig", pbjson::private::base64::encode(&self.sig).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for pre_authorization::EcdsaP256 {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vk",
+            "sig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Vk,
+            Sig,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vk" => Ok(GeneratedField::Vk),
+                            "sig" => Ok(GeneratedField::Sig),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = pre_authorization::EcdsaP256;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.PreAuthorization.EcdsaP256")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<pre_authorization::EcdsaP256, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vk__ = None;
+                let mut sig__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Vk => {
+                            if vk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vk"));
+                            }
+                            vk__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Sig => {
+                            if sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sig"));
+                            }
+                            sig__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(pre_authorization::EcdsaP256 {
+                    vk: vk__.unwrap_or_default(),
+                    sig: sig__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.PreAuthorization.EcdsaP256", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for pre_authorization::Ed25519 {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
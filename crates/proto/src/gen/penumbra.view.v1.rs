@@ -1694,6 +1694,169 @@ impl ::prost::Name for UnbondingTokensByAddressIndexResponse {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
+/// Requests the acquisition lots known to the view service.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaxLotsRequest {
+    /// If present, return only lots of this asset.
+    #[prost(message, optional, tag = "1")]
+    pub asset_id: ::core::option::Option<super::super::core::asset::v1::AssetId>,
+}
+impl ::prost::Name for TaxLotsRequest {
+    const NAME: &'static str = "TaxLotsRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaxLotsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub lot: ::core::option::Option<TaxLot>,
+}
+impl ::prost::Name for TaxLotsResponse {
+    const NAME: &'static str = "TaxLotsResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// A quantity of an asset acquired by the wallet at a particular height.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaxLot {
+    /// The asset and amount acquired.
+    #[prost(message, optional, tag = "1")]
+    pub value: ::core::option::Option<super::super::core::asset::v1::Value>,
+    /// The height at which the lot was acquired.
+    #[prost(uint64, tag = "2")]
+    pub height_acquired: u64,
+    /// The value given up to acquire the lot, if known.
+    ///
+    /// This is derived from the `BatchSwapOutputData` of the swap that produced
+    /// the lot, and is absent for lots received from elsewhere.
+    #[prost(message, optional, tag = "3")]
+    pub cost: ::core::option::Option<super::super::core::asset::v1::Value>,
+    /// The source of the lot.
+    #[prost(message, optional, tag = "4")]
+    pub source: ::core::option::Option<
+        super::super::core::component::sct::v1::CommitmentSource,
+    >,
+}
+impl ::prost::Name for TaxLot {
+    const NAME: &'static str = "TaxLot";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// Requests the disposals known to the view service.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaxDisposalsRequest {
+    /// If present, return only disposals of this asset.
+    #[prost(message, optional, tag = "1")]
+    pub asset_id: ::core::option::Option<super::super::core::asset::v1::AssetId>,
+}
+impl ::prost::Name for TaxDisposalsRequest {
+    const NAME: &'static str = "TaxDisposalsRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaxDisposalsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub disposal: ::core::option::Option<TaxDisposal>,
+}
+impl ::prost::Name for TaxDisposalsResponse {
+    const NAME: &'static str = "TaxDisposalsResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// A quantity of an asset that left the wallet at a particular height.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaxDisposal {
+    /// The asset and amount disposed of.
+    #[prost(message, optional, tag = "1")]
+    pub value: ::core::option::Option<super::super::core::asset::v1::Value>,
+    /// The height at which the disposal happened.
+    #[prost(uint64, tag = "2")]
+    pub height_disposed: u64,
+    /// The value received in exchange, if known.
+    ///
+    /// This is derived from the `BatchSwapOutputData` of the swap that consumed
+    /// the disposed value, and is absent for transfers and fees.
+    #[prost(message, optional, tag = "3")]
+    pub proceeds: ::core::option::Option<super::super::core::asset::v1::Value>,
+    /// The source of the disposal.
+    #[prost(message, optional, tag = "4")]
+    pub source: ::core::option::Option<
+        super::super::core::component::sct::v1::CommitmentSource,
+    >,
+}
+impl ::prost::Name for TaxDisposal {
+    const NAME: &'static str = "TaxDisposal";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// Requests the transactions the view service is tracking as pending.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingTransactionsRequest {}
+impl ::prost::Name for PendingTransactionsRequest {
+    const NAME: &'static str = "PendingTransactionsRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingTransactionsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub pending_transaction: ::core::option::Option<PendingTransaction>,
+}
+impl ::prost::Name for PendingTransactionsResponse {
+    const NAME: &'static str = "PendingTransactionsResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// A transaction that was broadcast by the view service but has not yet been
+/// detected on chain.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingTransaction {
+    /// The transaction that was submitted.
+    #[prost(message, optional, tag = "1")]
+    pub transaction: ::core::option::Option<
+        super::super::core::transaction::v1::Transaction,
+    >,
+    /// The sync height of the view service when the transaction was submitted.
+    #[prost(uint64, tag = "2")]
+    pub submitted_height: u64,
+    /// The sync height of the view service when the transaction was last
+    /// (re)broadcast.
+    #[prost(uint64, tag = "3")]
+    pub last_broadcast_height: u64,
+}
+impl ::prost::Name for PendingTransaction {
+    const NAME: &'static str = "PendingTransaction";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod view_service_client {
@@ -2629,6 +2792,93 @@ pub mod view_service_client {
                 .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Auctions"));
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Gets the acquisition lots recorded for the user's holdings, used for
+        /// cost-basis accounting.
+        pub async fn tax_lots(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TaxLotsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::TaxLotsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/TaxLots",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "TaxLots"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Gets the disposals recorded for the user's holdings, used for cost-basis
+        /// accounting.
+        pub async fn tax_disposals(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TaxDisposalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::TaxDisposalsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/TaxDisposals",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "TaxDisposals"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Gets the transactions submitted through the view service that have not
+        /// yet been included in a block, expired, or been superseded by a conflicting
+        /// transaction.
+        pub async fn pending_transactions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PendingTransactionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::PendingTransactionsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/PendingTransactions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1.ViewService",
+                        "PendingTransactions",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -2988,6 +3238,49 @@ pub mod view_service_server {
             &self,
             request: tonic::Request<super::AuctionsRequest>,
         ) -> std::result::Result<tonic::Response<Self::AuctionsStream>, tonic::Status>;
+        /// Server streaming response type for the TaxLots method.
+        type TaxLotsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::TaxLotsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Gets the acquisition lots recorded for the user's holdings, used for
+        /// cost-basis accounting.
+        async fn tax_lots(
+            &self,
+            request: tonic::Request<super::TaxLotsRequest>,
+        ) -> std::result::Result<tonic::Response<Self::TaxLotsStream>, tonic::Status>;
+        /// Server streaming response type for the TaxDisposals method.
+        type TaxDisposalsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::TaxDisposalsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Gets the disposals recorded for the user's holdings, used for cost-basis
+        /// accounting.
+        async fn tax_disposals(
+            &self,
+            request: tonic::Request<super::TaxDisposalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::TaxDisposalsStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the PendingTransactions method.
+        type PendingTransactionsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::PendingTransactionsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Gets the transactions submitted through the view service that have not
+        /// yet been included in a block, expired, or been superseded by a conflicting
+        /// transaction.
+        async fn pending_transactions(
+            &self,
+            request: tonic::Request<super::PendingTransactionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::PendingTransactionsStream>,
+            tonic::Status,
+        >;
     }
     /// The view RPC is used by a view client, who wants to do some
     /// transaction-related actions, to request data from a view service, which is
@@ -4450,6 +4743,147 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1.ViewService/TaxLots" => {
+                    #[allow(non_camel_case_types)]
+                    struct TaxLotsSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::ServerStreamingService<super::TaxLotsRequest>
+                    for TaxLotsSvc<T> {
+                        type Response = super::TaxLotsResponse;
+                        type ResponseStream = T::TaxLotsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TaxLotsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::tax_lots(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = TaxLotsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/TaxDisposals" => {
+                    #[allow(non_camel_case_types)]
+                    struct TaxDisposalsSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::ServerStreamingService<super::TaxDisposalsRequest>
+                    for TaxDisposalsSvc<T> {
+                        type Response = super::TaxDisposalsResponse;
+                        type ResponseStream = T::TaxDisposalsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TaxDisposalsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::tax_disposals(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = TaxDisposalsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/PendingTransactions" => {
+                    #[allow(non_camel_case_types)]
+                    struct PendingTransactionsSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::ServerStreamingService<super::PendingTransactionsRequest>
+                    for PendingTransactionsSvc<T> {
+                        type Response = super::PendingTransactionsResponse;
+                        type ResponseStream = T::PendingTransactionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PendingTransactionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::pending_transactions(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PendingTransactionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -30,7 +30,10 @@ use penumbra_transaction::{
     txhash::TransactionId, AuthorizationData, Transaction, TransactionPlan, WitnessData,
 };
 
-use crate::{SpendableNoteRecord, StatusStreamResponse, SwapRecord, TransactionInfo};
+use crate::{
+    cost_basis::{TaxDisposal, TaxLot},
+    PendingTransaction, SpendableNoteRecord, StatusStreamResponse, SwapRecord, TransactionInfo,
+};
 
 pub(crate) type BroadcastStatusStream = Pin<
     Box<dyn Future<Output = Result<Streaming<BroadcastTransactionResponse>, anyhow::Error>> + Send>,
@@ -338,6 +341,23 @@ pub trait ViewClient {
     fn unclaimed_swaps(
         &mut self,
     ) -> Pin<Box<dyn Future<Output = Result<Vec<SwapRecord>>> + Send + 'static>>;
+
+    /// Queries for the acquisition lots of the wallet, optionally filtered by asset.
+    fn tax_lots(
+        &mut self,
+        asset_id: Option<asset::Id>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<TaxLot>>> + Send + 'static>>;
+
+    /// Queries for the disposals of the wallet, optionally filtered by asset.
+    fn tax_disposals(
+        &mut self,
+        asset_id: Option<asset::Id>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<TaxDisposal>>> + Send + 'static>>;
+
+    /// Queries for the transactions that were broadcast, but have not yet been detected on chain.
+    fn pending_transactions(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<PendingTransaction>>> + Send + 'static>>;
 }
 
 // We need to tell `async_trait` not to add a `Send` bound to the boxed
@@ -1050,4 +1070,85 @@ where
         }
         .boxed()
     }
+
+    fn tax_lots(
+        &mut self,
+        asset_id: Option<asset::Id>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<TaxLot>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let request = tonic::Request::new(pb::TaxLotsRequest {
+                asset_id: asset_id.map(Into::into),
+            });
+            let lots: Vec<pb::TaxLotsResponse> = ViewServiceClient::tax_lots(&mut self2, request)
+                .await?
+                .into_inner()
+                .try_collect()
+                .await?;
+
+            lots.into_iter()
+                .map(|rsp| {
+                    rsp.lot
+                        .ok_or_else(|| anyhow::anyhow!("empty TaxLotsResponse message"))?
+                        .try_into()
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
+    fn tax_disposals(
+        &mut self,
+        asset_id: Option<asset::Id>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<TaxDisposal>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let request = tonic::Request::new(pb::TaxDisposalsRequest {
+                asset_id: asset_id.map(Into::into),
+            });
+            let disposals: Vec<pb::TaxDisposalsResponse> =
+                ViewServiceClient::tax_disposals(&mut self2, request)
+                    .await?
+                    .into_inner()
+                    .try_collect()
+                    .await?;
+
+            disposals
+                .into_iter()
+                .map(|rsp| {
+                    rsp.disposal
+                        .ok_or_else(|| anyhow::anyhow!("empty TaxDisposalsResponse message"))?
+                        .try_into()
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
+    fn pending_transactions(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<PendingTransaction>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let request = tonic::Request::new(pb::PendingTransactionsRequest {});
+            let pending: Vec<pb::PendingTransactionsResponse> =
+                ViewServiceClient::pending_transactions(&mut self2, request)
+                    .await?
+                    .into_inner()
+                    .try_collect()
+                    .await?;
+
+            pending
+                .into_iter()
+                .map(|rsp| {
+                    rsp.pending_transaction
+                        .ok_or_else(|| {
+                            anyhow::anyhow!("empty PendingTransactionsResponse message")
+                        })?
+                        .try_into()
+                })
+                .collect()
+        }
+        .boxed()
+    }
 }
@@ -0,0 +1,713 @@
+//! Cost-basis tracking for the assets held by the wallet.
+//!
+//! The view service records a [`TaxLot`] whenever value enters the wallet and a
+//! [`TaxDisposal`] whenever value leaves it. When the value was exchanged
+//! through the DEX, the lot's cost and the disposal's proceeds are derived from
+//! the [`BatchSwapOutputData`] of the batch the swap was executed in; otherwise
+//! they are left unknown, and can later be estimated from market prices with
+//! [`apply_market_prices`].
+//!
+//! Lots and disposals are then matched against one another by
+//! [`realized_gains`], according to an [`AccountingMethod`].
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use penumbra_asset::{asset, Value};
+use penumbra_dex::{swap::SwapPlaintext, BatchSwapOutputData, CandlestickData};
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_proto::{view::v1 as pb, DomainType};
+use penumbra_sct::CommitmentSource;
+use serde::{Deserialize, Serialize};
+
+/// A quantity of an asset acquired by the wallet at a particular height.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(try_from = "pb::TaxLot", into = "pb::TaxLot")]
+pub struct TaxLot {
+    /// The asset and amount acquired.
+    pub value: Value,
+    /// The height at which the lot was acquired.
+    pub height_acquired: u64,
+    /// The value given up to acquire the lot, if known.
+    pub cost: Option<Value>,
+    /// Where the lot came from.
+    pub source: CommitmentSource,
+}
+
+/// A quantity of an asset that left the wallet at a particular height.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(try_from = "pb::TaxDisposal", into = "pb::TaxDisposal")]
+pub struct TaxDisposal {
+    /// The asset and amount disposed of.
+    pub value: Value,
+    /// The height at which the disposal happened.
+    pub height_disposed: u64,
+    /// The value received in exchange, if known.
+    pub proceeds: Option<Value>,
+    /// Where the disposal happened.
+    pub source: CommitmentSource,
+}
+
+/// The order in which open lots are consumed by a disposal.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AccountingMethod {
+    /// First in, first out: the oldest lots are disposed of first.
+    #[default]
+    Fifo,
+    /// Last in, first out: the newest lots are disposed of first.
+    Lifo,
+    /// Highest in, first out: the lots with the highest unit cost are disposed of first.
+    Hifo,
+}
+
+impl FromStr for AccountingMethod {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "fifo" => Ok(AccountingMethod::Fifo),
+            "lifo" => Ok(AccountingMethod::Lifo),
+            "hifo" => Ok(AccountingMethod::Hifo),
+            other => {
+                anyhow::bail!("unknown accounting method {other}, expected fifo, lifo or hifo")
+            }
+        }
+    }
+}
+
+impl Display for AccountingMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccountingMethod::Fifo => write!(f, "fifo"),
+            AccountingMethod::Lifo => write!(f, "lifo"),
+            AccountingMethod::Hifo => write!(f, "hifo"),
+        }
+    }
+}
+
+/// The part of a disposal that was matched against (part of) a single lot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RealizedGain {
+    /// The asset that was disposed of.
+    pub asset_id: asset::Id,
+    /// The amount of the asset covered by this match.
+    pub amount: Amount,
+    /// The height at which the matched lot was acquired, or `None` if the
+    /// disposal exceeded the lots known to the wallet.
+    pub height_acquired: Option<u64>,
+    /// The height at which the disposal happened.
+    pub height_disposed: u64,
+    /// The cost of the matched amount, denominated in the numeraire, if known.
+    pub basis: Option<Amount>,
+    /// The proceeds of the matched amount, denominated in the numeraire, if known.
+    pub proceeds: Option<Amount>,
+}
+
+impl RealizedGain {
+    /// The gain (or, if negative, the loss) realized by this match, if both the
+    /// basis and the proceeds are known.
+    pub fn gain(&self) -> Option<i128> {
+        let basis = i128::try_from(u128::from(self.basis?)).ok()?;
+        let proceeds = i128::try_from(u128::from(self.proceeds?)).ok()?;
+        proceeds.checked_sub(basis)
+    }
+}
+
+/// Computes the realized gains of the given disposals, matching them against
+/// the given lots according to `method`.
+///
+/// Costs and proceeds are only counted when they are denominated in the
+/// `numeraire`; otherwise the corresponding field of the [`RealizedGain`] is
+/// `None`. Disposals of the numeraire itself never realize a gain, and are
+/// skipped.
+pub fn realized_gains(
+    lots: impl IntoIterator<Item = TaxLot>,
+    disposals: impl IntoIterator<Item = TaxDisposal>,
+    method: AccountingMethod,
+    numeraire: asset::Id,
+) -> anyhow::Result<Vec<RealizedGain>> {
+    let mut open: BTreeMap<asset::Id, Vec<OpenLot>> = BTreeMap::new();
+    for (index, lot) in lots.into_iter().enumerate() {
+        open.entry(lot.value.asset_id).or_default().push(OpenLot {
+            index,
+            remaining: lot.value.amount,
+            lot,
+        });
+    }
+
+    let mut disposals: Vec<TaxDisposal> = disposals.into_iter().collect();
+    disposals.sort_by_key(|disposal| disposal.height_disposed);
+
+    let mut gains = Vec::new();
+    for disposal in disposals {
+        let asset_id = disposal.value.asset_id;
+        if asset_id == numeraire {
+            continue;
+        }
+
+        let proceeds = disposal
+            .proceeds
+            .filter(|proceeds| proceeds.asset_id == numeraire)
+            .map(|proceeds| proceeds.amount);
+
+        let lots = open.entry(asset_id).or_default();
+        let mut remaining = disposal.value.amount;
+        while remaining > Amount::zero() {
+            let Some(next) = select_lot(lots, disposal.height_disposed, method, numeraire)? else {
+                break;
+            };
+            let lot = &mut lots[next];
+            let matched = remaining.min(lot.remaining);
+            let basis = lot
+                .lot
+                .cost
+                .filter(|cost| cost.asset_id == numeraire)
+                .map(|cost| pro_rata(cost.amount, matched, lot.lot.value.amount))
+                .transpose()?;
+
+            gains.push(RealizedGain {
+                asset_id,
+                amount: matched,
+                height_acquired: Some(lot.lot.height_acquired),
+                height_disposed: disposal.height_disposed,
+                basis,
+                proceeds: proceeds
+                    .map(|proceeds| pro_rata(proceeds, matched, disposal.value.amount))
+                    .transpose()?,
+            });
+
+            lot.remaining -= matched;
+            remaining -= matched;
+            if lot.remaining == Amount::zero() {
+                lots.remove(next);
+            }
+        }
+
+        // The wallet disposed of more than it knows it acquired, e.g. because
+        // the lots predate cost-basis tracking. Report the excess with an
+        // unknown basis rather than dropping it.
+        if remaining > Amount::zero() {
+            gains.push(RealizedGain {
+                asset_id,
+                amount: remaining,
+                height_acquired: None,
+                height_disposed: disposal.height_disposed,
+                basis: None,
+                proceeds: proceeds
+                    .map(|proceeds| pro_rata(proceeds, remaining, disposal.value.amount))
+                    .transpose()?,
+            });
+        }
+    }
+
+    Ok(gains)
+}
+
+/// Computes the lots and disposals produced by a single transaction.
+///
+/// `spent` are the values of the wallet's notes spent by the transaction,
+/// `created` the values of the wallet's notes it created (excluding swap claim
+/// outputs, which were already accounted for when the swap executed), and
+/// `swaps` the wallet's swaps it contained, along with the output data of the
+/// batch they were executed in.
+///
+/// The value exchanged through each swap becomes a disposal of the input asset
+/// and a lot of the output asset, priced by the batch clearing price. Any other
+/// net outflow of an asset (a transfer or a fee) becomes a disposal without
+/// proceeds, and any other net inflow a lot without a cost.
+pub fn transaction_lots(
+    height: u64,
+    source: CommitmentSource,
+    spent: impl IntoIterator<Item = Value>,
+    created: impl IntoIterator<Item = Value>,
+    swaps: impl IntoIterator<Item = (SwapPlaintext, BatchSwapOutputData)>,
+) -> (Vec<TaxLot>, Vec<TaxDisposal>) {
+    let mut lots = Vec::new();
+    let mut disposals = Vec::new();
+
+    // The net value flowing out of the wallet, per asset, as (outflow, inflow).
+    let mut flows: BTreeMap<asset::Id, (Amount, Amount)> = BTreeMap::new();
+    for value in spent {
+        flows.entry(value.asset_id).or_default().0 += value.amount;
+    }
+    for value in created {
+        flows.entry(value.asset_id).or_default().1 += value.amount;
+    }
+
+    for (swap, output_data) in swaps {
+        let pair = swap.trading_pair;
+        // Split the swap into its two directions, so that each input is
+        // matched with the output it was traded for.
+        let (unfilled_1, output_2) = output_data.pro_rata_outputs((swap.delta_1_i, Amount::zero()));
+        let (output_1, unfilled_2) = output_data.pro_rata_outputs((Amount::zero(), swap.delta_2_i));
+
+        for (input, unfilled, output) in [
+            (
+                Value {
+                    asset_id: pair.asset_1(),
+                    amount: swap.delta_1_i,
+                },
+                unfilled_1,
+                Value {
+                    asset_id: pair.asset_2(),
+                    amount: output_2,
+                },
+            ),
+            (
+                Value {
+                    asset_id: pair.asset_2(),
+                    amount: swap.delta_2_i,
+                },
+                unfilled_2,
+                Value {
+                    asset_id: pair.asset_1(),
+                    amount: output_1,
+                },
+            ),
+        ] {
+            if input.amount == Amount::zero() {
+                continue;
+            }
+
+            // The swap input is not a transfer: account for it here instead.
+            // The unfilled part is returned by the swap claim, so it is
+            // neither disposed of nor acquired.
+            flows.entry(input.asset_id).or_default().1 += input.amount;
+
+            let filled = Value {
+                asset_id: input.asset_id,
+                amount: input.amount.saturating_sub(&unfilled),
+            };
+            if filled.amount == Amount::zero() {
+                continue;
+            }
+
+            disposals.push(TaxDisposal {
+                value: filled,
+                height_disposed: height,
+                proceeds: Some(output),
+                source: source.clone(),
+            });
+            if output.amount > Amount::zero() {
+                lots.push(TaxLot {
+                    value: output,
+                    height_acquired: height,
+                    cost: Some(filled),
+                    source: source.clone(),
+                });
+            }
+        }
+    }
+
+    for (asset_id, (outflow, inflow)) in flows {
+        if outflow > inflow {
+            disposals.push(TaxDisposal {
+                value: Value {
+                    asset_id,
+                    amount: outflow - inflow,
+                },
+                height_disposed: height,
+                proceeds: None,
+                source: source.clone(),
+            });
+        } else if inflow > outflow {
+            lots.push(TaxLot {
+                value: Value {
+                    asset_id,
+                    amount: inflow - outflow,
+                },
+                height_acquired: height,
+                cost: None,
+                source: source.clone(),
+            });
+        }
+    }
+
+    (lots, disposals)
+}
+
+/// The price of an asset at `height`, from the per-block candlesticks of a
+/// trading pair selling it: the closing price of the last candlestick at or
+/// before `height`.
+pub fn candlestick_price(candlesticks: &[CandlestickData], height: u64) -> Option<f64> {
+    candlesticks
+        .iter()
+        .filter(|candlestick| candlestick.height <= height)
+        .max_by_key(|candlestick| candlestick.height)
+        .map(|candlestick| candlestick.close)
+}
+
+/// Estimates the costs of `lots` and the proceeds of `disposals` that are not
+/// denominated in the `numeraire`, from market prices.
+///
+/// `price` returns the price of one unit of an asset, in units of the
+/// numeraire, at a given height, if it is known. A cost (or proceeds) paid in
+/// another asset is converted into the numeraire at the price of that asset;
+/// an unknown one is estimated at the price of the lot (or disposal) itself.
+/// Values that can't be priced are left as they are.
+pub fn apply_market_prices(
+    lots: &mut [TaxLot],
+    disposals: &mut [TaxDisposal],
+    numeraire: asset::Id,
+    price: impl Fn(asset::Id, u64) -> Option<f64>,
+) {
+    let convert = |value: Value, height: u64| -> Option<Value> {
+        if value.asset_id == numeraire {
+            return Some(value);
+        }
+        let price = U128x128::try_from(price(value.asset_id, height)?).ok()?;
+        let amount = U128x128::from(value.amount)
+            .checked_mul(&price)
+            .ok()?
+            .round_down()
+            .try_into()
+            .ok()?;
+        Some(Value {
+            asset_id: numeraire,
+            amount,
+        })
+    };
+
+    for lot in lots {
+        let paid = lot.cost.unwrap_or(lot.value);
+        if let Some(cost) = convert(paid, lot.height_acquired) {
+            lot.cost = Some(cost);
+        }
+    }
+    for disposal in disposals {
+        let received = disposal.proceeds.unwrap_or(disposal.value);
+        if let Some(proceeds) = convert(received, disposal.height_disposed) {
+            disposal.proceeds = Some(proceeds);
+        }
+    }
+}
+
+struct OpenLot {
+    /// The position of the lot in the input, used to break ties deterministically.
+    index: usize,
+    remaining: Amount,
+    lot: TaxLot,
+}
+
+/// Picks the index of the next lot to consume, among those acquired no later
+/// than `height`.
+fn select_lot(
+    lots: &[OpenLot],
+    height: u64,
+    method: AccountingMethod,
+    numeraire: asset::Id,
+) -> anyhow::Result<Option<usize>> {
+    let mut best: Option<(usize, Option<U128x128>)> = None;
+    for (i, candidate) in lots.iter().enumerate() {
+        if candidate.lot.height_acquired > height {
+            continue;
+        }
+        let unit_cost = match method {
+            AccountingMethod::Hifo => unit_cost(&candidate.lot, numeraire)?,
+            _ => None,
+        };
+        let Some((best_index, best_cost)) = best else {
+            best = Some((i, unit_cost));
+            continue;
+        };
+        let current = &lots[best_index];
+        let key = (candidate.lot.height_acquired, candidate.index);
+        let best_key = (current.lot.height_acquired, current.index);
+        let better = match method {
+            AccountingMethod::Fifo => key < best_key,
+            AccountingMethod::Lifo => key > best_key,
+            // Lots with an unknown cost sort below every lot with a known one;
+            // ties are broken in acquisition order.
+            AccountingMethod::Hifo => {
+                unit_cost > best_cost || (unit_cost == best_cost && key < best_key)
+            }
+        };
+        if better {
+            best = Some((i, unit_cost));
+        }
+    }
+    Ok(best.map(|(i, _)| i))
+}
+
+/// The cost of a single unit of the lot, denominated in the numeraire, if known.
+fn unit_cost(lot: &TaxLot, numeraire: asset::Id) -> anyhow::Result<Option<U128x128>> {
+    match lot.cost {
+        Some(cost) if cost.asset_id == numeraire && lot.value.amount > Amount::zero() => {
+            Ok(Some(U128x128::ratio(cost.amount, lot.value.amount)?))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Computes `total * part / whole`, rounding down.
+fn pro_rata(total: Amount, part: Amount, whole: Amount) -> anyhow::Result<Amount> {
+    if part == whole {
+        return Ok(total);
+    }
+    let fraction = U128x128::ratio(part, whole)?;
+    let share = fraction.checked_mul(&U128x128::from(total))?.round_down();
+    Ok(share.try_into()?)
+}
+
+impl DomainType for TaxLot {
+    type Proto = pb::TaxLot;
+}
+
+impl From<TaxLot> for pb::TaxLot {
+    fn from(msg: TaxLot) -> Self {
+        pb::TaxLot {
+            value: Some(msg.value.into()),
+            height_acquired: msg.height_acquired,
+            cost: msg.cost.map(Into::into),
+            source: Some(msg.source.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::TaxLot> for TaxLot {
+    type Error = anyhow::Error;
+    fn try_from(value: pb::TaxLot) -> Result<Self, Self::Error> {
+        Ok(Self {
+            value: value
+                .value
+                .ok_or_else(|| anyhow::anyhow!("missing value"))?
+                .try_into()?,
+            height_acquired: value.height_acquired,
+            cost: value.cost.map(TryInto::try_into).transpose()?,
+            source: value
+                .source
+                .ok_or_else(|| anyhow::anyhow!("missing source"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl DomainType for TaxDisposal {
+    type Proto = pb::TaxDisposal;
+}
+
+impl From<TaxDisposal> for pb::TaxDisposal {
+    fn from(msg: TaxDisposal) -> Self {
+        pb::TaxDisposal {
+            value: Some(msg.value.into()),
+            height_disposed: msg.height_disposed,
+            proceeds: msg.proceeds.map(Into::into),
+            source: Some(msg.source.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::TaxDisposal> for TaxDisposal {
+    type Error = anyhow::Error;
+    fn try_from(value: pb::TaxDisposal) -> Result<Self, Self::Error> {
+        Ok(Self {
+            value: value
+                .value
+                .ok_or_else(|| anyhow::anyhow!("missing value"))?
+                .try_into()?,
+            height_disposed: value.height_disposed,
+            proceeds: value.proceeds.map(TryInto::try_into).transpose()?,
+            source: value
+                .source
+                .ok_or_else(|| anyhow::anyhow!("missing source"))?
+                .try_into()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn asset(n: u64) -> asset::Id {
+        asset::Id(decaf377::Fq::from(n))
+    }
+
+    fn value(asset_id: asset::Id, amount: u64) -> Value {
+        Value {
+            asset_id,
+            amount: amount.into(),
+        }
+    }
+
+    fn lot(
+        asset_id: asset::Id,
+        amount: u64,
+        height: u64,
+        cost: u64,
+        numeraire: asset::Id,
+    ) -> TaxLot {
+        TaxLot {
+            value: value(asset_id, amount),
+            height_acquired: height,
+            cost: Some(value(numeraire, cost)),
+            source: CommitmentSource::Genesis,
+        }
+    }
+
+    fn disposal(
+        asset_id: asset::Id,
+        amount: u64,
+        height: u64,
+        proceeds: u64,
+        numeraire: asset::Id,
+    ) -> TaxDisposal {
+        TaxDisposal {
+            value: value(asset_id, amount),
+            height_disposed: height,
+            proceeds: Some(value(numeraire, proceeds)),
+            source: CommitmentSource::Genesis,
+        }
+    }
+
+    fn basis_of(gains: &[RealizedGain]) -> Vec<(Option<u64>, u128)> {
+        gains
+            .iter()
+            .map(|gain| {
+                (
+                    gain.height_acquired,
+                    gain.basis.map(u128::from).unwrap_or(0),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn accounting_methods_choose_different_lots() -> anyhow::Result<()> {
+        let (gm, usd) = (asset(1), asset(2));
+        let lots = vec![
+            lot(gm, 10, 1, 100, usd),
+            lot(gm, 10, 2, 300, usd),
+            lot(gm, 10, 3, 200, usd),
+        ];
+        let disposals = vec![disposal(gm, 10, 4, 250, usd)];
+
+        let fifo = realized_gains(lots.clone(), disposals.clone(), AccountingMethod::Fifo, usd)?;
+        assert_eq!(basis_of(&fifo), vec![(Some(1), 100)]);
+        assert_eq!(fifo[0].gain(), Some(150));
+
+        let lifo = realized_gains(lots.clone(), disposals.clone(), AccountingMethod::Lifo, usd)?;
+        assert_eq!(basis_of(&lifo), vec![(Some(3), 200)]);
+
+        let hifo = realized_gains(lots, disposals, AccountingMethod::Hifo, usd)?;
+        assert_eq!(basis_of(&hifo), vec![(Some(2), 300)]);
+        assert_eq!(hifo[0].gain(), Some(-50));
+
+        Ok(())
+    }
+
+    #[test]
+    fn disposals_split_across_lots_and_report_excess() -> anyhow::Result<()> {
+        let (gm, usd) = (asset(1), asset(2));
+        let lots = vec![lot(gm, 10, 1, 100, usd), lot(gm, 10, 5, 200, usd)];
+        // The second lot is acquired after the disposal, so it can't be used.
+        let disposals = vec![disposal(gm, 15, 3, 300, usd)];
+
+        let gains = realized_gains(lots, disposals, AccountingMethod::Fifo, usd)?;
+        assert_eq!(basis_of(&gains), vec![(Some(1), 100), (None, 0)]);
+        assert_eq!(gains[0].proceeds, Some(200u64.into()));
+        assert_eq!(gains[1].amount, 5u64.into());
+        assert_eq!(gains[1].basis, None);
+
+        Ok(())
+    }
+
+    fn candlestick(height: u64, close: f64) -> CandlestickData {
+        CandlestickData {
+            height,
+            open: close,
+            close,
+            high: close,
+            low: close,
+            direct_volume: 0.0,
+            swap_volume: 0.0,
+            start_height: 0,
+            bucket: 0,
+        }
+    }
+
+    #[test]
+    fn candlestick_price_uses_last_close_at_or_before_height() {
+        let candlesticks = vec![
+            candlestick(10, 2.0),
+            candlestick(20, 3.0),
+            candlestick(30, 4.0),
+        ];
+        assert_eq!(candlestick_price(&candlesticks, 5), None);
+        assert_eq!(candlestick_price(&candlesticks, 10), Some(2.0));
+        assert_eq!(candlestick_price(&candlesticks, 25), Some(3.0));
+        assert_eq!(candlestick_price(&candlesticks, 100), Some(4.0));
+    }
+
+    #[test]
+    fn market_prices_fill_unknown_and_foreign_costs() {
+        let (gm, gn, usd) = (asset(1), asset(2), asset(3));
+        let mut lots = vec![
+            // Received for free: priced at the market price of gm.
+            TaxLot {
+                value: value(gm, 10),
+                height_acquired: 1,
+                cost: None,
+                source: CommitmentSource::Genesis,
+            },
+            // Bought with gn: priced at the market price of gn.
+            TaxLot {
+                value: value(gm, 10),
+                height_acquired: 2,
+                cost: Some(value(gn, 4)),
+                source: CommitmentSource::Genesis,
+            },
+            // Already priced in the numeraire.
+            lot(gm, 10, 3, 7, usd),
+        ];
+        let mut disposals = vec![TaxDisposal {
+            value: value(gn, 6),
+            height_disposed: 4,
+            proceeds: None,
+            source: CommitmentSource::Genesis,
+        }];
+
+        apply_market_prices(&mut lots, &mut disposals, usd, |asset_id, _| {
+            if asset_id == gm {
+                Some(1.5)
+            } else if asset_id == gn {
+                Some(2.5)
+            } else {
+                None
+            }
+        });
+
+        assert_eq!(
+            lots.iter().map(|lot| lot.cost).collect::<Vec<_>>(),
+            vec![
+                Some(value(usd, 15)),
+                Some(value(usd, 10)),
+                Some(value(usd, 7))
+            ]
+        );
+        assert_eq!(disposals[0].proceeds, Some(value(usd, 15)));
+    }
+
+    #[test]
+    fn transfers_net_against_change() {
+        let (gm, usd) = (asset(1), asset(2));
+        let (lots, disposals) = transaction_lots(
+            7,
+            CommitmentSource::Genesis,
+            [value(gm, 100), value(usd, 5)],
+            [value(gm, 70)],
+            [],
+        );
+        assert!(lots.is_empty());
+        assert_eq!(
+            disposals
+                .iter()
+                .map(|disposal| (disposal.value, disposal.proceeds))
+                .collect::<Vec<_>>(),
+            vec![(value(gm, 30), None), (value(usd, 5), None)]
+        );
+    }
+}
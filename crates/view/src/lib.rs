@@ -14,8 +14,10 @@
 // Requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 mod client;
+pub mod cost_basis;
 mod metrics;
 mod note_record;
+mod pending_transaction;
 mod planner;
 mod service;
 mod status;
@@ -28,6 +30,7 @@ mod worker;
 pub use crate::client::ViewClient;
 pub use crate::metrics::register_metrics;
 pub use crate::note_record::SpendableNoteRecord;
+pub use crate::pending_transaction::{PendingTransaction, PENDING_TRANSACTION_TIMEOUT};
 pub use crate::planner::Planner;
 pub use crate::service::ViewServer;
 pub use crate::status::StatusStreamResponse;
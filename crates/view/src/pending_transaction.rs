@@ -0,0 +1,111 @@
+use penumbra_proto::{view::v1 as pb, DomainType};
+use penumbra_transaction::{txhash::TransactionId, Transaction};
+
+/// The number of blocks after which the view service gives up on a pending
+/// transaction that never expires, releasing the notes it spends.
+pub const PENDING_TRANSACTION_TIMEOUT: u64 = 720;
+
+/// A transaction that was broadcast by the view service, but has not yet been
+/// detected on chain.
+#[derive(Debug, Clone)]
+pub struct PendingTransaction {
+    /// The transaction that was submitted.
+    pub transaction: Transaction,
+    /// The sync height of the view service when the transaction was submitted.
+    pub submitted_height: u64,
+    /// The sync height of the view service when the transaction was last (re)broadcast.
+    pub last_broadcast_height: u64,
+}
+
+impl PendingTransaction {
+    /// The hash of the pending transaction.
+    pub fn id(&self) -> TransactionId {
+        self.transaction.id()
+    }
+
+    /// The height after which the transaction can no longer be included, or
+    /// `0` if the transaction never expires.
+    pub fn expiry_height(&self) -> u64 {
+        self.transaction.transaction_parameters().expiry_height
+    }
+
+    /// The height at which the view service stops tracking the transaction:
+    /// its expiry height or, if it never expires,
+    /// [`PENDING_TRANSACTION_TIMEOUT`] blocks after it was submitted.
+    pub fn timeout_height(&self) -> u64 {
+        match self.expiry_height() {
+            0 => self.submitted_height + PENDING_TRANSACTION_TIMEOUT,
+            expiry_height => expiry_height,
+        }
+    }
+
+    /// Returns `true` if the view service should stop tracking the transaction
+    /// at `height`, either because it can no longer be included, or because it
+    /// never expires and has been pending for too long.
+    pub fn is_expired(&self, height: u64) -> bool {
+        self.timeout_height() <= height
+    }
+}
+
+impl DomainType for PendingTransaction {
+    type Proto = pb::PendingTransaction;
+}
+
+impl From<PendingTransaction> for pb::PendingTransaction {
+    fn from(msg: PendingTransaction) -> Self {
+        pb::PendingTransaction {
+            transaction: Some(msg.transaction.into()),
+            submitted_height: msg.submitted_height,
+            last_broadcast_height: msg.last_broadcast_height,
+        }
+    }
+}
+
+impl TryFrom<pb::PendingTransaction> for PendingTransaction {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::PendingTransaction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            transaction: value
+                .transaction
+                .ok_or_else(|| anyhow::anyhow!("missing transaction"))?
+                .try_into()?,
+            submitted_height: value.submitted_height,
+            last_broadcast_height: value.last_broadcast_height,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending(expiry_height: u64, submitted_height: u64) -> PendingTransaction {
+        let mut transaction = Transaction::default();
+        transaction
+            .transaction_body
+            .transaction_parameters
+            .expiry_height = expiry_height;
+        PendingTransaction {
+            transaction,
+            submitted_height,
+            last_broadcast_height: submitted_height,
+        }
+    }
+
+    #[test]
+    fn expires_at_expiry_height() {
+        let pending = pending(100, 10);
+        assert!(!pending.is_expired(99));
+        assert!(pending.is_expired(100));
+    }
+
+    #[test]
+    fn times_out_without_expiry_height() {
+        let pending = pending(0, 10);
+        let timeout_height = 10 + PENDING_TRANSACTION_TIMEOUT;
+        assert_eq!(pending.timeout_height(), timeout_height);
+        assert!(!pending.is_expired(timeout_height - 1));
+        assert!(pending.is_expired(timeout_height));
+    }
+}
@@ -22,7 +22,7 @@ use penumbra_auction::auction::{
 use penumbra_community_pool::CommunityPoolDeposit;
 use penumbra_dex::{
     lp::action::{PositionClose, PositionOpen},
-    lp::plan::PositionWithdrawPlan,
+    lp::plan::{PositionRewardClaimPlan, PositionWithdrawPlan},
     lp::position::{self, Position},
     lp::Reserves,
     swap::SwapPlaintext,
@@ -206,6 +206,25 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Claim the liquidity rewards accrued by a withdrawn position.
+    ///
+    /// The `sequence` is that of the withdrawn position NFT produced by the
+    /// claim, one more than that of the NFT it consumes.
+    #[instrument(skip(self))]
+    pub fn position_reward_claim(
+        &mut self,
+        position_id: position::Id,
+        sequence: u64,
+        rewards: Amount,
+    ) -> &mut Self {
+        self.action_list.push(PositionRewardClaimPlan {
+            position_id,
+            sequence,
+            rewards,
+        });
+        self
+    }
+
     /// Schedule a Dutch auction.
     #[instrument(skip(self))]
     pub fn dutch_auction_schedule(&mut self, description: DutchAuctionDescription) -> &mut Self {
@@ -254,6 +273,10 @@ impl<R: RngCore + CryptoRng> Planner<R> {
     }
 
     /// Perform a swap based on input notes in the transaction.
+    ///
+    /// If `min_output` is set and the swap's pro rata share of its batch's
+    /// output would be less than `min_output`, that direction of the batch is
+    /// not executed, and the swap claim returns the input.
     #[instrument(skip(self))]
     pub fn swap(
         &mut self,
@@ -261,6 +284,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         into_asset: asset::Id,
         swap_claim_fee: Fee,
         claim_address: Address,
+        min_output: Option<Amount>,
     ) -> Result<&mut Self> {
         // Determine the canonical order for the assets being swapped.
         // This will determine whether the input amount is assigned to delta_1 or delta_2.
@@ -291,7 +315,8 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             claim_address,
         );
 
-        let swap = SwapPlan::new(&mut self.rng, swap_plaintext);
+        let mut swap = SwapPlan::new(&mut self.rng, swap_plaintext);
+        swap.min_output = min_output;
         self.action_list.push(swap);
 
         Ok(self)
@@ -199,47 +199,34 @@ impl ViewServer {
                         ))
                     })?;
 
-                // 2. Broadcast the transaction to the network.
-                // Note that "synchronous" here means "wait for the tx to be accepted by
-                // the fullnode", not "wait for the tx to be included on chain.
-                let mut fullnode_client = self2.tendermint_proxy_client().await
-                            .map_err(|e| {
-                                tonic::Status::unavailable(format!(
-                                    "couldn't connect to fullnode: {:#?}",
-                                    e
-                                ))
-                            })?
-                        ;
-                let node_rsp = fullnode_client
-                    .broadcast_tx_sync(BroadcastTxSyncRequest {
-                        params: transaction.encode_to_vec(),
-                        req_id: OsRng.gen(),
-                    })
+                // 2. Track the transaction until it is detected on chain, so that the worker
+                // can rebroadcast it, and the planner won't reuse the notes it spends. This
+                // must happen before broadcasting: otherwise, the worker could detect the
+                // transaction on chain before it is tracked, and it would stay pending.
+                let submitted_height = self2.storage
+                    .last_sync_height()
                     .await
-                    .map_err(|e| {
-                        tonic::Status::unavailable(format!(
-                            "error broadcasting tx: {:#?}",
-                            e
-                        ))
-                    })?
-                    .into_inner();
-                tracing::info!(?node_rsp);
-                match node_rsp.code {
-                    0 => Ok(()),
-                    _ => Err(tonic::Status::new(
-                        tonic::Code::Internal,
-                        format!(
-                            "Error submitting transaction: code {}, log: {}",
-                            node_rsp.code,
-                            node_rsp.log,
-                        ),
-                    )),
-                }?;
+                    .map_err(|e| tonic::Status::internal(format!("error querying storage: {:#}", e)))?
+                    .unwrap_or(0);
+                self2.storage
+                    .record_pending_transaction(&transaction, submitted_height)
+                    .await
+                    .map_err(|e| tonic::Status::internal(format!("error recording pending transaction: {:#}", e)))?;
+
+                // 3. Broadcast the transaction to the network.
+                if let Err(e) = self2.broadcast_tx_sync(&transaction).await {
+                    // The transaction was rejected, so it will never be detected.
+                    self2.storage
+                        .forget_pending_transaction(transaction.id())
+                        .await
+                        .map_err(|e| tonic::Status::internal(format!("error forgetting pending transaction: {:#}", e)))?;
+                    Err::<(), _>(e)?;
+                }
 
                 // The transaction was submitted so we provide a status update
                 yield BroadcastTransactionResponse{ status: Some(BroadcastStatus::BroadcastSuccess(BroadcastSuccess{id:Some(transaction.id().into())}))};
 
-                // 3. Optionally wait for the transaction to be detected by the view service.
+                // 4. Optionally wait for the transaction to be detected by the view service.
                 let nullifier = if await_detection {
                     // This needs to be only *spend* nullifiers because the nullifier detection
                     // is broken for swaps, https://github.com/penumbra-zone/penumbra/issues/1749
@@ -301,6 +288,36 @@ impl ViewServer {
             .map_err(anyhow::Error::from)
     }
 
+    /// Submits a transaction to the fullnode, waiting until it is accepted into
+    /// its mempool.
+    ///
+    /// Note that "synchronous" here means "wait for the tx to be accepted by the
+    /// fullnode", not "wait for the tx to be included on chain".
+    async fn broadcast_tx_sync(&self, transaction: &Transaction) -> Result<(), tonic::Status> {
+        let mut fullnode_client = self.tendermint_proxy_client().await.map_err(|e| {
+            tonic::Status::unavailable(format!("couldn't connect to fullnode: {:#?}", e))
+        })?;
+        let node_rsp = fullnode_client
+            .broadcast_tx_sync(BroadcastTxSyncRequest {
+                params: transaction.encode_to_vec(),
+                req_id: OsRng.gen(),
+            })
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error broadcasting tx: {:#?}", e)))?
+            .into_inner();
+        tracing::info!(?node_rsp);
+        match node_rsp.code {
+            0 => Ok(()),
+            _ => Err(tonic::Status::new(
+                tonic::Code::Internal,
+                format!(
+                    "Error submitting transaction: code {}, log: {}",
+                    node_rsp.code, node_rsp.log,
+                ),
+            )),
+        }
+    }
+
     /// Return the latest block height known by the fullnode or its peers, as
     /// well as whether the fullnode is caught up with that height.
     #[instrument(skip(self))]
@@ -414,6 +431,17 @@ impl ViewService for ViewServer {
     >;
     type AuctionsStream =
         Pin<Box<dyn futures::Stream<Item = Result<pb::AuctionsResponse, tonic::Status>> + Send>>;
+    type TaxLotsStream =
+        Pin<Box<dyn futures::Stream<Item = Result<pb::TaxLotsResponse, tonic::Status>> + Send>>;
+    type TaxDisposalsStream = Pin<
+        Box<dyn futures::Stream<Item = Result<pb::TaxDisposalsResponse, tonic::Status>> + Send>,
+    >;
+    type PendingTransactionsStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<pb::PendingTransactionsResponse, tonic::Status>>
+                + Send,
+        >,
+    >;
 
     #[instrument(skip_all, level = "trace")]
     async fn auctions(
@@ -585,7 +613,7 @@ impl ViewService for ViewServer {
                 })?;
 
             planner
-                .swap(value, target_asset, fee, claim_address)
+                .swap(value, target_asset, fee, claim_address, None)
                 .map_err(|e| {
                     tonic::Status::invalid_argument(format!("Could not plan swap: {e:#}"))
                 })?;
@@ -1820,4 +1848,104 @@ impl ViewService for ViewServer {
     ) -> Result<tonic::Response<Self::UnbondingTokensByAddressIndexStream>, tonic::Status> {
         unimplemented!("unbonding_tokens_by_address_index currently only implemented on web")
     }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn tax_lots(
+        &self,
+        request: tonic::Request<pb::TaxLotsRequest>,
+    ) -> Result<tonic::Response<Self::TaxLotsStream>, tonic::Status> {
+        self.check_worker().await?;
+
+        let asset_id = request
+            .into_inner()
+            .asset_id
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(format!("{e:#}")))?;
+
+        let lots = self
+            .storage
+            .tax_lots(asset_id)
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error fetching tax lots: {e}")))?;
+
+        let stream = try_stream! {
+            for lot in lots {
+                yield pb::TaxLotsResponse {
+                    lot: Some(lot.into()),
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error getting tax lots: {e}"))
+                })
+                .boxed(),
+        ))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn tax_disposals(
+        &self,
+        request: tonic::Request<pb::TaxDisposalsRequest>,
+    ) -> Result<tonic::Response<Self::TaxDisposalsStream>, tonic::Status> {
+        self.check_worker().await?;
+
+        let asset_id = request
+            .into_inner()
+            .asset_id
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(format!("{e:#}")))?;
+
+        let disposals = self.storage.tax_disposals(asset_id).await.map_err(|e| {
+            tonic::Status::unavailable(format!("error fetching tax disposals: {e}"))
+        })?;
+
+        let stream = try_stream! {
+            for disposal in disposals {
+                yield pb::TaxDisposalsResponse {
+                    disposal: Some(disposal.into()),
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error getting tax disposals: {e}"))
+                })
+                .boxed(),
+        ))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn pending_transactions(
+        &self,
+        _request: tonic::Request<pb::PendingTransactionsRequest>,
+    ) -> Result<tonic::Response<Self::PendingTransactionsStream>, tonic::Status> {
+        self.check_worker().await?;
+
+        let pending = self.storage.pending_transactions().await.map_err(|e| {
+            tonic::Status::unavailable(format!("error fetching pending transactions: {e}"))
+        })?;
+
+        let stream = try_stream! {
+            for pending_transaction in pending {
+                yield pb::PendingTransactionsResponse {
+                    pending_transaction: Some(pending_transaction.into()),
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error getting pending transactions: {e}"))
+                })
+                .boxed(),
+        ))
+    }
 }
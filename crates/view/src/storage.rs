@@ -1,4 +1,10 @@
-use std::{collections::BTreeMap, num::NonZeroU64, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    num::NonZeroU64,
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
 
 use anyhow::{anyhow, Context};
 use camino::Utf8Path;
@@ -38,11 +44,15 @@ use penumbra_sct::{CommitmentSource, Nullifier};
 use penumbra_shielded_pool::{fmd, note, Note, Rseed};
 use penumbra_stake::{DelegationToken, IdentityKey};
 use penumbra_tct as tct;
-use penumbra_transaction::Transaction;
+use penumbra_transaction::{txhash::TransactionId, Action, Transaction};
 use sct::TreeStore;
 use tct::StateCommitment;
 
-use crate::{sync::FilteredBlock, SpendableNoteRecord, SwapRecord};
+use crate::{
+    cost_basis::{self, TaxDisposal, TaxLot},
+    sync::FilteredBlock,
+    PendingTransaction, SpendableNoteRecord, SwapRecord,
+};
 
 mod sct;
 
@@ -874,6 +884,16 @@ impl Storage {
          */
         let address_clause = "address_index".to_string();
 
+        // Unless spent notes were requested, also skip notes that are spent by a
+        // transaction we've broadcast but not yet seen on chain, so that they
+        // aren't selected twice.
+        let pending_clause = match include_spent {
+            false => {
+                "AND spendable_notes.nullifier NOT IN (SELECT nullifier FROM pending_nullifiers)"
+            }
+            true => "",
+        };
+
         // If set, stop returning notes once the total exceeds this amount.
         //
         // Ignored if `asset_id` is unset or if `include_spent` is set.
@@ -907,7 +927,8 @@ impl Storage {
                 LEFT JOIN tx ON spendable_notes.tx_hash = tx.tx_hash
                 WHERE spendable_notes.height_spent IS {spent_clause}
                 AND notes.asset_id IS {asset_clause}
-                AND spendable_notes.address_index IS {address_clause}"
+                AND spendable_notes.address_index IS {address_clause}
+                {pending_clause}"
                 ))?
                 .query_and_then((), |row| SpendableNoteRecord::try_from(row))?
             {
@@ -1234,6 +1255,317 @@ impl Storage {
         Ok(())
     }
 
+    fn record_tax_lot_inner(
+        dbtx: &r2d2_sqlite::rusqlite::Transaction<'_>,
+        lot: &TaxLot,
+    ) -> anyhow::Result<()> {
+        dbtx.execute(
+            "INSERT INTO tax_lots (asset_id, amount, height_acquired, cost_asset_id, cost_amount, source)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            (
+                lot.value.asset_id.to_bytes().to_vec(),
+                lot.value.amount.to_be_bytes().to_vec(),
+                lot.height_acquired as i64,
+                lot.cost.map(|cost| cost.asset_id.to_bytes().to_vec()),
+                lot.cost.map(|cost| cost.amount.to_be_bytes().to_vec()),
+                lot.source.encode_to_vec(),
+            ),
+        )?;
+
+        Ok(())
+    }
+
+    fn record_tax_disposal_inner(
+        dbtx: &r2d2_sqlite::rusqlite::Transaction<'_>,
+        disposal: &TaxDisposal,
+    ) -> anyhow::Result<()> {
+        dbtx.execute(
+            "INSERT INTO tax_disposals (asset_id, amount, height_disposed, proceeds_asset_id, proceeds_amount, source)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            (
+                disposal.value.asset_id.to_bytes().to_vec(),
+                disposal.value.amount.to_be_bytes().to_vec(),
+                disposal.height_disposed as i64,
+                disposal
+                    .proceeds
+                    .map(|proceeds| proceeds.asset_id.to_bytes().to_vec()),
+                disposal
+                    .proceeds
+                    .map(|proceeds| proceeds.amount.to_be_bytes().to_vec()),
+                disposal.source.encode_to_vec(),
+            ),
+        )?;
+
+        Ok(())
+    }
+
+    /// The value of the wallet's note with the given nullifier, if there is one.
+    fn spent_note_value_inner(
+        dbtx: &r2d2_sqlite::rusqlite::Transaction<'_>,
+        nullifier: &Nullifier,
+    ) -> anyhow::Result<Option<Value>> {
+        dbtx.prepare_cached(
+            "SELECT notes.asset_id, notes.amount
+            FROM notes
+            JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+            WHERE spendable_notes.nullifier = ?1",
+        )?
+        .query_and_then([nullifier.0.to_bytes().to_vec()], |row| {
+            anyhow::Ok(Value {
+                asset_id: row.get::<_, Vec<u8>>("asset_id")?[..].try_into()?,
+                amount: Amount::from_be_bytes(row.get::<_, [u8; 16]>("amount")?),
+            })
+        })?
+        .next()
+        .transpose()
+    }
+
+    /// Records the lots and disposals produced by one of the wallet's transactions.
+    ///
+    /// This must run after the notes of the block have been recorded, so that
+    /// the values of the notes spent by the transaction can be looked up.
+    fn record_transaction_lots_inner(
+        dbtx: &r2d2_sqlite::rusqlite::Transaction<'_>,
+        height: u64,
+        transaction: &Transaction,
+        filtered_block: &FilteredBlock,
+    ) -> anyhow::Result<()> {
+        let source = CommitmentSource::Transaction {
+            id: Some(transaction.id().0),
+        };
+
+        let mut spent = Vec::new();
+        for nf in transaction.spent_nullifiers() {
+            // Nullifiers of swaps we're claiming don't correspond to notes.
+            spent.extend(Storage::spent_note_value_inner(dbtx, &nf)?);
+        }
+
+        // Swap claim outputs were already accounted for when the swap executed.
+        let claim_outputs: Vec<StateCommitment> = transaction
+            .actions()
+            .filter_map(|action| match action {
+                Action::SwapClaim(claim) => Some([
+                    claim.body.output_1_commitment,
+                    claim.body.output_2_commitment,
+                ]),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+
+        let created = filtered_block
+            .new_notes
+            .values()
+            .filter(|record| record.source == source)
+            .filter(|record| !claim_outputs.contains(&record.note_commitment))
+            .map(|record| record.note.value());
+
+        let swaps = filtered_block
+            .new_swaps
+            .values()
+            .filter(|record| record.source == source)
+            .map(|record| (record.swap.clone(), record.output_data));
+
+        let (lots, disposals) =
+            cost_basis::transaction_lots(height, source.clone(), spent, created, swaps);
+        for lot in &lots {
+            Storage::record_tax_lot_inner(dbtx, lot)?;
+        }
+        for disposal in &disposals {
+            Storage::record_tax_disposal_inner(dbtx, disposal)?;
+        }
+
+        Ok(())
+    }
+
+    /// Query for the acquisition lots of the wallet, optionally filtered by asset.
+    pub async fn tax_lots(&self, asset_id: Option<asset::Id>) -> anyhow::Result<Vec<TaxLot>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT * FROM tax_lots
+                    WHERE ?1 IS NULL OR asset_id = ?1
+                    ORDER BY height_acquired",
+                )?
+                .query_and_then([asset_id.map(|id| id.to_bytes().to_vec())], |row| {
+                    let cost = match (
+                        row.get::<_, Option<Vec<u8>>>("cost_asset_id")?,
+                        row.get::<_, Option<[u8; 16]>>("cost_amount")?,
+                    ) {
+                        (Some(asset_id), Some(amount)) => Some(Value {
+                            asset_id: asset_id[..].try_into()?,
+                            amount: Amount::from_be_bytes(amount),
+                        }),
+                        _ => None,
+                    };
+                    anyhow::Ok(TaxLot {
+                        value: Value {
+                            asset_id: row.get::<_, Vec<u8>>("asset_id")?[..].try_into()?,
+                            amount: Amount::from_be_bytes(row.get::<_, [u8; 16]>("amount")?),
+                        },
+                        height_acquired: row.get("height_acquired")?,
+                        cost,
+                        source: CommitmentSource::decode(&row.get::<_, Vec<u8>>("source")?[..])?,
+                    })
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Query for the disposals of the wallet, optionally filtered by asset.
+    pub async fn tax_disposals(
+        &self,
+        asset_id: Option<asset::Id>,
+    ) -> anyhow::Result<Vec<TaxDisposal>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT * FROM tax_disposals
+                    WHERE ?1 IS NULL OR asset_id = ?1
+                    ORDER BY height_disposed",
+                )?
+                .query_and_then([asset_id.map(|id| id.to_bytes().to_vec())], |row| {
+                    let proceeds = match (
+                        row.get::<_, Option<Vec<u8>>>("proceeds_asset_id")?,
+                        row.get::<_, Option<[u8; 16]>>("proceeds_amount")?,
+                    ) {
+                        (Some(asset_id), Some(amount)) => Some(Value {
+                            asset_id: asset_id[..].try_into()?,
+                            amount: Amount::from_be_bytes(amount),
+                        }),
+                        _ => None,
+                    };
+                    anyhow::Ok(TaxDisposal {
+                        value: Value {
+                            asset_id: row.get::<_, Vec<u8>>("asset_id")?[..].try_into()?,
+                            amount: Amount::from_be_bytes(row.get::<_, [u8; 16]>("amount")?),
+                        },
+                        height_disposed: row.get("height_disposed")?,
+                        proceeds,
+                        source: CommitmentSource::decode(&row.get::<_, Vec<u8>>("source")?[..])?,
+                    })
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Record a transaction that was just broadcast, so that it can be rebroadcast until it is
+    /// detected on chain, and so that the notes it spends are not selected again in the meantime.
+    pub async fn record_pending_transaction(
+        &self,
+        transaction: &Transaction,
+        height: u64,
+    ) -> anyhow::Result<()> {
+        let tx_hash = transaction.id().0.to_vec();
+        let tx_bytes = transaction.encode_to_vec();
+        let expiry_height = transaction.transaction_parameters().expiry_height as i64;
+        let nullifiers = transaction
+            .spent_nullifiers()
+            .map(|nf| nf.to_bytes().to_vec())
+            .collect::<Vec<_>>();
+
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let dbtx = lock.transaction()?;
+
+            dbtx.execute(
+                "INSERT OR IGNORE INTO pending_transactions
+                (tx_hash, tx_bytes, expiry_height, submitted_height, last_broadcast_height)
+                VALUES (?1, ?2, ?3, ?4, ?4)",
+                (&tx_hash, &tx_bytes, expiry_height, height as i64),
+            )?;
+
+            for nullifier in nullifiers {
+                dbtx.execute(
+                    "INSERT OR REPLACE INTO pending_nullifiers (nullifier, tx_hash) VALUES (?1, ?2)",
+                    (&nullifier, &tx_hash),
+                )?;
+            }
+
+            dbtx.commit()?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    /// Query for the transactions that were broadcast but have not yet been detected on chain.
+    pub async fn pending_transactions(&self) -> anyhow::Result<Vec<PendingTransaction>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT tx_bytes, submitted_height, last_broadcast_height
+                    FROM pending_transactions
+                    ORDER BY submitted_height",
+                )?
+                .query_and_then((), |row| {
+                    anyhow::Ok(PendingTransaction {
+                        transaction: Transaction::decode(&row.get::<_, Vec<u8>>("tx_bytes")?[..])?,
+                        submitted_height: row.get("submitted_height")?,
+                        last_broadcast_height: row.get("last_broadcast_height")?,
+                    })
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Record that a pending transaction was rebroadcast at the given height.
+    pub async fn record_pending_transaction_broadcast(
+        &self,
+        id: TransactionId,
+        height: u64,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?.execute(
+                "UPDATE pending_transactions SET last_broadcast_height = ?1 WHERE tx_hash = ?2",
+                (height as i64, &id.0[..]),
+            )?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    /// Stop tracking a pending transaction, releasing the notes it spends.
+    pub async fn forget_pending_transaction(&self, id: TransactionId) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let dbtx = lock.transaction()?;
+            Storage::forget_pending_transaction_inner(&dbtx, &id.0)?;
+            dbtx.commit()?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    fn forget_pending_transaction_inner(
+        dbtx: &r2d2_sqlite::rusqlite::Transaction<'_>,
+        tx_hash: &[u8],
+    ) -> anyhow::Result<()> {
+        dbtx.execute(
+            "DELETE FROM pending_transactions WHERE tx_hash = ?1",
+            [tx_hash],
+        )?;
+        dbtx.execute(
+            "DELETE FROM pending_nullifiers WHERE tx_hash = ?1",
+            [tx_hash],
+        )?;
+        Ok(())
+    }
+
     pub async fn give_advice(&self, note: Note) -> anyhow::Result<()> {
         let pool = self.pool.clone();
         let mut lock = pool.get()?;
@@ -1543,9 +1875,35 @@ impl Storage {
                 };
             }
 
+            // Any pending transaction revealing a nullifier spent in this block was either
+            // included, or superseded by a conflicting transaction, so stop tracking it.
+            for nullifier in &filtered_block.spent_nullifiers {
+                let pending_tx_hash: Option<Vec<u8>> = dbtx
+                    .prepare_cached("SELECT tx_hash FROM pending_nullifiers WHERE nullifier = ?1")?
+                    .query_row([nullifier.to_bytes().to_vec()], |row| row.get("tx_hash"))
+                    .optional()?;
+                if let Some(pending_tx_hash) = pending_tx_hash {
+                    tracing::debug!(tx_hash = ?hex::encode(&pending_tx_hash), "pending transaction no longer pending");
+                    Storage::forget_pending_transaction_inner(&dbtx, &pending_tx_hash)?;
+                }
+            }
+
             // Update SCT table with current SCT state
             new_sct.to_writer(&mut TreeStore(&mut dbtx))?;
 
+            // Notes and nullifiers of the transactions we fetched, whose lots and
+            // disposals are derived from the transactions themselves.
+            let fetched_sources: Vec<CommitmentSource> = transactions
+                .iter()
+                .map(|transaction| CommitmentSource::Transaction {
+                    id: Some(transaction.id().0),
+                })
+                .collect();
+            let fetched_nullifiers: BTreeSet<Nullifier> = transactions
+                .iter()
+                .flat_map(|transaction| transaction.spent_nullifiers())
+                .collect();
+
             // Record all transactions
             for transaction in transactions {
                 let tx_bytes = transaction.encode_to_vec();
@@ -1557,6 +1915,8 @@ impl Storage {
 
                 tracing::debug!(tx_hash = ?hex::encode(tx_hash), "recording extended transaction");
 
+                Storage::forget_pending_transaction_inner(&dbtx, tx_hash)?;
+
                 dbtx.execute(
                     "INSERT OR IGNORE INTO tx (tx_hash, tx_bytes, block_height, return_address) VALUES (?1, ?2, ?3, ?4)",
                     (&tx_hash, &tx_bytes, tx_block_height, return_address),
@@ -1570,6 +1930,49 @@ impl Storage {
                         (&nf_bytes, &tx_hash),
                     )?;
                 }
+
+                Storage::record_transaction_lots_inner(
+                    &dbtx,
+                    filtered_block.height,
+                    &transaction,
+                    &filtered_block,
+                )?;
+            }
+
+            // Notes that didn't come from a transaction we fetched (genesis allocations,
+            // funding stream rewards, inbound transfers...) are acquired at no known cost.
+            for note_record in filtered_block.new_notes.values() {
+                if fetched_sources.contains(&note_record.source) {
+                    continue;
+                }
+                Storage::record_tax_lot_inner(
+                    &dbtx,
+                    &TaxLot {
+                        value: note_record.note.value(),
+                        height_acquired: filtered_block.height,
+                        cost: None,
+                        source: note_record.source.clone(),
+                    },
+                )?;
+            }
+
+            // Likewise, notes spent by a transaction we didn't fetch are disposed of
+            // for unknown proceeds.
+            for nullifier in &filtered_block.spent_nullifiers {
+                if fetched_nullifiers.contains(nullifier) {
+                    continue;
+                }
+                if let Some(value) = Storage::spent_note_value_inner(&dbtx, nullifier)? {
+                    Storage::record_tax_disposal_inner(
+                        &dbtx,
+                        &TaxDisposal {
+                            value,
+                            height_disposed: filtered_block.height,
+                            proceeds: None,
+                            source: CommitmentSource::Transaction { id: None },
+                        },
+                    )?;
+                }
             }
 
             // Update FMD parameters if they've changed.
@@ -1721,3 +2124,156 @@ impl Storage {
         Ok(records)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use penumbra_keys::test_keys;
+    use penumbra_proto::core::component::shielded_pool::v1::ZkSpendProof;
+    use penumbra_shielded_pool::{spend, Spend, SpendProof};
+    use penumbra_transaction::TransactionBody;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    async fn storage() -> Storage {
+        Storage::initialize(
+            None::<&str>,
+            test_keys::FULL_VIEWING_KEY.clone(),
+            AppParameters::default(),
+        )
+        .await
+        .unwrap()
+    }
+
+    fn channel() -> tonic::transport::Channel {
+        tonic::transport::Channel::from_static("http://127.0.0.1:1").connect_lazy()
+    }
+
+    fn block(height: u64) -> FilteredBlock {
+        FilteredBlock {
+            new_notes: BTreeMap::new(),
+            new_swaps: BTreeMap::new(),
+            spent_nullifiers: Vec::new(),
+            height,
+            fmd_parameters: None,
+            app_parameters_updated: false,
+            gas_prices: None,
+        }
+    }
+
+    /// Records a block at height 0 containing a single note of the test wallet,
+    /// returning the note's nullifier.
+    async fn record_note(storage: &Storage, sct: &mut tct::Tree) -> Nullifier {
+        let note = Note::generate(
+            &mut OsRng,
+            &test_keys::ADDRESS_0,
+            Value {
+                amount: 100u64.into(),
+                asset_id: asset::Id(Fq::from(1u64)),
+            },
+        );
+        let nullifier = Nullifier(Fq::from(2u64));
+        let mut block = block(0);
+        block.new_notes.insert(
+            note.commit(),
+            SpendableNoteRecord {
+                note_commitment: note.commit(),
+                note,
+                address_index: 0u32.into(),
+                nullifier,
+                height_created: 0,
+                height_spent: None,
+                position: 0u64.into(),
+                source: CommitmentSource::Genesis,
+                return_address: None,
+            },
+        );
+        storage
+            .record_block(block, Vec::new(), sct, channel())
+            .await
+            .unwrap();
+        nullifier
+    }
+
+    /// A transaction spending the note with the given nullifier.
+    fn spending_transaction(nullifier: Nullifier) -> Transaction {
+        let spend = Spend {
+            body: spend::Body {
+                balance_commitment: Value {
+                    amount: 100u64.into(),
+                    asset_id: asset::Id(Fq::from(1u64)),
+                }
+                .commit(decaf377::Fr::from(1u64)),
+                nullifier,
+                rk: *test_keys::FULL_VIEWING_KEY.spend_verification_key(),
+            },
+            auth_sig: [0u8; 64].into(),
+            proof: SpendProof::try_from(ZkSpendProof {
+                inner: vec![0u8; 192],
+            })
+            .unwrap(),
+        };
+        Transaction {
+            transaction_body: TransactionBody {
+                actions: vec![Action::Spend(spend)],
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    async fn unspent_notes(storage: &Storage) -> usize {
+        storage.notes(false, None, None, None).await.unwrap().len()
+    }
+
+    #[tokio::test]
+    async fn pending_spends_are_locked_until_detected() {
+        let storage = storage().await;
+        let mut sct = tct::Tree::new();
+        let nullifier = record_note(&storage, &mut sct).await;
+        assert_eq!(unspent_notes(&storage).await, 1);
+
+        let transaction = spending_transaction(nullifier);
+        storage
+            .record_pending_transaction(&transaction, 0)
+            .await
+            .unwrap();
+        assert_eq!(unspent_notes(&storage).await, 0);
+        assert_eq!(storage.pending_transactions().await.unwrap().len(), 1);
+
+        // The nullifier is revealed on chain: the transaction is no longer
+        // pending, and the note is spent.
+        let mut block = block(1);
+        block.spent_nullifiers.push(nullifier);
+        storage
+            .record_block(block, Vec::new(), &mut sct, channel())
+            .await
+            .unwrap();
+        assert!(storage.pending_transactions().await.unwrap().is_empty());
+        assert_eq!(unspent_notes(&storage).await, 0);
+        let notes = storage.notes(true, None, None, None).await.unwrap();
+        assert_eq!(notes[0].height_spent, Some(1));
+    }
+
+    #[tokio::test]
+    async fn forgotten_pending_spends_are_unlocked() {
+        let storage = storage().await;
+        let mut sct = tct::Tree::new();
+        let nullifier = record_note(&storage, &mut sct).await;
+
+        let transaction = spending_transaction(nullifier);
+        storage
+            .record_pending_transaction(&transaction, 0)
+            .await
+            .unwrap();
+        assert_eq!(unspent_notes(&storage).await, 0);
+
+        // The transaction expired or was rejected, so its notes can be spent again.
+        storage
+            .forget_pending_transaction(transaction.id())
+            .await
+            .unwrap();
+        assert!(storage.pending_transactions().await.unwrap().is_empty());
+        assert_eq!(unspent_notes(&storage).await, 1);
+    }
+}
@@ -138,3 +138,50 @@ CREATE TABLE auctions (
      auction_state          BIGINT NOT NULL,
      note_commitment        BLOB
 );
+
+-- Acquisition lots for cost-basis accounting: each row records value that
+-- entered the wallet, and what was given up for it when that is known.
+CREATE TABLE tax_lots (
+    asset_id                BLOB NOT NULL,
+    amount                  BLOB NOT NULL,
+    height_acquired         BIGINT NOT NULL,
+    -- null unless the lot was acquired through a swap
+    cost_asset_id           BLOB,
+    cost_amount             BLOB,
+    source                  BLOB NOT NULL
+);
+
+CREATE INDEX tax_lots_idx ON tax_lots (asset_id, height_acquired);
+
+-- Disposals for cost-basis accounting: each row records value that left the
+-- wallet, and what was received for it when that is known.
+CREATE TABLE tax_disposals (
+    asset_id                BLOB NOT NULL,
+    amount                  BLOB NOT NULL,
+    height_disposed         BIGINT NOT NULL,
+    -- null unless the value was disposed of through a swap
+    proceeds_asset_id       BLOB,
+    proceeds_amount         BLOB,
+    source                  BLOB NOT NULL
+);
+
+CREATE INDEX tax_disposals_idx ON tax_disposals (asset_id, height_disposed);
+
+-- Transactions broadcast through the view service that have not yet been
+-- detected on chain. Rows are removed once the transaction is included,
+-- expires or times out, or one of its nullifiers is spent by some other
+-- transaction.
+CREATE TABLE pending_transactions (
+    tx_hash                 BLOB PRIMARY KEY NOT NULL,
+    tx_bytes                BLOB NOT NULL,
+    expiry_height           BIGINT NOT NULL,
+    submitted_height        BIGINT NOT NULL,
+    last_broadcast_height   BIGINT NOT NULL
+);
+
+-- The nullifiers revealed by each pending transaction, so that the notes they
+-- spend are not selected again while the transaction is in flight.
+CREATE TABLE pending_nullifiers (
+    nullifier               BLOB PRIMARY KEY NOT NULL,
+    tx_hash                 BLOB NOT NULL
+);
@@ -9,24 +9,32 @@ use penumbra_auction::auction::AuctionNft;
 use penumbra_compact_block::CompactBlock;
 use penumbra_dex::lp::{position, LpNft};
 use penumbra_keys::FullViewingKey;
-use penumbra_proto::core::{
-    app::v1::{
-        query_service_client::QueryServiceClient as AppQueryServiceClient,
-        TransactionsByHeightRequest,
-    },
-    component::{
-        compact_block::v1::{
-            query_service_client::QueryServiceClient as CompactBlockQueryServiceClient,
-            CompactBlockRangeRequest,
+use penumbra_proto::{
+    core::{
+        app::v1::{
+            query_service_client::QueryServiceClient as AppQueryServiceClient,
+            TransactionsByHeightRequest,
         },
-        shielded_pool::v1::{
-            query_service_client::QueryServiceClient as ShieldedPoolQueryServiceClient,
-            AssetMetadataByIdRequest,
+        component::{
+            compact_block::v1::{
+                query_service_client::QueryServiceClient as CompactBlockQueryServiceClient,
+                CompactBlockRangeRequest,
+            },
+            shielded_pool::v1::{
+                query_service_client::QueryServiceClient as ShieldedPoolQueryServiceClient,
+                AssetMetadataByIdRequest,
+            },
         },
     },
+    util::tendermint_proxy::v1::{
+        tendermint_proxy_service_client::TendermintProxyServiceClient, BroadcastTxSyncRequest,
+    },
+    DomainType,
 };
 use penumbra_sct::{CommitmentSource, Nullifier};
 use penumbra_transaction::Transaction;
+use rand::Rng;
+use rand_core::OsRng;
 use tap::Tap;
 use tokio::sync::{watch, RwLock};
 use tonic::transport::Channel;
@@ -37,6 +45,9 @@ use crate::{
     Storage,
 };
 
+/// How often, in blocks, pending transactions are rebroadcast while they remain undetected.
+const REBROADCAST_INTERVAL: u64 = 10;
+
 pub struct Worker {
     storage: Storage,
     sct: Arc<RwLock<penumbra_tct::Tree>>,
@@ -301,6 +312,22 @@ impl Worker {
                                 // Update the position record
                                 self.storage.update_position(position_id, state).await?;
                             }
+                            penumbra_transaction::Action::PositionRewardClaim(
+                                position_reward_claim,
+                            ) => {
+                                let position_id = position_reward_claim.position_id;
+
+                                // Record the LPNFT for the new sequence number.
+                                let state = position::State::Withdrawn {
+                                    sequence: position_reward_claim.sequence,
+                                };
+                                let lp_nft = LpNft::new(position_id, state);
+                                let denom = lp_nft.denom();
+                                self.storage.record_asset(denom).await?;
+
+                                // Update the position record
+                                self.storage.update_position(position_id, state).await?;
+                            }
                             penumbra_transaction::Action::ActionDutchAuctionSchedule(
                                 schedule_da,
                             ) => {
@@ -408,6 +435,13 @@ impl Worker {
             // Release the SCT RwLock
             drop(sct_guard);
 
+            if height % REBROADCAST_INTERVAL == 0 {
+                // Failing to rebroadcast shouldn't interrupt sync; we'll try again later.
+                if let Err(error) = self.rebroadcast_pending_transactions(height).await {
+                    tracing::warn!(?error, "failed to rebroadcast pending transactions");
+                }
+            }
+
             // Check if we should stop waiting for blocks to arrive, because the view
             // services are dropped and we're supposed to shut down.
             if self.sync_height_tx.is_closed() {
@@ -418,6 +452,56 @@ impl Worker {
         Ok(())
     }
 
+    /// Resubmits any pending transactions that haven't been seen on chain, and stops tracking
+    /// those that can no longer be included because they've expired.
+    #[instrument(skip(self))]
+    async fn rebroadcast_pending_transactions(&self, height: u64) -> anyhow::Result<()> {
+        let pending = self.storage.pending_transactions().await?;
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let mut client = TendermintProxyServiceClient::new(self.channel.clone());
+
+        for pending_transaction in pending {
+            let id = pending_transaction.id();
+
+            if pending_transaction.is_expired(height) {
+                tracing::info!(
+                    %id,
+                    timeout_height = pending_transaction.timeout_height(),
+                    "pending transaction expired"
+                );
+                self.storage.forget_pending_transaction(id).await?;
+                continue;
+            }
+
+            if height < pending_transaction.last_broadcast_height + REBROADCAST_INTERVAL {
+                continue;
+            }
+
+            tracing::info!(%id, "rebroadcasting pending transaction");
+            let rsp = client
+                .broadcast_tx_sync(BroadcastTxSyncRequest {
+                    params: pending_transaction.transaction.encode_to_vec(),
+                    req_id: OsRng.gen(),
+                })
+                .await?
+                .into_inner();
+            if rsp.code != 0 {
+                // The node may already have the transaction in its mempool, so a
+                // rejection here isn't necessarily a problem.
+                tracing::debug!(%id, code = rsp.code, log = %rsp.log, "rebroadcast was not accepted");
+            }
+
+            self.storage
+                .record_pending_transaction_broadcast(id, height)
+                .await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn run(mut self) -> anyhow::Result<()> {
         loop {
             // Do a single sync run, recording any errors.
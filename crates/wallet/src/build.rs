@@ -26,6 +26,20 @@ where
         .ok_or_else(|| anyhow::anyhow!("empty AuthorizeResponse message"))?
         .try_into()?;
 
+    build_authorized_transaction(fvk, view, plan, auth_data).await
+}
+
+/// Build a transaction from a plan and authorization data that was produced separately,
+/// for instance by an offline signer.
+pub async fn build_authorized_transaction<V>(
+    fvk: &FullViewingKey,
+    view: &mut V,
+    plan: TransactionPlan,
+    auth_data: AuthorizationData,
+) -> Result<Transaction>
+where
+    V: ViewClient,
+{
     // Send a witness request to the view service to get witness data
     let witness_data = view.witness(&plan).await?;
 
@@ -1,6 +1,6 @@
 #![deny(clippy::unwrap_used)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 mod build;
-pub use build::build_transaction;
+pub use build::{build_authorized_transaction, build_transaction};
 
 pub mod plan;